@@ -0,0 +1,42 @@
+use std::path::Path;
+use std::process::Command;
+
+/// Build the web UI into `web-ui/dist` for embedding, unless the
+/// `web-ui` feature is off or `VIGIL_WEB_UI_DIST` says it was built
+/// already, as in CI
+fn main() {
+    println!("cargo:rerun-if-env-changed=VIGIL_WEB_UI_DIST");
+    if std::env::var_os("CARGO_FEATURE_WEB_UI").is_none()
+        || std::env::var_os("VIGIL_WEB_UI_DIST").is_some()
+    {
+        return;
+    }
+
+    let dir = Path::new("web-ui");
+    for watched in [
+        "src",
+        "index.html",
+        "package.json",
+        "pnpm-lock.yaml",
+        "tsconfig.json",
+        "vite.config.ts",
+    ] {
+        println!("cargo:rerun-if-changed={}", dir.join(watched).display());
+    }
+
+    for args in [&["install", "--frozen-lockfile"][..], &["run", "build"][..]] {
+        let status = Command::new("pnpm")
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .unwrap_or_else(|err| {
+                panic!(
+                    "Failed to run pnpm to build the web UI, install it or build without the web-ui feature: {}",
+                    err
+                )
+            });
+        if !status.success() {
+            panic!("pnpm {} failed building the web UI", args.join(" "));
+        }
+    }
+}
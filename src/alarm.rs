@@ -0,0 +1,831 @@
+use std::collections::{HashMap, VecDeque};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use actix::{Actor, AsyncContext, Context, Handler, Message, MessageResult, SystemService};
+use anyhow::{anyhow, Error};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::command::{
+    Acknowledgement, Alarm, AlarmKind, AlarmSource, Severity, Shelf, ShelvedCondition,
+};
+use crate::config::data_dir;
+use crate::history::DailyHistory;
+use crate::node::{AlarmMessage, NodeManager};
+
+/// How many past alarm events are kept in memory for the API
+const HISTORY_LENGTH: usize = 10000;
+/// How many daily history files are kept before the oldest are removed
+const HISTORY_DAYS: usize = 90;
+/// How often shelves are checked for having run out
+const SHELF_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A condition on a particular source, only ever one alarm at a time
+type AlarmKey = (AlarmKind, AlarmSource);
+
+/// What happened to an alarm
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlarmAction {
+    Raised,
+    Cleared,
+    Acknowledged,
+    Shelved,
+    Unshelved,
+}
+
+/// An entry in the alarm history
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct AlarmEvent {
+    pub time: DateTime<Utc>,
+    pub action: AlarmAction,
+    /// The alarm as it was after the event
+    pub alarm: Alarm,
+}
+
+/// Alarms and shelves as persisted, so they survive a restart
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase", default)]
+struct AlarmState {
+    active: Vec<Alarm>,
+    shelves: Vec<ShelvedCondition>,
+}
+
+/// Keeps every alarm of the system, from raising through to clearing
+/// and acknowledgement, and a history of them on disk
+#[derive(Debug)]
+pub struct AlarmManager {
+    /// Alarms not yet both cleared and acknowledged
+    active: HashMap<AlarmKey, Alarm>,
+    /// Conditions set aside by users, which outlive their alarms
+    shelves: HashMap<AlarmKey, Shelf>,
+    /// Most recent events, oldest first
+    history: VecDeque<AlarmEvent>,
+    /// Alarms changed since they were last published
+    changed: Vec<Alarm>,
+    /// Whether alarms or shelves changed since they were last saved
+    dirty: bool,
+    /// Where a file of events is appended each day
+    history_files: DailyHistory,
+    /// Where active alarms and shelves are persisted
+    state_path: PathBuf,
+}
+
+impl Default for AlarmManager {
+    fn default() -> Self {
+        Self::new(&data_dir())
+    }
+}
+
+impl Actor for AlarmManager {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        if let Err(err) = self.load(Utc::now()) {
+            error!("Failed to load alarms: {}", err);
+        }
+        self.publish();
+
+        info!("Alarm manager coming online");
+
+        ctx.run_interval(SHELF_INTERVAL, |act, _| {
+            act.expire_shelves(Utc::now());
+            act.publish();
+        });
+    }
+}
+
+impl actix::Supervised for AlarmManager {}
+
+impl SystemService for AlarmManager {}
+
+impl AlarmManager {
+    /// An alarm manager keeping its files in `dir`
+    fn new(dir: &Path) -> Self {
+        Self {
+            active: HashMap::new(),
+            shelves: HashMap::new(),
+            history: VecDeque::new(),
+            changed: vec![],
+            dirty: false,
+            history_files: DailyHistory::new(dir.join("alarm-history"), "alarms", HISTORY_DAYS),
+            state_path: dir.join("alarms.json"),
+        }
+    }
+
+    /// Load recent history, alarms and shelves from disk. Sources
+    /// raise their conditions afresh after a restart, so alarms left
+    /// raised are cleared, to be raised again if they persist
+    fn load(&mut self, now: DateTime<Utc>) -> Result<(), Error> {
+        for event in self.history_files.load(HISTORY_LENGTH)? {
+            self.push_history(event);
+        }
+
+        if self.state_path.exists() {
+            let file = std::fs::File::open(&self.state_path)?;
+            let state: AlarmState = serde_json::from_reader(BufReader::new(file))?;
+
+            for condition in state.shelves {
+                self.shelves
+                    .insert((condition.kind, condition.source), condition.shelf);
+            }
+            for alarm in state.active {
+                self.active
+                    .insert((alarm.kind, alarm.source.clone()), alarm);
+            }
+
+            let raised: Vec<AlarmKey> = self
+                .active
+                .iter()
+                .filter(|(_, alarm)| alarm.cleared.is_none())
+                .map(|(key, _)| key.clone())
+                .collect();
+            for key in raised {
+                self.clear(key, now);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write the active alarms and shelves to disk, replacing the
+    /// previous file
+    fn save(&self) -> Result<(), Error> {
+        if let Some(dir) = self.state_path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let state = AlarmState {
+            active: self.active.values().cloned().collect(),
+            shelves: self.list_shelves(),
+        };
+
+        let partial = self.state_path.with_extension("json.part");
+        serde_json::to_writer_pretty(std::fs::File::create(&partial)?, &state)?;
+        std::fs::rename(&partial, &self.state_path)?;
+
+        Ok(())
+    }
+
+    fn push_history(&mut self, event: AlarmEvent) {
+        if self.history.len() == HISTORY_LENGTH {
+            self.history.pop_front();
+        }
+        self.history.push_back(event);
+    }
+
+    /// Add something happening to an alarm to the history, to be
+    /// published
+    fn record(&mut self, action: AlarmAction, alarm: Alarm, now: DateTime<Utc>) {
+        let event = AlarmEvent {
+            time: now,
+            action,
+            alarm,
+        };
+
+        if let Err(err) = self.history_files.append(now, &event) {
+            error!("Failed to write alarm history: {}", err);
+        }
+
+        self.dirty = true;
+        self.changed.push(event.alarm.clone());
+        self.push_history(event);
+    }
+
+    /// Persist the alarms and tell everyone about those that changed
+    fn publish(&mut self) {
+        if self.dirty {
+            if let Err(err) = self.save() {
+                error!("Failed to save alarms: {}", err);
+            }
+            self.dirty = false;
+        }
+
+        for alarm in self.changed.drain(..) {
+            NodeManager::from_registry().do_send(AlarmMessage { alarm });
+        }
+    }
+
+    /// Drop an alarm that has cleared and been acknowledged
+    fn retire(&mut self, key: &AlarmKey) {
+        if let Some(alarm) = self.active.get(key) {
+            if alarm.cleared.is_some() && alarm.acknowledged.is_some() {
+                self.active.remove(key);
+            }
+        }
+    }
+
+    fn key_of(&self, id: Uuid) -> Result<AlarmKey, Error> {
+        self.active
+            .iter()
+            .find(|(_, alarm)| alarm.id == id)
+            .map(|(key, _)| key.clone())
+            .ok_or_else(|| anyhow!("No active alarm with id {}", id))
+    }
+
+    /// Raise an alarm, or reopen or count again the one of the same
+    /// condition
+    fn raise(&mut self, msg: RaiseAlarmMessage, now: DateTime<Utc>) -> Alarm {
+        let key = (msg.kind, msg.source);
+
+        let alarm = match self.active.get_mut(&key) {
+            Some(alarm) => {
+                if alarm.cleared.is_some() {
+                    // Back again before anyone saw it go, so it needs
+                    // seeing afresh
+                    alarm.cleared = None;
+                    alarm.acknowledged = None;
+                }
+                alarm.count += 1;
+                alarm.severity = msg.severity;
+                alarm.message = msg.message;
+                alarm.clone()
+            }
+            None => {
+                let alarm = Alarm {
+                    id: Uuid::new_v4(),
+                    kind: msg.kind,
+                    severity: msg.severity,
+                    source: key.1.clone(),
+                    message: msg.message,
+                    raised: msg.since.unwrap_or(now),
+                    cleared: None,
+                    count: 1,
+                    acknowledged: None,
+                    shelved: self.shelves.get(&key).cloned(),
+                };
+                self.active.insert(key, alarm.clone());
+                alarm
+            }
+        };
+
+        if alarm.shelved.is_none() {
+            warn!("Alarm raised ({:?}): {}", alarm.severity, alarm.message);
+        }
+
+        self.record(AlarmAction::Raised, alarm.clone(), now);
+
+        alarm
+    }
+
+    /// Clear the alarm of a condition, if it is raised
+    fn clear(&mut self, key: AlarmKey, now: DateTime<Utc>) -> Option<Alarm> {
+        let alarm = match self.active.get_mut(&key) {
+            Some(alarm) if alarm.cleared.is_none() => {
+                alarm.cleared = Some(now);
+                alarm.clone()
+            }
+            _ => return None,
+        };
+
+        info!("Alarm cleared: {}", alarm.message);
+
+        self.record(AlarmAction::Cleared, alarm.clone(), now);
+        self.retire(&key);
+
+        Some(alarm)
+    }
+
+    fn acknowledge(&mut self, id: Uuid, by: String, now: DateTime<Utc>) -> Result<Alarm, Error> {
+        let key = self.key_of(id)?;
+
+        let alarm = self.active.get_mut(&key).expect("alarm disappeared");
+        if alarm.acknowledged.is_none() {
+            alarm.acknowledged = Some(Acknowledgement { by, at: now });
+            let alarm = alarm.clone();
+            self.record(AlarmAction::Acknowledged, alarm, now);
+        }
+
+        let alarm = self.active[&key].clone();
+        self.retire(&key);
+
+        Ok(alarm)
+    }
+
+    fn shelve(
+        &mut self,
+        id: Uuid,
+        by: String,
+        duration: Option<Duration>,
+        now: DateTime<Utc>,
+    ) -> Result<Alarm, Error> {
+        let key = self.key_of(id)?;
+
+        let until = duration
+            .map(chrono::Duration::from_std)
+            .transpose()?
+            .map(|duration| now + duration);
+        let shelf = Shelf {
+            id: Uuid::new_v4(),
+            by,
+            at: now,
+            until,
+        };
+        self.shelves.insert(key.clone(), shelf.clone());
+
+        let alarm = self.active.get_mut(&key).expect("alarm disappeared");
+        alarm.shelved = Some(shelf);
+        let alarm = alarm.clone();
+        self.record(AlarmAction::Shelved, alarm.clone(), now);
+
+        Ok(alarm)
+    }
+
+    /// Bring back alarms whose shelves have run out
+    fn expire_shelves(&mut self, now: DateTime<Utc>) {
+        let expired: Vec<AlarmKey> = self
+            .shelves
+            .iter()
+            .filter(|(_, shelf)| shelf.until.map_or(false, |until| until <= now))
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for key in expired {
+            self.unshelve(&key, now);
+        }
+    }
+
+    /// Remove the shelf of a condition, bringing back its alarm.
+    /// Returns whether the condition was shelved
+    fn unshelve(&mut self, key: &AlarmKey, now: DateTime<Utc>) -> bool {
+        if self.shelves.remove(key).is_none() {
+            return false;
+        }
+        self.dirty = true;
+
+        if let Some(alarm) = self.active.get_mut(key) {
+            alarm.shelved = None;
+            let alarm = alarm.clone();
+            self.record(AlarmAction::Unshelved, alarm, now);
+        }
+
+        true
+    }
+
+    /// Remove a shelf by its id
+    fn remove_shelf(&mut self, id: Uuid, now: DateTime<Utc>) -> Result<(), Error> {
+        let key = self
+            .shelves
+            .iter()
+            .find(|(_, shelf)| shelf.id == id)
+            .map(|(key, _)| key.clone())
+            .ok_or_else(|| anyhow!("No shelf with id {}", id))?;
+
+        self.unshelve(&key, now);
+
+        Ok(())
+    }
+
+    /// Active alarms, most severe and then most recent first
+    fn list(&self) -> Vec<Alarm> {
+        let mut alarms: Vec<Alarm> = self.active.values().cloned().collect();
+        alarms.sort_by(|a, b| b.severity.cmp(&a.severity).then(b.raised.cmp(&a.raised)));
+        alarms
+    }
+
+    /// Shelved conditions, most recently shelved first
+    fn list_shelves(&self) -> Vec<ShelvedCondition> {
+        let mut shelves: Vec<ShelvedCondition> = self
+            .shelves
+            .iter()
+            .map(|((kind, source), shelf)| ShelvedCondition {
+                kind: *kind,
+                source: source.clone(),
+                shelf: shelf.clone(),
+            })
+            .collect();
+        shelves.sort_by(|a, b| b.shelf.at.cmp(&a.shelf.at));
+        shelves
+    }
+}
+
+/// Raise an alarm, or note another occurrence of one already raised.
+/// Sources only send this when their condition starts
+#[derive(Debug)]
+pub struct RaiseAlarmMessage {
+    pub kind: AlarmKind,
+    pub severity: Severity,
+    pub source: AlarmSource,
+    pub message: String,
+    /// When the condition started, defaults to now
+    pub since: Option<DateTime<Utc>>,
+}
+
+impl Message for RaiseAlarmMessage {
+    type Result = ();
+}
+
+impl Handler<RaiseAlarmMessage> for AlarmManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: RaiseAlarmMessage, _ctx: &mut Context<Self>) {
+        self.raise(msg, Utc::now());
+        self.publish();
+    }
+}
+
+/// Clear the alarm of a condition that has ended
+#[derive(Debug)]
+pub struct ClearAlarmMessage {
+    pub kind: AlarmKind,
+    pub source: AlarmSource,
+}
+
+impl Message for ClearAlarmMessage {
+    type Result = ();
+}
+
+impl Handler<ClearAlarmMessage> for AlarmManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: ClearAlarmMessage, _ctx: &mut Context<Self>) {
+        self.clear((msg.kind, msg.source), Utc::now());
+        self.publish();
+    }
+}
+
+/// Clear every alarm of a device, as it is no longer being watched
+#[derive(Debug)]
+pub struct ClearDeviceAlarmsMessage {
+    pub device_id: Uuid,
+    /// Only clear alarms of these kinds
+    pub kinds: Vec<AlarmKind>,
+}
+
+impl Message for ClearDeviceAlarmsMessage {
+    type Result = ();
+}
+
+impl Handler<ClearDeviceAlarmsMessage> for AlarmManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: ClearDeviceAlarmsMessage, _ctx: &mut Context<Self>) {
+        let now = Utc::now();
+        let keys: Vec<AlarmKey> = self
+            .active
+            .keys()
+            .filter(|(kind, source)| {
+                source.device_id == Some(msg.device_id) && msg.kinds.contains(kind)
+            })
+            .cloned()
+            .collect();
+
+        for key in keys {
+            self.clear(key, now);
+        }
+        self.publish();
+    }
+}
+
+/// List the active alarms
+#[derive(Debug)]
+pub struct ListAlarmsMessage;
+
+impl Message for ListAlarmsMessage {
+    type Result = Vec<Alarm>;
+}
+
+impl Handler<ListAlarmsMessage> for AlarmManager {
+    type Result = MessageResult<ListAlarmsMessage>;
+
+    fn handle(&mut self, _msg: ListAlarmsMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(self.list())
+    }
+}
+
+/// Acknowledge an alarm on behalf of a user
+#[derive(Debug)]
+pub struct AcknowledgeAlarmMessage {
+    pub id: Uuid,
+    pub by: String,
+}
+
+impl Message for AcknowledgeAlarmMessage {
+    type Result = Result<Alarm, Error>;
+}
+
+impl Handler<AcknowledgeAlarmMessage> for AlarmManager {
+    type Result = Result<Alarm, Error>;
+
+    fn handle(&mut self, msg: AcknowledgeAlarmMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        let alarm = self.acknowledge(msg.id, msg.by, Utc::now());
+        self.publish();
+
+        alarm
+    }
+}
+
+/// Set an alarm aside, for a while or until unshelved
+#[derive(Debug)]
+pub struct ShelveAlarmMessage {
+    pub id: Uuid,
+    pub by: String,
+    /// How long for, indefinitely when absent
+    pub duration: Option<Duration>,
+}
+
+impl Message for ShelveAlarmMessage {
+    type Result = Result<Alarm, Error>;
+}
+
+impl Handler<ShelveAlarmMessage> for AlarmManager {
+    type Result = Result<Alarm, Error>;
+
+    fn handle(&mut self, msg: ShelveAlarmMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        let alarm = self.shelve(msg.id, msg.by, msg.duration, Utc::now());
+        self.publish();
+
+        alarm
+    }
+}
+
+/// Bring back a shelved alarm
+#[derive(Debug)]
+pub struct UnshelveAlarmMessage {
+    pub id: Uuid,
+}
+
+impl Message for UnshelveAlarmMessage {
+    type Result = Result<Alarm, Error>;
+}
+
+impl Handler<UnshelveAlarmMessage> for AlarmManager {
+    type Result = Result<Alarm, Error>;
+
+    fn handle(&mut self, msg: UnshelveAlarmMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        let key = self.key_of(msg.id)?;
+
+        if !self.unshelve(&key, Utc::now()) {
+            return Err(anyhow!("Alarm {} is not shelved", msg.id));
+        }
+        self.publish();
+
+        Ok(self.active[&key].clone())
+    }
+}
+
+/// List the shelved conditions
+#[derive(Debug)]
+pub struct ListShelvesMessage;
+
+impl Message for ListShelvesMessage {
+    type Result = Vec<ShelvedCondition>;
+}
+
+impl Handler<ListShelvesMessage> for AlarmManager {
+    type Result = MessageResult<ListShelvesMessage>;
+
+    fn handle(&mut self, _msg: ListShelvesMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(self.list_shelves())
+    }
+}
+
+/// Remove a shelf, whether or not its condition has an active alarm,
+/// answering with the shelves left
+#[derive(Debug)]
+pub struct RemoveShelfMessage {
+    pub id: Uuid,
+}
+
+impl Message for RemoveShelfMessage {
+    type Result = Result<Vec<ShelvedCondition>, Error>;
+}
+
+impl Handler<RemoveShelfMessage> for AlarmManager {
+    type Result = Result<Vec<ShelvedCondition>, Error>;
+
+    fn handle(&mut self, msg: RemoveShelfMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        self.remove_shelf(msg.id, Utc::now())?;
+        self.publish();
+
+        Ok(self.list_shelves())
+    }
+}
+
+/// List recent alarm events, newest first
+#[derive(Debug)]
+pub struct AlarmHistoryMessage {
+    /// Only list events of alarms of this device
+    pub device_id: Option<Uuid>,
+}
+
+impl Message for AlarmHistoryMessage {
+    type Result = Vec<AlarmEvent>;
+}
+
+impl Handler<AlarmHistoryMessage> for AlarmManager {
+    type Result = MessageResult<AlarmHistoryMessage>;
+
+    fn handle(&mut self, msg: AlarmHistoryMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(
+            self.history
+                .iter()
+                .rev()
+                .filter(|event| {
+                    msg.device_id
+                        .map_or(true, |id| event.alarm.source.device_id == Some(id))
+                })
+                .cloned()
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stall(manager: &mut AlarmManager, now: DateTime<Utc>) -> Alarm {
+        manager.raise(
+            RaiseAlarmMessage {
+                kind: AlarmKind::Stall,
+                severity: Severity::Major,
+                source: source(),
+                message: "Output 1 stalled".to_string(),
+                since: None,
+            },
+            now,
+        )
+    }
+
+    fn source() -> AlarmSource {
+        AlarmSource {
+            device_id: Some(Uuid::nil()),
+            element: None,
+        }
+    }
+
+    fn actions(manager: &AlarmManager) -> Vec<AlarmAction> {
+        manager.history.iter().map(|event| event.action).collect()
+    }
+
+    #[test]
+    fn alarm_retires_once_cleared_and_acknowledged() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = AlarmManager::new(dir.path());
+        let now = Utc::now();
+
+        let alarm = stall(&mut manager, now);
+        assert_eq!(alarm.count, 1);
+        assert_eq!(manager.list().len(), 1);
+
+        let cleared = manager.clear((AlarmKind::Stall, source()), now).unwrap();
+        assert_eq!(cleared.cleared, Some(now));
+        // Still to be seen by someone
+        assert_eq!(manager.list().len(), 1);
+
+        let acknowledged = manager
+            .acknowledge(alarm.id, "operator".to_string(), now)
+            .unwrap();
+        assert_eq!(acknowledged.acknowledged.unwrap().by, "operator");
+        assert!(manager.list().is_empty());
+        assert!(manager
+            .acknowledge(alarm.id, "operator".to_string(), now)
+            .is_err());
+
+        assert_eq!(
+            actions(&manager),
+            [
+                AlarmAction::Raised,
+                AlarmAction::Cleared,
+                AlarmAction::Acknowledged
+            ]
+        );
+    }
+
+    #[test]
+    fn acknowledged_alarm_retires_when_cleared() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = AlarmManager::new(dir.path());
+        let now = Utc::now();
+
+        let alarm = stall(&mut manager, now);
+        manager
+            .acknowledge(alarm.id, "operator".to_string(), now)
+            .unwrap();
+        assert_eq!(manager.list().len(), 1);
+
+        manager.clear((AlarmKind::Stall, source()), now);
+        assert!(manager.list().is_empty());
+        // Nothing left to clear
+        assert!(manager.clear((AlarmKind::Stall, source()), now).is_none());
+    }
+
+    #[test]
+    fn cleared_alarm_reopens_when_raised_again() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = AlarmManager::new(dir.path());
+        let now = Utc::now();
+
+        let alarm = stall(&mut manager, now);
+        manager.clear((AlarmKind::Stall, source()), now);
+
+        let again = stall(&mut manager, now + chrono::Duration::seconds(1));
+        assert_eq!(again.id, alarm.id);
+        assert_eq!(again.count, 2);
+        assert_eq!(again.raised, alarm.raised);
+        assert!(again.cleared.is_none());
+        assert!(again.acknowledged.is_none());
+        assert_eq!(manager.list().len(), 1);
+    }
+
+    #[test]
+    fn shelf_expires_and_brings_alarm_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = AlarmManager::new(dir.path());
+        let now = Utc::now();
+
+        let alarm = stall(&mut manager, now);
+        let shelved = manager
+            .shelve(
+                alarm.id,
+                "operator".to_string(),
+                Some(Duration::from_secs(60)),
+                now,
+            )
+            .unwrap();
+        assert!(shelved.shelved.is_some());
+
+        manager.expire_shelves(now + chrono::Duration::seconds(30));
+        assert!(manager.list()[0].shelved.is_some());
+        assert_eq!(manager.list_shelves().len(), 1);
+
+        manager.expire_shelves(now + chrono::Duration::seconds(60));
+        assert!(manager.list()[0].shelved.is_none());
+        assert!(manager.list_shelves().is_empty());
+        assert_eq!(actions(&manager).last(), Some(&AlarmAction::Unshelved));
+    }
+
+    #[test]
+    fn shelf_outlives_its_alarm() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = AlarmManager::new(dir.path());
+        let now = Utc::now();
+
+        let alarm = stall(&mut manager, now);
+        manager
+            .shelve(alarm.id, "operator".to_string(), None, now)
+            .unwrap();
+        manager.clear((AlarmKind::Stall, source()), now);
+        manager
+            .acknowledge(alarm.id, "operator".to_string(), now)
+            .unwrap();
+        assert!(manager.list().is_empty());
+
+        // The condition comes back already shelved
+        assert!(stall(&mut manager, now).shelved.is_some());
+
+        let shelf = manager.list_shelves()[0].shelf.id;
+        manager.remove_shelf(shelf, now).unwrap();
+        assert!(manager.list_shelves().is_empty());
+        assert!(manager.list()[0].shelved.is_none());
+        assert!(manager.remove_shelf(shelf, now).is_err());
+    }
+
+    #[test]
+    fn unshelving_unshelved_alarm_changes_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = AlarmManager::new(dir.path());
+        let now = Utc::now();
+
+        stall(&mut manager, now);
+        manager.dirty = false;
+        manager.changed.clear();
+        let before = actions(&manager);
+
+        assert!(!manager.unshelve(&(AlarmKind::Stall, source()), now));
+        assert!(!manager.dirty);
+        assert!(manager.changed.is_empty());
+        assert_eq!(actions(&manager), before);
+    }
+
+    #[test]
+    fn alarms_and_shelves_survive_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let now = Utc::now();
+
+        let mut manager = AlarmManager::new(dir.path());
+        let alarm = stall(&mut manager, now);
+        manager
+            .shelve(alarm.id, "operator".to_string(), None, now)
+            .unwrap();
+        manager.save().unwrap();
+
+        let later = now + chrono::Duration::seconds(10);
+        let mut restarted = AlarmManager::new(dir.path());
+        restarted.load(later).unwrap();
+
+        let alarms = restarted.list();
+        assert_eq!(alarms.len(), 1);
+        assert_eq!(alarms[0].id, alarm.id);
+        // Left for its source to raise again
+        assert_eq!(alarms[0].cleared, Some(later));
+        assert_eq!(restarted.list_shelves().len(), 1);
+        assert_eq!(restarted.history.len(), manager.history.len() + 1);
+    }
+}
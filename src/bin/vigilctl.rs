@@ -0,0 +1,442 @@
+//! Control vigil from a shell, over the same websocket protocol as the
+//! web UI
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::{anyhow, Error};
+use chrono::Utc;
+use clap::{Parser, Subcommand};
+use futures::{SinkExt, StreamExt};
+use serde::Serialize;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+use uuid::Uuid;
+
+// Shared with the server, so the protocol cannot drift
+use vigil::command::{
+    Command, CommandResult, ControllerMessage, Device, Input, LogLevel, OutputFormat,
+    ServerMessage, VideoMode,
+};
+
+/// How long the server may take to answer a command
+const REPLY_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Parser)]
+#[command(name = "vigilctl", about = "Control vigil outputs and inputs")]
+struct Cli {
+    /// Control websocket of the server
+    #[arg(
+        long,
+        env = "VIGIL_URL",
+        default_value = "ws://localhost:3000/api/control"
+    )]
+    url: String,
+    /// Print JSON instead of tables
+    #[arg(long, global = true)]
+    json: bool,
+    #[command(subcommand)]
+    action: Action,
+}
+
+#[derive(Debug, Subcommand)]
+enum Action {
+    /// List outputs
+    Devices,
+    /// List inputs
+    Inputs,
+    /// Start an output or input
+    Start { device_id: Uuid },
+    /// Stop an output or input
+    Stop { device_id: Uuid },
+    /// Change what an output shows, as `testcard:<pattern>`,
+    /// `stream:<uri>`, `still:<location>`, `playlist` or JSON
+    Mode { device_id: Uuid, mode: String },
+    /// Print changes and events as they happen, until interrupted
+    Watch {
+        /// Only events of this output or input
+        #[arg(long)]
+        device: Option<Uuid>,
+        /// Also print log records at least this severe
+        #[arg(long, value_parser = parse_level)]
+        logs: Option<LogLevel>,
+    },
+}
+
+/// A session on the control websocket
+struct Connection {
+    socket: WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>,
+}
+
+impl Connection {
+    async fn open(url: &str) -> Result<Self, Error> {
+        let (socket, _) = tokio_tungstenite::connect_async(url)
+            .await
+            .map_err(|err| anyhow!("Failed to connect to {}: {}", url, err))?;
+
+        Ok(Self { socket })
+    }
+
+    /// Send a command, returning its identifier
+    async fn send(&mut self, command: Command) -> Result<Uuid, Error> {
+        let id = Uuid::new_v4();
+        let text = serde_json::to_string(&ControllerMessage { id, command })?;
+        self.socket.send(Message::Text(text)).await?;
+
+        Ok(id)
+    }
+
+    /// The next message from the server. Pings are answered while
+    /// reading, preview chunks are skipped
+    async fn next(&mut self) -> Result<ServerMessage, Error> {
+        while let Some(message) = self.socket.next().await {
+            match message? {
+                Message::Text(text) => return Ok(serde_json::from_str(&text)?),
+                Message::Close(_) => break,
+                _ => (),
+            }
+        }
+
+        Err(anyhow!("Server closed the connection"))
+    }
+
+    /// Wait for a message picked by `pick`, skipping the others
+    async fn wait_for<T>(
+        &mut self,
+        mut pick: impl FnMut(ServerMessage) -> Option<T>,
+    ) -> Result<T, Error> {
+        tokio::time::timeout(REPLY_TIMEOUT, async {
+            loop {
+                if let Some(picked) = pick(self.next().await?) {
+                    return Ok(picked);
+                }
+            }
+        })
+        .await
+        .map_err(|_| anyhow!("No answer from the server within {:?}", REPLY_TIMEOUT))?
+    }
+
+    /// Run a command, failing if it does
+    async fn run(&mut self, command: Command) -> Result<CommandResult, Error> {
+        let id = self.send(command).await?;
+        let result = self
+            .wait_for(|message| (message.id == Some(id)).then_some(message.result))
+            .await?;
+
+        match result {
+            CommandResult::Error(err) => Err(anyhow!(err)),
+            result => Ok(result),
+        }
+    }
+
+    /// Every output, as pushed to new sessions
+    async fn devices(&mut self) -> Result<Vec<Device>, Error> {
+        let mut devices = self
+            .wait_for(|message| match message.result {
+                CommandResult::Sync(devices) => Some(devices),
+                _ => None,
+            })
+            .await?;
+        devices.sort_by_key(|device| device.device_num);
+
+        Ok(devices)
+    }
+
+    async fn inputs(&mut self) -> Result<Vec<Input>, Error> {
+        match self.run(Command::ListInputs {}).await? {
+            CommandResult::Inputs(inputs) => Ok(inputs),
+            result => Err(anyhow!("Unexpected answer {:?}", result)),
+        }
+    }
+}
+
+/// Print rows under headers, in columns as wide as their widest cell
+fn print_table(headers: &[&str], rows: Vec<Vec<String>>) {
+    let mut widths: Vec<usize> = headers.iter().map(|header| header.len()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let line = |cells: Vec<String>| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+            .collect();
+        println!("{}", padded.join("  ").trim_end());
+    };
+
+    line(headers.iter().map(|header| header.to_string()).collect());
+    for row in rows {
+        line(row);
+    }
+}
+
+fn print_json<T: Serialize>(value: &T) -> Result<(), Error> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+/// A format the way DeckLink modes are named, such as `1080i50`
+fn format_name(format: &OutputFormat) -> String {
+    let (numerator, denominator) = format.framerate;
+    let rate = format!("{:.2}", numerator as f64 / denominator.max(1) as f64);
+
+    format!(
+        "{}x{}{}{}",
+        format.width,
+        format.height,
+        if format.interlaced { "i" } else { "p" },
+        rate.trim_end_matches('0').trim_end_matches('.')
+    )
+}
+
+/// A mode the way it is given on the command line
+fn mode_name(mode: &VideoMode) -> String {
+    match mode {
+        VideoMode::TestCard(pattern) => format!("testcard:{}", pattern),
+        VideoMode::Stream(uri) => format!("stream:{}", uri),
+        VideoMode::Still { location, .. } => format!("still:{}", location),
+        VideoMode::Playlist => String::from("playlist"),
+    }
+}
+
+fn parse_mode(mode: &str) -> Result<VideoMode, Error> {
+    if mode.starts_with('{') || mode.starts_with('"') {
+        return serde_json::from_str(mode).map_err(|err| anyhow!("Invalid mode {}: {}", mode, err));
+    }
+
+    match mode.split_once(':') {
+        Some(("testcard", pattern)) => Ok(VideoMode::TestCard(pattern.to_string())),
+        Some(("stream", uri)) => Ok(VideoMode::Stream(uri.to_string())),
+        Some(("still", location)) => Ok(VideoMode::Still {
+            location: location.to_string(),
+            tone: false,
+        }),
+        None if mode == "playlist" => Ok(VideoMode::Playlist),
+        _ => Err(anyhow!(
+            "Invalid mode {}, expected testcard:<pattern>, stream:<uri>, still:<location>, playlist or JSON",
+            mode
+        )),
+    }
+}
+
+fn parse_level(level: &str) -> Result<LogLevel, String> {
+    serde_json::from_value(serde_json::Value::String(level.to_lowercase()))
+        .map_err(|_| String::from("expected error, warn, info, debug or trace"))
+}
+
+fn print_devices(devices: &[Device]) {
+    print_table(
+        &["#", "ID", "STATE", "FORMAT", "MODE", "DROPPED", "LATE"],
+        devices
+            .iter()
+            .map(|device| {
+                vec![
+                    device.device_num.to_string(),
+                    device.id.to_string(),
+                    format!("{:?}", device.state),
+                    format_name(&device.format),
+                    mode_name(&device.mode),
+                    device.frames.dropped.to_string(),
+                    device.frames.late.to_string(),
+                ]
+            })
+            .collect(),
+    );
+}
+
+fn print_inputs(inputs: &[Input]) {
+    print_table(
+        &["ID", "LABEL", "STATE", "SIGNAL", "MODE", "ERRORS"],
+        inputs
+            .iter()
+            .map(|input| {
+                vec![
+                    input.id.to_string(),
+                    input.label.clone(),
+                    format!("{:?}", input.state),
+                    if input.status.signal { "yes" } else { "no" }.to_string(),
+                    input
+                        .status
+                        .mode
+                        .clone()
+                        .unwrap_or_else(|| String::from("--")),
+                    input.status.errors.to_string(),
+                ]
+            })
+            .collect(),
+    );
+}
+
+/// What was last seen of each output and input, to only print changes
+#[derive(Debug, Default)]
+struct Watched {
+    states: HashMap<Uuid, String>,
+}
+
+impl Watched {
+    /// Note the state of a node, returning it if it changed
+    fn changed(&mut self, id: Uuid, state: String) -> Option<String> {
+        match self.states.insert(id, state.clone()) {
+            Some(previous) if previous == state => None,
+            _ => Some(state),
+        }
+    }
+}
+
+/// Print the events of `result` worth telling about, optionally only
+/// those of `device`
+fn print_event(
+    result: CommandResult,
+    device: Option<Uuid>,
+    watched: &mut Watched,
+    json: bool,
+) -> Result<(), Error> {
+    let concerns = |id: Uuid| device.map_or(true, |device| device == id);
+    let time = Utc::now().format("%H:%M:%S%.3f");
+
+    match result {
+        CommandResult::Sync(devices) => {
+            for device in devices.into_iter().filter(|device| concerns(device.id)) {
+                let state = format!("{:?} {}", device.state, mode_name(&device.mode));
+                if let Some(state) = watched.changed(device.id, state) {
+                    if json {
+                        println!(
+                            "{}",
+                            serde_json::to_string(&CommandResult::Sync(vec![device]))?
+                        );
+                    } else {
+                        println!("{} output {} {}", time, device.device_num, state);
+                    }
+                }
+            }
+        }
+        CommandResult::Inputs(inputs) => {
+            for input in inputs.into_iter().filter(|input| concerns(input.id)) {
+                let state = format!(
+                    "{:?} {}",
+                    input.state,
+                    if input.status.signal {
+                        "signal"
+                    } else {
+                        "no signal"
+                    }
+                );
+                if let Some(state) = watched.changed(input.id, state) {
+                    if json {
+                        println!("{}", serde_json::to_string(&CommandResult::Input(input))?);
+                    } else {
+                        println!("{} input {} {}", time, input.label, state);
+                    }
+                }
+            }
+        }
+        CommandResult::Alarm(alarm)
+            if alarm.source.device_id.map_or(device.is_none(), concerns) =>
+        {
+            if json {
+                println!("{}", serde_json::to_string(&CommandResult::Alarm(alarm))?);
+            } else {
+                println!(
+                    "{} alarm {:?} {}{}",
+                    time,
+                    alarm.severity,
+                    alarm.message,
+                    if alarm.cleared.is_some() {
+                        " (cleared)"
+                    } else {
+                        ""
+                    }
+                );
+            }
+        }
+        // Log tails are already filtered by the server
+        CommandResult::Log(record) => {
+            if json {
+                println!("{}", serde_json::to_string(&CommandResult::Log(record))?);
+            } else {
+                println!(
+                    "{} {:?} {} {}",
+                    record.time.format("%H:%M:%S%.3f"),
+                    record.level,
+                    record.target,
+                    record.message
+                );
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+async fn run(cli: Cli) -> Result<(), Error> {
+    let mut connection = Connection::open(&cli.url).await?;
+
+    match cli.action {
+        Action::Devices => {
+            let devices = connection.devices().await?;
+            if cli.json {
+                print_json(&devices)?;
+            } else {
+                print_devices(&devices);
+            }
+        }
+        Action::Inputs => {
+            let inputs = connection.inputs().await?;
+            if cli.json {
+                print_json(&inputs)?;
+            } else {
+                print_inputs(&inputs);
+            }
+        }
+        Action::Start { device_id } => {
+            let result = connection.run(Command::Start { device_id }).await?;
+            if cli.json {
+                print_json(&result)?;
+            }
+        }
+        Action::Stop { device_id } => {
+            let result = connection.run(Command::Stop { device_id }).await?;
+            if cli.json {
+                print_json(&result)?;
+            }
+        }
+        Action::Mode { device_id, mode } => {
+            let mode = parse_mode(&mode)?;
+            let result = connection.run(Command::SetMode { device_id, mode }).await?;
+            if cli.json {
+                print_json(&result)?;
+            }
+        }
+        Action::Watch { device, logs } => {
+            if let Some(level) = logs {
+                connection
+                    .run(Command::TailLogs {
+                        device_id: device,
+                        level,
+                        enabled: true,
+                    })
+                    .await?;
+            }
+
+            let mut watched = Watched::default();
+            loop {
+                let message = connection.next().await?;
+                print_event(message.result, device, &mut watched, cli.json)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<(), Error> {
+    let cli = Cli::parse();
+
+    let system = actix_rt::System::new();
+    system.block_on(run(cli))
+}
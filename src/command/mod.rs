@@ -1,6 +1,36 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Error};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Modes `decklinkvideosink` accepts that an [`OutputFormat`] can map to
+const DECKLINK_MODES: &[&str] = &[
+    "720p50",
+    "720p5994",
+    "720p60",
+    "1080i50",
+    "1080i5994",
+    "1080i60",
+    "1080p2398",
+    "1080p24",
+    "1080p25",
+    "1080p2997",
+    "1080p30",
+    "1080p50",
+    "1080p5994",
+    "1080p60",
+    "2160p2398",
+    "2160p24",
+    "2160p25",
+    "2160p2997",
+    "2160p30",
+    "2160p50",
+    "2160p5994",
+    "2160p60",
+];
+
 /// Messages sent from the controller to the server.
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -11,21 +41,669 @@ pub struct ControllerMessage {
     pub command: Command,
 }
 
-/// Messages sent from the controller to the server.
-#[derive(Debug, Serialize, Deserialize)]
+/// What an output shows
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum VideoMode {
+    /// A `videotestsrc` pattern
     TestCard(String),
+    /// A stream or file, by URI
     Stream(String),
+    /// A still image (PNG/JPEG/TIFF), by path or URI, scaled to the
+    /// output format
+    Still {
+        location: String,
+        /// Whether to carry line-up tone on the audio
+        #[serde(default)]
+        tone: bool,
+    },
+    /// A playlist, loaded with [`Command::LoadPlaylist`]
+    Playlist,
+}
+
+/// A point in a stream to seek to
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SeekPosition {
+    /// Milliseconds from the start
+    Time(u64),
+    /// Frames from the start, at the stream's frame rate
+    Frame(u64),
+}
+
+/// Playback progress of a stream
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct TransportStatus {
+    pub position_ms: Option<u64>,
+    pub duration_ms: Option<u64>,
+    /// Whether the stream goes back to the start when it ends
+    pub looping: bool,
+}
+
+/// Audio level of one channel, in dBFS
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct ChannelLevel {
+    pub peak: f64,
+    pub rms: f64,
+}
+
+/// Control of the integrated loudness measurement of a device
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoudnessAction {
+    /// Carry on integrating
+    Start,
+    /// Hold the integrated reading where it is
+    Stop,
+    /// Clear the integrated reading and start over
+    Reset,
+}
+
+/// A loudness threshold that has been crossed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoudnessAlarm {
+    /// True-peak above the permitted maximum
+    TruePeak,
+    /// Short-term loudness above the permitted maximum
+    ShortTerm,
+    /// Integrated loudness outside the tolerance around the target
+    Integrated,
+}
+
+/// Severity of a log record, most severe first
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+/// One event that was logged
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct LogRecord {
+    pub time: DateTime<Utc>,
+    pub level: LogLevel,
+    /// Module the event came from
+    pub target: String,
+    pub message: String,
+    /// Device the event is about, from its fields or spans
+    pub device_id: Option<Uuid>,
+    /// Every other field of the event
+    pub fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// How verbose logging is, and until when
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct LoggingStatus {
+    /// The tracing filter, in `VIGIL_LOG` syntax
+    pub filter: String,
+    /// When the filter goes back to its startup value
+    pub filter_expires: Option<DateTime<Utc>>,
+    /// GStreamer debug thresholds, in `GST_DEBUG` syntax
+    pub gst_debug: String,
+    /// When the thresholds go back to their startup value
+    pub gst_debug_expires: Option<DateTime<Utc>>,
+}
+
+/// Frame counters of an output since it was created
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct FrameStats {
+    /// Frames the sink threw away for being too late to show
+    pub dropped: u64,
+    /// Frames repeated to keep the output rate, which includes those
+    /// of a frame rate conversion
+    pub duplicated: u64,
+    /// Frames that reached the sink after they were due
+    pub late: u64,
+    /// Times the sink was left waiting for a frame
+    pub underruns: u64,
+}
+
+impl FrameStats {
+    /// How much each counter grew since `earlier`
+    pub fn since(&self, earlier: &FrameStats) -> FrameStats {
+        FrameStats {
+            dropped: self.dropped.saturating_sub(earlier.dropped),
+            duplicated: self.duplicated.saturating_sub(earlier.duplicated),
+            late: self.late.saturating_sub(earlier.late),
+            underruns: self.underruns.saturating_sub(earlier.underruns),
+        }
+    }
+
+    /// Frames that visibly went wrong on air. Duplicates are left out
+    /// as they are expected whenever the source rate differs
+    pub fn glitches(&self) -> u64 {
+        self.dropped + self.late + self.underruns
+    }
+}
+
+impl std::ops::Add for FrameStats {
+    type Output = FrameStats;
+
+    fn add(self, other: FrameStats) -> FrameStats {
+        FrameStats {
+            dropped: self.dropped + other.dropped,
+            duplicated: self.duplicated + other.duplicated,
+            late: self.late + other.late,
+            underruns: self.underruns + other.underruns,
+        }
+    }
+}
+
+/// EBU R128 loudness of one audio program, in LUFS unless noted
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct ProgramLoudness {
+    /// Channels of the signal making up the program
+    pub channels: Vec<usize>,
+    pub momentary: Option<f64>,
+    pub short_term: Option<f64>,
+    pub integrated: Option<f64>,
+    /// Loudness range, in LU
+    pub range: Option<f64>,
+    /// Highest true-peak since the measurement started, in dBTP
+    pub true_peak: Option<f64>,
+    /// Highest true-peak since the previous reading, in dBTP
+    pub recent_true_peak: Option<f64>,
+    /// Whether the integrated measurement is running
+    pub integrating: bool,
+    /// When the integrated measurement was started or last reset
+    pub since: Option<DateTime<Utc>>,
+    /// Thresholds currently crossed
+    #[serde(default)]
+    pub alarms: Vec<LoudnessAlarm>,
+}
+
+/// A kind of bad content a detector looks for
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentFault {
+    /// Black picture
+    Black,
+    /// Picture not changing from frame to frame
+    Freeze,
+    /// No sound on a channel
+    Silence,
+    /// A steady tone on a channel
+    Tone,
+}
+
+/// How serious an alarm is, least first
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    /// Service is degraded
+    Major,
+    /// Service is lost
+    Critical,
+}
+
+/// What an alarm is about
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlarmKind {
+    /// A pipeline posted an error
+    Pipeline,
+    /// A content detector found bad content
+    Content(ContentFault),
+    /// An input stopped receiving a signal
+    SignalLoss,
+    /// The clock of a pipeline went away
+    ClockLoss,
+    /// A DeckLink device was unplugged
+    DeviceRemoved,
+    /// An output dropped, delayed or ran out of frames
+    FrameLoss,
+    /// Buffers stopped flowing through a playing pipeline
+    Stall,
+}
+
+/// Where an alarm comes from
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct AlarmSource {
+    /// The output or input, absent for alarms of the whole system
+    pub device_id: Option<Uuid>,
+    /// The element, channel or hardware within the device
+    pub element: Option<String>,
+}
+
+/// A user having seen an alarm
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct Acknowledgement {
+    pub by: String,
+    pub at: DateTime<Utc>,
+}
+
+/// A user having set an alarm aside, so it does not demand attention
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct Shelf {
+    #[serde(default = "Uuid::new_v4")]
+    pub id: Uuid,
+    pub by: String,
+    pub at: DateTime<Utc>,
+    /// When the alarm comes back, never when absent
+    pub until: Option<DateTime<Utc>>,
+}
+
+/// A condition set aside by a user. The shelf applies to every alarm
+/// of the condition until it runs out or is removed, whether or not
+/// one is active
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct ShelvedCondition {
+    pub kind: AlarmKind,
+    pub source: AlarmSource,
+    pub shelf: Shelf,
+}
+
+/// An abnormal condition of the system. It stays active until it has
+/// both cleared and been acknowledged
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct Alarm {
+    pub id: Uuid,
+    pub kind: AlarmKind,
+    pub severity: Severity,
+    pub source: AlarmSource,
+    /// Description of the latest occurrence
+    pub message: String,
+    pub raised: DateTime<Utc>,
+    /// When the condition ended, absent while it persists
+    pub cleared: Option<DateTime<Utc>>,
+    /// How many times the condition occurred while the alarm was
+    /// active
+    pub count: u32,
+    pub acknowledged: Option<Acknowledgement>,
+    pub shelved: Option<Shelf>,
+}
+
+/// How one playlist item hands over to the next
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Transition {
+    /// Gapless cut
+    #[default]
+    Cut,
+    /// Mix of picture and sound over the given time
+    Crossfade { duration_ms: u64 },
+}
+
+/// An entry in a playlist
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct PlaylistItem {
+    /// What to play
+    pub source: VideoMode,
+    /// How long to play for, defaults to the length between the in
+    /// and out points
+    #[serde(default)]
+    pub duration_ms: Option<u64>,
+    /// Where to start playing a clip from
+    #[serde(default)]
+    pub in_ms: Option<u64>,
+    /// Where to stop playing a clip
+    #[serde(default)]
+    pub out_ms: Option<u64>,
+}
+
+/// A list of items played out in order on one output
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct Playlist {
+    pub items: Vec<PlaylistItem>,
+    /// Whether to go back to the first item after the last
+    #[serde(default, rename = "loop")]
+    pub looping: bool,
+    #[serde(default)]
+    pub transition: Transition,
+}
+
+/// Where a playlist is up to
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct PlayoutStatus {
+    /// Index of the item on air, absent once the playlist has ended
+    pub current: Option<usize>,
+    /// Number of items in the playlist
+    pub count: usize,
+    /// Time since the item went to air
+    pub elapsed_ms: u64,
+    /// Time until the item ends, absent when it runs indefinitely
+    pub remaining_ms: Option<u64>,
+}
+
+/// The video format an output runs at
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct OutputFormat {
+    pub width: i32,
+    pub height: i32,
+    /// Frames per second as a fraction
+    pub framerate: (i32, i32),
+    pub interlaced: bool,
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        Self {
+            width: 1920,
+            height: 1080,
+            framerate: (60, 1),
+            interlaced: false,
+        }
+    }
+}
+
+impl OutputFormat {
+    /// The `decklinkvideosink` mode name for this format, e.g.
+    /// `1080p5994`. Interlaced modes are named by field rate
+    pub fn decklink_mode(&self) -> String {
+        let (num, den) = self.framerate;
+        let fields = if self.interlaced { 2 } else { 1 };
+
+        let rate = if den == 1 {
+            (num * fields).to_string()
+        } else {
+            ((num as f64 * fields as f64 * 100.0 / den as f64).round() as i64).to_string()
+        };
+
+        format!(
+            "{}{}{}",
+            self.height,
+            if self.interlaced { "i" } else { "p" },
+            rate
+        )
+    }
+
+    /// Check the format is one a DeckLink output can run at
+    pub fn validate(&self) -> Result<(), Error> {
+        let mode = self.decklink_mode();
+
+        if self.width * 9 != self.height * 16 || !DECKLINK_MODES.contains(&mode.as_str()) {
+            return Err(anyhow!(
+                "Unsupported output format {}x{} ({})",
+                self.width,
+                self.height,
+                mode
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Where an input takes its signal from
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InputSource {
+    /// A test pattern and tone, for trying things out without hardware
+    #[default]
+    Simulated,
+    /// A DeckLink input by device number
+    Decklink(i32),
+}
+
+/// What is arriving on an input
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct InputStatus {
+    /// Whether a signal is being received
+    pub signal: bool,
+    /// The detected video format, while there is a signal
+    pub format: Option<OutputFormat>,
+    /// Name of the detected video mode, e.g. `1080i50`
+    pub mode: Option<String>,
+    /// Timecode of the latest frame
+    pub timecode: Option<String>,
+    /// Number of embedded audio channels
+    pub audio_channels: Option<u32>,
+    /// Errors since the input was added
+    pub errors: u32,
+    pub last_error: Option<String>,
+}
+
+/// A monitored input
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct Input {
+    pub id: Uuid,
+    pub label: String,
+    pub source: InputSource,
+    pub state: gstreamer::State,
+    #[serde(flatten)]
+    pub status: InputStatus,
+    /// Loudness of each audio program, while running
+    #[serde(default)]
+    pub loudness: Vec<ProgramLoudness>,
+}
+
+/// The devices a bulk command applies to
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Target {
+    All,
+    /// A named group
+    Group(String),
+    Devices(Vec<Uuid>),
+}
+
+/// What a bulk command does to each device
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BulkAction {
+    Start,
+    Stop,
+    /// Show a `videotestsrc` pattern
+    SetPattern(String),
+    SetFormat(OutputFormat),
+}
+
+impl BulkAction {
+    /// The single-device command for this action
+    pub fn command(&self, device_id: Uuid) -> Command {
+        match self {
+            BulkAction::Start => Command::Start { device_id },
+            BulkAction::Stop => Command::Stop { device_id },
+            BulkAction::SetPattern(pattern) => Command::SetMode {
+                device_id,
+                mode: VideoMode::TestCard(pattern.clone()),
+            },
+            BulkAction::SetFormat(format) => Command::SetFormat {
+                device_id,
+                format: *format,
+            },
+        }
+    }
+}
+
+/// The result of a bulk command on one device
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct DeviceResult {
+    pub device_id: Uuid,
+    pub result: CommandResult,
+}
+
+/// Monitor alignment markers that can be drawn over an output
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Overlay {
+    /// EBU R95 action safe area
+    ActionSafe,
+    /// EBU R95 graphics (title) safe area
+    TitleSafe,
+    /// Cross at the centre of the picture
+    CentreCross,
+    /// 4:3 aspect markers
+    Aspect4x3,
+    /// 14:9 aspect markers
+    Aspect14x9,
+    /// 2.39:1 aspect markers
+    Aspect239,
+    /// Grid over the whole picture
+    Grid,
 }
 
 /// Command variants
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Command {
     Ping {},
-    Start { device_id: Uuid },
-    Stop { device_id: Uuid },
+    Start {
+        device_id: Uuid,
+    },
+    Stop {
+        device_id: Uuid,
+    },
+    SetOverlay {
+        device_id: Uuid,
+        overlay: Overlay,
+        enabled: bool,
+    },
+    SetMode {
+        device_id: Uuid,
+        mode: VideoMode,
+    },
+    LoadPlaylist {
+        device_id: Uuid,
+        playlist: Playlist,
+    },
+    /// Take the next playlist item to air
+    Next {
+        device_id: Uuid,
+    },
+    /// Take the previous playlist item to air
+    Previous {
+        device_id: Uuid,
+    },
+    /// Jump straight to a playlist item
+    Skip {
+        device_id: Uuid,
+        index: usize,
+    },
+    /// Hold a stream on its current frame
+    Pause {
+        device_id: Uuid,
+    },
+    /// Carry on playing a paused or cued stream
+    Resume {
+        device_id: Uuid,
+    },
+    Seek {
+        device_id: Uuid,
+        position: SeekPosition,
+    },
+    Loop {
+        device_id: Uuid,
+        enabled: bool,
+    },
+    /// Preroll a stream paused on its first frame, ready for an
+    /// instant `Start`
+    Cue {
+        device_id: Uuid,
+    },
+    SetFormat {
+        device_id: Uuid,
+        format: OutputFormat,
+    },
+    /// Apply an action to many devices at once
+    Bulk {
+        target: Target,
+        action: BulkAction,
+    },
+    /// Create or replace a named group of devices
+    SetGroup {
+        name: String,
+        devices: Vec<Uuid>,
+    },
+    RemoveGroup {
+        name: String,
+    },
+    ListGroups {},
+    /// Start or stop streaming a device's preview over the websocket
+    /// the command came in on
+    Preview {
+        device_id: Uuid,
+        enabled: bool,
+    },
+    /// Start or stop streaming log records over the websocket the
+    /// command came in on, optionally only those of one device
+    TailLogs {
+        #[serde(default)]
+        device_id: Option<Uuid>,
+        #[serde(default)]
+        level: LogLevel,
+        enabled: bool,
+    },
+    /// Start monitoring a new input, stopped until started
+    AddInput {
+        label: String,
+        source: InputSource,
+    },
+    RemoveInput {
+        device_id: Uuid,
+    },
+    ListInputs {},
+    /// List the active alarms
+    ListAlarms {},
+    /// Acknowledge an alarm on behalf of a user
+    AcknowledgeAlarm {
+        alarm_id: Uuid,
+        by: String,
+    },
+    /// Set an alarm aside, for a while or until unshelved
+    ShelveAlarm {
+        alarm_id: Uuid,
+        by: String,
+        #[serde(default)]
+        duration_s: Option<u64>,
+    },
+    /// Bring back a shelved alarm
+    UnshelveAlarm {
+        alarm_id: Uuid,
+    },
+    /// List the shelved conditions
+    ListShelves {},
+    /// Remove a shelf, whether or not its condition has an alarm
+    RemoveShelf {
+        shelf_id: Uuid,
+    },
+    /// Get the log filter and GStreamer debug thresholds
+    GetLogging {},
+    /// Change the log filter, in `VIGIL_LOG` syntax, for a while or
+    /// until changed again
+    SetLogFilter {
+        filter: String,
+        #[serde(default)]
+        duration_s: Option<u64>,
+    },
+    /// Change GStreamer debug thresholds, in `GST_DEBUG` syntax, for a
+    /// while or until changed again
+    SetGstDebug {
+        threshold: String,
+        #[serde(default)]
+        duration_s: Option<u64>,
+    },
+    /// Start, stop or reset a device's integrated loudness
+    Loudness {
+        device_id: Uuid,
+        action: LoudnessAction,
+    },
 }
 
 /// A map of node-specific information in reply to a GetInfo command
@@ -35,10 +713,65 @@ pub struct Device {
     pub id: Uuid,
     pub device_num: i32,
     pub state: gstreamer::State,
+    pub format: OutputFormat,
+    pub mode: VideoMode,
+    pub overlays: Vec<Overlay>,
+    /// Playlist progress, when a playlist is loaded
+    pub playout: Option<PlayoutStatus>,
+    /// Playback progress, when showing a stream
+    pub transport: Option<TransportStatus>,
+    /// Loudness of each audio program, while running
+    #[serde(default)]
+    pub loudness: Vec<ProgramLoudness>,
+    /// Frame counters of the output
+    #[serde(default)]
+    pub frames: FrameStats,
+}
+
+impl Command {
+    /// The name the command goes by on the wire
+    pub fn name(&self) -> &'static str {
+        match self {
+            Command::Ping { .. } => "ping",
+            Command::Start { .. } => "start",
+            Command::Stop { .. } => "stop",
+            Command::SetOverlay { .. } => "setoverlay",
+            Command::SetMode { .. } => "setmode",
+            Command::LoadPlaylist { .. } => "loadplaylist",
+            Command::Next { .. } => "next",
+            Command::Previous { .. } => "previous",
+            Command::Skip { .. } => "skip",
+            Command::Pause { .. } => "pause",
+            Command::Resume { .. } => "resume",
+            Command::Seek { .. } => "seek",
+            Command::Loop { .. } => "loop",
+            Command::Cue { .. } => "cue",
+            Command::SetFormat { .. } => "setformat",
+            Command::Bulk { .. } => "bulk",
+            Command::SetGroup { .. } => "setgroup",
+            Command::RemoveGroup { .. } => "removegroup",
+            Command::ListGroups { .. } => "listgroups",
+            Command::Preview { .. } => "preview",
+            Command::TailLogs { .. } => "taillogs",
+            Command::AddInput { .. } => "addinput",
+            Command::RemoveInput { .. } => "removeinput",
+            Command::ListInputs { .. } => "listinputs",
+            Command::ListAlarms { .. } => "listalarms",
+            Command::AcknowledgeAlarm { .. } => "acknowledgealarm",
+            Command::ShelveAlarm { .. } => "shelvealarm",
+            Command::UnshelveAlarm { .. } => "unshelvealarm",
+            Command::ListShelves { .. } => "listshelves",
+            Command::RemoveShelf { .. } => "removeshelf",
+            Command::GetLogging { .. } => "getlogging",
+            Command::SetLogFilter { .. } => "setlogfilter",
+            Command::SetGstDebug { .. } => "setgstdebug",
+            Command::Loudness { .. } => "loudness",
+        }
+    }
 }
 
 /// Messages sent from the the server to the controller.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum CommandResult {
     /// The command resulted in an error
@@ -49,6 +782,37 @@ pub enum CommandResult {
     Pong,
     /// Information about one or all nodes
     Sync(Vec<Device>),
+    /// The result of a bulk command for each device
+    Bulk(Vec<DeviceResult>),
+    /// All device groups by name
+    Groups(BTreeMap<String, Vec<Uuid>>),
+    /// Latest audio levels of every output, in dBFS per channel
+    Metering(BTreeMap<Uuid, Vec<ChannelLevel>>),
+    /// A new thumbnail of a device is available from
+    /// `/api/devices/{device_id}/thumbnail`
+    Thumbnail {
+        device_id: Uuid,
+        updated: DateTime<Utc>,
+    },
+    /// Every input
+    Inputs(Vec<Input>),
+    /// The latest state of an input
+    Input(Input),
+    /// Latest loudness of each audio program of a device
+    Loudness {
+        device_id: Uuid,
+        programs: Vec<ProgramLoudness>,
+    },
+    /// Every active alarm
+    Alarms(Vec<Alarm>),
+    /// The latest state of an alarm
+    Alarm(Alarm),
+    /// Every shelved condition
+    Shelves(Vec<ShelvedCondition>),
+    /// How verbose logging is
+    Logging(LoggingStatus),
+    /// A record of a live log tail
+    Log(LogRecord),
 }
 
 /// Messages sent from the the server to the controller.
@@ -67,3 +831,71 @@ pub struct ServerMessage {
 pub struct NodeState {
     pub state: gstreamer::State,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(width: i32, height: i32, framerate: (i32, i32), interlaced: bool) -> OutputFormat {
+        OutputFormat {
+            width,
+            height,
+            framerate,
+            interlaced,
+        }
+    }
+
+    #[test]
+    fn name_matches_wire_format() {
+        for command in [
+            Command::Ping {},
+            Command::SetOverlay {
+                device_id: Uuid::nil(),
+                overlay: Overlay::CentreCross,
+                enabled: true,
+            },
+            Command::ListInputs {},
+            Command::TailLogs {
+                device_id: None,
+                level: LogLevel::default(),
+                enabled: true,
+            },
+        ] {
+            let value = serde_json::to_value(&command).unwrap();
+            let wire = value.as_object().unwrap().keys().next().unwrap();
+            assert_eq!(command.name(), wire);
+        }
+    }
+
+    #[test]
+    fn decklink_mode_names() {
+        for (output, mode) in [
+            (format(1920, 1080, (60, 1), false), "1080p60"),
+            (format(1920, 1080, (60000, 1001), false), "1080p5994"),
+            (format(1920, 1080, (24000, 1001), false), "1080p2398"),
+            (format(1920, 1080, (25, 1), true), "1080i50"),
+            (format(1920, 1080, (30000, 1001), true), "1080i5994"),
+            (format(1280, 720, (50, 1), false), "720p50"),
+            (format(3840, 2160, (30000, 1001), false), "2160p2997"),
+        ] {
+            assert_eq!(output.decklink_mode(), mode);
+            assert!(output.validate().is_ok(), "rejected {}", mode);
+        }
+    }
+
+    #[test]
+    fn unsupported_formats_rejected() {
+        for output in [
+            // Not 16:9
+            format(1920, 1200, (60, 1), false),
+            format(1440, 1080, (25, 1), true),
+            // No such DeckLink mode
+            format(1280, 720, (25, 1), true),
+            format(1920, 1080, (48, 1), false),
+            format(1920, 1080, (15, 1), false),
+            format(3840, 2160, (30, 1), true),
+        ] {
+            assert!(output.validate().is_err(), "accepted {:?}", output);
+        }
+    }
+}
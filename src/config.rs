@@ -0,0 +1,11 @@
+use std::path::PathBuf;
+
+/// Data directory used when `VIGIL_DATA_DIR` is not set
+const DEFAULT_DATA_DIR: &str = "data";
+
+/// The directory state that must survive restarts is kept in
+pub fn data_dir() -> PathBuf {
+    std::env::var_os("VIGIL_DATA_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_DATA_DIR))
+}
@@ -5,12 +5,14 @@ use actix_web::dev::ConnectionInfo;
 use actix_web_actors::ws;
 use anyhow::{format_err, Error};
 use serde::{Deserialize, Serialize};
-use tracing::{debug, error, info, instrument, trace};
+use tracing::{debug, error, info, info_span, instrument, trace};
 use uuid::Uuid;
 
 use crate::{
     command::{Command, CommandResult, ControllerMessage, Device, ServerMessage},
-    node::{CommandMessage, NodeManager, WebsocketMessage},
+    logs::{self, Tail},
+    node::{CommandMessage, NodeManager, PreviewMessage, WebsocketMessage},
+    pipeline::preview::PreviewDataMessage,
 };
 
 #[derive(Debug)]
@@ -60,16 +62,20 @@ impl Controller {
         })
     }
 
-    /// Send a command to [`NodeManager`] for dispatching, then notify
+    /// Send a message to [`NodeManager`] for dispatching, then notify
     /// the remote controller
-    fn send_command_future(
+    fn send_command_future<M>(
         &self,
         command_id: uuid::Uuid,
-        command: Command,
-    ) -> impl ActorFuture<Self, Output = ()> {
+        msg: M,
+    ) -> impl ActorFuture<Self, Output = ()>
+    where
+        M: Message<Result = CommandResult> + Send + 'static,
+        NodeManager: Handler<M>,
+    {
         let node_manager = NodeManager::from_registry();
 
-        async move { node_manager.send(CommandMessage { command }).await }
+        async move { node_manager.send(msg).await }
             .into_actor(self)
             .then(move |res, _, ctx| {
                 match res {
@@ -99,8 +105,58 @@ impl Controller {
     fn handle_message(&mut self, ctx: &mut ws::WebsocketContext<Self>, text: &str) {
         trace!("Handling message: {}", text);
         match serde_json::from_str::<ControllerMessage>(text) {
+            // Previews are streamed to this session, so it has to go
+            // along with the command
+            Ok(ControllerMessage {
+                id,
+                command: Command::Preview { device_id, enabled },
+            }) => {
+                ctx.spawn(self.send_command_future(
+                    id,
+                    PreviewMessage {
+                        device_id,
+                        session: self.id,
+                        enabled,
+                    },
+                ));
+            }
+            // Same goes for log records
+            Ok(ControllerMessage {
+                id,
+                command:
+                    Command::TailLogs {
+                        device_id,
+                        level,
+                        enabled,
+                    },
+            }) => {
+                logs::tail(
+                    self.id,
+                    enabled.then(|| Tail {
+                        device_id,
+                        level,
+                        recipient: ctx.address().recipient(),
+                    }),
+                );
+                ctx.text(
+                    serde_json::to_string(&ServerMessage {
+                        id: Some(id),
+                        result: CommandResult::Success,
+                    })
+                    .expect("failed to serialize CommandResult message"),
+                );
+            }
             Ok(ControllerMessage { id, command }) => {
-                ctx.spawn(self.send_command_future(id, command));
+                // The root of the trace of the command, carried along
+                // by the message
+                let span = info_span!(
+                    "command",
+                    command_id = %id,
+                    command = %command.name(),
+                    remote_addr = %self.remote_addr
+                );
+                let msg = span.in_scope(|| CommandMessage::new(command));
+                ctx.spawn(self.send_command_future(id, msg));
             }
             Err(err) => {
                 error!(
@@ -161,6 +217,8 @@ impl Actor for Controller {
     }
 
     fn stopped(&mut self, _: &mut Self::Context) {
+        logs::tail(self.id, None);
+
         let node_manager = NodeManager::from_registry();
         node_manager.do_send(WebsocketMessage::Disconect { id: self.id })
     }
@@ -262,3 +320,40 @@ impl Handler<SyncMessage> for Controller {
         )
     }
 }
+
+/// Pushes a result to the remote controller unprompted, sent from
+/// [`NodeManager`] when something changes
+#[derive(Debug)]
+pub struct NotifyMessage {
+    pub result: CommandResult,
+}
+
+impl Message for NotifyMessage {
+    type Result = ();
+}
+
+impl Handler<NotifyMessage> for Controller {
+    type Result = ();
+
+    fn handle(&mut self, msg: NotifyMessage, ctx: &mut ws::WebsocketContext<Self>) -> Self::Result {
+        ctx.text(
+            serde_json::to_string(&ServerMessage {
+                id: None,
+                result: msg.result,
+            })
+            .expect("failed to serialize CommandResult message"),
+        )
+    }
+}
+
+impl Handler<PreviewDataMessage> for Controller {
+    type Result = ();
+
+    fn handle(
+        &mut self,
+        msg: PreviewDataMessage,
+        ctx: &mut ws::WebsocketContext<Self>,
+    ) -> Self::Result {
+        ctx.binary(msg.0)
+    }
+}
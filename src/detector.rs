@@ -0,0 +1,661 @@
+use std::collections::{HashMap, HashSet};
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use actix::{Actor, AsyncContext, Context, Handler, Message, MessageResult, SystemService};
+use anyhow::{anyhow, Error};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::alarm::{AlarmManager, ClearAlarmMessage, RaiseAlarmMessage};
+use crate::command::{AlarmKind, AlarmSource, ChannelLevel, ContentFault, Severity};
+use crate::config::data_dir;
+
+/// How long a node can go without sending statistics before its
+/// alarms are cleared, as it is no longer being watched
+const STATS_TIMEOUT: Duration = Duration::from_secs(2);
+/// Crest factor of a sine wave, in dB
+const SINE_CREST: f64 = 3.01;
+/// How far the crest factor may stray from a sine's and still count
+/// as tone, in dB
+const TONE_CREST_TOLERANCE: f64 = 1.0;
+/// How much the RMS level of a tone may vary, in dB
+const TONE_STEADINESS: f64 = 0.5;
+
+/// Black picture detection
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase", default)]
+pub struct BlackDetector {
+    pub enabled: bool,
+    /// Average luma below which a frame is black, from 0 to 1
+    pub threshold: f64,
+    /// How long the picture has to be black before an alarm
+    pub duration_ms: u64,
+}
+
+impl Default for BlackDetector {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            threshold: 0.1,
+            duration_ms: 2000,
+        }
+    }
+}
+
+/// Frozen picture detection
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase", default)]
+pub struct FreezeDetector {
+    pub enabled: bool,
+    /// Average difference between frames below which they count as
+    /// repeated, from 0 to 1
+    pub threshold: f64,
+    /// How long the picture has to be frozen before an alarm
+    pub duration_ms: u64,
+}
+
+impl Default for FreezeDetector {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            threshold: 0.002,
+            duration_ms: 5000,
+        }
+    }
+}
+
+/// Per-channel silence detection
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase", default)]
+pub struct SilenceDetector {
+    pub enabled: bool,
+    /// Peak level below which a channel is silent, in dBFS
+    pub threshold: f64,
+    /// How long a channel has to be silent before an alarm
+    pub duration_ms: u64,
+}
+
+impl Default for SilenceDetector {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            threshold: -60.0,
+            duration_ms: 10000,
+        }
+    }
+}
+
+/// Per-channel stuck tone detection
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase", default)]
+pub struct ToneDetector {
+    pub enabled: bool,
+    /// How long a channel has to carry a steady tone before an alarm
+    pub duration_ms: u64,
+}
+
+impl Default for ToneDetector {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            duration_ms: 30000,
+        }
+    }
+}
+
+/// Settings of every content detector
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase", default)]
+pub struct DetectorConfig {
+    pub black: BlackDetector,
+    pub freeze: FreezeDetector,
+    pub silence: SilenceDetector,
+    pub tone: ToneDetector,
+}
+
+impl DetectorConfig {
+    /// Check the thresholds make sense
+    fn validate(&self) -> Result<(), Error> {
+        if !(0.0..=1.0).contains(&self.black.threshold) {
+            return Err(anyhow!("Black threshold must be between 0 and 1"));
+        }
+        if !(0.0..=1.0).contains(&self.freeze.threshold) {
+            return Err(anyhow!("Freeze threshold must be between 0 and 1"));
+        }
+        if self.silence.threshold.is_nan() {
+            return Err(anyhow!("Silence threshold must be a number"));
+        }
+
+        Ok(())
+    }
+
+    /// How long `fault` has to persist before an alarm
+    fn duration(&self, fault: ContentFault) -> chrono::Duration {
+        let ms = match fault {
+            ContentFault::Black => self.black.duration_ms,
+            ContentFault::Freeze => self.freeze.duration_ms,
+            ContentFault::Silence => self.silence.duration_ms,
+            ContentFault::Tone => self.tone.duration_ms,
+        };
+
+        chrono::Duration::milliseconds(ms as i64)
+    }
+}
+
+/// Statistics of the picture of a node since the last were sent
+#[derive(Clone, Copy, Debug)]
+pub struct PictureStats {
+    pub frames: u32,
+    /// Highest average luma of any frame, from 0 to 1
+    pub max_luma: f64,
+    /// Highest average difference between consecutive frames, from 0
+    /// to 1
+    pub max_difference: f64,
+}
+
+/// Statistics of one audio channel of a node since the last were sent
+#[derive(Clone, Copy, Debug)]
+pub struct ChannelStats {
+    /// Highest peak level, in dBFS
+    pub max_peak: f64,
+    /// Whether every reading looked like the same steady sine
+    pub tone: bool,
+}
+
+/// Readings of one channel gathered into a [`ChannelStats`]
+#[derive(Debug)]
+struct ChannelWindow {
+    max_peak: f64,
+    min_rms: f64,
+    max_rms: f64,
+    tone: bool,
+}
+
+impl Default for ChannelWindow {
+    fn default() -> Self {
+        Self {
+            max_peak: f64::NEG_INFINITY,
+            min_rms: f64::INFINITY,
+            max_rms: f64::NEG_INFINITY,
+            tone: true,
+        }
+    }
+}
+
+/// Audio levels of a node gathered between statistics being sent
+#[derive(Debug, Default)]
+pub struct AudioWindow {
+    channels: Vec<ChannelWindow>,
+}
+
+impl AudioWindow {
+    /// Fold in a reading of every channel
+    pub fn add(&mut self, levels: &[ChannelLevel]) {
+        if self.channels.len() != levels.len() {
+            self.channels = levels.iter().map(|_| ChannelWindow::default()).collect();
+        }
+
+        for (window, level) in self.channels.iter_mut().zip(levels) {
+            window.max_peak = window.max_peak.max(level.peak);
+            window.min_rms = window.min_rms.min(level.rms);
+            window.max_rms = window.max_rms.max(level.rms);
+            window.tone &= ((level.peak - level.rms) - SINE_CREST).abs() <= TONE_CREST_TOLERANCE;
+        }
+    }
+
+    /// The statistics since they were last taken
+    pub fn take(&mut self) -> Vec<ChannelStats> {
+        self.channels
+            .drain(..)
+            .map(|window| ChannelStats {
+                max_peak: window.max_peak,
+                tone: window.tone && window.max_rms - window.min_rms <= TONE_STEADINESS,
+            })
+            .collect()
+    }
+}
+
+/// A fault on a particular device, and audio channel for sound
+type FaultKey = (Uuid, ContentFault, Option<usize>);
+
+/// What a fault coming or going means for its alarm
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Change {
+    /// The fault has lasted long enough, since the time given
+    Raise(DateTime<Utc>),
+    Clear,
+}
+
+/// Looks for black, frozen, silent and stuck content in statistics
+/// sent by nodes, raising and clearing alarms with the
+/// [`AlarmManager`]
+#[derive(Debug)]
+pub struct ContentDetector {
+    config: DetectorConfig,
+    /// When each fault currently seen started
+    faults: HashMap<FaultKey, DateTime<Utc>>,
+    /// Faults whose alarms are raised
+    raised: HashSet<FaultKey>,
+    /// When each node last sent statistics
+    last_seen: HashMap<Uuid, Instant>,
+    /// Where the settings are persisted
+    config_path: PathBuf,
+}
+
+impl Default for ContentDetector {
+    fn default() -> Self {
+        Self {
+            config: DetectorConfig::default(),
+            faults: HashMap::new(),
+            raised: HashSet::new(),
+            last_seen: HashMap::new(),
+            config_path: data_dir().join("detectors.json"),
+        }
+    }
+}
+
+impl Actor for ContentDetector {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        if let Err(err) = self.load() {
+            error!("Failed to load detector settings: {}", err);
+        }
+
+        info!("Content detector coming online");
+
+        ctx.run_interval(STATS_TIMEOUT, |act, _| act.expire());
+    }
+}
+
+impl actix::Supervised for ContentDetector {}
+
+impl SystemService for ContentDetector {}
+
+impl ContentDetector {
+    /// Load settings from disk
+    fn load(&mut self) -> Result<(), Error> {
+        if self.config_path.exists() {
+            let file = std::fs::File::open(&self.config_path)?;
+            self.config = serde_json::from_reader(BufReader::new(file))?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the settings to disk, replacing the previous file
+    fn save(&self) -> Result<(), Error> {
+        if let Some(dir) = self.config_path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let partial = self.config_path.with_extension("json.part");
+        serde_json::to_writer_pretty(std::fs::File::create(&partial)?, &self.config)?;
+        std::fs::rename(&partial, &self.config_path)?;
+
+        Ok(())
+    }
+
+    /// Track whether a fault is present, answering whether its alarm
+    /// should be raised, once it has lasted long enough, or cleared,
+    /// once it has gone
+    fn check(&mut self, key: FaultKey, present: bool, now: DateTime<Utc>) -> Option<Change> {
+        if !present {
+            self.faults.remove(&key);
+            return self.raised.remove(&key).then_some(Change::Clear);
+        }
+
+        let since = *self.faults.entry(key).or_insert(now);
+        if !self.raised.contains(&key) && now - since >= self.config.duration(key.1) {
+            self.raised.insert(key);
+            return Some(Change::Raise(since));
+        }
+
+        None
+    }
+
+    /// Pass a change of a fault on to the [`AlarmManager`], `name`
+    /// being what the node is called in alarm messages
+    fn apply(&self, key: FaultKey, change: Change, name: &str) {
+        let (id, fault, channel) = key;
+        let on_channel = channel
+            .map(|channel| format!(" on channel {}", channel + 1))
+            .unwrap_or_default();
+
+        let kind = AlarmKind::Content(fault);
+        let source = AlarmSource {
+            device_id: Some(id),
+            element: channel.map(|channel| format!("channel {}", channel + 1)),
+        };
+
+        match change {
+            Change::Raise(since) => {
+                warn!("{:?} detected{} of {}", fault, on_channel, name);
+
+                // Tone may be deliberate, the rest is never wanted on
+                // air
+                let severity = match fault {
+                    ContentFault::Tone => Severity::Warning,
+                    _ => Severity::Major,
+                };
+                let description = match fault {
+                    ContentFault::Black => "Black picture",
+                    ContentFault::Freeze => "Frozen picture",
+                    ContentFault::Silence => "Silence",
+                    ContentFault::Tone => "Stuck tone",
+                };
+
+                AlarmManager::from_registry().do_send(RaiseAlarmMessage {
+                    kind,
+                    severity,
+                    source,
+                    message: format!("{}{} of {}", description, on_channel, name),
+                    since: Some(since),
+                });
+            }
+            Change::Clear => {
+                info!("{:?} cleared{} of {}", fault, on_channel, name);
+
+                AlarmManager::from_registry().do_send(ClearAlarmMessage { kind, source });
+            }
+        }
+    }
+
+    /// Clear everything about nodes that have stopped sending
+    /// statistics
+    fn expire(&mut self) {
+        let stale: Vec<Uuid> = self
+            .last_seen
+            .iter()
+            .filter(|(_, seen)| seen.elapsed() >= STATS_TIMEOUT)
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in stale {
+            self.forget(id);
+        }
+    }
+
+    /// Clear every fault of a node
+    fn forget(&mut self, id: Uuid) {
+        self.last_seen.remove(&id);
+        self.faults.retain(|key, _| key.0 != id);
+
+        let mut keys: Vec<FaultKey> = self
+            .raised
+            .iter()
+            .filter(|key| key.0 == id)
+            .copied()
+            .collect();
+        keys.sort_by_key(|key| key.2);
+        for key in keys {
+            self.raised.remove(&key);
+            self.apply(key, Change::Clear, &id.to_string());
+        }
+    }
+}
+
+/// Content statistics of a node, sent from any node to
+/// [`ContentDetector`] while it is playing
+#[derive(Debug)]
+pub struct ContentStatsMessage {
+    /// Unique identifier of the node
+    pub id: Uuid,
+    /// What the node is called in alarm messages
+    pub name: String,
+    /// Absent when no frames arrived
+    pub picture: Option<PictureStats>,
+    pub audio: Vec<ChannelStats>,
+}
+
+impl Message for ContentStatsMessage {
+    type Result = ();
+}
+
+impl Handler<ContentStatsMessage> for ContentDetector {
+    type Result = ();
+
+    fn handle(&mut self, msg: ContentStatsMessage, _ctx: &mut Context<Self>) {
+        let now = Utc::now();
+        let id = msg.id;
+
+        self.last_seen.insert(id, Instant::now());
+
+        if let Some(picture) = msg.picture {
+            let black = self.config.black.enabled && picture.max_luma < self.config.black.threshold;
+            let frozen = self.config.freeze.enabled
+                && picture.frames > 1
+                && picture.max_difference < self.config.freeze.threshold;
+
+            let changes = [
+                ((id, ContentFault::Black, None), black),
+                // A black picture never changes, so only the black
+                // alarm is raised for it
+                ((id, ContentFault::Freeze, None), frozen && !black),
+            ];
+            for (key, present) in changes {
+                if let Some(change) = self.check(key, present, now) {
+                    self.apply(key, change, &msg.name);
+                }
+            }
+        }
+
+        for (channel, stats) in msg.audio.iter().enumerate() {
+            let silent =
+                self.config.silence.enabled && stats.max_peak < self.config.silence.threshold;
+            let tone = self.config.tone.enabled && !silent && stats.tone;
+
+            let changes = [
+                ((id, ContentFault::Silence, Some(channel)), silent),
+                ((id, ContentFault::Tone, Some(channel)), tone),
+            ];
+            for (key, present) in changes {
+                if let Some(change) = self.check(key, present, now) {
+                    self.apply(key, change, &msg.name);
+                }
+            }
+        }
+
+        // Channels that have gone away can no longer be at fault. No
+        // channels at all means no levels arrived in time, which says
+        // nothing about the sound
+        let channels = msg.audio.len();
+        if channels == 0 {
+            return;
+        }
+        let gone: Vec<FaultKey> = self
+            .faults
+            .keys()
+            .chain(self.raised.iter())
+            .filter(|key| key.0 == id && key.2.map_or(false, |channel| channel >= channels))
+            .copied()
+            .collect();
+        for key in gone {
+            if let Some(change) = self.check(key, false, now) {
+                self.apply(key, change, &msg.name);
+            }
+        }
+    }
+}
+
+/// Clear every alarm of a node that has stopped or been removed
+#[derive(Debug)]
+pub struct ForgetContentMessage {
+    /// Unique identifier of the node
+    pub id: Uuid,
+}
+
+impl Message for ForgetContentMessage {
+    type Result = ();
+}
+
+impl Handler<ForgetContentMessage> for ContentDetector {
+    type Result = ();
+
+    fn handle(&mut self, msg: ForgetContentMessage, _ctx: &mut Context<Self>) {
+        self.forget(msg.id);
+    }
+}
+
+/// Get the detector settings
+#[derive(Debug)]
+pub struct GetDetectorConfigMessage;
+
+impl Message for GetDetectorConfigMessage {
+    type Result = DetectorConfig;
+}
+
+impl Handler<GetDetectorConfigMessage> for ContentDetector {
+    type Result = MessageResult<GetDetectorConfigMessage>;
+
+    fn handle(&mut self, _msg: GetDetectorConfigMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(self.config.clone())
+    }
+}
+
+/// Replace the detector settings
+#[derive(Debug)]
+pub struct SetDetectorConfigMessage {
+    pub config: DetectorConfig,
+}
+
+impl Message for SetDetectorConfigMessage {
+    type Result = Result<DetectorConfig, Error>;
+}
+
+impl Handler<SetDetectorConfigMessage> for ContentDetector {
+    type Result = Result<DetectorConfig, Error>;
+
+    fn handle(&mut self, msg: SetDetectorConfigMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        msg.config.validate()?;
+
+        self.config = msg.config;
+        self.save()?;
+
+        Ok(self.config.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A reading of a sine at `rms` dBFS
+    fn sine(rms: f64) -> ChannelLevel {
+        ChannelLevel {
+            peak: rms + SINE_CREST,
+            rms,
+        }
+    }
+
+    #[test]
+    fn steady_sine_is_tone() {
+        let mut window = AudioWindow::default();
+        for _ in 0..10 {
+            window.add(&[sine(-21.0), sine(-21.2)]);
+        }
+
+        let stats = window.take();
+        assert_eq!(stats.len(), 2);
+        assert!(stats.iter().all(|channel| channel.tone));
+        assert!((stats[0].max_peak - (-21.0 + SINE_CREST)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn programme_is_not_tone() {
+        let mut window = AudioWindow::default();
+        window.add(&[
+            sine(-21.0),
+            ChannelLevel {
+                peak: -6.0,
+                rms: -20.0,
+            },
+        ]);
+
+        let stats = window.take();
+        assert!(stats[0].tone);
+        assert!(!stats[1].tone);
+    }
+
+    #[test]
+    fn wandering_sine_is_not_tone() {
+        let mut window = AudioWindow::default();
+        window.add(&[sine(-21.0)]);
+        window.add(&[sine(-20.0)]);
+
+        assert!(!window.take()[0].tone);
+    }
+
+    #[test]
+    fn take_starts_afresh() {
+        let mut window = AudioWindow::default();
+        window.add(&[ChannelLevel {
+            peak: -6.0,
+            rms: -20.0,
+        }]);
+        assert!(!window.take()[0].tone);
+        assert!(window.take().is_empty());
+
+        window.add(&[sine(-18.0)]);
+        assert!(window.take()[0].tone);
+    }
+
+    #[test]
+    fn change_of_channels_starts_afresh() {
+        let mut window = AudioWindow::default();
+        window.add(&[ChannelLevel {
+            peak: -6.0,
+            rms: -20.0,
+        }]);
+        window.add(&[sine(-18.0), sine(-18.0)]);
+
+        let stats = window.take();
+        assert_eq!(stats.len(), 2);
+        assert!(stats.iter().all(|channel| channel.tone));
+    }
+
+    #[test]
+    fn fault_raises_once_it_has_lasted() {
+        let mut detector = ContentDetector::default();
+        let key = (Uuid::nil(), ContentFault::Black, None);
+        let start = Utc::now();
+        let after = |ms| start + chrono::Duration::milliseconds(ms);
+
+        assert_eq!(detector.check(key, true, start), None);
+        assert_eq!(detector.check(key, true, after(1999)), None);
+        assert_eq!(
+            detector.check(key, true, after(2000)),
+            Some(Change::Raise(start))
+        );
+        // Raised only once however long it lasts
+        assert_eq!(detector.check(key, true, after(3000)), None);
+
+        assert_eq!(detector.check(key, false, after(3500)), Some(Change::Clear));
+        assert_eq!(detector.check(key, false, after(4000)), None);
+    }
+
+    #[test]
+    fn brief_fault_is_forgotten() {
+        let mut detector = ContentDetector::default();
+        let key = (Uuid::nil(), ContentFault::Silence, Some(1));
+        let start = Utc::now();
+        let after = |ms| start + chrono::Duration::milliseconds(ms);
+
+        assert_eq!(detector.check(key, true, start), None);
+        assert_eq!(detector.check(key, true, after(9000)), None);
+        // Gone before it was raised, so there is nothing to clear
+        assert_eq!(detector.check(key, false, after(9500)), None);
+
+        // Coming back starts the wait again
+        assert_eq!(detector.check(key, true, after(10000)), None);
+        assert_eq!(detector.check(key, true, after(19999)), None);
+        assert_eq!(
+            detector.check(key, true, after(20000)),
+            Some(Change::Raise(after(10000)))
+        );
+    }
+}
@@ -1,6 +1,12 @@
+use actix::prelude::*;
 use anyhow::{anyhow, Result};
-use gst::prelude::DeviceProviderExtManual;
+use futures::prelude::*;
+use gst::prelude::*;
 use gstreamer as gst;
+use tracing::{error, info, warn};
+
+use crate::alarm::{AlarmManager, ClearAlarmMessage, RaiseAlarmMessage};
+use crate::command::{AlarmKind, AlarmSource, Severity};
 
 pub fn find_decklink_devices() -> Result<u16> {
     if let Some(provider) = gst::DeviceProviderFactory::by_name("decklinkvideosink") {
@@ -11,3 +17,92 @@ pub fn find_decklink_devices() -> Result<u16> {
 
     Err(anyhow!("No decklink devices found"))
 }
+
+/// Maps messages of the device provider for consumption by a
+/// [`DeviceWatcher`]
+#[derive(Debug)]
+struct DeviceEvent(gst::Message);
+
+impl Message for DeviceEvent {
+    type Result = ();
+}
+
+/// Watches DeckLink hardware coming and going, raising an alarm for
+/// each device that goes missing
+#[derive(Debug, Default)]
+pub struct DeviceWatcher {
+    provider: Option<gst::DeviceProvider>,
+}
+
+impl Actor for DeviceWatcher {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        let provider = match gst::DeviceProviderFactory::by_name("decklinkdeviceprovider") {
+            Some(provider) => provider,
+            None => {
+                warn!("No DeckLink device provider, hotplug is not watched");
+                return;
+            }
+        };
+
+        Self::add_stream(provider.bus().stream().map(DeviceEvent), ctx);
+
+        if let Err(err) = provider.start() {
+            error!("Failed to watch DeckLink devices: {}", err);
+            return;
+        }
+
+        for device in provider.devices() {
+            info!("Found DeckLink device {}", device.display_name());
+        }
+
+        self.provider = Some(provider);
+    }
+
+    fn stopped(&mut self, _ctx: &mut Self::Context) {
+        if let Some(provider) = self.provider.take() {
+            provider.stop();
+        }
+    }
+}
+
+impl actix::Supervised for DeviceWatcher {}
+
+impl SystemService for DeviceWatcher {}
+
+impl StreamHandler<DeviceEvent> for DeviceWatcher {
+    fn handle(&mut self, msg: DeviceEvent, _ctx: &mut Context<Self>) {
+        use gst::MessageView;
+
+        match msg.0.view() {
+            MessageView::DeviceAdded(added) => {
+                let name = added.device().display_name().to_string();
+                info!("DeckLink device {} added", name);
+
+                AlarmManager::from_registry().do_send(ClearAlarmMessage {
+                    kind: AlarmKind::DeviceRemoved,
+                    source: AlarmSource {
+                        device_id: None,
+                        element: Some(name),
+                    },
+                });
+            }
+            MessageView::DeviceRemoved(removed) => {
+                let name = removed.device().display_name().to_string();
+
+                AlarmManager::from_registry().do_send(RaiseAlarmMessage {
+                    kind: AlarmKind::DeviceRemoved,
+                    severity: Severity::Critical,
+                    source: AlarmSource {
+                        device_id: None,
+                        element: Some(name.clone()),
+                    },
+                    message: format!("DeckLink device {} removed", name),
+                    since: None,
+                });
+            }
+            _ => (),
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use actix::SystemService;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::alarm::{AlarmManager, ListAlarmsMessage};
+use crate::command::{Alarm, AlarmKind, Command, CommandResult};
+use crate::node::{CommandMessage, NodeManager, NodeMetricsMessage};
+
+/// How long an actor may take to answer before it counts as stuck
+const ANSWER_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// How far off the system clock may be, by its own estimate, before
+/// it counts as unsynced
+#[cfg(target_os = "linux")]
+const MAX_CLOCK_ERROR: Duration = Duration::from_millis(100);
+
+/// How well a subsystem is doing, best first
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Ok,
+    /// Working, but not everything that should be is
+    Degraded,
+    /// Not working
+    Down,
+}
+
+/// How one subsystem is doing
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub struct SubsystemHealth {
+    pub status: HealthStatus,
+    /// What is wrong, or what was checked
+    pub detail: String,
+}
+
+impl SubsystemHealth {
+    fn new(status: HealthStatus, detail: impl Into<String>) -> Self {
+        Self {
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// How every subsystem is doing, overall as bad as the worst
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub checked: DateTime<Utc>,
+    pub subsystems: BTreeMap<&'static str, SubsystemHealth>,
+}
+
+impl HealthReport {
+    fn new(subsystems: BTreeMap<&'static str, SubsystemHealth>) -> Self {
+        Self {
+            status: subsystems
+                .values()
+                .map(|subsystem| subsystem.status)
+                .max()
+                .unwrap_or(HealthStatus::Ok),
+            checked: Utc::now(),
+            subsystems,
+        }
+    }
+}
+
+/// Whether the [`NodeManager`] answers a ping in time
+async fn node_manager() -> SubsystemHealth {
+    let ping = NodeManager::from_registry()
+        .send(CommandMessage::new(Command::Ping {}))
+        .timeout(ANSWER_TIMEOUT);
+
+    match ping.await {
+        Ok(CommandResult::Pong) => SubsystemHealth::new(HealthStatus::Ok, "Answering"),
+        Ok(result) => SubsystemHealth::new(
+            HealthStatus::Down,
+            format!("Unexpected answer to ping: {:?}", result),
+        ),
+        Err(err) => SubsystemHealth::new(HealthStatus::Down, format!("Not answering: {}", err)),
+    }
+}
+
+/// Whether any output that was started is failing, as told by its
+/// active alarms
+async fn outputs(alarms: &[Alarm]) -> SubsystemHealth {
+    let metrics = match NodeManager::from_registry()
+        .send(NodeMetricsMessage)
+        .timeout(ANSWER_TIMEOUT)
+        .await
+    {
+        Ok(metrics) => metrics,
+        Err(err) => {
+            return SubsystemHealth::new(HealthStatus::Down, format!("Unknown: {}", err));
+        }
+    };
+
+    let expected: Vec<_> = metrics
+        .nodes
+        .iter()
+        .filter(|node| node.kind == "output" && node.state == gstreamer::State::Playing)
+        .collect();
+    let failing: Vec<String> = expected
+        .iter()
+        .filter(|node| {
+            alarms.iter().any(|alarm| {
+                alarm.cleared.is_none()
+                    && alarm.source.device_id == Some(node.id)
+                    && matches!(alarm.kind, AlarmKind::Pipeline | AlarmKind::Stall)
+            })
+        })
+        .map(|node| node.label.clone())
+        .collect();
+
+    if failing.is_empty() {
+        SubsystemHealth::new(
+            HealthStatus::Ok,
+            format!("{} started outputs running", expected.len()),
+        )
+    } else {
+        SubsystemHealth::new(
+            HealthStatus::Degraded,
+            format!(
+                "{} of {} started outputs not running: {}",
+                failing.len(),
+                expected.len(),
+                failing.join(", ")
+            ),
+        )
+    }
+}
+
+/// Whether any DeckLink device has gone away. Devices are only known
+/// by name, not by the outputs using them
+fn devices(alarms: &[Alarm]) -> SubsystemHealth {
+    let removed: Vec<&str> = alarms
+        .iter()
+        .filter(|alarm| alarm.cleared.is_none() && alarm.kind == AlarmKind::DeviceRemoved)
+        .filter_map(|alarm| alarm.source.element.as_deref())
+        .collect();
+
+    if removed.is_empty() {
+        SubsystemHealth::new(HealthStatus::Ok, "No devices removed")
+    } else {
+        SubsystemHealth::new(
+            HealthStatus::Degraded,
+            format!("Devices removed: {}", removed.join(", ")),
+        )
+    }
+}
+
+/// Whether the system clock is synced closely enough, as told by the
+/// kernel
+#[cfg(target_os = "linux")]
+fn system_clock() -> SubsystemHealth {
+    // SAFETY: with no modes set, adjtimex only reads into `timex`
+    let mut timex: libc::timex = unsafe { std::mem::zeroed() };
+    let state = unsafe { libc::adjtimex(&mut timex) };
+
+    if state == libc::TIME_ERROR || timex.status & libc::STA_UNSYNC != 0 {
+        return SubsystemHealth::new(HealthStatus::Degraded, "System clock not synchronized");
+    }
+
+    let error = Duration::from_micros(timex.maxerror.max(0) as u64);
+    if error > MAX_CLOCK_ERROR {
+        SubsystemHealth::new(
+            HealthStatus::Degraded,
+            format!("System clock off by up to {:?}", error),
+        )
+    } else {
+        SubsystemHealth::new(
+            HealthStatus::Ok,
+            format!("System clock synchronized within {:?}", error),
+        )
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn system_clock() -> SubsystemHealth {
+    SubsystemHealth::new(HealthStatus::Ok, "System clock not checked")
+}
+
+/// Whether the system clock is synced, and every pipeline has a clock
+fn clocks(alarms: &[Alarm]) -> SubsystemHealth {
+    let lost = alarms
+        .iter()
+        .filter(|alarm| alarm.cleared.is_none() && alarm.kind == AlarmKind::ClockLoss)
+        .count();
+    if lost > 0 {
+        return SubsystemHealth::new(
+            HealthStatus::Degraded,
+            format!("{} pipelines lost their clock", lost),
+        );
+    }
+
+    system_clock()
+}
+
+/// Whether the process is alive and its actors are answering
+pub async fn liveness() -> HealthReport {
+    let mut subsystems = BTreeMap::new();
+    subsystems.insert("nodemanager", node_manager().await);
+
+    HealthReport::new(subsystems)
+}
+
+/// Whether vigil is doing everything it was asked to
+pub async fn readiness() -> HealthReport {
+    let mut subsystems = BTreeMap::new();
+    subsystems.insert("nodemanager", node_manager().await);
+
+    let alarms = match AlarmManager::from_registry()
+        .send(ListAlarmsMessage)
+        .timeout(ANSWER_TIMEOUT)
+        .await
+    {
+        Ok(alarms) => {
+            subsystems.insert(
+                "alarms",
+                SubsystemHealth::new(HealthStatus::Ok, "Answering"),
+            );
+            alarms
+        }
+        Err(err) => {
+            subsystems.insert(
+                "alarms",
+                SubsystemHealth::new(HealthStatus::Down, format!("Not answering: {}", err)),
+            );
+            vec![]
+        }
+    };
+
+    subsystems.insert("outputs", outputs(&alarms).await);
+    subsystems.insert("devices", devices(&alarms));
+    subsystems.insert("clocks", clocks(&alarms));
+
+    HealthReport::new(subsystems)
+}
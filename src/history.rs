@@ -0,0 +1,140 @@
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use anyhow::Error;
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Serialize};
+use tracing::debug;
+
+/// A history kept on disk as a file of JSON lines per day, named
+/// `{prefix}-YYYYMMDD.jsonl`, of which only the newest are kept
+#[derive(Debug)]
+pub struct DailyHistory {
+    /// Where the files are
+    dir: PathBuf,
+    prefix: &'static str,
+    /// How many files are kept before the oldest are removed
+    days: usize,
+}
+
+impl DailyHistory {
+    pub fn new(dir: PathBuf, prefix: &'static str, days: usize) -> Self {
+        Self { dir, prefix, days }
+    }
+
+    /// The daily files, oldest first
+    fn files(&self) -> Result<Vec<PathBuf>, Error> {
+        if !self.dir.exists() {
+            return Ok(vec![]);
+        }
+
+        let start = format!("{}-", self.prefix);
+        let mut files: Vec<PathBuf> = std::fs::read_dir(&self.dir)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .map_or(false, |name| {
+                        name.starts_with(&start) && name.ends_with(".jsonl")
+                    })
+            })
+            .collect();
+        files.sort();
+
+        Ok(files)
+    }
+
+    /// Remove the oldest files over the limit
+    fn prune(&self) -> Result<(), Error> {
+        let mut files = self.files()?;
+
+        if files.len() > self.days {
+            for path in files.drain(..files.len() - self.days) {
+                debug!("removing old history {}", path.display());
+                std::fs::remove_file(path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Read the newest records, at most `limit` of them, oldest first.
+    /// Only the newest files needed are read
+    pub fn load<T: DeserializeOwned>(&self, limit: usize) -> Result<Vec<T>, Error> {
+        let mut files = self.files()?;
+        let mut days: Vec<Vec<T>> = vec![];
+        let mut count = 0;
+
+        while let Some(path) = files.pop() {
+            if count >= limit {
+                break;
+            }
+
+            let file = std::fs::File::open(&path)?;
+            let mut day = vec![];
+            for line in BufReader::new(file).lines() {
+                match serde_json::from_str::<T>(&line?) {
+                    Ok(record) => day.push(record),
+                    Err(err) => debug!("skipping bad history line: {}", err),
+                }
+            }
+
+            count += day.len();
+            days.push(day);
+        }
+
+        let records: Vec<T> = days.into_iter().rev().flatten().collect();
+        let skip = records.len().saturating_sub(limit);
+
+        Ok(records.into_iter().skip(skip).collect())
+    }
+
+    /// Append a record to the file of the day of `time`, removing the
+    /// oldest files when a new day starts
+    pub fn append<T: Serialize>(&self, time: DateTime<Utc>, record: &T) -> Result<(), Error> {
+        std::fs::create_dir_all(&self.dir)?;
+
+        let path = self
+            .dir
+            .join(format!("{}-{}.jsonl", self.prefix, time.format("%Y%m%d")));
+        let new_day = !path.exists();
+
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+
+        if new_day {
+            self.prune()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn oldest_days_are_removed() {
+        let dir = tempfile::tempdir().unwrap();
+        let history = DailyHistory::new(dir.path().to_path_buf(), "test", 2);
+
+        for day in 1..=3 {
+            let time = Utc.with_ymd_and_hms(2024, 1, day, 12, 0, 0).unwrap();
+            history.append(time, &day).unwrap();
+            history.append(time, &(day * 10)).unwrap();
+        }
+
+        assert_eq!(
+            history.files().unwrap(),
+            vec![
+                dir.path().join("test-20240102.jsonl"),
+                dir.path().join("test-20240103.jsonl"),
+            ]
+        );
+        assert_eq!(history.load::<u32>(100).unwrap(), vec![2, 20, 3, 30]);
+        assert_eq!(history.load::<u32>(3).unwrap(), vec![20, 3, 30]);
+    }
+}
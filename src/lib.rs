@@ -0,0 +1,63 @@
+//! Plays out and monitors DeckLink outputs and inputs, controlled over
+//! HTTP and a websocket.
+//!
+//! The API is what is exported here: [`NodeManager`], the output and
+//! input backends and the messages they take, the [`command`] types
+//! spoken over the websocket, [`ServerBuilder`], or [`configure`] and
+//! [`start_services`] to serve from an app of one's own, and
+//! [`init_logging`]. Without the `web-ui` feature the server only
+//! serves the API, and the `vigilctl` client is only built with the
+//! `cli` feature.
+//!
+//! To drive nodes from a service of one's own, running in an actix
+//! system after GStreamer was initialized:
+//!
+//! ```no_run
+//! use actix::SystemService;
+//! use vigil::command::{Command, CommandResult};
+//! use vigil::{CommandMessage, NodeManager};
+//!
+//! # async fn example(device_id: uuid::Uuid) -> Result<(), anyhow::Error> {
+//! let result = NodeManager::from_registry()
+//!     .send(CommandMessage::new(Command::Start { device_id }))
+//!     .await?;
+//! if let CommandResult::Error(err) = result {
+//!     eprintln!("Failed to start {}: {}", device_id, err);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod command;
+
+mod alarm;
+mod config;
+mod controller;
+mod detector;
+mod device;
+mod health;
+mod history;
+mod logging;
+mod logs;
+mod loudness;
+mod media;
+mod metrics;
+mod multiviewer;
+mod node;
+mod pipeline;
+mod scheduler;
+mod server;
+#[cfg(feature = "otlp")]
+mod telemetry;
+mod watchdog;
+
+pub use logging::{init as init_logging, LoggingGuard};
+pub use node::{
+    CommandMessage, FormatMessage, GetLevelsMessage, GetThumbnailMessage, ListDevicesMessage,
+    LoudnessControlMessage, ModeMessage, NodeManager, OverlayMessage, PlayoutMessage,
+    RemoveMessage, StartMessage, StopMessage, SubscribePreviewMessage, Thumbnail, TransportMessage,
+};
+pub use pipeline::decklink::DecklinkStream;
+pub use pipeline::input::DecklinkInput;
+pub use pipeline::preview::PreviewDataMessage;
+pub use server::{configure, start_services, ServerBuilder};
@@ -0,0 +1,366 @@
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use actix::{Actor, AsyncContext, Context, Handler, Message, SpawnHandle, SystemService};
+use anyhow::{anyhow, Error};
+use chrono::{DateTime, Utc};
+use gstreamer as gst;
+use tracing::{error, info, Subscriber};
+use tracing_appender::non_blocking::{NonBlocking, WorkerGuard};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{reload, EnvFilter, Layer, Registry};
+
+use crate::command::LoggingStatus;
+use crate::logs::{LogFiles, LogLayer};
+
+/// Filter used when `VIGIL_LOG` is not set
+const DEFAULT_FILTER: &str = "warn";
+/// Filter of exported spans, apart from `VIGIL_LOG` so traces stay
+/// whole however quiet the log is
+#[cfg(feature = "otlp")]
+const EXPORT_FILTER: &str = "info";
+
+/// Where the tracing filter is swapped at runtime
+static FILTER: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+
+/// Keeps log files being written and spans being exported, flushing
+/// what is left when dropped
+#[derive(Debug)]
+pub struct LoggingGuard {
+    _files: Option<WorkerGuard>,
+    #[cfg(feature = "otlp")]
+    _telemetry: Option<crate::telemetry::Telemetry>,
+}
+
+/// The subscriber logging to stderr and to [`crate::logs`], and
+/// through it to `writer`, as `filter` lets through
+fn subscriber(
+    filter: reload::Layer<EnvFilter, Registry>,
+    writer: Option<NonBlocking>,
+) -> impl Subscriber + for<'a> LookupSpan<'a> {
+    let fmt_layer = tracing_subscriber::fmt::layer()
+        .with_thread_ids(true)
+        .with_target(true)
+        .with_span_events(
+            tracing_subscriber::fmt::format::FmtSpan::NEW
+                | tracing_subscriber::fmt::format::FmtSpan::CLOSE,
+        );
+
+    Registry::default().with(
+        fmt_layer
+            .and_then(LogLayer::new(writer))
+            .with_filter(filter),
+    )
+}
+
+/// Install the global subscriber, logging as filtered by `VIGIL_LOG`
+/// until changed through [`LogControl`]. Records are also kept for
+/// [`crate::logs`], written to log files and, with the `otlp` feature,
+/// exported as spans at info and above, for as long as the returned
+/// guard is held
+pub fn init() -> Result<LoggingGuard, Error> {
+    tracing_log::LogTracer::init()?;
+
+    let env_filter =
+        EnvFilter::try_from_env("VIGIL_LOG").unwrap_or_else(|_| EnvFilter::new(DEFAULT_FILTER));
+    let (filter_layer, handle) = reload::Layer::new(env_filter);
+    let _ = FILTER.set(handle);
+
+    // Running without log files beats not running at all
+    let (writer, guard) = match LogFiles::from_env() {
+        Ok(files) => {
+            let (writer, guard) = tracing_appender::non_blocking(files);
+            (Some(writer), Some(guard))
+        }
+        Err(err) => {
+            eprintln!("Failed to open log files, logging to stderr only: {}", err);
+            (None, None)
+        }
+    };
+
+    let subscriber = subscriber(filter_layer, writer);
+
+    #[cfg(feature = "otlp")]
+    let (subscriber, telemetry) = {
+        let (layer, telemetry) = crate::telemetry::layer()?.unzip();
+        let layer = layer.map(|layer| layer.with_filter(EnvFilter::new(EXPORT_FILTER)));
+        (subscriber.with(layer), telemetry)
+    };
+
+    tracing::subscriber::set_global_default(subscriber)?;
+
+    Ok(LoggingGuard {
+        _files: guard,
+        #[cfg(feature = "otlp")]
+        _telemetry: telemetry,
+    })
+}
+
+/// The tracing filter in force
+fn current_filter() -> String {
+    FILTER
+        .get()
+        .and_then(|handle| handle.with_current(|filter| filter.to_string()).ok())
+        .unwrap_or_default()
+}
+
+/// One setting that can be changed for a while
+#[derive(Debug)]
+struct Override {
+    /// What the setting is, as given
+    value: String,
+    /// When it goes back to how it was at startup
+    expires: Option<DateTime<Utc>>,
+    /// The pending expiry
+    timer: Option<SpawnHandle>,
+}
+
+/// Changes the tracing filter and GStreamer debug thresholds while
+/// running, turning them back to their startup values when a change
+/// expires
+#[derive(Debug)]
+pub struct LogControl {
+    /// The tracing filter at startup
+    initial_filter: String,
+    /// `GST_DEBUG` at startup
+    initial_gst_debug: String,
+    filter: Option<Override>,
+    gst_debug: Option<Override>,
+}
+
+impl Default for LogControl {
+    fn default() -> Self {
+        Self {
+            initial_filter: current_filter(),
+            initial_gst_debug: std::env::var("GST_DEBUG").unwrap_or_default(),
+            filter: None,
+            gst_debug: None,
+        }
+    }
+}
+
+impl Actor for LogControl {
+    type Context = Context<Self>;
+}
+
+impl actix::Supervised for LogControl {}
+
+impl SystemService for LogControl {}
+
+impl LogControl {
+    fn status(&self) -> LoggingStatus {
+        LoggingStatus {
+            filter: current_filter(),
+            filter_expires: self.filter.as_ref().and_then(|filter| filter.expires),
+            gst_debug: self
+                .gst_debug
+                .as_ref()
+                .map_or_else(|| self.initial_gst_debug.clone(), |gst| gst.value.clone()),
+            gst_debug_expires: self.gst_debug.as_ref().and_then(|gst| gst.expires),
+        }
+    }
+
+    /// Replace the tracing filter
+    fn apply_filter(filter: &str) -> Result<(), Error> {
+        let filter = EnvFilter::try_new(filter)
+            .map_err(|err| anyhow!("Invalid log filter {}: {}", filter, err))?;
+
+        FILTER
+            .get()
+            .ok_or_else(|| anyhow!("Logging was not set up to be changed"))?
+            .reload(filter)
+            .map_err(|err| anyhow!("Failed to change log filter: {}", err))
+    }
+
+    /// Replace every GStreamer debug threshold
+    fn apply_gst_debug(threshold: &str) {
+        gst::debug_set_threshold_from_string(threshold, true);
+    }
+
+    /// Remember a change, scheduling it to be undone after `duration`
+    /// by `revert`
+    fn schedule(
+        previous: Option<Override>,
+        value: String,
+        duration: Option<Duration>,
+        ctx: &mut Context<Self>,
+        revert: fn(&mut Self),
+    ) -> Override {
+        if let Some(timer) = previous.and_then(|previous| previous.timer) {
+            ctx.cancel_future(timer);
+        }
+
+        Override {
+            value,
+            expires: duration
+                .and_then(|duration| chrono::Duration::from_std(duration).ok())
+                .map(|duration| Utc::now() + duration),
+            timer: duration.map(|duration| ctx.run_later(duration, move |act, _| revert(act))),
+        }
+    }
+
+    fn reset_filter(&mut self) {
+        self.filter = None;
+
+        info!("Log filter back to {}", self.initial_filter);
+        if let Err(err) = Self::apply_filter(&self.initial_filter) {
+            error!("{}", err);
+        }
+    }
+
+    fn reset_gst_debug(&mut self) {
+        self.gst_debug = None;
+
+        info!("GStreamer debug back to {:?}", self.initial_gst_debug);
+        Self::apply_gst_debug(&self.initial_gst_debug);
+    }
+}
+
+/// Get the log filter and GStreamer debug thresholds
+#[derive(Debug)]
+pub struct GetLoggingMessage;
+
+impl Message for GetLoggingMessage {
+    type Result = Result<LoggingStatus, Error>;
+}
+
+impl Handler<GetLoggingMessage> for LogControl {
+    type Result = Result<LoggingStatus, Error>;
+
+    fn handle(&mut self, _msg: GetLoggingMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        Ok(self.status())
+    }
+}
+
+/// Change the tracing filter, in `VIGIL_LOG` syntax
+#[derive(Debug)]
+pub struct SetLogFilterMessage {
+    pub filter: String,
+    /// How long until the startup filter is restored, forever if
+    /// `None`
+    pub duration: Option<Duration>,
+}
+
+impl Message for SetLogFilterMessage {
+    type Result = Result<LoggingStatus, Error>;
+}
+
+impl Handler<SetLogFilterMessage> for LogControl {
+    type Result = Result<LoggingStatus, Error>;
+
+    fn handle(&mut self, msg: SetLogFilterMessage, ctx: &mut Context<Self>) -> Self::Result {
+        Self::apply_filter(&msg.filter)?;
+        info!("Log filter set to {} for {:?}", msg.filter, msg.duration);
+
+        self.filter = Some(Self::schedule(
+            self.filter.take(),
+            msg.filter,
+            msg.duration,
+            ctx,
+            Self::reset_filter,
+        ));
+
+        Ok(self.status())
+    }
+}
+
+/// Change the GStreamer debug thresholds, in `GST_DEBUG` syntax
+#[derive(Debug)]
+pub struct SetGstDebugMessage {
+    pub threshold: String,
+    /// How long until `GST_DEBUG` at startup is restored, forever if
+    /// `None`
+    pub duration: Option<Duration>,
+}
+
+impl Message for SetGstDebugMessage {
+    type Result = Result<LoggingStatus, Error>;
+}
+
+impl Handler<SetGstDebugMessage> for LogControl {
+    type Result = Result<LoggingStatus, Error>;
+
+    fn handle(&mut self, msg: SetGstDebugMessage, ctx: &mut Context<Self>) -> Self::Result {
+        Self::apply_gst_debug(&msg.threshold);
+        info!(
+            "GStreamer debug set to {} for {:?}",
+            msg.threshold, msg.duration
+        );
+
+        self.gst_debug = Some(Self::schedule(
+            self.gst_debug.take(),
+            msg.threshold,
+            msg.duration,
+            ctx,
+            Self::reset_gst_debug,
+        ));
+
+        Ok(self.status())
+    }
+}
+
+#[cfg(all(test, feature = "otlp"))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use futures::future::BoxFuture;
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry_sdk::export::trace::{ExportResult, SpanData, SpanExporter};
+    use opentelemetry_sdk::trace::TracerProvider;
+    use tracing::{debug_span, info_span};
+    use uuid::Uuid;
+
+    use super::*;
+
+    /// Stands in for an OTLP collector, keeping what it is sent
+    #[derive(Debug, Clone, Default)]
+    struct Collector(Arc<Mutex<Vec<SpanData>>>);
+
+    impl SpanExporter for Collector {
+        fn export(&mut self, batch: Vec<SpanData>) -> BoxFuture<'static, ExportResult> {
+            self.0.lock().unwrap().extend(batch);
+            Box::pin(std::future::ready(Ok(())))
+        }
+    }
+
+    #[test]
+    fn spans_exported_whatever_the_log_filter() {
+        let collector = Collector::default();
+        let provider = TracerProvider::builder()
+            .with_simple_exporter(collector.clone())
+            .build();
+        let (filter, _handle) = reload::Layer::new(EnvFilter::new(DEFAULT_FILTER));
+        let layer = tracing_opentelemetry::layer()
+            .with_tracer(provider.tracer("vigil"))
+            .with_filter(EnvFilter::new(EXPORT_FILTER));
+
+        tracing::subscriber::with_default(subscriber(filter, None).with(layer), || {
+            info_span!("command", command_id = %Uuid::nil()).in_scope(|| {
+                info_span!("dispatch").in_scope(|| {
+                    info_span!("state change", device_id = %Uuid::nil()).in_scope(|| {
+                        debug_span!("too detailed").in_scope(|| {});
+                    });
+                });
+            });
+        });
+        provider.force_flush();
+
+        let spans = collector.0.lock().unwrap();
+        let names: Vec<&str> = spans.iter().map(|span| span.name.as_ref()).collect();
+        assert_eq!(names, ["state change", "dispatch", "command"]);
+
+        // One trace, each span the child of the next
+        let trace_id = spans[2].span_context.trace_id();
+        assert!(spans
+            .iter()
+            .all(|span| span.span_context.trace_id() == trace_id));
+        assert_eq!(spans[0].parent_span_id, spans[1].span_context.span_id());
+        assert_eq!(spans[1].parent_span_id, spans[2].span_context.span_id());
+
+        assert!(spans[0]
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key.as_str() == "device_id"));
+    }
+}
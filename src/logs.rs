@@ -0,0 +1,462 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+use actix::Recipient;
+use anyhow::{anyhow, Error};
+use chrono::{NaiveDate, Utc};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Level, Subscriber};
+use tracing_appender::non_blocking::NonBlocking;
+use tracing_log::NormalizeEvent;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+use uuid::Uuid;
+
+use crate::command::{CommandResult, LogLevel, LogRecord};
+use crate::config::data_dir;
+use crate::controller::NotifyMessage;
+
+/// How many records are kept in memory
+const RING_SIZE: usize = 10000;
+
+/// How many log files are kept when `VIGIL_LOG_KEEP` is not set
+const DEFAULT_KEEP: usize = 14;
+
+/// When log files are started over
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rotation {
+    /// Every day, at midnight UTC
+    Daily,
+    /// Once a file grows past this many bytes
+    Size(u64),
+}
+
+impl Rotation {
+    /// Parse `VIGIL_LOG_ROTATE`, either `daily` or a size in
+    /// mebibytes such as `64M`
+    fn parse(value: &str) -> Result<Self, Error> {
+        let value = value.trim();
+        if value.eq_ignore_ascii_case("daily") {
+            return Ok(Rotation::Daily);
+        }
+
+        value
+            .strip_suffix(['M', 'm'])
+            .and_then(|size| size.parse::<u64>().ok())
+            .filter(|size| *size > 0)
+            .map(|size| Rotation::Size(size * 1024 * 1024))
+            .ok_or_else(|| {
+                anyhow!(
+                    "Invalid log rotation {}, expected daily or a size like 64M",
+                    value
+                )
+            })
+    }
+}
+
+/// Writes JSON lines to files under `data/logs`, starting a new one
+/// on rotation and removing the oldest past the retention limit.
+/// Names start with a timestamp so they sort by age
+#[derive(Debug)]
+pub struct LogFiles {
+    dir: PathBuf,
+    rotation: Rotation,
+    /// How many files are kept, the current one included
+    keep: usize,
+    file: Option<File>,
+    /// Day the current file was started
+    day: NaiveDate,
+    /// Bytes written to the current file
+    written: u64,
+}
+
+impl LogFiles {
+    /// Set up log files as configured by `VIGIL_LOG_ROTATE` and
+    /// `VIGIL_LOG_KEEP`
+    pub fn from_env() -> Result<Self, Error> {
+        let rotation = match std::env::var("VIGIL_LOG_ROTATE") {
+            Ok(value) => Rotation::parse(&value)?,
+            Err(_) => Rotation::Daily,
+        };
+        let keep = match std::env::var("VIGIL_LOG_KEEP") {
+            Ok(value) => value
+                .parse::<usize>()
+                .ok()
+                .filter(|keep| *keep > 0)
+                .ok_or_else(|| anyhow!("Invalid number of log files to keep {}", value))?,
+            Err(_) => DEFAULT_KEEP,
+        };
+
+        Self::new(data_dir().join("logs"), rotation, keep)
+    }
+
+    /// Set up log files in `dir`, starting the first one
+    fn new(dir: PathBuf, rotation: Rotation, keep: usize) -> Result<Self, Error> {
+        let mut files = Self {
+            dir,
+            rotation,
+            keep,
+            file: None,
+            day: Utc::now().date_naive(),
+            written: 0,
+        };
+        files.rotate()?;
+
+        Ok(files)
+    }
+
+    /// Start a new file, then remove the oldest ones over the limit
+    fn rotate(&mut self) -> io::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+
+        let now = Utc::now();
+        let path = self
+            .dir
+            .join(format!("vigil-{}.jsonl", now.format("%Y%m%dT%H%M%S%.3fZ")));
+        self.file = Some(File::options().create(true).append(true).open(path)?);
+        self.day = now.date_naive();
+        self.written = 0;
+
+        let mut files: Vec<PathBuf> = std::fs::read_dir(&self.dir)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .map_or(false, |name| {
+                        name.starts_with("vigil-") && name.ends_with(".jsonl")
+                    })
+            })
+            .collect();
+
+        if files.len() > self.keep {
+            files.sort();
+            for path in files.drain(..files.len() - self.keep) {
+                std::fs::remove_file(path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether `len` more bytes belong in a new file
+    fn due(&self, len: usize) -> bool {
+        match self.rotation {
+            Rotation::Daily => Utc::now().date_naive() != self.day,
+            Rotation::Size(size) => self.written > 0 && self.written + len as u64 > size,
+        }
+    }
+}
+
+impl Write for LogFiles {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.file.is_none() || self.due(buf.len()) {
+            self.rotate()?;
+        }
+
+        let file = self
+            .file
+            .as_mut()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "No log file"))?;
+        let written = file.write(buf)?;
+        self.written += written as u64;
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.file.as_mut() {
+            Some(file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A websocket session following the log
+#[derive(Debug)]
+pub struct Tail {
+    /// Only records about this device, all if `None`
+    pub device_id: Option<Uuid>,
+    /// The least severe records sent
+    pub level: LogLevel,
+    pub recipient: Recipient<NotifyMessage>,
+}
+
+impl Tail {
+    fn wants(&self, record: &LogRecord) -> bool {
+        record.level <= self.level
+            && self
+                .device_id
+                .map_or(true, |device_id| record.device_id == Some(device_id))
+    }
+}
+
+/// The latest records, and who is following them
+#[derive(Debug, Default)]
+struct Logbook {
+    records: Mutex<VecDeque<LogRecord>>,
+    /// Sessions following the log, by their identifier
+    tails: Mutex<HashMap<Uuid, Tail>>,
+}
+
+static LOGBOOK: OnceLock<Logbook> = OnceLock::new();
+
+fn logbook() -> &'static Logbook {
+    LOGBOOK.get_or_init(Logbook::default)
+}
+
+/// The latest records at least as severe as `level`, optionally only
+/// those about one device, oldest first
+pub fn recent(device_id: Option<Uuid>, level: LogLevel, limit: usize) -> Vec<LogRecord> {
+    let records = logbook().records.lock().unwrap();
+    let mut recent: Vec<LogRecord> = records
+        .iter()
+        .rev()
+        .filter(|record| record.level <= level)
+        .filter(|record| device_id.map_or(true, |device_id| record.device_id == Some(device_id)))
+        .take(limit)
+        .cloned()
+        .collect();
+    recent.reverse();
+
+    recent
+}
+
+/// Start sending new records to `session`, or stop with `None`
+pub fn tail(session: Uuid, tail: Option<Tail>) {
+    let mut tails = logbook().tails.lock().unwrap();
+    match tail {
+        Some(tail) => {
+            tails.insert(session, tail);
+        }
+        None => {
+            tails.remove(&session);
+        }
+    }
+}
+
+/// Device a span is about, from its `device_id` field
+#[derive(Debug)]
+struct DeviceSpan(Uuid);
+
+/// Collects the fields of an event or span
+#[derive(Debug, Default)]
+struct FieldVisitor {
+    message: Option<String>,
+    fields: serde_json::Map<String, serde_json::Value>,
+}
+
+impl FieldVisitor {
+    fn insert(&mut self, field: &Field, value: serde_json::Value) {
+        // Records forwarded from the log crate carry their metadata as
+        // fields, already taken into account
+        if !field.name().starts_with("log.") {
+            self.fields.insert(field.name().to_string(), value);
+        }
+    }
+
+    /// The device the fields are about
+    fn device_id(&self) -> Option<Uuid> {
+        self.fields
+            .get("device_id")
+            .and_then(|value| value.as_str())
+            .and_then(|value| Uuid::parse_str(value).ok())
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = Some(value.to_string());
+        } else {
+            self.insert(field, value.into());
+        }
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.insert(field, value.into());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.insert(field, value.into());
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.insert(field, value.into());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.insert(field, value.into());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = Some(format!("{:?}", value));
+        } else {
+            self.insert(field, format!("{:?}", value).into());
+        }
+    }
+}
+
+impl From<&Level> for LogLevel {
+    fn from(level: &Level) -> Self {
+        match *level {
+            Level::ERROR => LogLevel::Error,
+            Level::WARN => LogLevel::Warn,
+            Level::INFO => LogLevel::Info,
+            Level::DEBUG => LogLevel::Debug,
+            Level::TRACE => LogLevel::Trace,
+        }
+    }
+}
+
+/// Keeps the latest records in memory, sends them to websocket
+/// sessions tailing the log and writes them to `writer` as JSON lines.
+/// Records are tied to a device by their fields, or failing that by
+/// the closest span with a `device_id` field
+#[derive(Debug)]
+pub struct LogLayer {
+    writer: Option<NonBlocking>,
+}
+
+impl LogLayer {
+    pub fn new(writer: Option<NonBlocking>) -> Self {
+        Self { writer }
+    }
+}
+
+impl<S> Layer<S> for LogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+
+        if let (Some(device_id), Some(span)) = (visitor.device_id(), ctx.span(id)) {
+            span.extensions_mut().insert(DeviceSpan(device_id));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let normalized = event.normalized_metadata();
+        let metadata = normalized.as_ref().unwrap_or_else(|| event.metadata());
+
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+
+        let device_id = visitor.device_id().or_else(|| {
+            ctx.event_scope(event)?
+                .find_map(|span| span.extensions().get::<DeviceSpan>().map(|device| device.0))
+        });
+
+        let record = LogRecord {
+            time: Utc::now(),
+            level: metadata.level().into(),
+            target: metadata.target().to_string(),
+            message: visitor.message.unwrap_or_default(),
+            device_id,
+            fields: visitor.fields,
+        };
+
+        if let Some(writer) = &self.writer {
+            if let Ok(mut line) = serde_json::to_vec(&record) {
+                line.push(b'\n');
+                let _ = writer.make_writer().write_all(&line);
+            }
+        }
+
+        let logbook = logbook();
+
+        // Sending must not happen under the lock, in case it logs
+        let recipients: Vec<Recipient<NotifyMessage>> = logbook
+            .tails
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|tail| tail.wants(&record))
+            .map(|tail| tail.recipient.clone())
+            .collect();
+        for recipient in recipients {
+            recipient.do_send(NotifyMessage {
+                result: CommandResult::Log(record.clone()),
+            });
+        }
+
+        let mut records = logbook.records.lock().unwrap();
+        if records.len() >= RING_SIZE {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rotation() {
+        assert_eq!(Rotation::parse("daily").unwrap(), Rotation::Daily);
+        assert_eq!(Rotation::parse(" Daily ").unwrap(), Rotation::Daily);
+        assert_eq!(
+            Rotation::parse("64M").unwrap(),
+            Rotation::Size(64 * 1024 * 1024)
+        );
+        assert_eq!(Rotation::parse("1m").unwrap(), Rotation::Size(1024 * 1024));
+
+        for invalid in ["", "weekly", "64", "0M", "-1M", "1.5M", "64K"] {
+            assert!(Rotation::parse(invalid).is_err(), "{} parsed", invalid);
+        }
+    }
+
+    fn log_files(dir: &std::path::Path) -> Vec<String> {
+        let mut names: Vec<String> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn oldest_files_removed_past_retention() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in [
+            "vigil-20240101T000000.000Z.jsonl",
+            "vigil-20240102T000000.000Z.jsonl",
+            "vigil-20240103T000000.000Z.jsonl",
+            "notes.txt",
+        ] {
+            std::fs::write(dir.path().join(name), "").unwrap();
+        }
+
+        let _files = LogFiles::new(dir.path().to_path_buf(), Rotation::Daily, 2).unwrap();
+
+        let names = log_files(dir.path());
+        assert_eq!(names.len(), 3);
+        assert_eq!(names[0], "notes.txt");
+        assert_eq!(names[1], "vigil-20240103T000000.000Z.jsonl");
+        // The file just started is the newest
+        assert!(names[2] > names[1]);
+    }
+
+    #[test]
+    fn size_rotation_is_due_once_full() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut files = LogFiles::new(dir.path().to_path_buf(), Rotation::Size(10), 2).unwrap();
+
+        // A record never goes to a new file on its own
+        assert!(!files.due(20));
+
+        files.write_all(b"12345678").unwrap();
+        assert!(!files.due(2));
+        assert!(files.due(3));
+    }
+}
@@ -0,0 +1,384 @@
+use std::collections::{HashMap, VecDeque};
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use actix::{Actor, AsyncContext, Context, Handler, Message, MessageResult, SystemService};
+use anyhow::{anyhow, Error};
+use chrono::{DateTime, DurationRound, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{error, info};
+use uuid::Uuid;
+
+use crate::command::{LoudnessAlarm, ProgramLoudness};
+use crate::config::data_dir;
+use crate::history::DailyHistory;
+use crate::node::{LoudnessStatusMessage, NodeManager};
+
+/// How many past records are kept in memory for the API
+const HISTORY_LENGTH: usize = 10000;
+/// How many daily history files are kept before the oldest are removed
+const HISTORY_DAYS: usize = 90;
+/// How often records of minutes that have ended are written out
+const FLUSH_INTERVAL: Duration = Duration::from_secs(10);
+/// How long integrated loudness has to settle before it is checked
+/// against the target
+const INTEGRATION_GRACE: chrono::Duration = chrono::Duration::seconds(30);
+
+/// Loudness alarm thresholds, defaulting to EBU R128
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase", default)]
+pub struct LoudnessConfig {
+    /// Target integrated loudness, in LUFS
+    pub target: f64,
+    /// How far integrated loudness may stray from the target, in LU
+    pub tolerance: f64,
+    /// Highest permitted true-peak, in dBTP
+    pub max_true_peak: f64,
+    /// Highest permitted short-term loudness, in LUFS, unchecked when
+    /// absent
+    pub max_short_term: Option<f64>,
+}
+
+impl Default for LoudnessConfig {
+    fn default() -> Self {
+        Self {
+            target: -23.0,
+            tolerance: 1.0,
+            max_true_peak: -1.0,
+            max_short_term: None,
+        }
+    }
+}
+
+impl LoudnessConfig {
+    /// Check the thresholds make sense
+    fn validate(&self) -> Result<(), Error> {
+        if self.tolerance.is_nan() || self.tolerance < 0.0 {
+            return Err(anyhow!("Tolerance must not be negative"));
+        }
+
+        Ok(())
+    }
+
+    /// The thresholds `program` crosses
+    fn alarms(&self, program: &ProgramLoudness, now: DateTime<Utc>) -> Vec<LoudnessAlarm> {
+        let mut alarms = vec![];
+
+        if program
+            .recent_true_peak
+            .map_or(false, |peak| peak > self.max_true_peak)
+        {
+            alarms.push(LoudnessAlarm::TruePeak);
+        }
+
+        if let (Some(short_term), Some(max)) = (program.short_term, self.max_short_term) {
+            if short_term > max {
+                alarms.push(LoudnessAlarm::ShortTerm);
+            }
+        }
+
+        let settled = program
+            .since
+            .map_or(true, |since| now - since >= INTEGRATION_GRACE);
+        if settled
+            && program.integrated.map_or(false, |integrated| {
+                (integrated - self.target).abs() > self.tolerance
+            })
+        {
+            alarms.push(LoudnessAlarm::Integrated);
+        }
+
+        alarms
+    }
+}
+
+/// A summary of a minute of loudness of one audio program, in LUFS
+/// unless noted
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct LoudnessRecord {
+    pub device_id: Uuid,
+    /// Index of the program on the device
+    pub program: usize,
+    /// Start of the minute
+    pub time: DateTime<Utc>,
+    pub max_momentary: Option<f64>,
+    pub max_short_term: Option<f64>,
+    /// Highest true-peak in the minute, in dBTP
+    pub max_true_peak: Option<f64>,
+    /// Integrated loudness at the end of the minute
+    pub integrated: Option<f64>,
+    /// Loudness range at the end of the minute, in LU
+    pub range: Option<f64>,
+    /// When the integrated measurement was started or last reset
+    pub since: Option<DateTime<Utc>>,
+    /// Thresholds crossed at any point in the minute
+    pub alarms: Vec<LoudnessAlarm>,
+}
+
+/// The higher of two optional readings
+fn highest(a: Option<f64>, b: Option<f64>) -> Option<f64> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+impl LoudnessRecord {
+    fn new(device_id: Uuid, program: usize, time: DateTime<Utc>) -> Self {
+        Self {
+            device_id,
+            program,
+            time,
+            max_momentary: None,
+            max_short_term: None,
+            max_true_peak: None,
+            integrated: None,
+            range: None,
+            since: None,
+            alarms: vec![],
+        }
+    }
+
+    /// Fold a reading into the summary
+    fn update(&mut self, loudness: &ProgramLoudness) {
+        self.max_momentary = highest(self.max_momentary, loudness.momentary);
+        self.max_short_term = highest(self.max_short_term, loudness.short_term);
+        self.max_true_peak = highest(self.max_true_peak, loudness.recent_true_peak);
+        self.integrated = loudness.integrated;
+        self.range = loudness.range;
+        self.since = loudness.since;
+
+        for alarm in loudness.alarms.iter() {
+            if !self.alarms.contains(alarm) {
+                self.alarms.push(*alarm);
+            }
+        }
+    }
+}
+
+/// Checks loudness readings against the alarm thresholds and keeps a
+/// per-minute history of them on disk, as a record of compliance
+#[derive(Debug)]
+pub struct LoudnessLog {
+    config: LoudnessConfig,
+    /// Summaries of the current minute by device and program
+    current: HashMap<(Uuid, usize), LoudnessRecord>,
+    /// Most recent summaries, oldest first
+    history: VecDeque<LoudnessRecord>,
+    /// Where the thresholds are persisted
+    config_path: PathBuf,
+    /// Where a file of summaries is appended each day
+    history_files: DailyHistory,
+}
+
+impl Default for LoudnessLog {
+    fn default() -> Self {
+        let dir = data_dir();
+
+        Self {
+            config: LoudnessConfig::default(),
+            current: HashMap::new(),
+            history: VecDeque::new(),
+            config_path: dir.join("loudness.json"),
+            history_files: DailyHistory::new(dir.join("loudness"), "loudness", HISTORY_DAYS),
+        }
+    }
+}
+
+impl Actor for LoudnessLog {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        if let Err(err) = self.load() {
+            error!("Failed to load loudness history: {}", err);
+        }
+
+        info!("Loudness log coming online");
+
+        ctx.run_interval(FLUSH_INTERVAL, |act, _| act.flush(false));
+    }
+
+    fn stopped(&mut self, _ctx: &mut Self::Context) {
+        self.flush(true);
+    }
+}
+
+impl actix::Supervised for LoudnessLog {}
+
+impl SystemService for LoudnessLog {}
+
+impl LoudnessLog {
+    /// Load thresholds and recent history from disk
+    fn load(&mut self) -> Result<(), Error> {
+        if self.config_path.exists() {
+            let file = std::fs::File::open(&self.config_path)?;
+            self.config = serde_json::from_reader(BufReader::new(file))?;
+        }
+
+        for record in self.history_files.load(HISTORY_LENGTH)? {
+            self.push_history(record);
+        }
+
+        Ok(())
+    }
+
+    /// Write the thresholds to disk, replacing the previous file
+    fn save(&self) -> Result<(), Error> {
+        if let Some(dir) = self.config_path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let partial = self.config_path.with_extension("json.part");
+        serde_json::to_writer_pretty(std::fs::File::create(&partial)?, &self.config)?;
+        std::fs::rename(&partial, &self.config_path)?;
+
+        Ok(())
+    }
+
+    fn push_history(&mut self, record: LoudnessRecord) {
+        if self.history.len() == HISTORY_LENGTH {
+            self.history.pop_front();
+        }
+        self.history.push_back(record);
+    }
+
+    /// Add a finished summary to the history
+    fn record(&mut self, record: LoudnessRecord) {
+        if let Err(err) = self.history_files.append(record.time, &record) {
+            error!("Failed to write loudness history: {}", err);
+        }
+
+        self.push_history(record);
+    }
+
+    /// Record the summaries of minutes that have ended, or of every
+    /// minute with `all`
+    fn flush(&mut self, all: bool) {
+        let minute = Self::minute(Utc::now());
+        let mut done: Vec<LoudnessRecord> = vec![];
+
+        self.current.retain(|_, record| {
+            if all || record.time < minute {
+                done.push(record.clone());
+                false
+            } else {
+                true
+            }
+        });
+
+        done.sort_by_key(|record| (record.time, record.device_id, record.program));
+        for record in done {
+            self.record(record);
+        }
+    }
+
+    /// The start of the minute `time` falls in
+    fn minute(time: DateTime<Utc>) -> DateTime<Utc> {
+        time.duration_trunc(chrono::Duration::minutes(1))
+            .unwrap_or(time)
+    }
+}
+
+/// Loudness readings of a node, sent from any node to [`LoudnessLog`]
+#[derive(Debug)]
+pub struct MeasuredLoudnessMessage {
+    /// Unique identifier of the node
+    pub id: Uuid,
+    pub programs: Vec<ProgramLoudness>,
+}
+
+impl Message for MeasuredLoudnessMessage {
+    type Result = ();
+}
+
+impl Handler<MeasuredLoudnessMessage> for LoudnessLog {
+    type Result = ();
+
+    fn handle(&mut self, mut msg: MeasuredLoudnessMessage, _ctx: &mut Context<Self>) {
+        let now = Utc::now();
+        let minute = Self::minute(now);
+
+        self.flush(false);
+
+        for (index, program) in msg.programs.iter_mut().enumerate() {
+            program.alarms = self.config.alarms(program, now);
+
+            self.current
+                .entry((msg.id, index))
+                .or_insert_with(|| LoudnessRecord::new(msg.id, index, minute))
+                .update(program);
+        }
+
+        NodeManager::from_registry().do_send(LoudnessStatusMessage {
+            id: msg.id,
+            programs: msg.programs,
+        });
+    }
+}
+
+/// Get the loudness alarm thresholds
+#[derive(Debug)]
+pub struct GetLoudnessConfigMessage;
+
+impl Message for GetLoudnessConfigMessage {
+    type Result = LoudnessConfig;
+}
+
+impl Handler<GetLoudnessConfigMessage> for LoudnessLog {
+    type Result = MessageResult<GetLoudnessConfigMessage>;
+
+    fn handle(&mut self, _msg: GetLoudnessConfigMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(self.config.clone())
+    }
+}
+
+/// Replace the loudness alarm thresholds
+#[derive(Debug)]
+pub struct SetLoudnessConfigMessage {
+    pub config: LoudnessConfig,
+}
+
+impl Message for SetLoudnessConfigMessage {
+    type Result = Result<LoudnessConfig, Error>;
+}
+
+impl Handler<SetLoudnessConfigMessage> for LoudnessLog {
+    type Result = Result<LoudnessConfig, Error>;
+
+    fn handle(&mut self, msg: SetLoudnessConfigMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        msg.config.validate()?;
+
+        self.config = msg.config;
+        self.save()?;
+
+        Ok(self.config.clone())
+    }
+}
+
+/// List recent loudness summaries, newest first
+#[derive(Debug)]
+pub struct LoudnessHistoryMessage {
+    /// Only list summaries of this device
+    pub device_id: Option<Uuid>,
+}
+
+impl Message for LoudnessHistoryMessage {
+    type Result = Vec<LoudnessRecord>;
+}
+
+impl Handler<LoudnessHistoryMessage> for LoudnessLog {
+    type Result = MessageResult<LoudnessHistoryMessage>;
+
+    fn handle(&mut self, msg: LoudnessHistoryMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(
+            self.history
+                .iter()
+                .rev()
+                .filter(|record| msg.device_id.map_or(true, |id| record.device_id == id))
+                .cloned()
+                .collect(),
+        )
+    }
+}
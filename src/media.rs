@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use actix::{Actor, ActorFutureExt, Context, Handler, Message, ResponseActFuture, WrapFuture};
+use anyhow::{anyhow, Error};
+use chrono::{DateTime, Utc};
+use gst_pbutils::prelude::*;
+use gstreamer as gst;
+use gstreamer_pbutils as gst_pbutils;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, error, instrument, warn};
+
+use crate::command::OutputFormat;
+
+/// Media directory used when `VIGIL_MEDIA_DIR` is not set
+const DEFAULT_MEDIA_DIR: &str = "media";
+/// Largest upload in bytes when `VIGIL_MEDIA_MAX_UPLOAD` is not set
+const DEFAULT_MAX_UPLOAD: u64 = 16 * 1024 * 1024 * 1024;
+/// How long the discoverer may spend probing one file
+const DISCOVER_TIMEOUT: gst::ClockTime = gst::ClockTime::from_seconds(10);
+
+/// A video stream found in a media file
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct VideoStream {
+    pub codec: String,
+    pub width: u32,
+    pub height: u32,
+    /// Frames per second as a fraction
+    pub framerate: (i32, i32),
+    pub interlaced: bool,
+    /// Whether the stream is a single still picture
+    pub image: bool,
+}
+
+/// An audio stream found in a media file
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct AudioStream {
+    pub codec: String,
+    pub channels: u32,
+    pub sample_rate: u32,
+}
+
+/// A file in the media library and what the discoverer found in it
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct MediaInfo {
+    pub name: String,
+    /// Size in bytes
+    pub size: u64,
+    pub modified: DateTime<Utc>,
+    /// Duration in milliseconds, absent for stills and unknown lengths
+    pub duration_ms: Option<u64>,
+    pub video: Vec<VideoStream>,
+    pub audio: Vec<AudioStream>,
+    /// Reasons the file won't play cleanly on the output format it
+    /// was listed for
+    pub issues: Vec<String>,
+}
+
+/// Manages the media directory and caches discoverer results
+#[derive(Debug)]
+pub struct MediaLibrary {
+    /// Where media files are stored
+    dir: PathBuf,
+    /// Discovered files by name, with the modification time probed
+    cache: HashMap<String, (SystemTime, MediaInfo)>,
+}
+
+impl Default for MediaLibrary {
+    fn default() -> Self {
+        Self {
+            dir: media_dir(),
+            cache: HashMap::new(),
+        }
+    }
+}
+
+impl Actor for MediaLibrary {
+    type Context = Context<Self>;
+
+    fn started(&mut self, _ctx: &mut Self::Context) {
+        if let Err(err) = std::fs::create_dir_all(&self.dir) {
+            error!(
+                "Failed to create media directory {}: {}",
+                self.dir.display(),
+                err
+            );
+        }
+    }
+}
+
+impl actix::Supervised for MediaLibrary {}
+
+impl actix::SystemService for MediaLibrary {}
+
+/// The configured media directory
+pub fn media_dir() -> PathBuf {
+    std::env::var_os("VIGIL_MEDIA_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_MEDIA_DIR))
+}
+
+/// The configured largest upload, in bytes
+pub fn max_upload_size() -> u64 {
+    std::env::var("VIGIL_MEDIA_MAX_UPLOAD")
+        .ok()
+        .and_then(|size| size.parse().ok())
+        .unwrap_or(DEFAULT_MAX_UPLOAD)
+}
+
+/// Resolve a file name in the media directory, rejecting anything
+/// that could escape it or clash with partial uploads
+pub fn media_path(name: &str) -> Result<PathBuf, Error> {
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+        return Err(anyhow!("Invalid media name {}", name));
+    }
+
+    Ok(media_dir().join(name))
+}
+
+/// Probe a file with the discoverer
+#[instrument(level = "debug")]
+fn discover(path: &Path) -> Result<MediaInfo, Error> {
+    let metadata = std::fs::metadata(path)?;
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let mut media = MediaInfo {
+        name,
+        size: metadata.len(),
+        modified: metadata.modified()?.into(),
+        duration_ms: None,
+        video: vec![],
+        audio: vec![],
+        issues: vec![],
+    };
+
+    let uri = gst::glib::filename_to_uri(std::fs::canonicalize(path)?, None)?;
+    let discoverer = gst_pbutils::Discoverer::new(DISCOVER_TIMEOUT)?;
+
+    let info = match discoverer.discover_uri(&uri) {
+        Ok(info) => info,
+        Err(err) => {
+            media.issues.push(format!("Failed to probe: {}", err));
+            return Ok(media);
+        }
+    };
+
+    media.duration_ms = info.duration().map(|duration| duration.mseconds());
+
+    for stream in info.video_streams() {
+        let framerate = stream.framerate();
+        media.video.push(VideoStream {
+            codec: codec_name(stream.caps()),
+            width: stream.width(),
+            height: stream.height(),
+            framerate: (framerate.numer(), framerate.denom()),
+            interlaced: stream.is_interlaced(),
+            image: stream.is_image(),
+        });
+    }
+
+    for stream in info.audio_streams() {
+        media.audio.push(AudioStream {
+            codec: codec_name(stream.caps()),
+            channels: stream.channels(),
+            sample_rate: stream.sample_rate(),
+        });
+    }
+
+    Ok(media)
+}
+
+/// The media type of a stream, as a short codec name
+fn codec_name(caps: Option<gst::Caps>) -> String {
+    caps.as_ref()
+        .and_then(|caps| caps.structure(0))
+        .map(|s| s.name().to_string())
+        .unwrap_or_else(|| String::from("unknown"))
+}
+
+/// List the reasons `media` can't play cleanly on `format`
+fn check_compatibility(media: &MediaInfo, format: &OutputFormat) -> Vec<String> {
+    let mut issues = vec![];
+
+    if media.video.is_empty() && media.audio.is_empty() {
+        issues.push(String::from("No audio or video streams"));
+    }
+
+    for video in media.video.iter().filter(|video| !video.image) {
+        let (num, den) = video.framerate;
+        let (out_num, out_den) = format.framerate;
+
+        if num as i64 * out_den as i64 != out_num as i64 * den as i64 {
+            issues.push(format!(
+                "Frame rate {}/{} differs from output {}/{}",
+                num, den, out_num, out_den
+            ));
+        }
+
+        if video.interlaced != format.interlaced {
+            issues.push(format!(
+                "{} video on {} output",
+                if video.interlaced {
+                    "Interlaced"
+                } else {
+                    "Progressive"
+                },
+                if format.interlaced {
+                    "an interlaced"
+                } else {
+                    "a progressive"
+                },
+            ));
+        }
+    }
+
+    issues
+}
+
+/// Probe a file of the media directory unless it is hidden, not a
+/// file, or in `cached` with the same modification time
+fn probe_entry(
+    entry: &std::fs::DirEntry,
+    cached: &HashMap<String, SystemTime>,
+) -> Result<Option<(SystemTime, MediaInfo)>, Error> {
+    let name = entry.file_name().to_string_lossy().to_string();
+    let metadata = entry.metadata()?;
+
+    if name.starts_with('.') || !metadata.is_file() {
+        return Ok(None);
+    }
+
+    let modified = metadata.modified()?;
+    if cached.get(&name) == Some(&modified) {
+        return Ok(None);
+    }
+
+    debug!("probing media {}", name);
+    Ok(Some((modified, discover(&entry.path())?)))
+}
+
+/// List the media library, probing files that are new or changed,
+/// with the issues of playing each on `format`
+#[derive(Debug)]
+pub struct ListMediaMessage {
+    pub format: OutputFormat,
+}
+
+impl Message for ListMediaMessage {
+    type Result = Result<Vec<MediaInfo>, Error>;
+}
+
+impl Handler<ListMediaMessage> for MediaLibrary {
+    type Result = ResponseActFuture<Self, Result<Vec<MediaInfo>, Error>>;
+
+    fn handle(&mut self, msg: ListMediaMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        let dir = self.dir.clone();
+        let cached: HashMap<String, SystemTime> = self
+            .cache
+            .iter()
+            .map(|(name, (modified, _))| (name.clone(), *modified))
+            .collect();
+
+        Box::pin(
+            async move {
+                actix_web::web::block(move || -> Result<Vec<(SystemTime, MediaInfo)>, Error> {
+                    let mut probed = vec![];
+
+                    // One unreadable file doesn't hide the others
+                    for entry in std::fs::read_dir(&dir)? {
+                        let entry = match entry {
+                            Ok(entry) => entry,
+                            Err(err) => {
+                                warn!("Failed to read media directory entry: {}", err);
+                                continue;
+                            }
+                        };
+
+                        match probe_entry(&entry, &cached) {
+                            Ok(Some(media)) => probed.push(media),
+                            Ok(None) => {}
+                            Err(err) => warn!(
+                                "Skipping media {}: {}",
+                                entry.file_name().to_string_lossy(),
+                                err
+                            ),
+                        }
+                    }
+
+                    Ok(probed)
+                })
+                .await
+                .map_err(|err| anyhow!("Media probe failed: {}", err))?
+            }
+            .into_actor(self)
+            .map(move |res, slf, _ctx| {
+                for (modified, media) in res? {
+                    slf.cache.insert(media.name.clone(), (modified, media));
+                }
+
+                // Drop anything removed from the directory behind our back
+                let dir = slf.dir.clone();
+                slf.cache.retain(|name, _| dir.join(name).is_file());
+
+                let mut media: Vec<MediaInfo> = slf
+                    .cache
+                    .values()
+                    .map(|(_, media)| {
+                        let mut media = media.clone();
+                        let issues = check_compatibility(&media, &msg.format);
+                        media.issues.extend(issues);
+                        media
+                    })
+                    .collect();
+                media.sort_by(|a, b| a.name.cmp(&b.name));
+
+                Ok(media)
+            }),
+        )
+    }
+}
+
+/// Forget what was discovered about a file, sent after it is
+/// uploaded, renamed or deleted
+#[derive(Debug)]
+pub struct InvalidateMediaMessage {
+    pub name: String,
+}
+
+impl Message for InvalidateMediaMessage {
+    type Result = ();
+}
+
+impl Handler<InvalidateMediaMessage> for MediaLibrary {
+    type Result = ();
+
+    fn handle(&mut self, msg: InvalidateMediaMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        self.cache.remove(&msg.name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn media_path_stays_in_media_dir() {
+        for name in [
+            "",
+            "..",
+            "../etc/passwd",
+            "a/b",
+            "/etc/passwd",
+            "a\\b",
+            "..\\x",
+            ".hidden",
+            ".clip.mov.part",
+        ] {
+            assert!(media_path(name).is_err(), "accepted {:?}", name);
+        }
+
+        for name in ["clip.mov", "bars and tone.mp4", "a..b.mxf"] {
+            assert_eq!(media_path(name).unwrap(), media_dir().join(name));
+        }
+    }
+}
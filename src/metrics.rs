@@ -0,0 +1,530 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::time::Duration;
+
+use actix::{Actor, Context, Handler, Message, MessageResult, SystemService};
+use uuid::Uuid;
+
+use crate::command::{ChannelLevel, FrameStats};
+use crate::multiviewer::multiviewer_id;
+
+/// What a node looks like at the moment of a scrape, gathered by the
+/// [`NodeManager`](crate::node::NodeManager)
+#[derive(Clone, Debug)]
+pub struct NodeSample {
+    pub id: Uuid,
+    pub label: String,
+    /// "output" or "input"
+    pub kind: &'static str,
+    pub state: gstreamer::State,
+    /// How long the node has been playing
+    pub uptime: Option<Duration>,
+    pub levels: Vec<ChannelLevel>,
+    /// Frame counts of outputs
+    pub frames: Option<FrameStats>,
+}
+
+/// Everything the [`NodeManager`](crate::node::NodeManager) knows
+/// that is worth scraping
+#[derive(Clone, Debug, Default)]
+pub struct NodeMetrics {
+    pub nodes: Vec<NodeSample>,
+    /// Connected controller sessions
+    pub sessions: usize,
+}
+
+/// How much a counter of an element grew, taking a smaller value to
+/// mean the element was replaced and started again
+pub fn growth(previous: u64, current: u64) -> u64 {
+    if current >= previous {
+        current - previous
+    } else {
+        current
+    }
+}
+
+/// One metric with all its samples, written in the Prometheus text
+/// format
+struct Family<'a> {
+    name: &'a str,
+    help: &'a str,
+    kind: &'a str,
+    samples: Vec<(Vec<(&'a str, String)>, f64)>,
+}
+
+impl<'a> Family<'a> {
+    fn new(name: &'a str, kind: &'a str, help: &'a str) -> Self {
+        Self {
+            name,
+            help,
+            kind,
+            samples: vec![],
+        }
+    }
+
+    fn add(&mut self, labels: Vec<(&'a str, String)>, value: f64) {
+        self.samples.push((labels, value));
+    }
+
+    fn render(&self, out: &mut String) {
+        let _ = writeln!(out, "# HELP {} {}", self.name, self.help);
+        let _ = writeln!(out, "# TYPE {} {}", self.name, self.kind);
+
+        for (labels, value) in self.samples.iter() {
+            let labels: Vec<String> = labels
+                .iter()
+                .map(|(name, value)| format!("{}=\"{}\"", name, escape(value)))
+                .collect();
+
+            if labels.is_empty() {
+                let _ = writeln!(out, "{} {}", self.name, number(*value));
+            } else {
+                let _ = writeln!(
+                    out,
+                    "{}{{{}}} {}",
+                    self.name,
+                    labels.join(","),
+                    number(*value)
+                );
+            }
+        }
+    }
+}
+
+/// Escape a label value
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Format a sample value, spelling infinities the Prometheus way
+fn number(value: f64) -> String {
+    if value == f64::INFINITY {
+        "+Inf".to_string()
+    } else if value == f64::NEG_INFINITY {
+        "-Inf".to_string()
+    } else if value.is_nan() {
+        "NaN".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Numeric value of a pipeline state for the state gauge
+fn state_value(state: gstreamer::State) -> f64 {
+    match state {
+        gstreamer::State::VoidPending => 0.0,
+        gstreamer::State::Null => 1.0,
+        gstreamer::State::Ready => 2.0,
+        gstreamer::State::Paused => 3.0,
+        gstreamer::State::Playing => 4.0,
+        _ => 0.0,
+    }
+}
+
+/// Keeps the counters that nothing else tracks and turns them, along
+/// with a snapshot of the nodes, into Prometheus metrics
+#[derive(Debug, Default)]
+pub struct Metrics {
+    /// Commands handled by name and whether they succeeded
+    commands: BTreeMap<(&'static str, bool), u64>,
+    /// Pipeline errors of each node
+    errors: HashMap<Uuid, u64>,
+    /// Pipeline rebuilds of each node after an error
+    restarts: HashMap<Uuid, u64>,
+    /// Frames rendered by the video sinks of each node
+    rendered: HashMap<Uuid, u64>,
+    /// Frames last reported processed by node and sink, counted from
+    /// when the sink was created
+    sinks: HashMap<(Uuid, String), u64>,
+    /// Latency of each pipeline
+    latency: HashMap<Uuid, Duration>,
+}
+
+impl Actor for Metrics {
+    type Context = Context<Self>;
+}
+
+impl actix::Supervised for Metrics {}
+
+impl SystemService for Metrics {}
+
+impl Metrics {
+    /// Labels of the node with `id`
+    fn labels<'a>(
+        labels: &HashMap<Uuid, (String, &'static str)>,
+        id: &Uuid,
+    ) -> Vec<(&'a str, String)> {
+        let (label, kind) = match labels.get(id) {
+            Some((label, kind)) => (label.clone(), *kind),
+            None if *id == multiviewer_id() => ("Multiviewer".to_string(), "multiviewer"),
+            None => (id.to_string(), "unknown"),
+        };
+
+        vec![
+            ("id", id.to_string()),
+            ("label", label),
+            ("kind", kind.to_string()),
+        ]
+    }
+
+    fn render(&self, nodes: &NodeMetrics) -> String {
+        let labels: HashMap<Uuid, (String, &'static str)> = nodes
+            .nodes
+            .iter()
+            .map(|node| (node.id, (node.label.clone(), node.kind)))
+            .collect();
+
+        let mut state = Family::new(
+            "vigil_node_state",
+            "gauge",
+            "Pipeline state of the node (1 null, 2 ready, 3 paused, 4 playing)",
+        );
+        let mut up = Family::new("vigil_node_up", "gauge", "Whether the node is playing");
+        let mut uptime = Family::new(
+            "vigil_node_uptime_seconds",
+            "gauge",
+            "How long the node has been playing",
+        );
+        let mut peak = Family::new(
+            "vigil_audio_peak_dbfs",
+            "gauge",
+            "Latest peak audio level of each channel",
+        );
+        let mut rms = Family::new(
+            "vigil_audio_rms_dbfs",
+            "gauge",
+            "Latest RMS audio level of each channel",
+        );
+
+        for node in nodes.nodes.iter() {
+            let labels = Self::labels(&labels, &node.id);
+
+            state.add(labels.clone(), state_value(node.state));
+            up.add(
+                labels.clone(),
+                (node.state == gstreamer::State::Playing) as u8 as f64,
+            );
+            uptime.add(
+                labels.clone(),
+                node.uptime.map_or(0.0, |uptime| uptime.as_secs_f64()),
+            );
+
+            for (channel, level) in node.levels.iter().enumerate() {
+                let mut labels = labels.clone();
+                labels.push(("channel", (channel + 1).to_string()));
+                peak.add(labels.clone(), level.peak);
+                rms.add(labels, level.rms);
+            }
+        }
+
+        let mut errors = Family::new(
+            "vigil_node_errors_total",
+            "counter",
+            "Pipeline errors of the node",
+        );
+        for (id, count) in self.errors.iter() {
+            errors.add(Self::labels(&labels, id), *count as f64);
+        }
+
+        let mut restarts = Family::new(
+            "vigil_node_restarts_total",
+            "counter",
+            "Pipeline rebuilds of the node after an error",
+        );
+        for (id, count) in self.restarts.iter() {
+            restarts.add(Self::labels(&labels, id), *count as f64);
+        }
+
+        let mut rendered = Family::new(
+            "vigil_frames_rendered_total",
+            "counter",
+            "Frames rendered by the video sinks of the node, as reported by QoS",
+        );
+        for (id, count) in self.rendered.iter() {
+            rendered.add(Self::labels(&labels, id), *count as f64);
+        }
+
+        let mut dropped = Family::new(
+            "vigil_frames_dropped_total",
+            "counter",
+            "Frames the video sink of the output threw away for being too late",
+        );
+        let mut duplicated = Family::new(
+            "vigil_frames_duplicated_total",
+            "counter",
+            "Frames repeated to keep the output rate",
+        );
+        let mut late = Family::new(
+            "vigil_frames_late_total",
+            "counter",
+            "Frames reaching the video sink of the output after they were due",
+        );
+        let mut underruns = Family::new(
+            "vigil_frames_underruns_total",
+            "counter",
+            "Times the video sink of the output was left waiting for a frame",
+        );
+        for node in nodes.nodes.iter() {
+            if let Some(frames) = node.frames {
+                let labels = Self::labels(&labels, &node.id);
+                dropped.add(labels.clone(), frames.dropped as f64);
+                duplicated.add(labels.clone(), frames.duplicated as f64);
+                late.add(labels.clone(), frames.late as f64);
+                underruns.add(labels, frames.underruns as f64);
+            }
+        }
+
+        let mut latency = Family::new(
+            "vigil_pipeline_latency_seconds",
+            "gauge",
+            "Latency of the pipeline of the node",
+        );
+        for (id, value) in self.latency.iter() {
+            latency.add(Self::labels(&labels, id), value.as_secs_f64());
+        }
+
+        let mut sessions = Family::new(
+            "vigil_controller_sessions",
+            "gauge",
+            "Connected controller sessions",
+        );
+        sessions.add(vec![], nodes.sessions as f64);
+
+        let mut commands = Family::new(
+            "vigil_commands_total",
+            "counter",
+            "Commands handled by name and result",
+        );
+        for ((command, ok), count) in self.commands.iter() {
+            let result = if *ok { "success" } else { "error" };
+            commands.add(
+                vec![
+                    ("command", command.to_string()),
+                    ("result", result.to_string()),
+                ],
+                *count as f64,
+            );
+        }
+
+        let mut out = String::new();
+        for family in [
+            state, up, uptime, errors, restarts, rendered, dropped, duplicated, late, underruns,
+            peak, rms, latency, sessions, commands,
+        ] {
+            family.render(&mut out);
+        }
+
+        out
+    }
+}
+
+/// A command was handled, sent from the
+/// [`NodeManager`](crate::node::NodeManager) to [`Metrics`]
+#[derive(Debug)]
+pub struct CommandHandledMessage {
+    /// Name of the command
+    pub command: &'static str,
+    /// Whether it succeeded
+    pub ok: bool,
+}
+
+impl Message for CommandHandledMessage {
+    type Result = ();
+}
+
+impl Handler<CommandHandledMessage> for Metrics {
+    type Result = ();
+
+    fn handle(&mut self, msg: CommandHandledMessage, _ctx: &mut Context<Self>) {
+        *self.commands.entry((msg.command, msg.ok)).or_default() += 1;
+    }
+}
+
+/// Something happened to the pipeline of a node
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NodeEvent {
+    Error,
+    Restart,
+}
+
+/// Count an event of the pipeline of a node, sent from any node to
+/// [`Metrics`]
+#[derive(Debug)]
+pub struct NodeEventMessage {
+    /// Unique identifier of the node
+    pub id: Uuid,
+    pub event: NodeEvent,
+}
+
+impl Message for NodeEventMessage {
+    type Result = ();
+}
+
+impl Handler<NodeEventMessage> for Metrics {
+    type Result = ();
+
+    fn handle(&mut self, msg: NodeEventMessage, _ctx: &mut Context<Self>) {
+        let counts = match msg.event {
+            NodeEvent::Error => &mut self.errors,
+            NodeEvent::Restart => &mut self.restarts,
+        };
+
+        *counts.entry(msg.id).or_default() += 1;
+    }
+}
+
+/// QoS statistics of a video sink, sent from
+/// [`PipelineManager`](crate::pipeline::manager::PipelineManager) to
+/// [`Metrics`]
+#[derive(Debug)]
+pub struct QosMessage {
+    /// Unique identifier of the node
+    pub id: Uuid,
+    /// Path of the sink
+    pub element: String,
+    /// Frames processed since the sink was created
+    pub processed: u64,
+}
+
+impl Message for QosMessage {
+    type Result = ();
+}
+
+impl Handler<QosMessage> for Metrics {
+    type Result = ();
+
+    fn handle(&mut self, msg: QosMessage, _ctx: &mut Context<Self>) {
+        let previous = self
+            .sinks
+            .insert((msg.id, msg.element), msg.processed)
+            .unwrap_or_default();
+
+        *self.rendered.entry(msg.id).or_default() += growth(previous, msg.processed);
+    }
+}
+
+/// The latency of the pipeline of a node changed, sent from
+/// [`PipelineManager`](crate::pipeline::manager::PipelineManager) to
+/// [`Metrics`]
+#[derive(Debug)]
+pub struct LatencyMessage {
+    /// Unique identifier of the node
+    pub id: Uuid,
+    pub latency: Duration,
+}
+
+impl Message for LatencyMessage {
+    type Result = ();
+}
+
+impl Handler<LatencyMessage> for Metrics {
+    type Result = ();
+
+    fn handle(&mut self, msg: LatencyMessage, _ctx: &mut Context<Self>) {
+        self.latency.insert(msg.id, msg.latency);
+    }
+}
+
+/// Render every metric in the Prometheus text format
+#[derive(Debug)]
+pub struct RenderMetricsMessage {
+    pub nodes: NodeMetrics,
+}
+
+impl Message for RenderMetricsMessage {
+    type Result = String;
+}
+
+impl Handler<RenderMetricsMessage> for Metrics {
+    type Result = MessageResult<RenderMetricsMessage>;
+
+    fn handle(&mut self, msg: RenderMetricsMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(self.render(&msg.nodes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn family_without_labels() {
+        let mut family = Family::new("vigil_sessions", "gauge", "Connected sessions");
+        family.add(vec![], 3.0);
+
+        let mut out = String::new();
+        family.render(&mut out);
+        assert_eq!(
+            out,
+            "# HELP vigil_sessions Connected sessions\n\
+             # TYPE vigil_sessions gauge\n\
+             vigil_sessions 3\n"
+        );
+    }
+
+    #[test]
+    fn family_with_labels() {
+        let mut family = Family::new("vigil_audio_peak_dbfs", "gauge", "Peak level");
+        family.add(
+            vec![
+                ("label", "output 1".to_string()),
+                ("channel", "1".to_string()),
+            ],
+            -20.5,
+        );
+        family.add(
+            vec![
+                ("label", "output 2".to_string()),
+                ("channel", "1".to_string()),
+            ],
+            f64::NEG_INFINITY,
+        );
+
+        let mut out = String::new();
+        family.render(&mut out);
+        assert_eq!(
+            out,
+            "# HELP vigil_audio_peak_dbfs Peak level\n\
+             # TYPE vigil_audio_peak_dbfs gauge\n\
+             vigil_audio_peak_dbfs{label=\"output 1\",channel=\"1\"} -20.5\n\
+             vigil_audio_peak_dbfs{label=\"output 2\",channel=\"1\"} -Inf\n"
+        );
+    }
+
+    #[test]
+    fn empty_family_still_described() {
+        let mut out = String::new();
+        Family::new("vigil_errors_total", "counter", "Errors").render(&mut out);
+        assert_eq!(
+            out,
+            "# HELP vigil_errors_total Errors\n# TYPE vigil_errors_total counter\n"
+        );
+    }
+
+    #[test]
+    fn label_values_are_escaped() {
+        assert_eq!(escape("studio 1"), "studio 1");
+        assert_eq!(escape(r#"say "hi""#), r#"say \"hi\""#);
+        assert_eq!(escape(r"C:\media"), r"C:\\media");
+        assert_eq!(escape("two\nlines"), r"two\nlines");
+
+        let mut family = Family::new("vigil_up", "gauge", "Up");
+        family.add(vec![("label", "a \"b\"\\c".to_string())], 1.0);
+        let mut out = String::new();
+        family.render(&mut out);
+        assert!(out.ends_with("vigil_up{label=\"a \\\"b\\\"\\\\c\"} 1\n"));
+    }
+
+    #[test]
+    fn special_numbers() {
+        assert_eq!(number(f64::INFINITY), "+Inf");
+        assert_eq!(number(f64::NEG_INFINITY), "-Inf");
+        assert_eq!(number(f64::NAN), "NaN");
+        assert_eq!(number(0.0), "0");
+        assert_eq!(number(1.5), "1.5");
+        assert_eq!(number(-60.0), "-60");
+    }
+}
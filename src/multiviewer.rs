@@ -0,0 +1,534 @@
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use actix::{
+    Actor, ActorFutureExt, Addr, AsyncContext, Context, Handler, Message, MessageResult,
+    SystemService, WrapFuture,
+};
+use anyhow::{anyhow, Error};
+use gst::prelude::*;
+use gstreamer as gst;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, error, info, instrument};
+use uuid::Uuid;
+
+use crate::alarm::{AlarmManager, ClearDeviceAlarmsMessage, RaiseAlarmMessage};
+use crate::command::{AlarmKind, AlarmSource, Device, OutputFormat, Severity};
+use crate::config::data_dir;
+use crate::metrics::{Metrics, NodeEvent, NodeEventMessage};
+use crate::node::{ListDevicesMessage, NodeManager, SubscribePreviewMessage};
+use crate::pipeline::flow::Flow;
+use crate::pipeline::manager::{PipelineManager, StopManagerMessage};
+use crate::pipeline::multiviewer::{
+    build_mosaic, monitor_channel, tile_of_level, MosaicTile, TileState,
+};
+use crate::pipeline::preview::Preview;
+use crate::pipeline::{ErrorMessage, LevelMessage, SegmentDoneMessage};
+use crate::watchdog::{FlowMessage, RestartMessage, Watchdog};
+
+/// How often tally and labels are refreshed from the devices
+const TALLY_INTERVAL: Duration = Duration::from_millis(500);
+/// How long to wait before rebuilding a failed mosaic
+const RESTART_DELAY: Duration = Duration::from_secs(5);
+
+/// Identifier of the multiviewer, used in place of a device id to
+/// watch its preview
+pub fn multiviewer_id() -> Uuid {
+    Uuid::new_v5(&Uuid::NAMESPACE_OID, b"vigil-multiviewer")
+}
+
+/// Where the mosaic is sent
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MultiviewerOutput {
+    /// Nowhere but the browser preview
+    #[default]
+    Simulated,
+    /// A spare DeckLink output, by device number
+    Decklink(i32),
+}
+
+/// Where a device appears in the mosaic
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct TileSpec {
+    pub device_id: Uuid,
+    /// Name under the picture, defaults to the SDI output
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Top left cell of the tile
+    pub column: u32,
+    pub row: u32,
+    /// Size of the tile in cells
+    #[serde(default = "one_cell")]
+    pub width: u32,
+    #[serde(default = "one_cell")]
+    pub height: u32,
+}
+
+fn one_cell() -> u32 {
+    1
+}
+
+/// Layout and output of the multiviewer
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct MultiviewerConfig {
+    pub enabled: bool,
+    /// Format of the mosaic
+    #[serde(default)]
+    pub format: OutputFormat,
+    /// Size of the grid tiles are placed on
+    pub columns: u32,
+    pub rows: u32,
+    /// Tiles to show, every device in order when empty
+    #[serde(default)]
+    pub tiles: Vec<TileSpec>,
+    #[serde(default)]
+    pub output: MultiviewerOutput,
+}
+
+impl Default for MultiviewerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            format: OutputFormat::default(),
+            columns: 2,
+            rows: 2,
+            tiles: vec![],
+            output: MultiviewerOutput::default(),
+        }
+    }
+}
+
+impl MultiviewerConfig {
+    /// Check the format runs and every tile fits on the grid
+    fn validate(&self) -> Result<(), Error> {
+        self.format.validate()?;
+
+        if self.columns == 0 || self.rows == 0 {
+            return Err(anyhow!("Multiviewer grid needs at least one cell"));
+        }
+
+        for tile in self.tiles.iter() {
+            if tile.width == 0
+                || tile.height == 0
+                || tile.column + tile.width > self.columns
+                || tile.row + tile.height > self.rows
+            {
+                return Err(anyhow!(
+                    "Tile for {} does not fit a {}x{} grid",
+                    tile.device_id,
+                    self.columns,
+                    self.rows
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The tiles to show, filling the grid with `devices` in order when
+    /// none are configured
+    fn resolve_tiles(&self, devices: &[Device]) -> Vec<TileSpec> {
+        if !self.tiles.is_empty() {
+            return self.tiles.clone();
+        }
+
+        devices
+            .iter()
+            .take((self.columns * self.rows) as usize)
+            .enumerate()
+            .map(|(index, device)| TileSpec {
+                device_id: device.id,
+                label: None,
+                column: index as u32 % self.columns,
+                row: index as u32 / self.columns,
+                width: 1,
+                height: 1,
+            })
+            .collect()
+    }
+}
+
+/// The multiviewer configuration along with the id to watch it by
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct MultiviewerInfo {
+    pub id: Uuid,
+    #[serde(flatten)]
+    pub config: MultiviewerConfig,
+}
+
+/// Composites every output into one monitoring feed, with tally,
+/// labels, meters and timecode per tile
+#[derive(Debug)]
+pub struct Multiviewer {
+    /// Layout and output
+    config: MultiviewerConfig,
+    /// Where the configuration is persisted
+    config_path: PathBuf,
+    /// The mosaic pipeline, while running
+    pipeline: Option<gst::Pipeline>,
+    /// A helper for managing the pipeline
+    pipeline_manager: Option<Addr<PipelineManager>>,
+    /// What each tile shows around its picture, by device
+    tiles: Vec<(TileSpec, Arc<Mutex<TileState>>)>,
+    /// Who is watching the mosaic in a browser
+    preview: Arc<Mutex<Preview>>,
+    /// Buffers reaching the sink, for the [`Watchdog`]
+    flow: Arc<Mutex<Flow>>,
+}
+
+impl Default for Multiviewer {
+    fn default() -> Self {
+        Self {
+            config: MultiviewerConfig::default(),
+            config_path: data_dir().join("multiviewer.json"),
+            pipeline: None,
+            pipeline_manager: None,
+            tiles: vec![],
+            preview: Arc::new(Mutex::new(Preview::new(multiviewer_id()))),
+            flow: Arc::new(Mutex::new(Flow::default())),
+        }
+    }
+}
+
+impl Actor for Multiviewer {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        if let Err(err) = self.load() {
+            error!("Failed to load multiviewer configuration: {}", err);
+        }
+
+        info!("Multiviewer coming online");
+
+        self.rebuild(ctx);
+
+        ctx.run_interval(TALLY_INTERVAL, |act, ctx| {
+            act.refresh_tiles(ctx);
+            act.report_flow(ctx);
+        });
+    }
+
+    fn stopped(&mut self, _ctx: &mut Self::Context) {
+        self.teardown();
+    }
+}
+
+impl actix::Supervised for Multiviewer {}
+
+impl SystemService for Multiviewer {}
+
+impl Multiviewer {
+    /// Load the configuration from disk
+    fn load(&mut self) -> Result<(), Error> {
+        if self.config_path.exists() {
+            let file = std::fs::File::open(&self.config_path)?;
+            self.config = serde_json::from_reader(BufReader::new(file))?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the configuration to disk, replacing the previous file
+    fn save(&self) -> Result<(), Error> {
+        if let Some(dir) = self.config_path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let partial = self.config_path.with_extension("json.part");
+        serde_json::to_writer_pretty(std::fs::File::create(&partial)?, &self.config)?;
+        std::fs::rename(&partial, &self.config_path)?;
+
+        Ok(())
+    }
+
+    /// Stop the mosaic, releasing its output
+    fn teardown(&mut self) {
+        if let Some(manager) = self.pipeline_manager.take() {
+            manager.do_send(StopManagerMessage);
+        }
+
+        if let Some(pipeline) = self.pipeline.take() {
+            let _ = pipeline.set_state(gst::State::Null);
+        }
+
+        self.tiles.clear();
+    }
+
+    /// Rebuild the mosaic from the current configuration and devices
+    fn rebuild(&mut self, ctx: &mut Context<Self>) {
+        ctx.spawn(
+            async move { NodeManager::from_registry().send(ListDevicesMessage).await }
+                .into_actor(self)
+                .map(|res, act, ctx| {
+                    let res = res
+                        .map_err(|err| anyhow!("Internal server error {}", err))
+                        .and_then(|mut devices| {
+                            devices.sort_by_key(|device| device.device_num);
+                            act.build(&devices, ctx)
+                        });
+
+                    match res {
+                        Ok(()) => AlarmManager::from_registry().do_send(ClearDeviceAlarmsMessage {
+                            device_id: multiviewer_id(),
+                            kinds: vec![AlarmKind::Pipeline],
+                        }),
+                        Err(err) => error!("Failed to build multiviewer: {}", err),
+                    }
+                }),
+        );
+    }
+
+    /// Replace the mosaic with one showing `devices`
+    #[instrument(
+        level = "debug",
+        name = "building multiviewer",
+        skip(self, devices, ctx)
+    )]
+    fn build(&mut self, devices: &[Device], ctx: &mut Context<Self>) -> Result<(), Error> {
+        self.teardown();
+
+        if !self.config.enabled {
+            debug!("multiviewer disabled");
+            return Ok(());
+        }
+
+        let format = self.config.format;
+        let cell_width = format.width / self.config.columns as i32;
+        let cell_height = format.height / self.config.rows as i32;
+
+        let mut tiles = vec![];
+        let mut mosaic = vec![];
+
+        for spec in self.config.resolve_tiles(devices) {
+            let state = Arc::new(Mutex::new(TileState::default()));
+
+            mosaic.push(MosaicTile {
+                channel: monitor_channel(&spec.device_id),
+                x: spec.column as i32 * cell_width,
+                y: spec.row as i32 * cell_height,
+                // Even sizes keep subsampled formats happy
+                width: (spec.width as i32 * cell_width) & !1,
+                height: (spec.height as i32 * cell_height) & !1,
+                state: state.clone(),
+            });
+            tiles.push((spec, state));
+        }
+
+        let pipeline = build_mosaic(
+            &format,
+            &mosaic,
+            &self.config.output,
+            &self.preview,
+            &self.flow,
+        )?;
+
+        self.tiles = tiles;
+        self.update_tiles(devices);
+
+        self.pipeline_manager = Some(
+            PipelineManager::new(
+                pipeline.clone(),
+                ctx.address().downgrade().recipient(),
+                ctx.address().downgrade().recipient(),
+                Some(ctx.address().downgrade().recipient()),
+                multiviewer_id(),
+            )
+            .start(),
+        );
+
+        let addr = ctx.address();
+        pipeline.call_async(move |pipeline| {
+            if let Err(err) = pipeline.set_state(gst::State::Playing) {
+                addr.do_send(ErrorMessage::new(format!(
+                    "Failed to start multiviewer: {}",
+                    err
+                )));
+            }
+        });
+
+        self.pipeline = Some(pipeline);
+
+        Ok(())
+    }
+
+    /// Update tally and labels of the tiles from `devices`
+    fn update_tiles(&self, devices: &[Device]) {
+        for (spec, state) in self.tiles.iter() {
+            let device = devices.iter().find(|device| device.id == spec.device_id);
+            let mut state = state.lock().unwrap();
+
+            state.tally = device.map_or(false, |device| device.state == gst::State::Playing);
+            state.label = match (&spec.label, device) {
+                (Some(label), _) => label.clone(),
+                (None, Some(device)) => format!("SDI-{}", device.device_num + 1),
+                (None, None) => String::from("No device"),
+            };
+        }
+    }
+
+    /// Tell the [`Watchdog`] how long buffers have been stuck
+    fn report_flow(&self, ctx: &mut Context<Self>) {
+        let mut flow = self.flow.lock().unwrap();
+
+        match &self.pipeline {
+            Some(pipeline) if pipeline.current_state() == gst::State::Playing => flow.expect(),
+            _ => flow.pause(),
+        }
+
+        Watchdog::from_registry().do_send(FlowMessage {
+            id: multiviewer_id(),
+            name: String::from("Multiviewer"),
+            severity: Severity::Major,
+            stalled: flow.stalled(),
+            restart: ctx.address().recipient(),
+        });
+    }
+
+    /// Fetch the devices and update the tiles
+    fn refresh_tiles(&mut self, ctx: &mut Context<Self>) {
+        if self.tiles.is_empty() {
+            return;
+        }
+
+        ctx.spawn(
+            async move { NodeManager::from_registry().send(ListDevicesMessage).await }
+                .into_actor(self)
+                .map(|res, act, _ctx| {
+                    if let Ok(devices) = res {
+                        act.update_tiles(&devices);
+                    }
+                }),
+        );
+    }
+}
+
+impl Handler<ErrorMessage> for Multiviewer {
+    type Result = ();
+
+    fn handle(&mut self, msg: ErrorMessage, ctx: &mut Context<Self>) -> Self::Result {
+        error!("Got error message '{}' on multiviewer", msg.message);
+
+        AlarmManager::from_registry().do_send(RaiseAlarmMessage {
+            kind: AlarmKind::Pipeline,
+            severity: Severity::Major,
+            source: AlarmSource {
+                device_id: Some(multiviewer_id()),
+                element: msg.element,
+            },
+            message: format!("Multiviewer failed: {}", msg.message),
+            since: None,
+        });
+
+        Metrics::from_registry().do_send(NodeEventMessage {
+            id: multiviewer_id(),
+            event: NodeEvent::Error,
+        });
+
+        self.teardown();
+        ctx.run_later(RESTART_DELAY, |act, ctx| {
+            Metrics::from_registry().do_send(NodeEventMessage {
+                id: multiviewer_id(),
+                event: NodeEvent::Restart,
+            });
+            act.rebuild(ctx)
+        });
+    }
+}
+
+impl Handler<RestartMessage> for Multiviewer {
+    type Result = ();
+
+    fn handle(&mut self, _msg: RestartMessage, ctx: &mut Context<Self>) -> Self::Result {
+        Metrics::from_registry().do_send(NodeEventMessage {
+            id: multiviewer_id(),
+            event: NodeEvent::Restart,
+        });
+
+        self.rebuild(ctx);
+    }
+}
+
+impl Handler<SegmentDoneMessage> for Multiviewer {
+    type Result = ();
+
+    fn handle(&mut self, _msg: SegmentDoneMessage, _ctx: &mut Context<Self>) -> Self::Result {}
+}
+
+impl Handler<LevelMessage> for Multiviewer {
+    type Result = ();
+
+    fn handle(&mut self, msg: LevelMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        if let Some((_, state)) =
+            tile_of_level(&msg.element).and_then(|index| self.tiles.get(index))
+        {
+            state.lock().unwrap().levels = msg.levels;
+        }
+    }
+}
+
+impl Handler<SubscribePreviewMessage> for Multiviewer {
+    type Result = Result<(), Error>;
+
+    fn handle(&mut self, msg: SubscribePreviewMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        let mut preview = self.preview.lock().unwrap();
+
+        match msg.subscriber {
+            Some(subscriber) => preview.subscribe(msg.session, subscriber),
+            None => preview.unsubscribe(&msg.session),
+        }
+
+        Ok(())
+    }
+}
+
+/// Get the multiviewer configuration
+#[derive(Debug)]
+pub struct GetMultiviewerMessage;
+
+impl Message for GetMultiviewerMessage {
+    type Result = MultiviewerInfo;
+}
+
+impl Handler<GetMultiviewerMessage> for Multiviewer {
+    type Result = MessageResult<GetMultiviewerMessage>;
+
+    fn handle(&mut self, _msg: GetMultiviewerMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(MultiviewerInfo {
+            id: multiviewer_id(),
+            config: self.config.clone(),
+        })
+    }
+}
+
+/// Replace the multiviewer configuration, rebuilding the mosaic
+#[derive(Debug)]
+pub struct SetMultiviewerMessage {
+    pub config: MultiviewerConfig,
+}
+
+impl Message for SetMultiviewerMessage {
+    type Result = Result<MultiviewerInfo, Error>;
+}
+
+impl Handler<SetMultiviewerMessage> for Multiviewer {
+    type Result = Result<MultiviewerInfo, Error>;
+
+    fn handle(&mut self, msg: SetMultiviewerMessage, ctx: &mut Context<Self>) -> Self::Result {
+        msg.config.validate()?;
+
+        self.config = msg.config;
+        self.save()?;
+        self.rebuild(ctx);
+
+        Ok(MultiviewerInfo {
+            id: multiviewer_id(),
+            config: self.config.clone(),
+        })
+    }
+}
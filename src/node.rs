@@ -1,17 +1,38 @@
 use actix::{
-    Actor, ActorFutureExt, Addr, AsyncContext, Context, Handler, Message, MessageResult,
+    Actor, ActorFutureExt, Addr, AsyncContext, Context, Handler, Message, MessageResult, Recipient,
     ResponseActFuture, ResponseFuture, SystemService, WrapFuture,
 };
 use anyhow::{anyhow, Error};
-use std::collections::HashMap;
-use std::time::Duration;
-use tracing::{info, instrument};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tracing::{error, info, info_span, instrument, warn, Span};
 use tracing_actix::ActorInstrument;
 use uuid::Uuid;
 
-use crate::command::{Command, CommandResult, Device, NodeState, VideoMode};
-use crate::controller::{Controller, SyncMessage};
+use crate::alarm::{
+    AcknowledgeAlarmMessage, AlarmManager, ClearDeviceAlarmsMessage, ListAlarmsMessage,
+    ListShelvesMessage, RaiseAlarmMessage, RemoveShelfMessage, ShelveAlarmMessage,
+    UnshelveAlarmMessage,
+};
+use crate::command::{
+    Alarm, AlarmKind, AlarmSource, BulkAction, ChannelLevel, Command, CommandResult, Device,
+    DeviceResult, FrameStats, Input, InputSource, InputStatus, LoggingStatus, LoudnessAction,
+    NodeState, OutputFormat, Overlay, Playlist, PlayoutStatus, ProgramLoudness, SeekPosition,
+    Severity, Target, TransportStatus, VideoMode,
+};
+use crate::config::data_dir;
+use crate::controller::{Controller, NotifyMessage, SyncMessage};
+use crate::detector::{ContentDetector, ForgetContentMessage};
+use crate::logging::{GetLoggingMessage, LogControl, SetGstDebugMessage, SetLogFilterMessage};
+use crate::metrics::{CommandHandledMessage, Metrics, NodeMetrics, NodeSample};
+use crate::multiviewer::{multiviewer_id, Multiviewer};
 use crate::pipeline::decklink::DecklinkStream;
+use crate::pipeline::input::DecklinkInput;
+use crate::pipeline::preview::PreviewDataMessage;
 
 #[derive(Default)]
 pub struct NodeManager {
@@ -21,6 +42,38 @@ pub struct NodeManager {
     devices: HashMap<Uuid, Device>,
     /// connected socket sessions
     sessions: HashMap<Uuid, Addr<Controller>>,
+    /// Named groups of devices
+    groups: BTreeMap<String, Vec<Uuid>>,
+    /// Latest thumbnail of each device
+    thumbnails: HashMap<Uuid, Thumbnail>,
+    /// Latest audio level of each channel of each device
+    levels: BTreeMap<Uuid, Vec<ChannelLevel>>,
+    /// Whether levels changed since they were last pushed
+    levels_changed: bool,
+    /// All input nodes by id
+    input_nodes: HashMap<Uuid, Addr<DecklinkInput>>,
+    /// What is known of each input
+    inputs: HashMap<Uuid, Input>,
+    /// When each playing output or input started playing
+    up_since: HashMap<Uuid, Instant>,
+}
+
+/// How often audio levels are pushed to controllers
+const METERING_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A JPEG snapshot of what a device is outputting
+#[derive(Clone, Debug)]
+pub struct Thumbnail {
+    pub jpeg: Vec<u8>,
+    pub updated: DateTime<Utc>,
+}
+
+/// An input as persisted
+#[derive(Debug, Serialize, Deserialize)]
+struct InputSpec {
+    id: Uuid,
+    label: String,
+    source: InputSource,
 }
 
 /// Sent from [`controllers`](crate::controller::Controller), this is our
@@ -29,6 +82,19 @@ pub struct NodeManager {
 pub struct CommandMessage {
     /// The command to run
     pub command: Command,
+    /// Where the command came from, so it can be traced through the
+    /// nodes it reaches
+    pub span: Span,
+}
+
+impl CommandMessage {
+    /// A command sent from within the current span
+    pub fn new(command: Command) -> Self {
+        Self {
+            command,
+            span: Span::current(),
+        }
+    }
 }
 
 impl Message for CommandMessage {
@@ -45,12 +111,32 @@ impl SystemService for NodeManager {
     fn service_started(&mut self, ctx: &mut Context<Self>) {
         info!("Node manager coming online");
 
+        if let Err(err) = self.load_groups() {
+            error!("Failed to load device groups: {}", err);
+        }
+
+        if let Err(err) = self.load_inputs(ctx) {
+            error!("Failed to load inputs: {}", err);
+        }
+
         let devices: i32 = 4;
 
         for device_num in 0..devices {
-            let device_id = Uuid::new_v4();
+            // Derived from the device number, so ids survive restarts
+            let device_id = Uuid::new_v5(
+                &Uuid::NAMESPACE_OID,
+                format!("vigil-decklink-{}", device_num).as_bytes(),
+            );
+            let mode = VideoMode::TestCard("smpte".to_string());
+            let format = OutputFormat::default();
 
-            let _ = match DecklinkStream::new(ctx.address(), device_id, device_num) {
+            let _ = match DecklinkStream::new(
+                ctx.address(),
+                device_id,
+                device_num,
+                format,
+                mode.clone(),
+            ) {
                 Ok(stream) => {
                     let addr = stream.start();
 
@@ -61,6 +147,13 @@ impl SystemService for NodeManager {
                             id: device_id,
                             device_num,
                             state: gstreamer::State::Ready,
+                            format,
+                            mode,
+                            overlays: vec![],
+                            playout: None,
+                            transport: None,
+                            loudness: vec![],
+                            frames: FrameStats::default(),
                         },
                     );
 
@@ -70,24 +163,395 @@ impl SystemService for NodeManager {
             };
         }
 
+        ctx.run_interval(METERING_INTERVAL, |act, _| {
+            if !act.levels_changed {
+                return;
+            }
+            act.levels_changed = false;
+
+            for controller in act.sessions.values() {
+                controller.do_send(NotifyMessage {
+                    result: CommandResult::Metering(act.levels.clone()),
+                });
+            }
+        });
+
         ctx.run_interval(Duration::from_secs(2), |act, _| {
+            act.track_uptime();
+
             let sessions = act.sessions.clone();
             for (_, controller) in sessions.into_iter() {
                 let devices = act.devices.clone();
                 controller.do_send(SyncMessage {
                     device: devices.values().cloned().collect(),
                 });
+                controller.do_send(NotifyMessage {
+                    result: CommandResult::Inputs(act.list_inputs()),
+                });
             }
         });
     }
 }
 
 impl NodeManager {
-    fn start_source(
+    /// Note when outputs and inputs started or stopped playing
+    fn track_uptime(&mut self) {
+        let states = self
+            .devices
+            .values()
+            .map(|device| (device.id, device.state))
+            .chain(self.inputs.values().map(|input| (input.id, input.state)));
+
+        for (id, state) in states.collect::<Vec<_>>() {
+            if state == gstreamer::State::Playing {
+                self.up_since.entry(id).or_insert_with(Instant::now);
+            } else {
+                self.up_since.remove(&id);
+            }
+        }
+    }
+
+    /// Where device groups are persisted
+    fn groups_path() -> PathBuf {
+        data_dir().join("groups.json")
+    }
+
+    /// Load device groups from disk
+    fn load_groups(&mut self) -> Result<(), Error> {
+        let path = Self::groups_path();
+
+        if path.exists() {
+            let file = std::fs::File::open(&path)?;
+            self.groups = serde_json::from_reader(BufReader::new(file))?;
+        }
+
+        Ok(())
+    }
+
+    /// Write device groups to disk, replacing the previous file
+    fn save_groups(&self) -> Result<(), Error> {
+        let path = Self::groups_path();
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let partial = path.with_extension("json.part");
+        serde_json::to_writer_pretty(std::fs::File::create(&partial)?, &self.groups)?;
+        std::fs::rename(&partial, &path)?;
+
+        Ok(())
+    }
+
+    /// Where inputs are persisted
+    fn inputs_path() -> PathBuf {
+        data_dir().join("inputs.json")
+    }
+
+    /// Load inputs from disk, creating a node for each
+    fn load_inputs(&mut self, ctx: &mut Context<Self>) -> Result<(), Error> {
+        let path = Self::inputs_path();
+
+        if path.exists() {
+            let file = std::fs::File::open(&path)?;
+            let specs: Vec<InputSpec> = serde_json::from_reader(BufReader::new(file))?;
+
+            for spec in specs {
+                if let Err(err) = self.create_input(spec.id, spec.label, spec.source, ctx) {
+                    error!("Failed to create input {}: {}", spec.id, err);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write inputs to disk, replacing the previous file
+    fn save_inputs(&self) -> Result<(), Error> {
+        let path = Self::inputs_path();
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let specs: Vec<InputSpec> = self
+            .list_inputs()
+            .into_iter()
+            .map(|input| InputSpec {
+                id: input.id,
+                label: input.label,
+                source: input.source,
+            })
+            .collect();
+
+        let partial = path.with_extension("json.part");
+        serde_json::to_writer_pretty(std::fs::File::create(&partial)?, &specs)?;
+        std::fs::rename(&partial, &path)?;
+
+        Ok(())
+    }
+
+    /// Every input, by label
+    fn list_inputs(&self) -> Vec<Input> {
+        let mut inputs: Vec<Input> = self.inputs.values().cloned().collect();
+        inputs.sort_by(|a, b| a.label.cmp(&b.label).then(a.id.cmp(&b.id)));
+        inputs
+    }
+
+    /// Create a node for an input, stopped
+    fn create_input(
+        &mut self,
+        id: Uuid,
+        label: String,
+        source: InputSource,
+        ctx: &mut Context<Self>,
+    ) -> Result<(), Error> {
+        let node = DecklinkInput::new(ctx.address(), id, label.clone(), source)?;
+
+        self.input_nodes.insert(id, node.start());
+        self.inputs.insert(
+            id,
+            Input {
+                id,
+                label,
+                source,
+                state: gstreamer::State::Ready,
+                status: InputStatus::default(),
+                loudness: vec![],
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Start monitoring a new input
+    fn add_input(
+        &mut self,
+        label: String,
+        source: InputSource,
+        ctx: &mut Context<Self>,
+    ) -> CommandResult {
+        if let Some(existing) = self.inputs.values().find(|input| {
+            matches!(input.source, InputSource::Decklink(_)) && input.source == source
+        }) {
+            return CommandResult::Error(format!(
+                "{:?} is already monitored by input {}",
+                source, existing.id
+            ));
+        }
+
+        if let Err(err) = self.create_input(Uuid::new_v4(), label, source, ctx) {
+            return CommandResult::Error(format!("Failed to create input: {}", err));
+        }
+
+        match self.save_inputs() {
+            Ok(()) => CommandResult::Inputs(self.list_inputs()),
+            Err(err) => CommandResult::Error(format!("Failed to save inputs: {}", err)),
+        }
+    }
+
+    /// Stop monitoring an input and forget it
+    fn remove_input(&mut self, device_id: &Uuid) -> CommandResult {
+        match self.input_nodes.remove(device_id) {
+            Some(node) => node.do_send(RemoveMessage),
+            None => return CommandResult::Error(format!("No input with id {}", device_id)),
+        }
+
+        self.inputs.remove(device_id);
+        ContentDetector::from_registry().do_send(ForgetContentMessage { id: *device_id });
+        AlarmManager::from_registry().do_send(ClearDeviceAlarmsMessage {
+            device_id: *device_id,
+            kinds: vec![
+                AlarmKind::SignalLoss,
+                AlarmKind::Pipeline,
+                AlarmKind::ClockLoss,
+            ],
+        });
+        if self.levels.remove(device_id).is_some() {
+            self.levels_changed = true;
+        }
+
+        match self.save_inputs() {
+            Ok(()) => CommandResult::Inputs(self.list_inputs()),
+            Err(err) => CommandResult::Error(format!("Failed to save inputs: {}", err)),
+        }
+    }
+
+    /// Send a message to an input by id, applying `on_success` to our
+    /// view of the input once it has been handled
+    fn send_to_input<M, F>(
         &mut self,
         device_id: &Uuid,
-        _mode: &crate::command::VideoMode,
+        msg: M,
+        on_success: F,
+    ) -> ResponseActFuture<Self, CommandResult>
+    where
+        M: Message<Result = Result<(), Error>> + Send + 'static,
+        F: FnOnce(&mut Input) + 'static,
+        DecklinkInput: Handler<M>,
+    {
+        if let Some(node) = self.input_nodes.get(device_id) {
+            let node = node.clone();
+            let device_id = *device_id;
+            Box::pin(
+                {
+                    async move {
+                        match node.send(msg).await {
+                            Ok(res) => res,
+                            Err(err) => Err(anyhow!("Internal server error {}", err)),
+                        }
+                    }
+                    .into_actor(self)
+                    .then(move |res, slf, _ctx| {
+                        actix::fut::ready(match res {
+                            Ok(()) => {
+                                if let Some(input) = slf.inputs.get_mut(&device_id) {
+                                    on_success(input);
+                                }
+                                CommandResult::Success
+                            }
+                            Err(err) => CommandResult::Error(format!("{}", err)),
+                        })
+                    })
+                }
+                .in_current_actor_span(),
+            )
+        } else {
+            Box::pin(actix::fut::ready(CommandResult::Error(format!(
+                "No input with id {}",
+                device_id
+            ))))
+        }
+    }
+
+    /// Send a message to the [`AlarmManager`], answering with the
+    /// alarm it changed
+    fn send_to_alarms<M>(&mut self, msg: M) -> ResponseActFuture<Self, CommandResult>
+    where
+        M: Message<Result = Result<Alarm, Error>> + Send + 'static,
+        AlarmManager: Handler<M>,
+    {
+        Box::pin(
+            async move {
+                match AlarmManager::from_registry().send(msg).await {
+                    Ok(Ok(alarm)) => CommandResult::Alarm(alarm),
+                    Ok(Err(err)) => CommandResult::Error(format!("{}", err)),
+                    Err(err) => CommandResult::Error(format!("Internal server error {}", err)),
+                }
+            }
+            .into_actor(self),
+        )
+    }
+
+    /// Forward a command to the [`LogControl`]
+    fn send_to_logging<M>(&mut self, msg: M) -> ResponseActFuture<Self, CommandResult>
+    where
+        M: Message<Result = Result<LoggingStatus, Error>> + Send + 'static,
+        LogControl: Handler<M>,
+    {
+        Box::pin(
+            async move {
+                match LogControl::from_registry().send(msg).await {
+                    Ok(Ok(status)) => CommandResult::Logging(status),
+                    Ok(Err(err)) => CommandResult::Error(format!("{}", err)),
+                    Err(err) => CommandResult::Error(format!("Internal server error {}", err)),
+                }
+            }
+            .into_actor(self),
+        )
+    }
+
+    /// Create or replace a group
+    fn set_group(&mut self, name: String, devices: Vec<Uuid>) -> CommandResult {
+        if let Some(unknown) = devices.iter().find(|id| !self.devices.contains_key(id)) {
+            return CommandResult::Error(format!("No node with id {}", unknown));
+        }
+
+        self.groups.insert(name, devices);
+
+        match self.save_groups() {
+            Ok(()) => CommandResult::Groups(self.groups.clone()),
+            Err(err) => CommandResult::Error(format!("Failed to save groups: {}", err)),
+        }
+    }
+
+    /// Remove a group
+    fn remove_group(&mut self, name: &str) -> CommandResult {
+        if self.groups.remove(name).is_none() {
+            return CommandResult::Error(format!("No group named {}", name));
+        }
+
+        match self.save_groups() {
+            Ok(()) => CommandResult::Groups(self.groups.clone()),
+            Err(err) => CommandResult::Error(format!("Failed to save groups: {}", err)),
+        }
+    }
+
+    /// The devices a bulk command applies to, in device order
+    fn resolve_target(&self, target: &Target) -> Result<Vec<Uuid>, Error> {
+        let mut ids = match target {
+            Target::All => self.devices.keys().copied().collect(),
+            Target::Group(name) => self
+                .groups
+                .get(name)
+                .cloned()
+                .ok_or_else(|| anyhow!("No group named {}", name))?,
+            Target::Devices(ids) => ids.clone(),
+        };
+
+        // Ties broken by id, so repeats of one id end up next to each
+        // other, unknown ones included
+        ids.sort_by_key(|id| (self.devices.get(id).map(|device| device.device_num), *id));
+        ids.dedup();
+
+        Ok(ids)
+    }
+
+    /// Apply an action to every device in a target, collecting the
+    /// result for each
+    fn bulk(
+        &mut self,
+        target: &Target,
+        action: BulkAction,
+        ctx: &mut Context<Self>,
     ) -> ResponseActFuture<Self, CommandResult> {
+        let ids = match self.resolve_target(target) {
+            Ok(ids) => ids,
+            Err(err) => {
+                return Box::pin(actix::fut::ready(CommandResult::Error(format!("{}", err))))
+            }
+        };
+
+        // Each device goes through our own mailbox like a single
+        // command, so bulk and single commands behave the same
+        let addr = ctx.address();
+        let commands = ids.into_iter().map(move |device_id| {
+            let addr = addr.clone();
+            let command = action.command(device_id);
+
+            async move {
+                let result = match addr.send(CommandMessage::new(command)).await {
+                    Ok(result) => result,
+                    Err(err) => CommandResult::Error(format!("Internal server error {}", err)),
+                };
+
+                DeviceResult { device_id, result }
+            }
+        });
+
+        Box::pin(
+            async move { CommandResult::Bulk(futures::future::join_all(commands).await) }
+                .into_actor(self),
+        )
+    }
+
+    fn start_source(&mut self, device_id: &Uuid) -> ResponseActFuture<Self, CommandResult> {
+        if self.input_nodes.contains_key(device_id) {
+            return self.send_to_input(device_id, StartMessage::default(), |input| {
+                input.state = gstreamer::State::Playing
+            });
+        }
+
         if let Some(node) = self.nodes.get(device_id) {
             let node = node.clone();
             if let Some(device) = self.devices.get_mut(device_id) {
@@ -96,7 +560,7 @@ impl NodeManager {
             Box::pin(
                 {
                     async move {
-                        match node.recipient().send(StartMessage {}).await {
+                        match node.recipient().send(StartMessage::default()).await {
                             Ok(res) => res,
                             Err(err) => Err(anyhow!("Internal server error {}", err)),
                         }
@@ -119,13 +583,98 @@ impl NodeManager {
         }
     }
 
+    /// Send a message to a node by id, applying `on_success` to its
+    /// reply to update our view of the device
+    fn send_to_node<M, R, F>(
+        &mut self,
+        device_id: &Uuid,
+        msg: M,
+        on_success: F,
+    ) -> ResponseActFuture<Self, CommandResult>
+    where
+        M: Message<Result = Result<R, Error>> + Send + 'static,
+        R: Send + 'static,
+        F: FnOnce(&mut Device, R) + 'static,
+        DecklinkStream: Handler<M>,
+    {
+        if let Some(node) = self.nodes.get(device_id) {
+            let node = node.clone();
+            let device_id = *device_id;
+            Box::pin(
+                {
+                    async move {
+                        match node.send(msg).await {
+                            Ok(res) => res,
+                            Err(err) => Err(anyhow!("Internal server error {}", err)),
+                        }
+                    }
+                    .into_actor(self)
+                    .then(move |res, slf, _ctx| {
+                        actix::fut::ready(match res {
+                            Ok(reply) => {
+                                if let Some(device) = slf.devices.get_mut(&device_id) {
+                                    on_success(device, reply);
+                                }
+                                CommandResult::Success
+                            }
+                            Err(err) => CommandResult::Error(format!("{}", err)),
+                        })
+                    })
+                }
+                .in_current_actor_span(),
+            )
+        } else {
+            Box::pin(actix::fut::ready(CommandResult::Error(format!(
+                "No node with id {}",
+                device_id
+            ))))
+        }
+    }
+
     /// Tell a node to stop, by id
     fn stop_source(&mut self, device_id: &Uuid) -> CommandResult {
+        if let Some(node) = self.input_nodes.get(device_id) {
+            node.do_send(StopMessage::default());
+            ContentDetector::from_registry().do_send(ForgetContentMessage { id: *device_id });
+            // A stopped input is expected to have no signal
+            AlarmManager::from_registry().do_send(ClearDeviceAlarmsMessage {
+                device_id: *device_id,
+                kinds: vec![AlarmKind::SignalLoss, AlarmKind::Pipeline],
+            });
+
+            if let Some(input) = self.inputs.get_mut(device_id) {
+                input.state = gstreamer::State::Ready;
+                input.loudness.clear();
+            }
+
+            if self.levels.remove(device_id).is_some() {
+                self.levels_changed = true;
+            }
+
+            return CommandResult::Success;
+        }
+
         if let Some(node) = self.nodes.get_mut(device_id) {
-            node.clone().recipient().do_send(StopMessage);
+            node.clone().recipient().do_send(StopMessage::default());
+            ContentDetector::from_registry().do_send(ForgetContentMessage { id: *device_id });
 
             if let Some(device) = self.devices.get_mut(device_id) {
                 device.state = gstreamer::State::Ready;
+                device.loudness.clear();
+            }
+
+            // Stopped outputs post no more levels, so don't leave the
+            // last ones showing
+            if self.levels.remove(device_id).is_some() {
+                self.levels_changed = true;
+            }
+            for controller in self.sessions.values() {
+                controller.do_send(NotifyMessage {
+                    result: CommandResult::Loudness {
+                        device_id: *device_id,
+                        programs: vec![],
+                    },
+                });
             }
 
             CommandResult::Success
@@ -138,21 +687,216 @@ impl NodeManager {
 impl Handler<CommandMessage> for NodeManager {
     type Result = ResponseActFuture<Self, CommandResult>;
 
-    fn handle(&mut self, msg: CommandMessage, _ctx: &mut Self::Context) -> Self::Result {
-        match msg.command {
+    fn handle(&mut self, msg: CommandMessage, ctx: &mut Self::Context) -> Self::Result {
+        let command = msg.command.name();
+        // Health checks ping all the time, which would drown out the
+        // commands people send
+        let counted = !matches!(msg.command, Command::Ping {});
+        let span = info_span!(parent: &msg.span, "dispatch", command = %command);
+        let result = span.in_scope(|| self.dispatch(msg.command, ctx));
+
+        Box::pin(
+            result
+                .map(move |result, _, _| {
+                    if counted {
+                        Metrics::from_registry().do_send(CommandHandledMessage {
+                            command,
+                            ok: !matches!(result, CommandResult::Error(_)),
+                        });
+                    }
+                    result
+                })
+                .actor_instrument(span),
+        )
+    }
+}
+
+impl NodeManager {
+    /// Run a command on whichever node or service it is for
+    fn dispatch(
+        &mut self,
+        command: Command,
+        ctx: &mut Context<Self>,
+    ) -> ResponseActFuture<Self, CommandResult> {
+        match command {
             Command::Ping {} => Box::pin(actix::fut::ready(CommandResult::Pong)),
-            Command::Start { device_id } => {
-                self.start_source(&device_id, &VideoMode::TestCard("test".to_string()))
-            }
+            Command::Start { device_id } => self.start_source(&device_id),
             Command::Stop { device_id } => {
                 Box::pin(actix::fut::ready(self.stop_source(&device_id)))
             }
+            Command::SetOverlay {
+                device_id,
+                overlay,
+                enabled,
+            } => self.send_to_node(
+                &device_id,
+                OverlayMessage { overlay, enabled },
+                |device, overlays| device.overlays = overlays,
+            ),
+            Command::SetMode { device_id, mode } => self.send_to_node(
+                &device_id,
+                ModeMessage { mode: mode.clone() },
+                move |device, _| device.mode = mode,
+            ),
+            Command::LoadPlaylist {
+                device_id,
+                playlist,
+            } => self.send_to_node(&device_id, PlayoutMessage::Load(playlist), |device, _| {
+                device.mode = VideoMode::Playlist
+            }),
+            Command::Next { device_id } => {
+                self.send_to_node(&device_id, PlayoutMessage::Next, |_, _| ())
+            }
+            Command::Previous { device_id } => {
+                self.send_to_node(&device_id, PlayoutMessage::Previous, |_, _| ())
+            }
+            Command::Skip { device_id, index } => {
+                self.send_to_node(&device_id, PlayoutMessage::Skip(index), |_, _| ())
+            }
+            Command::Pause { device_id } => {
+                self.send_to_node(&device_id, TransportMessage::Pause, |device, _| {
+                    device.state = gstreamer::State::Paused
+                })
+            }
+            Command::Resume { device_id } => {
+                self.send_to_node(&device_id, TransportMessage::Resume, |device, _| {
+                    device.state = gstreamer::State::Playing
+                })
+            }
+            Command::Seek {
+                device_id,
+                position,
+            } => self.send_to_node(&device_id, TransportMessage::Seek(position), |_, _| ()),
+            Command::Loop { device_id, enabled } => {
+                self.send_to_node(&device_id, TransportMessage::Loop(enabled), |_, _| ())
+            }
+            Command::Cue { device_id } => {
+                self.send_to_node(&device_id, TransportMessage::Cue, |device, _| {
+                    device.state = gstreamer::State::Paused
+                })
+            }
+            Command::SetFormat { device_id, format } => {
+                if let Err(err) = format.validate() {
+                    return Box::pin(actix::fut::ready(CommandResult::Error(format!("{}", err))));
+                }
+
+                self.send_to_node(&device_id, FormatMessage { format }, move |device, _| {
+                    device.format = format
+                })
+            }
+            Command::Bulk { target, action } => self.bulk(&target, action, ctx),
+            Command::SetGroup { name, devices } => {
+                Box::pin(actix::fut::ready(self.set_group(name, devices)))
+            }
+            Command::RemoveGroup { name } => Box::pin(actix::fut::ready(self.remove_group(&name))),
+            Command::ListGroups {} => Box::pin(actix::fut::ready(CommandResult::Groups(
+                self.groups.clone(),
+            ))),
+            Command::AddInput { label, source } => {
+                Box::pin(actix::fut::ready(self.add_input(label, source, ctx)))
+            }
+            Command::RemoveInput { device_id } => {
+                Box::pin(actix::fut::ready(self.remove_input(&device_id)))
+            }
+            Command::ListInputs {} => {
+                Box::pin(actix::fut::ready(CommandResult::Inputs(self.list_inputs())))
+            }
+            Command::Loudness { device_id, action } => {
+                if self.input_nodes.contains_key(&device_id) {
+                    return self.send_to_input(
+                        &device_id,
+                        LoudnessControlMessage { action },
+                        |_| (),
+                    );
+                }
+
+                self.send_to_node(&device_id, LoudnessControlMessage { action }, |_, _| ())
+            }
+            Command::ListAlarms {} => Box::pin(
+                async move {
+                    match AlarmManager::from_registry().send(ListAlarmsMessage).await {
+                        Ok(alarms) => CommandResult::Alarms(alarms),
+                        Err(err) => CommandResult::Error(format!("Internal server error {}", err)),
+                    }
+                }
+                .into_actor(self),
+            ),
+            Command::AcknowledgeAlarm { alarm_id, by } => {
+                self.send_to_alarms(AcknowledgeAlarmMessage { id: alarm_id, by })
+            }
+            Command::ShelveAlarm {
+                alarm_id,
+                by,
+                duration_s,
+            } => self.send_to_alarms(ShelveAlarmMessage {
+                id: alarm_id,
+                by,
+                duration: duration_s.map(Duration::from_secs),
+            }),
+            Command::UnshelveAlarm { alarm_id } => {
+                self.send_to_alarms(UnshelveAlarmMessage { id: alarm_id })
+            }
+            Command::ListShelves {} => Box::pin(
+                async move {
+                    match AlarmManager::from_registry().send(ListShelvesMessage).await {
+                        Ok(shelves) => CommandResult::Shelves(shelves),
+                        Err(err) => CommandResult::Error(format!("Internal server error {}", err)),
+                    }
+                }
+                .into_actor(self),
+            ),
+            Command::RemoveShelf { shelf_id } => Box::pin(
+                async move {
+                    match AlarmManager::from_registry()
+                        .send(RemoveShelfMessage { id: shelf_id })
+                        .await
+                    {
+                        Ok(Ok(shelves)) => CommandResult::Shelves(shelves),
+                        Ok(Err(err)) => CommandResult::Error(format!("{}", err)),
+                        Err(err) => CommandResult::Error(format!("Internal server error {}", err)),
+                    }
+                }
+                .into_actor(self),
+            ),
+            Command::GetLogging {} => self.send_to_logging(GetLoggingMessage),
+            Command::SetLogFilter { filter, duration_s } => {
+                self.send_to_logging(SetLogFilterMessage {
+                    filter,
+                    duration: duration_s.map(Duration::from_secs),
+                })
+            }
+            Command::SetGstDebug {
+                threshold,
+                duration_s,
+            } => self.send_to_logging(SetGstDebugMessage {
+                threshold,
+                duration: duration_s.map(Duration::from_secs),
+            }),
+            Command::Preview { .. } => Box::pin(actix::fut::ready(CommandResult::Error(
+                String::from("Previews are only available over the control websocket"),
+            ))),
+            Command::TailLogs { .. } => Box::pin(actix::fut::ready(CommandResult::Error(
+                String::from("Log tails are only available over the control websocket"),
+            ))),
         }
     }
 }
 
+/// Stop a node, sent from [`NodeManager`] to any [`Node`], or stop
+/// every node when sent to [`NodeManager`]
 #[derive(Debug)]
-pub struct StopMessage;
+pub struct StopMessage {
+    /// Where the stop came from, to trace the state change it causes
+    pub span: Span,
+}
+
+impl Default for StopMessage {
+    fn default() -> Self {
+        Self {
+            span: Span::current(),
+        }
+    }
+}
 
 impl Message for StopMessage {
     type Result = Result<(), Error>;
@@ -161,7 +905,7 @@ impl Message for StopMessage {
 impl Handler<StoppedMessage> for NodeManager {
     type Result = MessageResult<StoppedMessage>;
 
-    #[instrument(level = "debug", name = "removing-node", skip(self, _ctx, msg), fields(id = %msg.id))]
+    #[instrument(level = "debug", name = "removing-node", skip(self, _ctx, msg), fields(device_id = %msg.id))]
     fn handle(&mut self, msg: StoppedMessage, _ctx: &mut Context<Self>) -> Self::Result {
         MessageResult(())
     }
@@ -173,7 +917,10 @@ impl Handler<StopMessage> for NodeManager {
     #[instrument(level = "info", name = "stopping manager", skip(self, _ctx, _msg))]
     fn handle(&mut self, _msg: StopMessage, _ctx: &mut Context<Self>) -> Self::Result {
         for (_id, node) in self.nodes.iter_mut() {
-            node.clone().recipient().do_send(StopMessage);
+            node.clone().recipient().do_send(StopMessage::default());
+        }
+        for node in self.input_nodes.values() {
+            node.do_send(StopMessage::default());
         }
 
         Box::pin(async move {
@@ -195,14 +942,545 @@ impl Message for StoppedMessage {
     type Result = ();
 }
 
+/// Shut a node down for good, sent from [`NodeManager`] to any
+/// [`Node`]
+#[derive(Debug)]
+pub struct RemoveMessage;
+
+impl Message for RemoveMessage {
+    type Result = ();
+}
+
+/// What is arriving on an input, sent from any input to
+/// [`NodeManager`]
+#[derive(Debug)]
+pub struct InputStatusMessage {
+    /// Unique identifier of the input
+    pub id: Uuid,
+    pub status: InputStatus,
+}
+
+impl Message for InputStatusMessage {
+    type Result = ();
+}
+
+impl Handler<InputStatusMessage> for NodeManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: InputStatusMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        let input = match self.inputs.get_mut(&msg.id) {
+            Some(input) => input,
+            None => return,
+        };
+
+        if input.status.signal != msg.status.signal {
+            if msg.status.signal {
+                info!(
+                    "Signal present on input {} ({})",
+                    input.label,
+                    msg.status.mode.as_deref().unwrap_or("unknown mode")
+                );
+            } else if input.state == gstreamer::State::Playing {
+                warn!("Signal lost on input {}", input.label);
+
+                AlarmManager::from_registry().do_send(RaiseAlarmMessage {
+                    kind: AlarmKind::SignalLoss,
+                    severity: Severity::Major,
+                    source: AlarmSource {
+                        device_id: Some(msg.id),
+                        element: None,
+                    },
+                    message: format!("Signal lost on input {}", input.label),
+                    since: None,
+                });
+            }
+        }
+
+        if msg.status.signal {
+            // A signal getting through means the pipeline has
+            // recovered from any error too
+            AlarmManager::from_registry().do_send(ClearDeviceAlarmsMessage {
+                device_id: msg.id,
+                kinds: vec![AlarmKind::SignalLoss, AlarmKind::Pipeline],
+            });
+        }
+
+        if !msg.status.signal {
+            // Nothing is being measured, so don't leave the last
+            // readings showing
+            input.loudness.clear();
+            if self.levels.remove(&msg.id).is_some() {
+                self.levels_changed = true;
+            }
+        }
+
+        input.status = msg.status;
+
+        for controller in self.sessions.values() {
+            controller.do_send(NotifyMessage {
+                result: CommandResult::Input(input.clone()),
+            });
+        }
+    }
+}
+
 /// Start a node, sent from [`NodeManager`] to any [`Node`]
 #[derive(Debug)]
-pub struct StartMessage {}
+pub struct StartMessage {
+    /// Where the start came from, to trace the state change it causes
+    pub span: Span,
+}
+
+impl Default for StartMessage {
+    fn default() -> Self {
+        Self {
+            span: Span::current(),
+        }
+    }
+}
 
 impl Message for StartMessage {
     type Result = Result<(), Error>;
 }
 
+/// Enable or disable an alignment marker, sent from [`NodeManager`]
+/// to any [`Node`]
+#[derive(Debug)]
+pub struct OverlayMessage {
+    /// The marker to change
+    pub overlay: Overlay,
+    /// Whether the marker should be drawn
+    pub enabled: bool,
+}
+
+impl Message for OverlayMessage {
+    /// The markers enabled after the change
+    type Result = Result<Vec<Overlay>, Error>;
+}
+
+/// Switch what a node outputs, sent from [`NodeManager`] to any
+/// [`Node`]
+#[derive(Debug)]
+pub struct ModeMessage {
+    /// The new mode
+    pub mode: VideoMode,
+}
+
+impl Message for ModeMessage {
+    type Result = Result<(), Error>;
+}
+
+/// Change the video format of a node, sent from [`NodeManager`] to
+/// any [`Node`]
+#[derive(Debug)]
+pub struct FormatMessage {
+    pub format: OutputFormat,
+}
+
+impl Message for FormatMessage {
+    type Result = Result<(), Error>;
+}
+
+/// Control the playlist of a node, sent from [`NodeManager`] to any
+/// [`Node`]
+#[derive(Debug)]
+pub enum PlayoutMessage {
+    /// Replace what the node shows with a playlist
+    Load(Playlist),
+    /// Take the next item
+    Next,
+    /// Take the previous item
+    Previous,
+    /// Take the item at an index
+    Skip(usize),
+}
+
+impl Message for PlayoutMessage {
+    type Result = Result<(), Error>;
+}
+
+/// Playlist progress of a node, sent from any node to [`NodeManager`]
+#[derive(Debug)]
+pub struct PlayoutStatusMessage {
+    /// Unique identifier of the node
+    pub id: Uuid,
+    /// Where the playlist is up to, `None` once unloaded
+    pub status: Option<PlayoutStatus>,
+}
+
+impl Message for PlayoutStatusMessage {
+    type Result = ();
+}
+
+impl Handler<PlayoutStatusMessage> for NodeManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: PlayoutStatusMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        if let Some(device) = self.devices.get_mut(&msg.id) {
+            device.playout = msg.status;
+        }
+    }
+}
+
+/// Frame counters of an output, sent from
+/// [`DecklinkStream`] to [`NodeManager`]
+#[derive(Debug)]
+pub struct FrameStatsMessage {
+    /// Unique identifier of the node
+    pub id: Uuid,
+    pub frames: FrameStats,
+}
+
+impl Message for FrameStatsMessage {
+    type Result = ();
+}
+
+impl Handler<FrameStatsMessage> for NodeManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: FrameStatsMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        if let Some(device) = self.devices.get_mut(&msg.id) {
+            device.frames = msg.frames;
+        }
+    }
+}
+
+/// Control playback of a stream, sent from [`NodeManager`] to any
+/// [`Node`]
+#[derive(Debug)]
+pub enum TransportMessage {
+    Pause,
+    Resume,
+    Seek(SeekPosition),
+    Loop(bool),
+    /// Preroll paused on the first frame
+    Cue,
+}
+
+impl Message for TransportMessage {
+    type Result = Result<(), Error>;
+}
+
+/// Playback progress of a node, sent from any node to [`NodeManager`]
+#[derive(Debug)]
+pub struct TransportStatusMessage {
+    /// Unique identifier of the node
+    pub id: Uuid,
+    /// Where the stream is up to, `None` when not showing a stream
+    pub status: Option<TransportStatus>,
+}
+
+impl Message for TransportStatusMessage {
+    type Result = ();
+}
+
+impl Handler<TransportStatusMessage> for NodeManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: TransportStatusMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        if let Some(device) = self.devices.get_mut(&msg.id) {
+            device.transport = msg.status;
+        }
+    }
+}
+
+/// List every device
+#[derive(Debug)]
+pub struct ListDevicesMessage;
+
+impl Message for ListDevicesMessage {
+    type Result = Vec<Device>;
+}
+
+impl Handler<ListDevicesMessage> for NodeManager {
+    type Result = MessageResult<ListDevicesMessage>;
+
+    fn handle(&mut self, _msg: ListDevicesMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(self.devices.values().cloned().collect())
+    }
+}
+
+/// Start or stop streaming a preview to a websocket session, sent
+/// from [`Controller`] to [`NodeManager`]
+#[derive(Debug)]
+pub struct PreviewMessage {
+    pub device_id: Uuid,
+    /// The session of the controller asking
+    pub session: Uuid,
+    pub enabled: bool,
+}
+
+impl Message for PreviewMessage {
+    type Result = CommandResult;
+}
+
+impl Handler<PreviewMessage> for NodeManager {
+    type Result = ResponseActFuture<Self, CommandResult>;
+
+    fn handle(&mut self, msg: PreviewMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        let subscriber = match self.sessions.get(&msg.session) {
+            Some(controller) if msg.enabled => Some(controller.clone().recipient()),
+            Some(_) => None,
+            None => {
+                return Box::pin(actix::fut::ready(CommandResult::Error(format!(
+                    "No session with id {}",
+                    msg.session
+                ))))
+            }
+        };
+
+        if msg.device_id == multiviewer_id() {
+            Multiviewer::from_registry().do_send(SubscribePreviewMessage {
+                session: msg.session,
+                subscriber,
+            });
+
+            return Box::pin(actix::fut::ready(CommandResult::Success));
+        }
+
+        if self.input_nodes.contains_key(&msg.device_id) {
+            return self.send_to_input(
+                &msg.device_id,
+                SubscribePreviewMessage {
+                    session: msg.session,
+                    subscriber,
+                },
+                |_| (),
+            );
+        }
+
+        self.send_to_node(
+            &msg.device_id,
+            SubscribePreviewMessage {
+                session: msg.session,
+                subscriber,
+            },
+            |_, _| (),
+        )
+    }
+}
+
+/// Add or remove a viewer of a node's preview, sent from
+/// [`NodeManager`] to any [`Node`]
+#[derive(Debug)]
+pub struct SubscribePreviewMessage {
+    /// The websocket session of the viewer
+    pub session: Uuid,
+    /// Where to send the stream, `None` to stop
+    pub subscriber: Option<Recipient<PreviewDataMessage>>,
+}
+
+impl Message for SubscribePreviewMessage {
+    type Result = Result<(), Error>;
+}
+
+/// A new thumbnail of a node, sent from any node to [`NodeManager`]
+#[derive(Debug)]
+pub struct ThumbnailMessage {
+    /// Unique identifier of the node
+    pub id: Uuid,
+    /// The thumbnail as a JPEG image
+    pub jpeg: Vec<u8>,
+}
+
+impl Message for ThumbnailMessage {
+    type Result = ();
+}
+
+impl Handler<ThumbnailMessage> for NodeManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: ThumbnailMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        let updated = Utc::now();
+
+        self.thumbnails.insert(
+            msg.id,
+            Thumbnail {
+                jpeg: msg.jpeg,
+                updated,
+            },
+        );
+
+        for controller in self.sessions.values() {
+            controller.do_send(NotifyMessage {
+                result: CommandResult::Thumbnail {
+                    device_id: msg.id,
+                    updated,
+                },
+            });
+        }
+    }
+}
+
+/// Audio levels of a node, sent from any node to [`NodeManager`]
+#[derive(Debug)]
+pub struct LevelsMessage {
+    /// Unique identifier of the node
+    pub id: Uuid,
+    pub levels: Vec<ChannelLevel>,
+}
+
+impl Message for LevelsMessage {
+    type Result = ();
+}
+
+impl Handler<LevelsMessage> for NodeManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: LevelsMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        self.levels.insert(msg.id, msg.levels);
+        self.levels_changed = true;
+    }
+}
+
+/// Get the latest audio levels of every node
+#[derive(Debug)]
+pub struct GetLevelsMessage;
+
+impl Message for GetLevelsMessage {
+    type Result = BTreeMap<Uuid, Vec<ChannelLevel>>;
+}
+
+impl Handler<GetLevelsMessage> for NodeManager {
+    type Result = MessageResult<GetLevelsMessage>;
+
+    fn handle(&mut self, _msg: GetLevelsMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(self.levels.clone())
+    }
+}
+
+/// Start, stop or reset the integrated loudness measurement of a
+/// node, sent from [`NodeManager`] to any [`Node`]
+#[derive(Debug)]
+pub struct LoudnessControlMessage {
+    pub action: LoudnessAction,
+}
+
+impl Message for LoudnessControlMessage {
+    type Result = Result<(), Error>;
+}
+
+/// Loudness of a node checked against the alarm thresholds, sent from
+/// [`LoudnessLog`](crate::loudness::LoudnessLog) to [`NodeManager`]
+#[derive(Debug)]
+pub struct LoudnessStatusMessage {
+    /// Unique identifier of the node
+    pub id: Uuid,
+    pub programs: Vec<ProgramLoudness>,
+}
+
+impl Message for LoudnessStatusMessage {
+    type Result = ();
+}
+
+impl Handler<LoudnessStatusMessage> for NodeManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: LoudnessStatusMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        if let Some(device) = self.devices.get_mut(&msg.id) {
+            device.loudness = msg.programs.clone();
+        }
+        if let Some(input) = self.inputs.get_mut(&msg.id) {
+            input.loudness = msg.programs.clone();
+        }
+
+        for controller in self.sessions.values() {
+            controller.do_send(NotifyMessage {
+                result: CommandResult::Loudness {
+                    device_id: msg.id,
+                    programs: msg.programs.clone(),
+                },
+            });
+        }
+    }
+}
+
+/// An alarm changed, sent from [`AlarmManager`] to [`NodeManager`]
+#[derive(Debug)]
+pub struct AlarmMessage {
+    pub alarm: Alarm,
+}
+
+impl Message for AlarmMessage {
+    type Result = ();
+}
+
+impl Handler<AlarmMessage> for NodeManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: AlarmMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        for controller in self.sessions.values() {
+            controller.do_send(NotifyMessage {
+                result: CommandResult::Alarm(msg.alarm.clone()),
+            });
+        }
+    }
+}
+
+/// Get what is worth scraping of every output and input
+#[derive(Debug)]
+pub struct NodeMetricsMessage;
+
+impl Message for NodeMetricsMessage {
+    type Result = NodeMetrics;
+}
+
+impl Handler<NodeMetricsMessage> for NodeManager {
+    type Result = MessageResult<NodeMetricsMessage>;
+
+    fn handle(&mut self, _msg: NodeMetricsMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        self.track_uptime();
+
+        let outputs = self.devices.values().map(|device| NodeSample {
+            id: device.id,
+            label: format!("Output {}", device.device_num),
+            kind: "output",
+            state: device.state,
+            uptime: None,
+            levels: vec![],
+            frames: Some(device.frames),
+        });
+        let inputs = self.inputs.values().map(|input| NodeSample {
+            id: input.id,
+            label: input.label.clone(),
+            kind: "input",
+            state: input.state,
+            uptime: None,
+            levels: vec![],
+            frames: None,
+        });
+
+        let mut nodes: Vec<NodeSample> = outputs.chain(inputs).collect();
+        for node in nodes.iter_mut() {
+            node.uptime = self.up_since.get(&node.id).map(|since| since.elapsed());
+            node.levels = self.levels.get(&node.id).cloned().unwrap_or_default();
+        }
+
+        MessageResult(NodeMetrics {
+            nodes,
+            sessions: self.sessions.len(),
+        })
+    }
+}
+
+/// Get the latest thumbnail of a node
+#[derive(Debug)]
+pub struct GetThumbnailMessage {
+    pub id: Uuid,
+}
+
+impl Message for GetThumbnailMessage {
+    type Result = Option<Thumbnail>;
+}
+
+impl Handler<GetThumbnailMessage> for NodeManager {
+    type Result = MessageResult<GetThumbnailMessage>;
+
+    fn handle(&mut self, msg: GetThumbnailMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(self.thumbnails.get(&msg.id).cloned())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum WebsocketMessage {
     /// Node state changed
@@ -226,6 +1504,23 @@ impl Handler<WebsocketMessage> for NodeManager {
             }
             WebsocketMessage::Disconect { id } => {
                 self.sessions.remove(&id);
+
+                for node in self.nodes.values() {
+                    node.do_send(SubscribePreviewMessage {
+                        session: id,
+                        subscriber: None,
+                    });
+                }
+                for node in self.input_nodes.values() {
+                    node.do_send(SubscribePreviewMessage {
+                        session: id,
+                        subscriber: None,
+                    });
+                }
+                Multiviewer::from_registry().do_send(SubscribePreviewMessage {
+                    session: id,
+                    subscriber: None,
+                });
                 //Ok(())
             }
         }
@@ -256,3 +1551,94 @@ impl Handler<NodeStateMessage> for NodeManager {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A manager knowing of outputs with these device numbers, and
+    /// their ids in the same order
+    fn manager(device_nums: &[i32]) -> (NodeManager, Vec<Uuid>) {
+        let mut manager = NodeManager::default();
+        let mut ids = vec![];
+
+        for &device_num in device_nums {
+            let id = Uuid::new_v4();
+            manager.devices.insert(
+                id,
+                Device {
+                    id,
+                    device_num,
+                    state: gstreamer::State::Null,
+                    format: OutputFormat::default(),
+                    mode: VideoMode::TestCard("smpte".to_string()),
+                    overlays: vec![],
+                    playout: None,
+                    transport: None,
+                    loudness: vec![],
+                    frames: FrameStats::default(),
+                },
+            );
+            ids.push(id);
+        }
+
+        (manager, ids)
+    }
+
+    #[test]
+    fn all_is_every_device_in_device_order() {
+        let (manager, ids) = manager(&[2, 0, 1]);
+
+        assert_eq!(
+            manager.resolve_target(&Target::All).unwrap(),
+            vec![ids[1], ids[2], ids[0]]
+        );
+    }
+
+    #[test]
+    fn group_expands_to_its_devices() {
+        let (mut manager, ids) = manager(&[2, 0, 1]);
+        manager
+            .groups
+            .insert("studio".to_string(), vec![ids[0], ids[1]]);
+
+        assert_eq!(
+            manager
+                .resolve_target(&Target::Group("studio".to_string()))
+                .unwrap(),
+            vec![ids[1], ids[0]]
+        );
+        assert!(manager
+            .resolve_target(&Target::Group("gallery".to_string()))
+            .is_err());
+    }
+
+    #[test]
+    fn devices_sorted_and_deduplicated() {
+        let (manager, ids) = manager(&[2, 0, 1]);
+
+        assert_eq!(
+            manager
+                .resolve_target(&Target::Devices(vec![
+                    ids[0], ids[2], ids[0], ids[1], ids[2]
+                ]))
+                .unwrap(),
+            vec![ids[1], ids[2], ids[0]]
+        );
+    }
+
+    #[test]
+    fn unknown_devices_kept_for_reporting() {
+        let (manager, ids) = manager(&[0]);
+        let unknown = Uuid::new_v4();
+
+        // Left for the bulk command to report as missing, before the
+        // known devices
+        assert_eq!(
+            manager
+                .resolve_target(&Target::Devices(vec![ids[0], unknown, unknown]))
+                .unwrap(),
+            vec![unknown, ids[0]]
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, Error};
+use gst::prelude::*;
+use gstreamer as gst;
+use gstreamer_app as gst_app;
+use gstreamer_video as gst_video;
+
+use crate::detector::PictureStats;
+
+/// Width frames are analysed at, a multiple of 4 so rows are not
+/// padded
+const ANALYSIS_WIDTH: i32 = 64;
+/// Height frames are analysed at
+const ANALYSIS_HEIGHT: i32 = 36;
+
+/// Statistics of the frames seen since they were last taken, shared
+/// between a node and the analysis branch of its pipeline
+#[derive(Debug, Default)]
+pub struct PictureAnalysis {
+    /// Luma of the previous frame
+    previous: Vec<u8>,
+    frames: u32,
+    /// Highest average luma of any frame, from 0 to 1
+    max_luma: f64,
+    /// Highest average difference between consecutive frames, from 0
+    /// to 1
+    max_difference: f64,
+}
+
+impl PictureAnalysis {
+    /// Measure a frame of luma
+    fn add(&mut self, luma: &[u8]) {
+        let average = luma.iter().map(|&y| y as f64).sum::<f64>() / luma.len() as f64 / 255.0;
+        self.max_luma = self.max_luma.max(average);
+
+        if self.previous.len() == luma.len() {
+            let difference = luma
+                .iter()
+                .zip(self.previous.iter())
+                .map(|(&a, &b)| (a as f64 - b as f64).abs())
+                .sum::<f64>()
+                / luma.len() as f64
+                / 255.0;
+            self.max_difference = self.max_difference.max(difference);
+        } else if !self.previous.is_empty() {
+            // A change of size is as good as a new picture
+            self.max_difference = 1.0;
+        }
+
+        self.previous.clear();
+        self.previous.extend_from_slice(luma);
+        self.frames += 1;
+    }
+
+    /// The statistics since they were last taken, if any frames
+    /// arrived
+    pub fn take(&mut self) -> Option<PictureStats> {
+        if self.frames == 0 {
+            return None;
+        }
+
+        let stats = PictureStats {
+            frames: self.frames,
+            max_luma: self.max_luma,
+            max_difference: self.max_difference,
+        };
+
+        self.frames = 0;
+        self.max_luma = 0.0;
+        self.max_difference = 0.0;
+
+        Some(stats)
+    }
+}
+
+/// Build a bin analysing the picture it takes on its `sink` ghost pad
+/// into `analysis`. It drops rather than blocks, so it can hang off a
+/// tee on the on-air path
+pub fn make_picture_analyser(analysis: &Arc<Mutex<PictureAnalysis>>) -> Result<gst::Bin, Error> {
+    let bin = gst::Bin::new();
+
+    let queue = gst::ElementFactory::make("queue")
+        .property_from_str("leaky", "downstream")
+        .property("max-size-buffers", 1u32)
+        .property("max-size-bytes", 0u32)
+        .property("max-size-time", 0u64)
+        .build()?;
+    let convert = gst::ElementFactory::make("videoconvert").build()?;
+    let scale = gst::ElementFactory::make("videoscale").build()?;
+    let caps = gst::ElementFactory::make("capsfilter")
+        .property(
+            "caps",
+            gst_video::VideoCapsBuilder::new()
+                .format(gst_video::VideoFormat::Gray8)
+                .width(ANALYSIS_WIDTH)
+                .height(ANALYSIS_HEIGHT)
+                .pixel_aspect_ratio((1, 1).into())
+                .build(),
+        )
+        .build()?;
+    let sink = gst::ElementFactory::make("appsink")
+        .property("sync", false)
+        .property("async", false)
+        .property("max-buffers", 1u32)
+        .property("drop", true)
+        .build()?
+        .downcast::<gst_app::AppSink>()
+        .map_err(|_| anyhow!("appsink is not an AppSink"))?;
+
+    let analysis = analysis.clone();
+    sink.set_callbacks(
+        gst_app::AppSinkCallbacks::builder()
+            .new_sample(move |sink| {
+                let sample = sink.pull_sample().map_err(|_| gst::FlowError::Eos)?;
+                let buffer = sample.buffer().ok_or(gst::FlowError::Error)?;
+                let map = buffer.map_readable().map_err(|_| gst::FlowError::Error)?;
+
+                analysis.lock().unwrap().add(map.as_slice());
+
+                Ok(gst::FlowSuccess::Ok)
+            })
+            .build(),
+    );
+
+    bin.add_many([
+        &queue,
+        &convert,
+        &scale,
+        &caps,
+        sink.upcast_ref::<gst::Element>(),
+    ])?;
+    gst::Element::link_many([
+        &queue,
+        &convert,
+        &scale,
+        &caps,
+        sink.upcast_ref::<gst::Element>(),
+    ])?;
+
+    bin.add_pad(
+        &gst::GhostPad::builder_with_target(
+            &queue.static_pad("sink").expect("queue with no sink pad"),
+        )?
+        .name("sink")
+        .build(),
+    )?;
+
+    Ok(bin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nothing_to_take_without_frames() {
+        assert!(PictureAnalysis::default().take().is_none());
+    }
+
+    #[test]
+    fn black_still_picture() {
+        let mut analysis = PictureAnalysis::default();
+        analysis.add(&[16; 64]);
+        analysis.add(&[16; 64]);
+
+        let stats = analysis.take().unwrap();
+        assert_eq!(stats.frames, 2);
+        assert!((stats.max_luma - 16.0 / 255.0).abs() < 1e-9);
+        assert_eq!(stats.max_difference, 0.0);
+    }
+
+    #[test]
+    fn changing_picture() {
+        let mut analysis = PictureAnalysis::default();
+        analysis.add(&[0; 64]);
+        analysis.add(&[255; 64]);
+        analysis.add(&[128; 64]);
+
+        let stats = analysis.take().unwrap();
+        assert_eq!(stats.frames, 3);
+        assert_eq!(stats.max_luma, 1.0);
+        assert_eq!(stats.max_difference, 1.0);
+    }
+
+    #[test]
+    fn first_frame_is_not_a_change() {
+        let mut analysis = PictureAnalysis::default();
+        analysis.add(&[200; 64]);
+
+        let stats = analysis.take().unwrap();
+        assert_eq!(stats.frames, 1);
+        assert_eq!(stats.max_difference, 0.0);
+    }
+
+    #[test]
+    fn change_of_size_is_a_new_picture() {
+        let mut analysis = PictureAnalysis::default();
+        analysis.add(&[50; 64]);
+        analysis.add(&[50; 32]);
+
+        assert_eq!(analysis.take().unwrap().max_difference, 1.0);
+    }
+
+    #[test]
+    fn take_starts_afresh() {
+        let mut analysis = PictureAnalysis::default();
+        analysis.add(&[0; 64]);
+        analysis.add(&[255; 64]);
+        analysis.take().unwrap();
+        assert!(analysis.take().is_none());
+
+        // Still compared with the last frame before taking
+        analysis.add(&[255; 64]);
+        let stats = analysis.take().unwrap();
+        assert_eq!(stats.frames, 1);
+        assert_eq!(stats.max_difference, 0.0);
+    }
+}
@@ -1,18 +1,63 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
 use actix::prelude::*;
 use actix::{Actor, Addr, Context};
-use anyhow::Error;
+use anyhow::{anyhow, Error};
 use gst::prelude::ElementExtManual;
 use gst::prelude::*;
 use gstreamer as gst;
 use gstreamer_video as gst_video;
 use tracing::instrument;
-use tracing::{debug, error};
+use tracing::{debug, error, info_span};
 use uuid::Uuid;
 
-use crate::node::{NodeManager, StartMessage, StopMessage, StoppedMessage};
-
-use super::manager::{PipelineManager, StopManagerMessage};
-use super::{make_element, ErrorMessage};
+use crate::alarm::{AlarmManager, ClearAlarmMessage, RaiseAlarmMessage};
+use crate::command::{
+    AlarmKind, AlarmSource, FrameStats, OutputFormat, Playlist, SeekPosition, Severity,
+    TransportStatus, VideoMode,
+};
+use crate::detector::{AudioWindow, ContentDetector, ContentStatsMessage};
+use crate::loudness::{LoudnessLog, MeasuredLoudnessMessage};
+use crate::metrics::{Metrics, NodeEvent, NodeEventMessage};
+use crate::node::{
+    FormatMessage, FrameStatsMessage, LevelsMessage, LoudnessControlMessage, ModeMessage,
+    NodeManager, OverlayMessage, PlayoutMessage, PlayoutStatusMessage, StartMessage, StopMessage,
+    StoppedMessage, SubscribePreviewMessage, ThumbnailMessage, TransportMessage,
+    TransportStatusMessage,
+};
+use crate::watchdog::{FlowMessage, RestartMessage, Watchdog};
+
+use super::analysis::{make_picture_analyser, PictureAnalysis};
+use super::flow::{watch_flow, Flow};
+use super::frames::{count_frames, watch_frames, FrameCounter};
+use super::loudness::{make_loudness_meter, LoudnessMeter};
+use super::manager::{PipelineManager, StopManagerMessage, TraceTransitionMessage};
+use super::multiviewer::monitor_channel;
+use super::overlay::{make_markers, Markers};
+use super::playout::{Playout, Program};
+use super::preview::{make_preview, Preview};
+use super::source::make_source;
+use super::thumbnail::make_thumbnailer;
+use super::{
+    make_element, optional_branch, video_caps, ErrorMessage, LevelMessage, SegmentDoneMessage,
+};
+
+/// How often a loaded playlist is advanced
+const PLAYOUT_TICK: Duration = Duration::from_millis(40);
+/// How often playlist and stream progress is reported to the
+/// [`NodeManager`]
+const STATUS_INTERVAL: Duration = Duration::from_millis(500);
+/// How often the output audio level is measured
+const LEVEL_INTERVAL: gst::ClockTime = gst::ClockTime::from_mseconds(100);
+/// How long a cued stream may take to preroll before seeking
+const PREROLL_TIMEOUT: gst::ClockTime = gst::ClockTime::from_seconds(5);
+/// How many status intervals of frame counters glitches are summed
+/// over
+const FRAME_WINDOW: usize = 20;
+/// Glitches within the window that raise an alarm
+const FRAME_GLITCH_THRESHOLD: u64 = 5;
 
 /// The pipeline and various GStreamer elements that the source
 /// optionally wraps, their lifetime is not directly bound to that
@@ -27,24 +72,79 @@ pub struct DecklinkStream {
     pipeline: gst::Pipeline,
     /// A helper for managing the pipeline
     pipeline_manager: Option<Addr<PipelineManager>>,
-    // node_manager: Addr<NodeManager>,
+    /// Alignment markers drawn over the output
+    markers: Arc<Mutex<Markers>>,
+    /// Video format of the output
+    format: OutputFormat,
+    /// What the output is currently showing
+    mode: VideoMode,
+    /// The playlist engine, when a playlist is loaded
+    playout: Option<Playout>,
+    /// Whether a stream goes back to the start when it ends
+    looping: bool,
+    /// Where thumbnails of the output are sent
+    node_manager: Addr<NodeManager>,
+    /// Who is watching the preview stream
+    preview: Arc<Mutex<Preview>>,
+    /// Loudness of what goes to air
+    loudness: Arc<Mutex<LoudnessMeter>>,
+    /// Picture of the source, for the content detectors
+    picture: Arc<Mutex<PictureAnalysis>>,
+    /// Audio levels since they were last sent to the content detectors
+    audio: AudioWindow,
+    /// Dropped, late and missing frames of the output
+    frames: Arc<Mutex<FrameCounter>>,
+    /// Recent frame counters, oldest first, while playing
+    frame_window: VecDeque<FrameStats>,
+    /// Whether too many frames went wrong on air
+    glitching: bool,
+    /// Buffers reaching the sinks, for the [`Watchdog`]
+    flow: Arc<Mutex<Flow>>,
 }
 
 impl Actor for DecklinkStream {
     type Context = Context<Self>;
 
-    #[instrument(level = "debug", name = "starting", skip(self, ctx), fields(id = %self.id))]
+    #[instrument(level = "debug", name = "starting", skip(self, ctx), fields(device_id = %self.id))]
     fn started(&mut self, ctx: &mut Self::Context) {
         debug!("pipeline manger created");
 
-        self.pipeline_manager = Some(
-            PipelineManager::new(
-                self.pipeline.clone(),
-                ctx.address().downgrade().recipient(),
-                self.id,
-            )
-            .start(),
-        );
+        self.start_manager(ctx);
+
+        ctx.run_interval(PLAYOUT_TICK, |act, _| {
+            if let Some(playout) = act.playout.as_mut() {
+                playout.tick();
+            }
+        });
+
+        ctx.run_interval(STATUS_INTERVAL, |act, ctx| {
+            if let Some(playout) = &act.playout {
+                NodeManager::from_registry().do_send(PlayoutStatusMessage {
+                    id: act.id,
+                    status: Some(playout.status()),
+                });
+            }
+
+            if matches!(act.mode, VideoMode::Stream(_)) {
+                act.report_transport();
+            }
+
+            act.check_frames();
+            act.report_flow(ctx);
+
+            if act.pipeline.current_state() == gst::State::Playing {
+                LoudnessLog::from_registry().do_send(MeasuredLoudnessMessage {
+                    id: act.id,
+                    programs: act.loudness.lock().unwrap().status(),
+                });
+                ContentDetector::from_registry().do_send(ContentStatsMessage {
+                    id: act.id,
+                    name: format!("output {}", act.device_num),
+                    picture: act.picture.lock().unwrap().take(),
+                    audio: act.audio.take(),
+                });
+            }
+        });
 
         // ctx.run_interval(Duration::from_secs(1), |act, _| {
         //     let state = act.pipeline.current_state();
@@ -62,12 +162,14 @@ impl Actor for DecklinkStream {
         // });
     }
 
-    #[instrument(level = "debug", name = "stopped", skip(self, _ctx), fields(id = %self.id))]
+    #[instrument(level = "debug", name = "stopped", skip(self, _ctx), fields(device_id = %self.id))]
     fn stopped(&mut self, _ctx: &mut Self::Context) {
         if let Some(manager) = self.pipeline_manager.take() {
             manager.do_send(StopManagerMessage);
         }
 
+        self.clear_glitching();
+
         NodeManager::from_registry().do_send(StoppedMessage {
             id: self.id.clone(),
         });
@@ -76,22 +178,57 @@ impl Actor for DecklinkStream {
 
 impl DecklinkStream {
     pub fn new(
-        _node_manager: Addr<NodeManager>,
+        node_manager: Addr<NodeManager>,
         device_id: Uuid,
         device_num: i32,
+        format: OutputFormat,
+        mode: VideoMode,
     ) -> Result<Self, Error> {
-        let pipeline = gst::Pipeline::new();
+        let source = make_source(&mode)?;
+        let mut stream = Self {
+            id: device_id,
+            pipeline: gst::Pipeline::new(),
+            pipeline_manager: None,
+            markers: Arc::new(Mutex::new(Markers::default())),
+            format,
+            mode,
+            playout: None,
+            looping: false,
+            device_num,
+            node_manager,
+            preview: Arc::new(Mutex::new(Preview::new(device_id))),
+            loudness: Arc::new(Mutex::new(LoudnessMeter::default())),
+            picture: Arc::new(Mutex::new(PictureAnalysis::default())),
+            audio: AudioWindow::default(),
+            frames: Arc::new(Mutex::new(FrameCounter::default())),
+            frame_window: VecDeque::new(),
+            glitching: false,
+            flow: Arc::new(Mutex::new(Flow::default())),
+        };
+        stream.pipeline = stream.build_pipeline(&source)?;
+
+        Ok(stream)
+    }
 
-        let video_caps = gst_video::VideoCapsBuilder::new()
-            .width(1920)
-            .height(1080)
-            .framerate((60, 1).into())
-            .build();
+    /// Build the output pipeline, converting `source` to our format.
+    /// What goes to air is also teed off to thumbnails for the
+    /// [`NodeManager`], the on-demand preview stream, the multiviewer
+    /// and the loudness meter. The source picture is analysed before
+    /// anything is drawn over it, so the content detectors see the
+    /// content alone
+    fn build_pipeline(&self, source: &gst::Bin) -> Result<gst::Pipeline, Error> {
+        let (id, device_num, format) = (self.id, self.device_num, &self.format);
+        let pipeline = gst::Pipeline::new();
 
-        let video_source = gst::ElementFactory::make("videotestsrc")
-            .property_from_str("pattern", "smpte")
-            .property("is-live", true)
+        let source_convert = gst::ElementFactory::make("videoconvert").build()?;
+        let scale = gst::ElementFactory::make("videoscale")
+            .property("add-borders", true)
+            .build()?;
+        let rate = gst::ElementFactory::make("videorate")
+            .name("rate")
             .build()?;
+        let content_tee = gst::ElementFactory::make("tee").build()?;
+        let analyser = make_picture_analyser(&self.picture)?;
 
         let overlay = gst::ElementFactory::make("timeoverlay")
             .property_from_str(
@@ -104,65 +241,360 @@ impl DecklinkStream {
             .build()?;
 
         let caps = gst::ElementFactory::make("capsfilter")
-            .property("caps", &video_caps)
+            .property("caps", video_caps(format))
             .build()?;
 
+        let markers_convert = gst::ElementFactory::make("videoconvert").build()?;
+        let markers_overlay = make_markers(self.markers.clone())?;
+
         let timecode = gst::ElementFactory::make("timecodestamper").build()?;
+        let tee = gst::ElementFactory::make("tee").build()?;
+        let queue = gst::ElementFactory::make("queue").build()?;
         let convert = gst::ElementFactory::make("videoconvert").build()?;
 
+        let node_manager = self.node_manager.clone();
+        let thumbnailer = optional_branch(
+            &id,
+            "thumbnails",
+            make_thumbnailer(move |jpeg| {
+                node_manager.do_send(ThumbnailMessage { id, jpeg });
+            }),
+        );
+        let preview = optional_branch(
+            &id,
+            "preview",
+            make_preview(&self.preview, Some(&monitor_channel(&id))),
+        );
+
         let video_sink = gst::ElementFactory::make("decklinkvideosink")
-            .property_from_str("mode", "1080p60")
+            .name("video-sink")
+            .property_from_str("mode", &format.decklink_mode())
             .property_from_str("mapping-format", "level-a")
             .property("device-number", device_num)
             .property_from_str("profile", "two-sub-devices-half")
             .property("sync", true)
+            .property("qos", true)
             .build()?;
+        watch_frames(&self.frames, &video_sink, format)?;
 
-        let audio_source = gst::ElementFactory::make("audiotestsrc").build()?;
+        let audio_convert = gst::ElementFactory::make("audioconvert").build()?;
+        let audio_resample = gst::ElementFactory::make("audioresample").build()?;
+        let level = gst::ElementFactory::make("level")
+            .property("interval", LEVEL_INTERVAL.nseconds())
+            .property("post-messages", true)
+            .build()?;
+        let audio_tee = gst::ElementFactory::make("tee").build()?;
+        let audio_queue = gst::ElementFactory::make("queue").build()?;
         let audio_sink = gst::ElementFactory::make("decklinkaudiosink")
+            .name("audio-sink")
             .property("device-number", device_num)
             .build()?;
+        watch_flow(&self.flow, &[&video_sink, &audio_sink])?;
+        let loudness = make_loudness_meter(&self.loudness)?;
 
         pipeline.add_many([
-            &video_source,
+            source.upcast_ref::<gst::Element>(),
+            &source_convert,
+            &scale,
+            &rate,
+            &content_tee,
+            analyser.upcast_ref::<gst::Element>(),
             &overlay,
             &caps,
+            &markers_convert,
+            &markers_overlay,
             &timecode,
+            &tee,
+            &queue,
             &convert,
             &video_sink,
-            &audio_source,
+            loudness.upcast_ref::<gst::Element>(),
+            &audio_convert,
+            &audio_resample,
+            &level,
+            &audio_tee,
+            &audio_queue,
             &audio_sink,
         ])?;
 
+        source.link_pads(Some("video"), &source_convert, None)?;
         gst::Element::link_many([
-            &video_source,
+            &source_convert,
+            &scale,
+            &rate,
+            &content_tee,
             &overlay,
             &caps,
+            &markers_convert,
+            &markers_overlay,
             &timecode,
+            &tee,
+            &queue,
             &convert,
             &video_sink,
         ])?;
+        content_tee.link(&analyser)?;
 
-        gst::Element::link_many([&audio_source, &audio_sink])?;
+        source.link_pads(Some("audio"), &audio_convert, None)?;
+        gst::Element::link_many([
+            &audio_convert,
+            &audio_resample,
+            &level,
+            &audio_tee,
+            &audio_queue,
+            &audio_sink,
+        ])?;
+        audio_tee.link(&loudness)?;
 
-        Ok(Self {
-            id: device_id,
-            pipeline,
-            pipeline_manager: None,
-            device_num,
-            //node_manager,
-        })
+        if let Some(thumbnailer) = &thumbnailer {
+            pipeline.add(thumbnailer)?;
+            tee.link(thumbnailer)?;
+        }
+        if let Some(preview) = &preview {
+            pipeline.add(preview)?;
+            tee.link_pads(None, preview, Some("video"))?;
+            audio_tee.link_pads(None, preview, Some("audio"))?;
+        }
+
+        Ok(pipeline)
+    }
+
+    /// Sample the frame counters for the [`NodeManager`], raising an
+    /// alarm when too many frames went wrong on air lately and
+    /// clearing it once a whole window went by without any
+    fn check_frames(&mut self) {
+        let frames = count_frames(&self.pipeline, &self.frames, "rate", "video-sink");
+        NodeManager::from_registry().do_send(FrameStatsMessage {
+            id: self.id,
+            frames,
+        });
+
+        if self.pipeline.current_state() != gst::State::Playing {
+            self.frames.lock().unwrap().pause();
+            self.frame_window.clear();
+            self.clear_glitching();
+            return;
+        }
+
+        self.frame_window.push_back(frames);
+        if self.frame_window.len() > FRAME_WINDOW {
+            self.frame_window.pop_front();
+        }
+
+        let recent = match self.frame_window.front() {
+            Some(oldest) => frames.since(oldest),
+            None => return,
+        };
+
+        if !self.glitching && recent.glitches() >= FRAME_GLITCH_THRESHOLD {
+            self.glitching = true;
+
+            AlarmManager::from_registry().do_send(RaiseAlarmMessage {
+                kind: AlarmKind::FrameLoss,
+                severity: Severity::Major,
+                source: AlarmSource {
+                    device_id: Some(self.id),
+                    element: Some("video-sink".to_string()),
+                },
+                message: format!(
+                    "Output {} glitched: {} dropped, {} late, {} underruns",
+                    self.device_num, recent.dropped, recent.late, recent.underruns
+                ),
+                since: None,
+            });
+        } else if self.frame_window.len() == FRAME_WINDOW && recent.glitches() == 0 {
+            self.clear_glitching();
+        }
+    }
+
+    /// Tell the [`Watchdog`] how long buffers have been stuck
+    fn report_flow(&self, ctx: &mut Context<Self>) {
+        let mut flow = self.flow.lock().unwrap();
+
+        if self.pipeline.current_state() == gst::State::Playing {
+            flow.expect();
+        } else {
+            flow.pause();
+        }
+
+        Watchdog::from_registry().do_send(FlowMessage {
+            id: self.id,
+            name: format!("Output {}", self.device_num),
+            severity: Severity::Critical,
+            stalled: flow.stalled(),
+            restart: ctx.address().recipient(),
+        });
+    }
+
+    /// Clear the alarm of frames going wrong on air
+    fn clear_glitching(&mut self) {
+        if std::mem::take(&mut self.glitching) {
+            AlarmManager::from_registry().do_send(ClearAlarmMessage {
+                kind: AlarmKind::FrameLoss,
+                source: AlarmSource {
+                    device_id: Some(self.id),
+                    element: Some("video-sink".to_string()),
+                },
+            });
+        }
+    }
+
+    /// Replace our pipeline with one fed by `source`, carrying over
+    /// whether the output was playing
+    fn switch_pipeline(&mut self, source: &gst::Bin, ctx: &mut Context<Self>) -> Result<(), Error> {
+        // Build the new pipeline first so a bad source leaves the
+        // output as it was
+        let pipeline = self.build_pipeline(source)?;
+
+        let was_playing = self.pipeline.current_state() == gst::State::Playing
+            || self.pipeline.pending_state() == gst::State::Playing;
+
+        if let Some(manager) = self.pipeline_manager.take() {
+            manager.do_send(StopManagerMessage);
+        }
+
+        // The device must be released before the new pipeline can
+        // claim it
+        let _ = self.pipeline.set_state(gst::State::Null);
+
+        self.pipeline = pipeline;
+        self.start_manager(ctx);
+
+        if was_playing {
+            self.start_pipeline(ctx)?;
+        }
+
+        Ok(())
+    }
+
+    /// Unload any playlist, clearing its progress from the device
+    fn unload_playout(&mut self) {
+        if self.playout.take().is_some() {
+            NodeManager::from_registry().do_send(PlayoutStatusMessage {
+                id: self.id,
+                status: None,
+            });
+        }
+    }
+
+    /// Switch the output to a playout of `playlist`
+    fn load_playlist(&mut self, playlist: Playlist, ctx: &mut Context<Self>) -> Result<(), Error> {
+        if playlist.items.is_empty() {
+            return Err(anyhow!("Playlist has no items"));
+        }
+
+        // Fresh inter channels let the new playout preroll while the
+        // old one stays on air, so a bad first item changes nothing
+        let channel = format!("{}-{}", self.id, Uuid::new_v4());
+        let program = Program::new(&channel)?;
+        let source = program.bin.clone();
+        let playout = Playout::new(playlist, program, &channel, self.format)?;
+        self.switch_pipeline(&source, ctx)?;
+
+        self.unload_playout();
+        self.playout = Some(playout);
+        self.mode = VideoMode::Playlist;
+        self.looping = false;
+        self.report_transport();
+
+        Ok(())
+    }
+
+    /// The loaded playlist engine
+    fn playout(&mut self) -> Result<&mut Playout, Error> {
+        self.playout
+            .as_mut()
+            .ok_or_else(|| anyhow!("No playlist loaded on {}", self.id))
+    }
+
+    /// Report the playback progress of a stream, or that there is
+    /// none, to the [`NodeManager`]
+    fn report_transport(&self) {
+        let status = match self.mode {
+            VideoMode::Stream(_) => Some(TransportStatus {
+                position_ms: self
+                    .pipeline
+                    .query_position::<gst::ClockTime>()
+                    .map(|position| position.mseconds()),
+                duration_ms: self
+                    .pipeline
+                    .query_duration::<gst::ClockTime>()
+                    .map(|duration| duration.mseconds()),
+                looping: self.looping,
+            }),
+            _ => None,
+        };
+
+        NodeManager::from_registry().do_send(TransportStatusMessage {
+            id: self.id,
+            status,
+        });
+    }
+
+    /// Flags for seeking the stream, segment seeks when looping so
+    /// the end of the stream is signalled without EOS
+    fn seek_flags(&self) -> gst::SeekFlags {
+        let flags = gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE;
+
+        if self.looping {
+            flags | gst::SeekFlags::SEGMENT
+        } else {
+            flags
+        }
+    }
+
+    /// Convert a seek position to a time in the stream
+    fn seek_time(&self, position: SeekPosition) -> Result<gst::ClockTime, Error> {
+        match position {
+            SeekPosition::Time(ms) => Ok(gst::ClockTime::from_mseconds(ms)),
+            SeekPosition::Frame(frame) => {
+                let caps = self
+                    .pipeline
+                    .by_name("source")
+                    .and_then(|source| source.static_pad("video"))
+                    .and_then(|pad| pad.current_caps())
+                    .ok_or_else(|| anyhow!("Frame rate of {} not known yet", self.id))?;
+                let fps = gst_video::VideoInfo::from_caps(&caps)?.fps();
+
+                if fps.numer() <= 0 {
+                    return Err(anyhow!(
+                        "Can't seek by frame in a variable frame rate stream"
+                    ));
+                }
+
+                Ok(gst::ClockTime::from_nseconds(
+                    (frame as u128
+                        * fps.denom() as u128
+                        * gst::ClockTime::SECOND.nseconds() as u128
+                        / fps.numer() as u128) as u64,
+                ))
+            }
+        }
+    }
+
+    /// Start a [`PipelineManager`] watching our current pipeline
+    fn start_manager(&mut self, ctx: &mut Context<Self>) {
+        self.pipeline_manager = Some(
+            PipelineManager::new(
+                self.pipeline.clone(),
+                ctx.address().downgrade().recipient(),
+                ctx.address().downgrade().recipient(),
+                Some(ctx.address().downgrade().recipient()),
+                self.id,
+            )
+            .start(),
+        );
     }
 
     /// Start our pipeline when cue_time is reached
-    #[instrument(level = "debug", name = "start_pipeline", skip(self, ctx), fields(id = %self.id))]
+    #[instrument(level = "debug", name = "start_pipeline", skip(self, ctx), fields(device_id = %self.id))]
     fn start_pipeline(&mut self, ctx: &mut Context<Self>) -> Result<(), Error> {
         let addr = ctx.address();
         let id = self.id.clone();
 
         self.pipeline.call_async(move |pipeline| {
             if let Err(err) = pipeline.set_state(gst::State::Playing) {
-                addr.do_send(ErrorMessage(format!(
+                addr.do_send(ErrorMessage::new(format!(
                     "Failed to start mixer {}: {}",
                     id, err
                 )));
@@ -176,7 +608,15 @@ impl DecklinkStream {
 impl Handler<StartMessage> for DecklinkStream {
     type Result = MessageResult<StartMessage>;
 
-    fn handle(&mut self, _: StartMessage, ctx: &mut Context<Self>) -> Self::Result {
+    fn handle(&mut self, msg: StartMessage, ctx: &mut Context<Self>) -> Self::Result {
+        if let Some(manager) = &self.pipeline_manager {
+            manager.do_send(TraceTransitionMessage {
+                target: gst::State::Playing,
+                span: msg.span.clone(),
+            });
+        }
+
+        let _entered = msg.span.enter();
         MessageResult(self.start_pipeline(ctx))
     }
 }
@@ -184,14 +624,23 @@ impl Handler<StartMessage> for DecklinkStream {
 impl Handler<StopMessage> for DecklinkStream {
     type Result = Result<(), Error>;
 
-    fn handle(&mut self, _: StopMessage, ctx: &mut Context<Self>) -> Self::Result {
+    fn handle(&mut self, msg: StopMessage, ctx: &mut Context<Self>) -> Self::Result {
         //ctx.stop();
         let addr = ctx.address();
         let id = self.id.clone();
 
         self.pipeline.call_async(move |pipeline| {
+            // Stopping is synchronous, so traced right here
+            let _entered = info_span!(
+                parent: &msg.span,
+                "state change",
+                device_id = %id,
+                target = ?gst::State::Null
+            )
+            .entered();
+
             if let Err(err) = pipeline.set_state(gst::State::Null) {
-                addr.do_send(ErrorMessage(format!(
+                addr.do_send(ErrorMessage::new(format!(
                     "Failed to start mixer {}: {}",
                     id, err
                 )));
@@ -201,17 +650,247 @@ impl Handler<StopMessage> for DecklinkStream {
     }
 }
 
+impl Handler<OverlayMessage> for DecklinkStream {
+    type Result = MessageResult<OverlayMessage>;
+
+    fn handle(&mut self, msg: OverlayMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        let mut markers = self.markers.lock().unwrap();
+        markers.set(msg.overlay, msg.enabled);
+
+        MessageResult(Ok(markers.enabled()))
+    }
+}
+
+impl Handler<ModeMessage> for DecklinkStream {
+    type Result = Result<(), Error>;
+
+    #[instrument(level = "debug", name = "switching mode", skip(self, ctx), fields(device_id = %self.id))]
+    fn handle(&mut self, msg: ModeMessage, ctx: &mut Context<Self>) -> Self::Result {
+        if msg.mode == self.mode {
+            return Ok(());
+        }
+
+        let source = make_source(&msg.mode)?;
+        self.switch_pipeline(&source, ctx)?;
+        self.unload_playout();
+        self.mode = msg.mode;
+        self.looping = false;
+        self.report_transport();
+
+        Ok(())
+    }
+}
+
+impl Handler<FormatMessage> for DecklinkStream {
+    type Result = Result<(), Error>;
+
+    #[instrument(level = "debug", name = "changing format", skip(self, ctx), fields(device_id = %self.id))]
+    fn handle(&mut self, msg: FormatMessage, ctx: &mut Context<Self>) -> Self::Result {
+        if msg.format == self.format {
+            return Ok(());
+        }
+
+        let previous = std::mem::replace(&mut self.format, msg.format);
+
+        // A playlist restarts from the top, as its players have to be
+        // rebuilt for the new format
+        let res = match self
+            .playout
+            .as_ref()
+            .map(|playout| playout.playlist().clone())
+        {
+            Some(playlist) => self.load_playlist(playlist, ctx),
+            None => make_source(&self.mode).and_then(|source| self.switch_pipeline(&source, ctx)),
+        };
+
+        if res.is_err() {
+            self.format = previous;
+        }
+
+        res
+    }
+}
+
+impl Handler<PlayoutMessage> for DecklinkStream {
+    type Result = Result<(), Error>;
+
+    #[instrument(level = "debug", name = "controlling playout", skip(self, ctx), fields(device_id = %self.id))]
+    fn handle(&mut self, msg: PlayoutMessage, ctx: &mut Context<Self>) -> Self::Result {
+        match msg {
+            PlayoutMessage::Load(playlist) => self.load_playlist(playlist, ctx),
+            PlayoutMessage::Next => self.playout()?.next(),
+            PlayoutMessage::Previous => self.playout()?.previous(),
+            PlayoutMessage::Skip(index) => self.playout()?.skip(index),
+        }
+    }
+}
+
+impl Handler<TransportMessage> for DecklinkStream {
+    type Result = Result<(), Error>;
+
+    #[instrument(level = "debug", name = "controlling transport", skip(self, ctx), fields(device_id = %self.id))]
+    fn handle(&mut self, msg: TransportMessage, ctx: &mut Context<Self>) -> Self::Result {
+        if !matches!(self.mode, VideoMode::Stream(_)) {
+            return Err(anyhow!("Transport controls only apply to streams"));
+        }
+
+        match msg {
+            TransportMessage::Pause => {
+                self.pipeline.set_state(gst::State::Paused)?;
+            }
+            TransportMessage::Resume => {
+                self.start_pipeline(ctx)?;
+            }
+            TransportMessage::Seek(position) => {
+                let time = self.seek_time(position)?;
+                self.pipeline.seek_simple(self.seek_flags(), time)?;
+            }
+            TransportMessage::Loop(enabled) => {
+                self.looping = enabled;
+
+                // Make what is playing a segment, so the end of the
+                // stream comes back as segment-done. Neither flushing
+                // nor moving, as the stream may be on air
+                if enabled {
+                    self.pipeline.seek(
+                        1.0,
+                        gst::SeekFlags::SEGMENT,
+                        gst::SeekType::None,
+                        gst::ClockTime::NONE,
+                        gst::SeekType::None,
+                        gst::ClockTime::NONE,
+                    )?;
+                }
+            }
+            TransportMessage::Cue => {
+                let flags = self.seek_flags();
+
+                self.pipeline.set_state(gst::State::Paused)?;
+                self.pipeline.call_async(move |pipeline| {
+                    let _ = pipeline.state(Some(PREROLL_TIMEOUT));
+                    if let Err(err) = pipeline.seek_simple(flags, gst::ClockTime::ZERO) {
+                        error!("Failed to cue stream: {}", err);
+                    }
+                });
+            }
+        }
+
+        self.report_transport();
+
+        Ok(())
+    }
+}
+
+impl Handler<SubscribePreviewMessage> for DecklinkStream {
+    type Result = Result<(), Error>;
+
+    fn handle(&mut self, msg: SubscribePreviewMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        let mut preview = self.preview.lock().unwrap();
+
+        match msg.subscriber {
+            Some(subscriber) => preview.subscribe(msg.session, subscriber),
+            None => preview.unsubscribe(&msg.session),
+        }
+
+        Ok(())
+    }
+}
+
+impl Handler<LevelMessage> for DecklinkStream {
+    type Result = ();
+
+    fn handle(&mut self, msg: LevelMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        self.audio.add(&msg.levels);
+        self.node_manager.do_send(LevelsMessage {
+            id: self.id,
+            levels: msg.levels,
+        });
+    }
+}
+
+impl Handler<SegmentDoneMessage> for DecklinkStream {
+    type Result = ();
+
+    fn handle(&mut self, _msg: SegmentDoneMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        if self.looping {
+            // Not flushing, so the start follows on from the end
+            // without a gap
+            if let Err(err) = self.pipeline.seek_simple(
+                gst::SeekFlags::SEGMENT | gst::SeekFlags::ACCURATE,
+                gst::ClockTime::ZERO,
+            ) {
+                error!("Failed to loop stream on {}: {}", self.id, err);
+            }
+        } else {
+            // Looping was turned off during the last segment
+            let _ = self.pipeline.send_event(gst::event::Eos::new());
+        }
+    }
+}
+
 impl Handler<ErrorMessage> for DecklinkStream {
     type Result = ();
 
     fn handle(&mut self, msg: ErrorMessage, ctx: &mut Context<Self>) -> Self::Result {
-        error!("Got error message '{}' on destination {}", msg.0, self.id,);
+        error!(
+            "Got error message '{}' on destination {}",
+            msg.message, self.id,
+        );
 
-        // NodeManager::from_registry().do_send(NodeStatusMessage::Error {
-        //     id: self.id.clone(),
-        //     message: msg.0,
-        // });
+        Metrics::from_registry().do_send(NodeEventMessage {
+            id: self.id,
+            event: NodeEvent::Error,
+        });
+
+        // The node stops, so the output is off air until restarted
+        AlarmManager::from_registry().do_send(RaiseAlarmMessage {
+            kind: AlarmKind::Pipeline,
+            severity: Severity::Critical,
+            source: AlarmSource {
+                device_id: Some(self.id),
+                element: msg.element,
+            },
+            message: format!("Output {} failed: {}", self.device_num, msg.message),
+            since: None,
+        });
 
         ctx.stop();
     }
 }
+
+impl Handler<RestartMessage> for DecklinkStream {
+    type Result = ();
+
+    fn handle(&mut self, _msg: RestartMessage, ctx: &mut Context<Self>) -> Self::Result {
+        Metrics::from_registry().do_send(NodeEventMessage {
+            id: self.id,
+            event: NodeEvent::Restart,
+        });
+
+        // A playlist feeds the pipeline through channels it would
+        // have to be reloaded to recreate, so only cycle its state
+        if self.playout.is_some() {
+            let _ = self.pipeline.set_state(gst::State::Ready);
+            if let Err(err) = self.start_pipeline(ctx) {
+                error!("Failed to restart output {}: {}", self.id, err);
+            }
+            return;
+        }
+
+        if let Err(err) =
+            make_source(&self.mode).and_then(|source| self.switch_pipeline(&source, ctx))
+        {
+            error!("Failed to restart output {}: {}", self.id, err);
+        }
+    }
+}
+
+impl Handler<LoudnessControlMessage> for DecklinkStream {
+    type Result = Result<(), Error>;
+
+    fn handle(&mut self, msg: LoudnessControlMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        self.loudness.lock().unwrap().control(msg.action);
+
+        Ok(())
+    }
+}
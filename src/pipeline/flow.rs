@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Error};
+use gst::prelude::*;
+use gstreamer as gst;
+
+/// How buffers are getting on at one sink
+#[derive(Debug, Default)]
+struct SinkFlow {
+    /// Running time of the latest buffer
+    running_time: Option<gst::ClockTime>,
+    /// When the running time last moved forward
+    progressed: Option<Instant>,
+    /// Whether the sink got EOS, and expects nothing more
+    finished: bool,
+}
+
+/// Buffer flow through the main sinks of a pipeline, shared between a
+/// node and probes on the sink pads
+#[derive(Debug, Default)]
+pub struct Flow {
+    sinks: HashMap<String, SinkFlow>,
+    /// Since when buffers are expected, `None` while they are not
+    expected: Option<Instant>,
+}
+
+impl Flow {
+    /// Expect buffers to flow, from now on if they were not already
+    pub fn expect(&mut self) {
+        self.expected.get_or_insert_with(Instant::now);
+    }
+
+    /// Stop expecting buffers, as when the pipeline is not playing
+    pub fn pause(&mut self) {
+        self.expected = None;
+    }
+
+    /// How long the sink stuck the longest has gone without its
+    /// running time moving forward, `None` if buffers are not
+    /// expected
+    pub fn stalled(&self) -> Option<Duration> {
+        let expected = self.expected?;
+
+        self.sinks
+            .values()
+            .filter(|sink| !sink.finished)
+            .map(|sink| {
+                sink.progressed
+                    .map_or(expected, |progressed| progressed.max(expected))
+                    .elapsed()
+            })
+            .max()
+    }
+}
+
+/// Track the running time of buffers reaching each of `sinks`,
+/// forgetting any sinks of a previous pipeline
+pub fn watch_flow(flow: &Arc<Mutex<Flow>>, sinks: &[&gst::Element]) -> Result<(), Error> {
+    let mut tracked = flow.lock().unwrap();
+    tracked.sinks.clear();
+    tracked.expected = None;
+
+    for sink in sinks {
+        let name = sink.name().to_string();
+        let pad = sink
+            .static_pad("sink")
+            .ok_or_else(|| anyhow!("Sink {} with no sink pad", name))?;
+        tracked.sinks.insert(name.clone(), SinkFlow::default());
+
+        let flow = flow.clone();
+        pad.add_probe(
+            gst::PadProbeType::BUFFER
+                | gst::PadProbeType::EVENT_DOWNSTREAM
+                | gst::PadProbeType::EVENT_FLUSH,
+            move |pad, info| {
+                let mut flow = flow.lock().unwrap();
+                let sink = flow.sinks.entry(name.clone()).or_default();
+
+                if let Some(buffer) = info.buffer() {
+                    let running_time = buffer.pts().and_then(|pts| {
+                        pad.sticky_event::<gst::event::Segment>(0)?
+                            .segment()
+                            .downcast_ref::<gst::ClockTime>()?
+                            .to_running_time(pts)
+                    });
+
+                    // Buffers without timestamps can only be taken on
+                    // trust
+                    if running_time.is_none() || running_time > sink.running_time {
+                        sink.progressed = Some(Instant::now());
+                    }
+                    sink.running_time = running_time.or(sink.running_time);
+                    sink.finished = false;
+                } else if let Some(event) = info.event() {
+                    match event.view() {
+                        gst::EventView::Eos(_) => sink.finished = true,
+                        // Running time starts over after a flushing
+                        // seek
+                        gst::EventView::FlushStop(_) => {
+                            sink.running_time = None;
+                            sink.finished = false;
+                        }
+                        _ => (),
+                    }
+                }
+
+                gst::PadProbeReturn::Ok
+            },
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Error};
+use gst::prelude::*;
+use gstreamer as gst;
+
+use crate::command::{FrameStats, OutputFormat};
+use crate::metrics::growth;
+
+/// How many frame periods the sink may go without a frame before it
+/// counts as an underrun
+const UNDERRUN_PERIODS: u32 = 2;
+
+/// Frame counters of an output, shared between a node and probes on
+/// the sink of its video branch. Counts carry over when the pipeline
+/// is restarted or rebuilt
+#[derive(Debug, Default)]
+pub struct FrameCounter {
+    /// When the last frame reached the sink, forgotten whenever a gap
+    /// is expected
+    last_frame: Option<Instant>,
+    stats: FrameStats,
+    /// What the sink and videorate last reported as dropped and
+    /// duplicated, which they count from when they were started
+    reported: (u64, u64),
+}
+
+impl FrameCounter {
+    /// Stop expecting frames, until the next one arrives
+    pub fn pause(&mut self) {
+        self.last_frame = None;
+    }
+}
+
+/// Count the frames reported late by `sink` and the times it waits
+/// for one longer than a few periods of `format`
+pub fn watch_frames(
+    counter: &Arc<Mutex<FrameCounter>>,
+    sink: &gst::Element,
+    format: &OutputFormat,
+) -> Result<(), Error> {
+    let pad = sink
+        .static_pad("sink")
+        .ok_or_else(|| anyhow!("Video sink with no sink pad"))?;
+    let (numerator, denominator) = format.framerate;
+    let allowed =
+        Duration::from_secs_f64(denominator as f64 / numerator.max(1) as f64) * UNDERRUN_PERIODS;
+
+    let frames = counter.clone();
+    pad.add_probe(
+        gst::PadProbeType::BUFFER | gst::PadProbeType::EVENT_FLUSH,
+        move |_, info| {
+            let mut frames = frames.lock().unwrap();
+
+            if info.buffer().is_some() {
+                let now = Instant::now();
+                if let Some(last) = frames.last_frame {
+                    if now.duration_since(last) > allowed {
+                        frames.stats.underruns += 1;
+                    }
+                }
+                frames.last_frame = Some(now);
+            } else {
+                // Seeking leaves a gap that is nobody's fault
+                frames.last_frame = None;
+            }
+
+            gst::PadProbeReturn::Ok
+        },
+    );
+
+    // The sink tells upstream about every frame it got too late
+    let late = counter.clone();
+    pad.add_probe(gst::PadProbeType::EVENT_UPSTREAM, move |_, info| {
+        if let Some(gst::EventView::Qos(qos)) = info.event().map(|event| event.view()) {
+            let (_, _, jitter, _) = qos.get();
+            if jitter > 0 {
+                late.lock().unwrap().stats.late += 1;
+            }
+        }
+
+        gst::PadProbeReturn::Ok
+    });
+
+    Ok(())
+}
+
+/// Bring `counter` up to date with the video branch of `pipeline`,
+/// with `rate` and `sink` being the names of its videorate and video
+/// sink
+pub fn count_frames(
+    pipeline: &gst::Pipeline,
+    counter: &Mutex<FrameCounter>,
+    rate: &str,
+    sink: &str,
+) -> FrameStats {
+    let dropped = pipeline
+        .by_name(sink)
+        .and_then(|sink| {
+            sink.property::<gst::Structure>("stats")
+                .get::<u64>("dropped")
+                .ok()
+        })
+        .unwrap_or(0);
+    let duplicated = pipeline
+        .by_name(rate)
+        .map(|rate| rate.property::<u64>("duplicate"))
+        .unwrap_or(0);
+    let mut counter = counter.lock().unwrap();
+
+    let (previous_dropped, previous_duplicated) = counter.reported;
+    counter.stats.dropped += growth(previous_dropped, dropped);
+    counter.stats.duplicated += growth(previous_duplicated, duplicated);
+    counter.reported = (dropped, duplicated);
+
+    counter.stats
+}
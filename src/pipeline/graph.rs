@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use actix::{Actor, Context, Handler, Message, SystemService};
+use anyhow::{anyhow, Error};
+use chrono::Utc;
+use gst::prelude::*;
+use gstreamer as gst;
+use serde::Deserialize;
+use tracing::{debug, error};
+use uuid::Uuid;
+
+use crate::config::data_dir;
+
+/// How many snapshots are kept before the oldest are removed
+const SNAPSHOT_LIMIT: usize = 200;
+
+/// How much of the pipeline a graph shows
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphDetails {
+    /// Media types on the links
+    Media,
+    /// Full caps on the links
+    Caps,
+    /// Properties that differ from their defaults
+    Params,
+    /// States of the elements
+    States,
+    /// All of the above
+    #[default]
+    All,
+    /// All of the above, without abbreviating anything
+    Verbose,
+}
+
+impl From<GraphDetails> for gst::DebugGraphDetails {
+    fn from(details: GraphDetails) -> Self {
+        match details {
+            GraphDetails::Media => gst::DebugGraphDetails::MEDIA_TYPE,
+            GraphDetails::Caps => gst::DebugGraphDetails::CAPS_DETAILS,
+            GraphDetails::Params => gst::DebugGraphDetails::NON_DEFAULT_PARAMS,
+            GraphDetails::States => gst::DebugGraphDetails::STATES,
+            GraphDetails::All => gst::DebugGraphDetails::ALL,
+            GraphDetails::Verbose => gst::DebugGraphDetails::VERBOSE,
+        }
+    }
+}
+
+/// Render a DOT graph to SVG with Graphviz, which must be installed
+pub fn render_svg(dot: &str) -> Result<Vec<u8>, Error> {
+    let mut child = Command::new("dot")
+        .arg("-Tsvg")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| anyhow!("Failed to run Graphviz dot: {}", err))?;
+
+    child
+        .stdin
+        .take()
+        .ok_or_else(|| anyhow!("No stdin to Graphviz dot"))?
+        .write_all(dot.as_bytes())?;
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "Graphviz dot failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(output.stdout)
+}
+
+/// Knows the pipeline of every node so its graph can be dumped on
+/// demand, and keeps snapshots of graphs on disk for post-mortems
+#[derive(Debug)]
+pub struct PipelineGraphs {
+    /// The current pipeline of each node, forgotten once dropped
+    pipelines: HashMap<Uuid, gst::glib::WeakRef<gst::Pipeline>>,
+    /// Where snapshots are written
+    snapshot_dir: PathBuf,
+}
+
+impl Default for PipelineGraphs {
+    fn default() -> Self {
+        Self {
+            pipelines: HashMap::new(),
+            snapshot_dir: data_dir().join("graphs"),
+        }
+    }
+}
+
+impl Actor for PipelineGraphs {
+    type Context = Context<Self>;
+}
+
+impl actix::Supervised for PipelineGraphs {}
+
+impl SystemService for PipelineGraphs {}
+
+impl PipelineGraphs {
+    /// Write a snapshot, then remove the oldest ones over the limit.
+    /// Names start with a timestamp so they sort by age
+    fn snapshot(&self, id: Uuid, reason: &str, dot: &str) -> Result<(), Error> {
+        std::fs::create_dir_all(&self.snapshot_dir)?;
+
+        let name = format!(
+            "{}-{}-{}.dot",
+            Utc::now().format("%Y%m%dT%H%M%S%.3fZ"),
+            id,
+            reason
+        );
+        std::fs::write(self.snapshot_dir.join(name), dot)?;
+
+        let mut snapshots: Vec<PathBuf> = std::fs::read_dir(&self.snapshot_dir)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().map_or(false, |ext| ext == "dot"))
+            .collect();
+
+        if snapshots.len() > SNAPSHOT_LIMIT {
+            snapshots.sort();
+            for path in snapshots.drain(..snapshots.len() - SNAPSHOT_LIMIT) {
+                debug!("removing old graph snapshot {}", path.display());
+                std::fs::remove_file(path)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Make the pipeline of a node available for graphing, sent from
+/// [`PipelineManager`](super::manager::PipelineManager) to
+/// [`PipelineGraphs`]
+#[derive(Debug)]
+pub struct RegisterPipelineMessage {
+    /// Unique identifier of the node
+    pub id: Uuid,
+    pub pipeline: gst::glib::WeakRef<gst::Pipeline>,
+}
+
+impl Message for RegisterPipelineMessage {
+    type Result = ();
+}
+
+impl Handler<RegisterPipelineMessage> for PipelineGraphs {
+    type Result = ();
+
+    fn handle(&mut self, msg: RegisterPipelineMessage, _ctx: &mut Context<Self>) {
+        self.pipelines
+            .retain(|_, pipeline| pipeline.upgrade().is_some());
+        self.pipelines.insert(msg.id, msg.pipeline);
+    }
+}
+
+/// Dump the current graph of the pipeline of a node in DOT format
+#[derive(Debug)]
+pub struct GraphMessage {
+    /// Unique identifier of the node
+    pub id: Uuid,
+    pub details: GraphDetails,
+}
+
+impl Message for GraphMessage {
+    type Result = Option<String>;
+}
+
+impl Handler<GraphMessage> for PipelineGraphs {
+    type Result = Option<String>;
+
+    fn handle(&mut self, msg: GraphMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        let pipeline = self.pipelines.get(&msg.id)?.upgrade()?;
+
+        Some(pipeline.debug_to_dot_data(msg.details.into()).to_string())
+    }
+}
+
+/// Save a snapshot of a graph, sent from
+/// [`PipelineManager`](super::manager::PipelineManager) to
+/// [`PipelineGraphs`] when a pipeline starts playing or fails
+#[derive(Debug)]
+pub struct SnapshotGraphMessage {
+    /// Unique identifier of the node
+    pub id: Uuid,
+    /// Why the snapshot was taken, part of the file name
+    pub reason: &'static str,
+    /// The graph, in DOT format
+    pub dot: String,
+}
+
+impl Message for SnapshotGraphMessage {
+    type Result = ();
+}
+
+impl Handler<SnapshotGraphMessage> for PipelineGraphs {
+    type Result = ();
+
+    fn handle(&mut self, msg: SnapshotGraphMessage, _ctx: &mut Context<Self>) {
+        if let Err(err) = self.snapshot(msg.id, msg.reason, &msg.dot) {
+            error!("Failed to save graph of {}: {}", msg.id, err);
+        }
+    }
+}
@@ -0,0 +1,563 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use actix::prelude::*;
+use actix::{Actor, Addr, Context};
+use anyhow::Error;
+use gst::prelude::*;
+use gstreamer as gst;
+use gstreamer_audio as gst_audio;
+use gstreamer_video as gst_video;
+use tracing::{debug, error, info_span, instrument, warn};
+use uuid::Uuid;
+
+use crate::alarm::{AlarmManager, RaiseAlarmMessage};
+use crate::command::{AlarmKind, AlarmSource, InputSource, InputStatus, OutputFormat, Severity};
+use crate::detector::{AudioWindow, ContentDetector, ContentStatsMessage};
+use crate::loudness::{LoudnessLog, MeasuredLoudnessMessage};
+use crate::metrics::{Metrics, NodeEvent, NodeEventMessage};
+use crate::node::{
+    InputStatusMessage, LevelsMessage, LoudnessControlMessage, NodeManager, RemoveMessage,
+    StartMessage, StopMessage, StoppedMessage, SubscribePreviewMessage, ThumbnailMessage,
+};
+use crate::watchdog::{FlowMessage, RestartMessage, Watchdog};
+
+use super::analysis::{make_picture_analyser, PictureAnalysis};
+use super::flow::{watch_flow, Flow};
+use super::loudness::{make_loudness_meter, LoudnessMeter};
+use super::manager::{PipelineManager, StopManagerMessage, TraceTransitionMessage};
+use super::multiviewer::monitor_channel;
+use super::preview::{make_preview, Preview};
+use super::thumbnail::make_thumbnailer;
+use super::{optional_branch, video_caps, ErrorMessage, LevelMessage, SegmentDoneMessage};
+
+/// How often the input status is reported to the [`NodeManager`]
+const STATUS_INTERVAL: Duration = Duration::from_millis(500);
+/// How long without a frame before the signal counts as lost
+const SIGNAL_TIMEOUT: Duration = Duration::from_secs(1);
+/// How long to wait before rebuilding a pipeline that failed
+const RESTART_DELAY: Duration = Duration::from_secs(5);
+/// How often the input audio level is measured
+const LEVEL_INTERVAL: gst::ClockTime = gst::ClockTime::from_mseconds(100);
+/// Level of the tone on simulated inputs, -18 dBFS per EBU R68
+const TONE_VOLUME: f64 = 0.125;
+
+/// What the pipeline has seen of the signal, shared with its pad
+/// probe and the `decklinkvideosrc` signal callback
+#[derive(Debug, Default)]
+struct Reception {
+    /// When the latest frame arrived
+    last_frame: Option<Instant>,
+    /// Timecode of the latest frame
+    timecode: Option<String>,
+    /// Whether the card reports a signal, for DeckLink inputs
+    card_signal: Option<bool>,
+}
+
+/// A pipeline watching an incoming feed. It takes the signal to
+/// thumbnails, previews, meters and the multiviewer like an output
+/// does, and reports what it detects of the signal
+#[derive(Debug)]
+pub struct DecklinkInput {
+    /// Unique identifier
+    id: Uuid,
+    /// What the input is called
+    label: String,
+    /// Where the signal comes from
+    source: InputSource,
+    /// The wrapped pipeline
+    pipeline: gst::Pipeline,
+    /// A helper for managing the pipeline
+    pipeline_manager: Option<Addr<PipelineManager>>,
+    /// What the pipeline has seen of the signal
+    reception: Arc<Mutex<Reception>>,
+    /// Where thumbnails and levels of the input are sent
+    node_manager: Addr<NodeManager>,
+    /// Who is watching the preview stream
+    preview: Arc<Mutex<Preview>>,
+    /// Loudness of the input
+    loudness: Arc<Mutex<LoudnessMeter>>,
+    /// Picture of the input, for the content detectors
+    picture: Arc<Mutex<PictureAnalysis>>,
+    /// Audio levels since they were last sent to the content detectors
+    audio: AudioWindow,
+    /// Buffers reaching the sinks, for the [`Watchdog`]
+    flow: Arc<Mutex<Flow>>,
+    /// Whether the input has been started
+    running: bool,
+    /// Errors since the input was added
+    errors: u32,
+    last_error: Option<String>,
+}
+
+impl Actor for DecklinkInput {
+    type Context = Context<Self>;
+
+    #[instrument(level = "debug", name = "starting", skip(self, ctx), fields(device_id = %self.id))]
+    fn started(&mut self, ctx: &mut Self::Context) {
+        self.start_manager(ctx);
+
+        ctx.run_interval(STATUS_INTERVAL, |act, ctx| {
+            act.report_flow(ctx);
+
+            let status = act.status();
+
+            if status.signal {
+                LoudnessLog::from_registry().do_send(MeasuredLoudnessMessage {
+                    id: act.id,
+                    programs: act.loudness.lock().unwrap().status(),
+                });
+                ContentDetector::from_registry().do_send(ContentStatsMessage {
+                    id: act.id,
+                    name: format!("input {}", act.label),
+                    picture: act.picture.lock().unwrap().take(),
+                    audio: act.audio.take(),
+                });
+            }
+
+            act.node_manager
+                .do_send(InputStatusMessage { id: act.id, status });
+        });
+    }
+
+    #[instrument(level = "debug", name = "stopped", skip(self, _ctx), fields(device_id = %self.id))]
+    fn stopped(&mut self, _ctx: &mut Self::Context) {
+        if let Some(manager) = self.pipeline_manager.take() {
+            manager.do_send(StopManagerMessage);
+        }
+
+        NodeManager::from_registry().do_send(StoppedMessage { id: self.id });
+    }
+}
+
+impl DecklinkInput {
+    pub fn new(
+        node_manager: Addr<NodeManager>,
+        id: Uuid,
+        label: String,
+        source: InputSource,
+    ) -> Result<Self, Error> {
+        let mut input = Self {
+            id,
+            label,
+            source,
+            pipeline: gst::Pipeline::new(),
+            pipeline_manager: None,
+            reception: Arc::new(Mutex::new(Reception::default())),
+            node_manager,
+            preview: Arc::new(Mutex::new(Preview::new(id))),
+            loudness: Arc::new(Mutex::new(LoudnessMeter::default())),
+            picture: Arc::new(Mutex::new(PictureAnalysis::default())),
+            audio: AudioWindow::default(),
+            flow: Arc::new(Mutex::new(Flow::default())),
+            running: false,
+            errors: 0,
+            last_error: None,
+        };
+        input.pipeline = input.build_pipeline()?;
+
+        Ok(input)
+    }
+
+    /// Build the pipeline receiving our source, teeing the picture and
+    /// sound off to thumbnails for the [`NodeManager`], the on-demand
+    /// preview stream, the multiviewer, the meters, the loudness
+    /// meter and the content detectors
+    fn build_pipeline(&self) -> Result<gst::Pipeline, Error> {
+        let id = self.id;
+        let pipeline = gst::Pipeline::new();
+
+        let (video_source, audio_source) = match self.source {
+            InputSource::Decklink(device_num) => {
+                let video = gst::ElementFactory::make("decklinkvideosrc")
+                    .property("device-number", device_num)
+                    .property_from_str("mode", "auto")
+                    .property_from_str("timecode-format", "rp188any")
+                    .property("drop-no-signal-frames", true)
+                    .build()?;
+                let audio = gst::ElementFactory::make("decklinkaudiosrc")
+                    .property("device-number", device_num)
+                    .property_from_str("channels", "max")
+                    .build()?;
+
+                let reception = self.reception.clone();
+                video.connect_notify(Some("signal"), move |src, _| {
+                    reception.lock().unwrap().card_signal = Some(src.property("signal"));
+                });
+
+                pipeline.add_many([&video, &audio])?;
+
+                (video, audio)
+            }
+            InputSource::Simulated => {
+                let video = gst::ElementFactory::make("videotestsrc")
+                    .property("is-live", true)
+                    .build()?;
+                let caps = gst::ElementFactory::make("capsfilter")
+                    .property("caps", video_caps(&OutputFormat::default()))
+                    .build()?;
+                let timecode = gst::ElementFactory::make("timecodestamper").build()?;
+                let audio = gst::ElementFactory::make("audiotestsrc")
+                    .property("is-live", true)
+                    .property("volume", TONE_VOLUME)
+                    .build()?;
+
+                pipeline.add_many([&video, &caps, &timecode, &audio])?;
+                gst::Element::link_many([&video, &caps, &timecode])?;
+
+                (timecode, audio)
+            }
+        };
+
+        let video_tee = gst::ElementFactory::make("tee").name("video-tee").build()?;
+        let video_queue = gst::ElementFactory::make("queue").build()?;
+        let video_sink = gst::ElementFactory::make("fakesink")
+            .property("sync", false)
+            .build()?;
+
+        let node_manager = self.node_manager.clone();
+        let thumbnailer = optional_branch(
+            &id,
+            "thumbnails",
+            make_thumbnailer(move |jpeg| {
+                node_manager.do_send(ThumbnailMessage { id, jpeg });
+            }),
+        );
+        let preview = optional_branch(
+            &id,
+            "preview",
+            make_preview(&self.preview, Some(&monitor_channel(&id))),
+        );
+        let analyser = make_picture_analyser(&self.picture)?;
+
+        let audio_convert = gst::ElementFactory::make("audioconvert").build()?;
+        let level = gst::ElementFactory::make("level")
+            .property("interval", LEVEL_INTERVAL.nseconds())
+            .property("post-messages", true)
+            .build()?;
+        let audio_tee = gst::ElementFactory::make("tee").name("audio-tee").build()?;
+        let audio_queue = gst::ElementFactory::make("queue").build()?;
+        let audio_sink = gst::ElementFactory::make("fakesink")
+            .property("sync", false)
+            .build()?;
+        let loudness = make_loudness_meter(&self.loudness)?;
+        watch_flow(&self.flow, &[&video_sink, &audio_sink])?;
+
+        pipeline.add_many([
+            &video_tee,
+            &video_queue,
+            &video_sink,
+            analyser.upcast_ref::<gst::Element>(),
+            &audio_convert,
+            &level,
+            &audio_tee,
+            &audio_queue,
+            &audio_sink,
+            loudness.upcast_ref::<gst::Element>(),
+        ])?;
+
+        gst::Element::link_many([&video_source, &video_tee, &video_queue, &video_sink])?;
+        video_tee.link(&analyser)?;
+
+        gst::Element::link_many([
+            &audio_source,
+            &audio_convert,
+            &level,
+            &audio_tee,
+            &audio_queue,
+            &audio_sink,
+        ])?;
+        audio_tee.link(&loudness)?;
+
+        if let Some(thumbnailer) = &thumbnailer {
+            pipeline.add(thumbnailer)?;
+            video_tee.link(thumbnailer)?;
+        }
+        if let Some(preview) = &preview {
+            pipeline.add(preview)?;
+            video_tee.link_pads(None, preview, Some("video"))?;
+            audio_tee.link_pads(None, preview, Some("audio"))?;
+        }
+
+        let reception = self.reception.clone();
+        video_tee
+            .static_pad("sink")
+            .expect("tee with no sink pad")
+            .add_probe(gst::PadProbeType::BUFFER, move |_, info| {
+                if let Some(buffer) = info.buffer() {
+                    let mut reception = reception.lock().unwrap();
+                    reception.last_frame = Some(Instant::now());
+                    reception.timecode = buffer
+                        .meta::<gst_video::VideoTimeCodeMeta>()
+                        .map(|meta| meta.tc().to_string());
+                }
+
+                gst::PadProbeReturn::Ok
+            });
+
+        Ok(pipeline)
+    }
+
+    /// Start a [`PipelineManager`] watching our current pipeline
+    fn start_manager(&mut self, ctx: &mut Context<Self>) {
+        self.pipeline_manager = Some(
+            PipelineManager::new(
+                self.pipeline.clone(),
+                ctx.address().downgrade().recipient(),
+                ctx.address().downgrade().recipient(),
+                Some(ctx.address().downgrade().recipient()),
+                self.id,
+            )
+            .start(),
+        );
+    }
+
+    /// Caps negotiated on the sink pad of one of our tees
+    fn tee_caps(&self, name: &str) -> Option<gst::Caps> {
+        self.pipeline
+            .by_name(name)
+            .and_then(|tee| tee.static_pad("sink"))
+            .and_then(|pad| pad.current_caps())
+    }
+
+    /// Tell the [`Watchdog`] how long buffers have been stuck. Frames
+    /// are only expected while the card reports a signal, as losing
+    /// it is alarmed on its own
+    fn report_flow(&self, ctx: &mut Context<Self>) {
+        let card_signal = self.reception.lock().unwrap().card_signal;
+        let mut flow = self.flow.lock().unwrap();
+
+        if self.pipeline.current_state() == gst::State::Playing && card_signal != Some(false) {
+            flow.expect();
+        } else {
+            flow.pause();
+        }
+
+        Watchdog::from_registry().do_send(FlowMessage {
+            id: self.id,
+            name: format!("Input {}", self.id),
+            severity: Severity::Major,
+            stalled: flow.stalled(),
+            restart: ctx.address().recipient(),
+        });
+    }
+
+    /// What is arriving on the input
+    fn status(&self) -> InputStatus {
+        let reception = self.reception.lock().unwrap();
+
+        let receiving = self.pipeline.current_state() == gst::State::Playing
+            && reception
+                .last_frame
+                .map_or(false, |last| last.elapsed() < SIGNAL_TIMEOUT);
+        let signal = receiving && reception.card_signal.unwrap_or(true);
+
+        let mut status = InputStatus {
+            signal,
+            errors: self.errors,
+            last_error: self.last_error.clone(),
+            ..Default::default()
+        };
+
+        if !signal {
+            return status;
+        }
+
+        status.format = self
+            .tee_caps("video-tee")
+            .and_then(|caps| gst_video::VideoInfo::from_caps(&caps).ok())
+            .map(|info| OutputFormat {
+                width: info.width() as i32,
+                height: info.height() as i32,
+                framerate: (info.fps().numer(), info.fps().denom()),
+                interlaced: info.is_interlaced(),
+            });
+        status.mode = status.format.map(|format| format.decklink_mode());
+        status.timecode = reception.timecode.clone();
+        status.audio_channels = self
+            .tee_caps("audio-tee")
+            .and_then(|caps| gst_audio::AudioInfo::from_caps(&caps).ok())
+            .map(|info| info.channels());
+
+        status
+    }
+
+    /// Set our pipeline playing
+    fn start_pipeline(&mut self, ctx: &mut Context<Self>) {
+        let addr = ctx.address();
+        let id = self.id;
+
+        self.pipeline.call_async(move |pipeline| {
+            if let Err(err) = pipeline.set_state(gst::State::Playing) {
+                addr.do_send(ErrorMessage::new(format!(
+                    "Failed to start input {}: {}",
+                    id, err
+                )));
+            }
+        });
+    }
+
+    /// Replace a failed pipeline with a fresh one, carrying on if the
+    /// input was started
+    fn restart(&mut self, ctx: &mut Context<Self>) {
+        match self.build_pipeline() {
+            Ok(pipeline) => {
+                Metrics::from_registry().do_send(NodeEventMessage {
+                    id: self.id,
+                    event: NodeEvent::Restart,
+                });
+
+                self.pipeline = pipeline;
+                self.start_manager(ctx);
+
+                if self.running {
+                    self.start_pipeline(ctx);
+                }
+            }
+            Err(err) => {
+                error!("Failed to rebuild input {}: {}", self.id, err);
+                ctx.run_later(RESTART_DELAY, |act, ctx| act.restart(ctx));
+            }
+        }
+    }
+}
+
+impl Handler<StartMessage> for DecklinkInput {
+    type Result = Result<(), Error>;
+
+    fn handle(&mut self, msg: StartMessage, ctx: &mut Context<Self>) -> Self::Result {
+        self.running = true;
+        if let Some(manager) = &self.pipeline_manager {
+            manager.do_send(TraceTransitionMessage {
+                target: gst::State::Playing,
+                span: msg.span.clone(),
+            });
+        }
+
+        let _entered = msg.span.enter();
+        self.start_pipeline(ctx);
+
+        Ok(())
+    }
+}
+
+impl Handler<StopMessage> for DecklinkInput {
+    type Result = Result<(), Error>;
+
+    fn handle(&mut self, msg: StopMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        self.running = false;
+        let id = self.id;
+        self.pipeline.call_async(move |pipeline| {
+            let _entered = info_span!(
+                parent: &msg.span,
+                "state change",
+                device_id = %id,
+                target = ?gst::State::Null
+            )
+            .entered();
+
+            let _ = pipeline.set_state(gst::State::Null);
+        });
+
+        Ok(())
+    }
+}
+
+impl Handler<RemoveMessage> for DecklinkInput {
+    type Result = ();
+
+    fn handle(&mut self, _: RemoveMessage, ctx: &mut Context<Self>) -> Self::Result {
+        ctx.stop();
+    }
+}
+
+impl Handler<SubscribePreviewMessage> for DecklinkInput {
+    type Result = Result<(), Error>;
+
+    fn handle(&mut self, msg: SubscribePreviewMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        let mut preview = self.preview.lock().unwrap();
+
+        match msg.subscriber {
+            Some(recipient) => preview.subscribe(msg.session, recipient),
+            None => preview.unsubscribe(&msg.session),
+        }
+
+        Ok(())
+    }
+}
+
+impl Handler<LoudnessControlMessage> for DecklinkInput {
+    type Result = Result<(), Error>;
+
+    fn handle(&mut self, msg: LoudnessControlMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        self.loudness.lock().unwrap().control(msg.action);
+
+        Ok(())
+    }
+}
+
+impl Handler<LevelMessage> for DecklinkInput {
+    type Result = ();
+
+    fn handle(&mut self, msg: LevelMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        self.audio.add(&msg.levels);
+        self.node_manager.do_send(LevelsMessage {
+            id: self.id,
+            levels: msg.levels,
+        });
+    }
+}
+
+impl Handler<SegmentDoneMessage> for DecklinkInput {
+    type Result = ();
+
+    fn handle(&mut self, _msg: SegmentDoneMessage, _ctx: &mut Context<Self>) -> Self::Result {}
+}
+
+impl Handler<RestartMessage> for DecklinkInput {
+    type Result = ();
+
+    fn handle(&mut self, _msg: RestartMessage, ctx: &mut Context<Self>) -> Self::Result {
+        if let Some(manager) = self.pipeline_manager.take() {
+            manager.do_send(StopManagerMessage);
+        }
+        let _ = self.pipeline.set_state(gst::State::Null);
+
+        self.restart(ctx);
+    }
+}
+
+impl Handler<ErrorMessage> for DecklinkInput {
+    type Result = ();
+
+    fn handle(&mut self, msg: ErrorMessage, ctx: &mut Context<Self>) -> Self::Result {
+        warn!("Input {} failed, restarting: {}", self.id, msg.message);
+
+        AlarmManager::from_registry().do_send(RaiseAlarmMessage {
+            kind: AlarmKind::Pipeline,
+            severity: Severity::Major,
+            source: AlarmSource {
+                device_id: Some(self.id),
+                element: msg.element,
+            },
+            message: format!("Input {} failed: {}", self.id, msg.message),
+            since: None,
+        });
+
+        Metrics::from_registry().do_send(NodeEventMessage {
+            id: self.id,
+            event: NodeEvent::Error,
+        });
+
+        self.errors += 1;
+        self.last_error = Some(msg.message);
+
+        if let Some(manager) = self.pipeline_manager.take() {
+            manager.do_send(StopManagerMessage);
+        }
+        let _ = self.pipeline.set_state(gst::State::Null);
+
+        debug!("rebuilding input {} in {:?}", self.id, RESTART_DELAY);
+        ctx.run_later(RESTART_DELAY, |act, ctx| act.restart(ctx));
+    }
+}
@@ -0,0 +1,246 @@
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, Error};
+use chrono::{DateTime, Utc};
+use ebur128::{EbuR128, Mode};
+use gst::prelude::*;
+use gstreamer as gst;
+use gstreamer_app as gst_app;
+use gstreamer_audio as gst_audio;
+use tracing::error;
+
+use crate::command::{LoudnessAction, ProgramLoudness};
+
+/// Channels per audio program, signals are measured as stereo pairs
+const PROGRAM_CHANNELS: usize = 2;
+
+/// Convert a linear sample peak to dBTP
+fn peak_db(linear: f64) -> Option<f64> {
+    (linear > 0.0).then(|| 20.0 * linear.log10())
+}
+
+/// A loudness reading, absent while there is nothing to measure
+fn reading(loudness: Result<f64, ebur128::Error>) -> Option<f64> {
+    loudness.ok().filter(|loudness| loudness.is_finite())
+}
+
+/// The highest of a true-peak reading across `channels`, linear
+fn highest_peak(channels: usize, peak: impl Fn(u32) -> Result<f64, ebur128::Error>) -> f64 {
+    (0..channels as u32)
+        .filter_map(|channel| peak(channel).ok())
+        .fold(0.0, f64::max)
+}
+
+/// Loudness of one audio program of a signal, per EBU R128
+#[derive(Debug)]
+struct ProgramMeter {
+    /// Channels of the signal making up the program
+    channels: Range<usize>,
+    /// Momentary and short-term loudness, always running
+    live: EbuR128,
+    /// Integrated loudness, loudness range and true-peak since the
+    /// measurement started
+    integrated: EbuR128,
+    /// Highest true-peak since the last status, linear
+    recent_peak: f64,
+}
+
+impl ProgramMeter {
+    fn new(channels: Range<usize>, rate: u32) -> Result<Self, Error> {
+        let count = channels.len() as u32;
+
+        Ok(Self {
+            channels,
+            live: EbuR128::new(count, rate, Mode::M | Mode::S | Mode::TRUE_PEAK)?,
+            integrated: EbuR128::new(count, rate, Mode::I | Mode::LRA | Mode::TRUE_PEAK)?,
+            recent_peak: 0.0,
+        })
+    }
+}
+
+/// Loudness of every audio program of a signal, shared between a node
+/// and the measuring branch of its pipeline. It outlives pipelines, so
+/// the integrated measurement carries on across changes of source
+#[derive(Debug)]
+pub struct LoudnessMeter {
+    /// Sample rate and channel count the programs are set up for
+    layout: Option<(u32, usize)>,
+    programs: Vec<ProgramMeter>,
+    /// Whether the integrated measurement is running
+    running: bool,
+    /// When the integrated measurement was started or last reset
+    since: Option<DateTime<Utc>>,
+    /// Samples of one program picked out of the signal
+    scratch: Vec<f32>,
+}
+
+impl Default for LoudnessMeter {
+    fn default() -> Self {
+        Self {
+            layout: None,
+            programs: vec![],
+            running: true,
+            since: Some(Utc::now()),
+            scratch: vec![],
+        }
+    }
+}
+
+impl LoudnessMeter {
+    /// Measure interleaved samples of a signal
+    fn add(&mut self, rate: u32, channels: usize, samples: &[f32]) -> Result<(), Error> {
+        if self.layout != Some((rate, channels)) {
+            // Measurements of a different layout can't be carried on
+            self.programs = (0..channels)
+                .step_by(PROGRAM_CHANNELS)
+                .map(|first| {
+                    ProgramMeter::new(first..(first + PROGRAM_CHANNELS).min(channels), rate)
+                })
+                .collect::<Result<_, _>>()?;
+            self.layout = Some((rate, channels));
+        }
+
+        for program in self.programs.iter_mut() {
+            let frames = if program.channels.len() == channels {
+                samples
+            } else {
+                self.scratch.clear();
+                for frame in samples.chunks_exact(channels) {
+                    self.scratch
+                        .extend_from_slice(&frame[program.channels.clone()]);
+                }
+                &self.scratch[..]
+            };
+
+            program.live.add_frames_f32(frames)?;
+            let live = &program.live;
+            program.recent_peak = program
+                .recent_peak
+                .max(highest_peak(program.channels.len(), |channel| {
+                    live.prev_true_peak(channel)
+                }));
+
+            if self.running {
+                program.integrated.add_frames_f32(frames)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Start, stop or reset the integrated measurement
+    pub fn control(&mut self, action: LoudnessAction) {
+        match action {
+            LoudnessAction::Start => {
+                self.running = true;
+                self.since.get_or_insert_with(Utc::now);
+            }
+            LoudnessAction::Stop => self.running = false,
+            LoudnessAction::Reset => {
+                for program in self.programs.iter_mut() {
+                    program.integrated.reset();
+                }
+                self.since = self.running.then(Utc::now);
+            }
+        }
+    }
+
+    /// The latest readings of every program, restarting the recent
+    /// true-peak
+    pub fn status(&mut self) -> Vec<ProgramLoudness> {
+        self.programs
+            .iter_mut()
+            .map(|program| {
+                let channels = program.channels.len();
+                let recent_peak = std::mem::take(&mut program.recent_peak);
+
+                ProgramLoudness {
+                    channels: program.channels.clone().collect(),
+                    momentary: reading(program.live.loudness_momentary()),
+                    short_term: reading(program.live.loudness_shortterm()),
+                    integrated: reading(program.integrated.loudness_global()),
+                    range: reading(program.integrated.loudness_range()),
+                    true_peak: peak_db(highest_peak(channels, |channel| {
+                        program.integrated.true_peak(channel)
+                    })),
+                    recent_true_peak: peak_db(recent_peak),
+                    integrating: self.running,
+                    since: self.since,
+                    alarms: vec![],
+                }
+            })
+            .collect()
+    }
+}
+
+/// Build a bin measuring the loudness of what it takes on its `sink`
+/// ghost pad into `meter`. It drops rather than blocks, so it can hang
+/// off a tee on the on-air path
+pub fn make_loudness_meter(meter: &Arc<Mutex<LoudnessMeter>>) -> Result<gst::Bin, Error> {
+    let bin = gst::Bin::new();
+
+    let queue = gst::ElementFactory::make("queue")
+        .property_from_str("leaky", "downstream")
+        .build()?;
+    let convert = gst::ElementFactory::make("audioconvert").build()?;
+    let caps = gst::ElementFactory::make("capsfilter")
+        .property(
+            "caps",
+            gst_audio::AudioCapsBuilder::new_interleaved()
+                .format(gst_audio::AudioFormat::F32le)
+                .build(),
+        )
+        .build()?;
+    let sink = gst::ElementFactory::make("appsink")
+        .property("sync", false)
+        .property("async", false)
+        .build()?
+        .downcast::<gst_app::AppSink>()
+        .map_err(|_| anyhow!("appsink is not an AppSink"))?;
+
+    let meter = meter.clone();
+    sink.set_callbacks(
+        gst_app::AppSinkCallbacks::builder()
+            .new_sample(move |sink| {
+                let sample = sink.pull_sample().map_err(|_| gst::FlowError::Eos)?;
+                let info = sample
+                    .caps()
+                    .and_then(|caps| gst_audio::AudioInfo::from_caps(caps).ok())
+                    .ok_or(gst::FlowError::NotNegotiated)?;
+                let buffer = sample.buffer().ok_or(gst::FlowError::Error)?;
+                let map = buffer.map_readable().map_err(|_| gst::FlowError::Error)?;
+
+                let samples: Vec<f32> = map
+                    .as_slice()
+                    .chunks_exact(4)
+                    .map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap()))
+                    .collect();
+
+                if let Err(err) =
+                    meter
+                        .lock()
+                        .unwrap()
+                        .add(info.rate(), info.channels() as usize, &samples)
+                {
+                    error!("Failed to measure loudness: {}", err);
+                }
+
+                Ok(gst::FlowSuccess::Ok)
+            })
+            .build(),
+    );
+
+    bin.add_many([&queue, &convert, &caps, sink.upcast_ref::<gst::Element>()])?;
+    gst::Element::link_many([&queue, &convert, &caps, sink.upcast_ref::<gst::Element>()])?;
+
+    bin.add_pad(
+        &gst::GhostPad::builder_with_target(
+            &queue.static_pad("sink").expect("queue with no sink pad"),
+        )?
+        .name("sink")
+        .build(),
+    )?;
+
+    Ok(bin)
+}
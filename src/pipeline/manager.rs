@@ -4,10 +4,15 @@ use futures::channel::oneshot;
 use futures::prelude::*;
 use gst::prelude::*;
 use gstreamer as gst;
-use tracing::{debug, instrument, trace};
+use tracing::{debug, error, info, info_span, instrument, trace, warn, Span};
 use uuid::Uuid;
 
-use super::ErrorMessage;
+use crate::alarm::{AlarmManager, ClearDeviceAlarmsMessage, RaiseAlarmMessage};
+use crate::command::{AlarmKind, AlarmSource, Severity};
+use crate::metrics::{LatencyMessage, Metrics, QosMessage};
+
+use super::graph::{PipelineGraphs, RegisterPipelineMessage, SnapshotGraphMessage};
+use super::{parse_levels, ErrorMessage, LevelMessage, SegmentDoneMessage};
 
 // Maps GStreamer messages for consumption by a [`PipelineManager`]
 /// actor
@@ -57,6 +62,34 @@ impl Handler<StopManagerMessage> for PipelineManager {
     }
 }
 
+/// Trace the pipeline changing state until it gets there or fails,
+/// sent from nodes to [`PipelineManager`] on behalf of the command
+/// that changed it
+#[derive(Debug)]
+pub struct TraceTransitionMessage {
+    pub target: gst::State,
+    /// Where the change came from
+    pub span: Span,
+}
+
+impl Message for TraceTransitionMessage {
+    type Result = ();
+}
+
+impl Handler<TraceTransitionMessage> for PipelineManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: TraceTransitionMessage, _ctx: &mut Context<Self>) {
+        let span = info_span!(
+            parent: &msg.span,
+            "state change",
+            device_id = %self.id,
+            target = ?msg.target
+        );
+        self.transition = Some((msg.target, span));
+    }
+}
+
 /// A wrapper around [`gst::Pipeline`] for monitoring its bus. May send
 /// messages to signal an error to an appropriate recipient (typically the
 /// creator node)
@@ -66,12 +99,18 @@ pub struct PipelineManager {
     pipeline: gst::Pipeline,
     /// The recipient for potential error messages
     recipient: actix::WeakRecipient<ErrorMessage>,
+    /// The recipient for segment seeks completing
+    segment_recipient: actix::WeakRecipient<SegmentDoneMessage>,
+    /// The recipient for audio levels, if anyone is interested
+    level_recipient: Option<actix::WeakRecipient<LevelMessage>>,
     /// The identifier of the creator node, for tracing
     id: Uuid,
     /// To signal that EOS was processed
     eos_sender: Option<oneshot::Sender<()>>,
     /// To wait for EOS to be processed
     eos_receiver: Option<oneshot::Receiver<()>>,
+    /// The state change being traced, ending with the span
+    transition: Option<(gst::State, Span)>,
 }
 
 impl Actor for PipelineManager {
@@ -81,17 +120,22 @@ impl Actor for PipelineManager {
         let bus = self.pipeline.bus().expect("Pipeline with no bus");
         let bus_stream = bus.stream();
         Self::add_stream(bus_stream.map(BusMessage), ctx);
+
+        PipelineGraphs::from_registry().do_send(RegisterPipelineMessage {
+            id: self.id,
+            pipeline: self.pipeline.downgrade(),
+        });
     }
 
-    #[instrument(level = "debug", name = "stopping", skip(self, _ctx), fields(id = %self.id))]
+    #[instrument(level = "debug", name = "stopping", skip(self, _ctx), fields(device_id = %self.id))]
     fn stopped(&mut self, _ctx: &mut Self::Context) {
-        debug!(id = %self.id, "tearing down pipeline");
+        debug!(device_id = %self.id, "tearing down pipeline");
         let _ = self.pipeline.set_state(gst::State::Null);
     }
 }
 
 impl StreamHandler<BusMessage> for PipelineManager {
-    #[instrument(name = "Handling GStreamer bus message", level = "trace", skip(self, msg, _ctx), fields(id = %self.id, source = msg.0.src().as_ref().map(|src| src.path_string()).as_deref().unwrap_or("UNKNOWN")))]
+    #[instrument(name = "Handling GStreamer bus message", level = "trace", skip(self, msg, _ctx), fields(device_id = %self.id, source = msg.0.src().as_ref().map(|src| src.path_string()).as_deref().unwrap_or("UNKNOWN")))]
     fn handle(&mut self, msg: BusMessage, _ctx: &mut Context<Self>) {
         use gst::MessageView;
 
@@ -100,6 +144,21 @@ impl StreamHandler<BusMessage> for PipelineManager {
                 if let Some(s) = m.structure() {
                     if s.name() == "level" {
                         trace!("audio output level: {}", s);
+
+                        if let Some(recipient) =
+                            self.level_recipient.as_ref().and_then(|r| r.upgrade())
+                        {
+                            if let Some(levels) = parse_levels(s) {
+                                recipient.do_send(LevelMessage {
+                                    element: msg
+                                        .0
+                                        .src()
+                                        .map(|src| src.name().to_string())
+                                        .unwrap_or_default(),
+                                    levels,
+                                });
+                            }
+                        }
                     }
                 }
             }
@@ -109,38 +168,50 @@ impl StreamHandler<BusMessage> for PipelineManager {
                         || src.has_as_ancestor(&self.pipeline)
                     {
                         trace!("Pipeline for node {} updated latency", self.id);
-                        self.pipeline.call_async(|pipeline| {
+                        let id = self.id;
+                        self.pipeline.call_async(move |pipeline| {
                             let _ = pipeline.recalculate_latency();
+
+                            let mut query = gst::query::Latency::new();
+                            if pipeline.query(&mut query) {
+                                let (_live, min, _max) = query.result();
+                                Metrics::from_registry().do_send(LatencyMessage {
+                                    id,
+                                    latency: min.into(),
+                                });
+                            }
                         });
                     }
                 }
             }
             MessageView::Error(err) => {
+                self.snapshot_graph("error");
+
                 if let Some(recipient) = self.recipient.upgrade() {
-                    let src = err.src();
+                    let element = err.src().map(|src| src.path_string().to_string());
                     let dbg = err.debug();
                     let err = err.error();
 
-                    if let Some(dbg) = dbg {
-                        recipient.do_send(ErrorMessage(format!(
+                    let message = if let Some(dbg) = dbg {
+                        format!(
                             "Got error from {}: {} ({})",
-                            src.as_ref()
-                                .map(|src| src.path_string())
-                                .as_deref()
-                                .unwrap_or("UNKNOWN"),
+                            element.as_deref().unwrap_or("UNKNOWN"),
                             err,
                             dbg
-                        )));
+                        )
                     } else {
-                        recipient.do_send(ErrorMessage(format!(
+                        format!(
                             "Got error from {}: {}",
-                            src.as_ref()
-                                .map(|src| src.path_string())
-                                .as_deref()
-                                .unwrap_or("UNKNOWN"),
+                            element.as_deref().unwrap_or("UNKNOWN"),
                             err
-                        )));
+                        )
+                    };
+
+                    if let Some((_, span)) = self.transition.take() {
+                        span.in_scope(|| error!("{}", message));
                     }
+
+                    recipient.do_send(ErrorMessage { message, element });
                 }
 
                 // No reason to wait for EOS indefinitely, won't
@@ -149,6 +220,61 @@ impl StreamHandler<BusMessage> for PipelineManager {
                     let _ = eos_sender.send(());
                 }
             }
+            MessageView::Qos(qos) => {
+                let (processed, _) = qos.stats();
+
+                // Audio sinks count samples, only frames are of
+                // interest. Dropped and late frames are counted by
+                // the outputs themselves
+                if processed.format() == gst::Format::Buffers {
+                    Metrics::from_registry().do_send(QosMessage {
+                        id: self.id,
+                        element: msg
+                            .0
+                            .src()
+                            .map(|src| src.path_string().to_string())
+                            .unwrap_or_default(),
+                        processed: processed.value().max(0) as u64,
+                    });
+                }
+            }
+            MessageView::ClockLost(lost) => {
+                let clock = lost.clock().map(|clock| clock.name().to_string());
+                warn!(
+                    "Pipeline for node {} lost its clock {}",
+                    self.id,
+                    clock.as_deref().unwrap_or("UNKNOWN")
+                );
+
+                AlarmManager::from_registry().do_send(RaiseAlarmMessage {
+                    kind: AlarmKind::ClockLoss,
+                    severity: Severity::Major,
+                    source: AlarmSource {
+                        device_id: Some(self.id),
+                        element: clock.clone(),
+                    },
+                    message: format!("Clock {} lost", clock.as_deref().unwrap_or("UNKNOWN")),
+                    since: None,
+                });
+
+                // Going back through paused makes the pipeline pick a
+                // new clock
+                self.pipeline.call_async(|pipeline| {
+                    let _ = pipeline.set_state(gst::State::Paused);
+                    let _ = pipeline.set_state(gst::State::Playing);
+                });
+            }
+            MessageView::NewClock(_) => {
+                AlarmManager::from_registry().do_send(ClearDeviceAlarmsMessage {
+                    device_id: self.id,
+                    kinds: vec![AlarmKind::ClockLoss],
+                });
+            }
+            MessageView::SegmentDone(_) => {
+                if let Some(recipient) = self.segment_recipient.upgrade() {
+                    recipient.do_send(SegmentDoneMessage);
+                }
+            }
             MessageView::Eos(_) => {
                 if let Some(eos_sender) = self.eos_sender.take() {
                     let _ = eos_sender.send(());
@@ -161,7 +287,20 @@ impl StreamHandler<BusMessage> for PipelineManager {
                         self.id,
                         state_changed.old(),
                         state_changed.current()
-                    )
+                    );
+
+                    if state_changed.current() == gst::State::Playing {
+                        self.snapshot_graph("playing");
+                    }
+
+                    if let Some((target, span)) = &self.transition {
+                        if *target == state_changed.current()
+                            && state_changed.pending() == gst::State::VoidPending
+                        {
+                            span.in_scope(|| info!("Pipeline reached {:?}", target));
+                            self.transition = None;
+                        }
+                    }
                 }
             }
             _ => (),
@@ -170,8 +309,26 @@ impl StreamHandler<BusMessage> for PipelineManager {
 }
 
 impl PipelineManager {
+    /// Save the graph of the pipeline as it is now for post-mortems
+    fn snapshot_graph(&self, reason: &'static str) {
+        PipelineGraphs::from_registry().do_send(SnapshotGraphMessage {
+            id: self.id,
+            reason,
+            dot: self
+                .pipeline
+                .debug_to_dot_data(gst::DebugGraphDetails::ALL)
+                .to_string(),
+        });
+    }
+
     /// Create a new manager
-    pub fn new(pipeline: gst::Pipeline, recipient: WeakRecipient<ErrorMessage>, id: Uuid) -> Self {
+    pub fn new(
+        pipeline: gst::Pipeline,
+        recipient: WeakRecipient<ErrorMessage>,
+        segment_recipient: WeakRecipient<SegmentDoneMessage>,
+        level_recipient: Option<WeakRecipient<LevelMessage>>,
+        id: Uuid,
+    ) -> Self {
         let (eos_sender, eos_receiver) = oneshot::channel::<()>();
 
         pipeline.use_clock(Some(&gst::SystemClock::obtain()));
@@ -181,9 +338,12 @@ impl PipelineManager {
         Self {
             pipeline,
             recipient,
+            segment_recipient,
+            level_recipient,
             id,
             eos_sender: Some(eos_sender),
             eos_receiver: Some(eos_receiver),
+            transition: None,
         }
     }
 }
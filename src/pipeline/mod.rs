@@ -1,9 +1,26 @@
 use actix::Message;
 use anyhow::{anyhow, Error};
 use gstreamer as gst;
+use gstreamer_video as gst_video;
+use tracing::warn;
+use uuid::Uuid;
 
+use crate::command::{ChannelLevel, OutputFormat};
+
+pub(crate) mod analysis;
 pub mod decklink;
+pub mod flow;
+pub mod frames;
+pub mod graph;
+pub mod input;
+pub mod loudness;
 pub mod manager;
+pub(crate) mod multiviewer;
+pub mod overlay;
+pub mod playout;
+pub mod preview;
+pub mod source;
+pub mod thumbnail;
 
 /// Wrapper around `gst::ElementFactory::make` with a better error
 /// message
@@ -12,10 +29,98 @@ pub fn make_element(element: &str, name: Option<&str>) -> Result<gst::Element, E
         .map_err(|err| anyhow!("Failed to make element {}: {}", element, err.message))
 }
 
+/// The elements of `elements` no installed plugin provides
+pub fn missing_elements<'a>(elements: &[&'a str]) -> Vec<&'a str> {
+    elements
+        .iter()
+        .copied()
+        .filter(|element| gst::ElementFactory::find(element).is_none())
+        .collect()
+}
+
+/// A monitoring branch to hang off the on-air path of node `id`, or
+/// `None` with a warning when it could not be built, so monitoring
+/// never keeps a node off air
+pub fn optional_branch(id: &Uuid, what: &str, branch: Result<gst::Bin, Error>) -> Option<gst::Bin> {
+    match branch {
+        Ok(bin) => Some(bin),
+        Err(err) => {
+            warn!("Running {} without {}: {}", id, what, err);
+            None
+        }
+    }
+}
+
+/// Raw video caps matching an output format, with square pixels so
+/// scaling letterboxes rather than stretches
+pub fn video_caps(format: &OutputFormat) -> gst::Caps {
+    gst_video::VideoCapsBuilder::new()
+        .width(format.width)
+        .height(format.height)
+        .pixel_aspect_ratio((1, 1).into())
+        .framerate(format.framerate.into())
+        .build()
+}
+
+/// Quietest level reported, standing in for silence
+const LEVEL_FLOOR: f64 = -100.0;
+
+/// The level of each channel from a `level` element message
+pub fn parse_levels(s: &gst::StructureRef) -> Option<Vec<ChannelLevel>> {
+    let peak = s.get::<gst::glib::ValueArray>("peak").ok()?;
+    let rms = s.get::<gst::glib::ValueArray>("rms").ok()?;
+
+    Some(
+        peak.iter()
+            .zip(rms.iter())
+            .map(|(peak, rms)| ChannelLevel {
+                peak: peak.get::<f64>().unwrap_or(LEVEL_FLOOR).max(LEVEL_FLOOR),
+                rms: rms.get::<f64>().unwrap_or(LEVEL_FLOOR).max(LEVEL_FLOOR),
+            })
+            .collect(),
+    )
+}
+
 /// Sent from [`PipelineManager`] to nodes to signal an error
 #[derive(Debug)]
-pub struct ErrorMessage(pub String);
+pub struct ErrorMessage {
+    pub message: String,
+    /// Path of the element that failed, when it was one
+    pub element: Option<String>,
+}
+
+impl ErrorMessage {
+    /// An error of the pipeline as a whole
+    pub fn new(message: String) -> Self {
+        Self {
+            message,
+            element: None,
+        }
+    }
+}
 
 impl Message for ErrorMessage {
     type Result = ();
 }
+
+/// Sent from [`PipelineManager`] to nodes with the audio level of
+/// each channel measured by one `level` element
+#[derive(Debug)]
+pub struct LevelMessage {
+    /// Name of the `level` element
+    pub element: String,
+    pub levels: Vec<ChannelLevel>,
+}
+
+impl Message for LevelMessage {
+    type Result = ();
+}
+
+/// Sent from [`PipelineManager`] to nodes when a segment seek
+/// reaches the end of its segment
+#[derive(Debug)]
+pub struct SegmentDoneMessage;
+
+impl Message for SegmentDoneMessage {
+    type Result = ();
+}
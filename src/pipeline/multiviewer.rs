@@ -0,0 +1,298 @@
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, Error};
+use gst::prelude::*;
+use gstreamer as gst;
+use gstreamer_video as gst_video;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::command::{ChannelLevel, OutputFormat};
+use crate::multiviewer::MultiviewerOutput;
+
+use super::flow::{watch_flow, Flow};
+use super::preview::{make_preview, Preview};
+use super::video_caps;
+
+/// Width of the border around each tile, red when the output is on air
+const BORDER_WIDTH: f64 = 4.0;
+/// Width of each audio meter bar
+const METER_WIDTH: f64 = 8.0;
+/// Level at the bottom of the meters, in dBFS
+const METER_FLOOR: f64 = -60.0;
+/// Above this the meters turn amber, in dBFS
+const METER_AMBER: f64 = -18.0;
+/// Above this the meters turn red, in dBFS
+const METER_RED: f64 = -9.0;
+/// How often the tile meters update
+const LEVEL_INTERVAL: gst::ClockTime = gst::ClockTime::from_mseconds(100);
+
+/// Name of the inter channels a node sends its scaled output to for
+/// the multiviewer
+pub fn monitor_channel(device_id: &Uuid) -> String {
+    format!("monitor-{}", device_id)
+}
+
+/// What a tile shows around the picture, shared between the
+/// multiviewer and the drawing callback of the tile
+#[derive(Debug, Default)]
+pub struct TileState {
+    /// Name under the picture
+    pub label: String,
+    /// Whether the output is on air
+    pub tally: bool,
+    /// Latest audio level of each channel
+    pub levels: Vec<ChannelLevel>,
+    /// Size of the tile in pixels
+    size: (f64, f64),
+}
+
+impl TileState {
+    /// Draw the tally border, label and meters
+    fn draw(&self, cr: &cairo::Context) -> Result<(), cairo::Error> {
+        let (width, height) = self.size;
+
+        if self.tally {
+            cr.set_source_rgb(0.9, 0.0, 0.0);
+        } else {
+            cr.set_source_rgb(0.25, 0.25, 0.25);
+        }
+        cr.set_line_width(BORDER_WIDTH);
+        cr.rectangle(
+            BORDER_WIDTH / 2.0,
+            BORDER_WIDTH / 2.0,
+            width - BORDER_WIDTH,
+            height - BORDER_WIDTH,
+        );
+        cr.stroke()?;
+
+        cr.select_font_face("Sans", cairo::FontSlant::Normal, cairo::FontWeight::Bold);
+        cr.set_font_size((height * 0.07).max(10.0));
+        let extents = cr.text_extents(&self.label)?;
+        let padding = extents.height() * 0.4;
+        let label_x = (width - extents.width()) / 2.0;
+        let label_y = height - BORDER_WIDTH - padding * 2.0;
+
+        cr.set_source_rgba(0.0, 0.0, 0.0, 0.6);
+        cr.rectangle(
+            label_x - padding,
+            label_y - extents.height() - padding,
+            extents.width() + padding * 2.0,
+            extents.height() + padding * 2.0,
+        );
+        cr.fill()?;
+        cr.set_source_rgb(1.0, 1.0, 1.0);
+        cr.move_to(label_x - extents.x_bearing(), label_y);
+        cr.show_text(&self.label)?;
+
+        let top = BORDER_WIDTH * 2.0;
+        let bottom = height - BORDER_WIDTH * 2.0;
+        let y =
+            |db: f64| bottom - (bottom - top) * ((db - METER_FLOOR) / -METER_FLOOR).clamp(0.0, 1.0);
+
+        for (channel, level) in self.levels.iter().enumerate() {
+            let x = BORDER_WIDTH * 2.0 + channel as f64 * (METER_WIDTH + 2.0);
+
+            cr.set_source_rgba(0.0, 0.0, 0.0, 0.6);
+            cr.rectangle(x, top, METER_WIDTH, bottom - top);
+            cr.fill()?;
+
+            for (from, to, (r, g, b)) in [
+                (METER_FLOOR, METER_AMBER, (0.0, 0.8, 0.0)),
+                (METER_AMBER, METER_RED, (0.9, 0.7, 0.0)),
+                (METER_RED, 0.0, (0.9, 0.0, 0.0)),
+            ] {
+                if level.peak <= from {
+                    break;
+                }
+
+                cr.set_source_rgb(r, g, b);
+                cr.rectangle(
+                    x,
+                    y(level.peak.min(to)),
+                    METER_WIDTH,
+                    y(from) - y(level.peak.min(to)),
+                );
+                cr.fill()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A tile of the mosaic
+#[derive(Debug)]
+pub struct MosaicTile {
+    /// Inter channel the tile shows
+    pub channel: String,
+    /// Position and size in pixels
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub state: Arc<Mutex<TileState>>,
+}
+
+/// Create a `cairooverlay` drawing the surround of a tile
+fn make_tile_overlay(state: Arc<Mutex<TileState>>) -> Result<gst::Element, Error> {
+    let overlay = gst::ElementFactory::make("cairooverlay").build()?;
+
+    overlay.connect("draw", false, move |args| {
+        if let Ok(cr) = args[1].get::<cairo::Context>() {
+            if let Err(err) = state.lock().unwrap().draw(&cr) {
+                error!("Failed to draw multiviewer tile: {}", err);
+            }
+        }
+
+        None
+    });
+
+    Ok(overlay)
+}
+
+/// Name of the `level` element metering the tile at `index`
+fn level_name(index: usize) -> String {
+    format!("level-{}", index)
+}
+
+/// The index of the tile metered by the `level` element `name`
+pub fn tile_of_level(name: &str) -> Option<usize> {
+    name.strip_prefix("level-")?.parse().ok()
+}
+
+/// Build the multiviewer pipeline, compositing `tiles` at `format`
+/// onto `output`, with the mosaic also available as `preview` and its
+/// progress tracked in `flow`. The level of each tile is posted on
+/// the bus by an element named after its index
+pub fn build_mosaic(
+    format: &OutputFormat,
+    tiles: &[MosaicTile],
+    output: &MultiviewerOutput,
+    preview: &Arc<Mutex<Preview>>,
+    flow: &Arc<Mutex<Flow>>,
+) -> Result<gst::Pipeline, Error> {
+    let pipeline = gst::Pipeline::new();
+
+    let compositor = gst::ElementFactory::make("compositor")
+        .property_from_str("background", "black")
+        .build()?;
+    let caps = gst::ElementFactory::make("capsfilter")
+        .property("caps", video_caps(format))
+        .build()?;
+    let tee = gst::ElementFactory::make("tee").build()?;
+    let queue = gst::ElementFactory::make("queue").build()?;
+    let convert = gst::ElementFactory::make("videoconvert").build()?;
+    let sink = match output {
+        MultiviewerOutput::Decklink(device_num) => gst::ElementFactory::make("decklinkvideosink")
+            .property_from_str("mode", &format.decklink_mode())
+            .property("device-number", *device_num)
+            .property("sync", true)
+            .build()?,
+        MultiviewerOutput::Simulated => gst::ElementFactory::make("fakesink")
+            .property("sync", true)
+            .build()?,
+    };
+
+    // The mosaic has no sound of its own, but the preview expects some
+    let silence = gst::ElementFactory::make("audiotestsrc")
+        .property("is-live", true)
+        .property_from_str("wave", "silence")
+        .build()?;
+    let preview = make_preview(preview, None)?;
+
+    pipeline.add_many([
+        &compositor,
+        &caps,
+        &tee,
+        &queue,
+        &convert,
+        &sink,
+        &silence,
+        preview.upcast_ref::<gst::Element>(),
+    ])?;
+    gst::Element::link_many([&compositor, &caps, &tee, &queue, &convert, &sink])?;
+    watch_flow(flow, &[&sink])?;
+    tee.link_pads(None, &preview, Some("video"))?;
+    silence.link_pads(None, &preview, Some("audio"))?;
+
+    for (index, tile) in tiles.iter().enumerate() {
+        tile.state.lock().unwrap().size = (tile.width as f64, tile.height as f64);
+
+        let video = gst::ElementFactory::make("intervideosrc")
+            .property("channel", &tile.channel)
+            .build()?;
+        let video_convert = gst::ElementFactory::make("videoconvert").build()?;
+        let scale = gst::ElementFactory::make("videoscale")
+            .property("add-borders", true)
+            .build()?;
+        let tile_caps = gst::ElementFactory::make("capsfilter")
+            .property(
+                "caps",
+                gst_video::VideoCapsBuilder::new()
+                    .width(tile.width)
+                    .height(tile.height)
+                    .pixel_aspect_ratio((1, 1).into())
+                    .build(),
+            )
+            .build()?;
+        let timecode = gst::ElementFactory::make("timeoverlay")
+            .property_from_str("time-mode", "time-code")
+            .property_from_str("halignment", "right")
+            .property_from_str("valignment", "top")
+            .property_from_str("font-desc", "Monospace, 12")
+            .build()?;
+        let overlay_convert = gst::ElementFactory::make("videoconvert").build()?;
+        let overlay = make_tile_overlay(tile.state.clone())?;
+
+        let audio = gst::ElementFactory::make("interaudiosrc")
+            .property("channel", &tile.channel)
+            .build()?;
+        let audio_convert = gst::ElementFactory::make("audioconvert").build()?;
+        let level = gst::ElementFactory::make("level")
+            .name(level_name(index))
+            .property("interval", LEVEL_INTERVAL.nseconds())
+            .property("post-messages", true)
+            .build()?;
+        let audio_sink = gst::ElementFactory::make("fakesink")
+            .property("sync", true)
+            .property("async", false)
+            .build()?;
+
+        pipeline.add_many([
+            &video,
+            &video_convert,
+            &scale,
+            &tile_caps,
+            &timecode,
+            &overlay_convert,
+            &overlay,
+            &audio,
+            &audio_convert,
+            &level,
+            &audio_sink,
+        ])?;
+        gst::Element::link_many([
+            &video,
+            &video_convert,
+            &scale,
+            &tile_caps,
+            &timecode,
+            &overlay_convert,
+            &overlay,
+        ])?;
+        gst::Element::link_many([&audio, &audio_convert, &level, &audio_sink])?;
+
+        let pad = compositor
+            .request_pad_simple("sink_%u")
+            .ok_or_else(|| anyhow!("Failed to request compositor pad"))?;
+        pad.set_property("xpos", tile.x);
+        pad.set_property("ypos", tile.y);
+        overlay
+            .static_pad("src")
+            .expect("cairooverlay with no src pad")
+            .link(&pad)?;
+    }
+
+    Ok(pipeline)
+}
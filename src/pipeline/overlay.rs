@@ -0,0 +1,163 @@
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Error;
+use gst::prelude::*;
+use gstreamer as gst;
+use gstreamer_video as gst_video;
+use tracing::{error, trace};
+
+use crate::command::Overlay;
+
+/// Action safe area per EBU R95, as a fraction of the picture
+const ACTION_SAFE: f64 = 0.93;
+/// Graphics (title) safe area per EBU R95, as a fraction of the picture
+const TITLE_SAFE: f64 = 0.90;
+/// Number of grid cells across the width of the picture
+const GRID_COLUMNS: u32 = 16;
+/// Number of grid cells down the height of the picture
+const GRID_ROWS: u32 = 9;
+
+/// The set of markers enabled on an output, shared between the node
+/// and the drawing callback of its `cairooverlay`
+#[derive(Debug, Default)]
+pub struct Markers {
+    /// Markers currently drawn
+    enabled: BTreeSet<Overlay>,
+    /// Negotiated format of the output, updated on caps changes
+    info: Option<gst_video::VideoInfo>,
+}
+
+impl Markers {
+    /// Enable or disable a marker
+    pub fn set(&mut self, overlay: Overlay, enabled: bool) {
+        if enabled {
+            self.enabled.insert(overlay);
+        } else {
+            self.enabled.remove(&overlay);
+        }
+    }
+
+    /// The markers currently enabled, in a stable order
+    pub fn enabled(&self) -> Vec<Overlay> {
+        self.enabled.iter().copied().collect()
+    }
+
+    /// Draw the enabled markers at the current output resolution
+    fn draw(&self, cr: &cairo::Context) -> Result<(), cairo::Error> {
+        let info = match &self.info {
+            Some(info) => info,
+            None => return Ok(()),
+        };
+
+        let width = info.width() as f64;
+        let height = info.height() as f64;
+        let par = info.par();
+        let picture_aspect = width * par.numer() as f64 / (height * par.denom() as f64);
+
+        cr.set_line_width((height / 540.0).max(1.0));
+
+        for overlay in self.enabled.iter() {
+            match overlay {
+                Overlay::ActionSafe => {
+                    cr.set_source_rgba(1.0, 1.0, 1.0, 0.8);
+                    safe_area(cr, width, height, ACTION_SAFE);
+                }
+                Overlay::TitleSafe => {
+                    cr.set_source_rgba(1.0, 1.0, 1.0, 0.8);
+                    safe_area(cr, width, height, TITLE_SAFE);
+                }
+                Overlay::CentreCross => {
+                    let size = height * 0.05;
+                    cr.set_source_rgba(1.0, 1.0, 1.0, 0.8);
+                    cr.move_to(width / 2.0 - size, height / 2.0);
+                    cr.line_to(width / 2.0 + size, height / 2.0);
+                    cr.move_to(width / 2.0, height / 2.0 - size);
+                    cr.line_to(width / 2.0, height / 2.0 + size);
+                }
+                Overlay::Aspect4x3 => {
+                    cr.set_source_rgba(1.0, 1.0, 0.0, 0.8);
+                    aspect(cr, width, height, picture_aspect, 4.0 / 3.0);
+                }
+                Overlay::Aspect14x9 => {
+                    cr.set_source_rgba(1.0, 1.0, 0.0, 0.8);
+                    aspect(cr, width, height, picture_aspect, 14.0 / 9.0);
+                }
+                Overlay::Aspect239 => {
+                    cr.set_source_rgba(1.0, 1.0, 0.0, 0.8);
+                    aspect(cr, width, height, picture_aspect, 2.39);
+                }
+                Overlay::Grid => {
+                    cr.set_source_rgba(0.5, 0.5, 0.5, 0.6);
+                    for column in 1..GRID_COLUMNS {
+                        let x = width * column as f64 / GRID_COLUMNS as f64;
+                        cr.move_to(x, 0.0);
+                        cr.line_to(x, height);
+                    }
+                    for row in 1..GRID_ROWS {
+                        let y = height * row as f64 / GRID_ROWS as f64;
+                        cr.move_to(0.0, y);
+                        cr.line_to(width, y);
+                    }
+                }
+            }
+
+            cr.stroke()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Outline a centred area covering `fraction` of the picture
+fn safe_area(cr: &cairo::Context, width: f64, height: f64, fraction: f64) {
+    let x = width * (1.0 - fraction) / 2.0;
+    let y = height * (1.0 - fraction) / 2.0;
+    cr.rectangle(x, y, width * fraction, height * fraction);
+}
+
+/// Mark the edges of a centred `target` aspect ratio, as pillars when
+/// it is narrower than the picture and as letterbox lines otherwise
+fn aspect(cr: &cairo::Context, width: f64, height: f64, picture: f64, target: f64) {
+    if target < picture {
+        let inset = width * (1.0 - target / picture) / 2.0;
+        cr.move_to(inset, 0.0);
+        cr.line_to(inset, height);
+        cr.move_to(width - inset, 0.0);
+        cr.line_to(width - inset, height);
+    } else {
+        let inset = height * (1.0 - picture / target) / 2.0;
+        cr.move_to(0.0, inset);
+        cr.line_to(width, inset);
+        cr.move_to(0.0, height - inset);
+        cr.line_to(width, height - inset);
+    }
+}
+
+/// Create a `cairooverlay` drawing the markers enabled in `markers`
+pub fn make_markers(markers: Arc<Mutex<Markers>>) -> Result<gst::Element, Error> {
+    let overlay = gst::ElementFactory::make("cairooverlay").build()?;
+
+    let caps_markers = markers.clone();
+    overlay.connect("caps-changed", false, move |args| {
+        if let Ok(caps) = args[1].get::<gst::Caps>() {
+            let info = gst_video::VideoInfo::from_caps(&caps).ok();
+            trace!("markers caps changed: {:?}", info);
+            caps_markers.lock().unwrap().info = info;
+        }
+
+        None
+    });
+
+    overlay.connect("draw", false, move |args| {
+        if let Ok(cr) = args[1].get::<cairo::Context>() {
+            if let Err(err) = markers.lock().unwrap().draw(&cr) {
+                error!("Failed to draw markers: {}", err);
+            }
+        }
+
+        None
+    });
+
+    Ok(overlay)
+}
@@ -0,0 +1,630 @@
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Error};
+use gst::prelude::*;
+use gstreamer as gst;
+use tracing::{debug, error, instrument};
+
+use crate::command::{OutputFormat, Playlist, PlaylistItem, PlayoutStatus, Transition};
+
+use super::source::make_source;
+use super::video_caps;
+
+/// How far ahead of its take the next item is prerolled
+const PREROLL_LEAD: Duration = Duration::from_secs(2);
+/// How long the player may take to preroll before seeking
+const PREROLL_TIMEOUT: gst::ClockTime = gst::ClockTime::from_seconds(5);
+
+/// The mixing end of a playout: a bin compositing two inter channels,
+/// one per player slot, over black. It exposes `video` and `audio`
+/// ghost source pads like any other source
+#[derive(Debug)]
+pub struct Program {
+    /// The bin to plug into the output pipeline
+    pub bin: gst::Bin,
+    /// Compositor pad of each slot
+    video_pads: [gst::Pad; 2],
+    /// Audio mixer pad of each slot
+    audio_pads: [gst::Pad; 2],
+}
+
+impl Program {
+    /// Build a program fed by the inter channels named after `channel`
+    pub fn new(channel: &str) -> Result<Self, Error> {
+        let bin = gst::Bin::new();
+
+        let compositor = gst::ElementFactory::make("compositor")
+            .property_from_str("background", "black")
+            .build()?;
+        let mixer = gst::ElementFactory::make("audiomixer").build()?;
+
+        bin.add_many([&compositor, &mixer])?;
+
+        let mut video_pads = vec![];
+        let mut audio_pads = vec![];
+
+        for slot in 0..2 {
+            let video = gst::ElementFactory::make("intervideosrc")
+                .property("channel", slot_channel(channel, slot))
+                .build()?;
+            let video_convert = gst::ElementFactory::make("videoconvert").build()?;
+            let audio = gst::ElementFactory::make("interaudiosrc")
+                .property("channel", slot_channel(channel, slot))
+                .build()?;
+            let audio_convert = gst::ElementFactory::make("audioconvert").build()?;
+            let audio_resample = gst::ElementFactory::make("audioresample").build()?;
+
+            bin.add_many([
+                &video,
+                &video_convert,
+                &audio,
+                &audio_convert,
+                &audio_resample,
+            ])?;
+            video.link(&video_convert)?;
+            gst::Element::link_many([&audio, &audio_convert, &audio_resample])?;
+
+            let video_pad = compositor
+                .request_pad_simple("sink_%u")
+                .ok_or_else(|| anyhow!("Failed to request compositor pad"))?;
+            video_pad.set_property("alpha", 0.0f64);
+            video_convert
+                .static_pad("src")
+                .expect("videoconvert with no src pad")
+                .link(&video_pad)?;
+
+            let audio_pad = mixer
+                .request_pad_simple("sink_%u")
+                .ok_or_else(|| anyhow!("Failed to request audio mixer pad"))?;
+            audio_pad.set_property("volume", 0.0f64);
+            audio_resample
+                .static_pad("src")
+                .expect("audioresample with no src pad")
+                .link(&audio_pad)?;
+
+            video_pads.push(video_pad);
+            audio_pads.push(audio_pad);
+        }
+
+        bin.add_pad(
+            &gst::GhostPad::builder_with_target(
+                &compositor
+                    .static_pad("src")
+                    .expect("compositor with no src pad"),
+            )?
+            .name("video")
+            .build(),
+        )?;
+        bin.add_pad(
+            &gst::GhostPad::builder_with_target(
+                &mixer.static_pad("src").expect("audiomixer with no src pad"),
+            )?
+            .name("audio")
+            .build(),
+        )?;
+
+        Ok(Self {
+            bin,
+            video_pads: video_pads.try_into().expect("two video pads"),
+            audio_pads: audio_pads.try_into().expect("two audio pads"),
+        })
+    }
+
+    /// Set how much of a slot is mixed into the program, from 0 to 1
+    fn set_level(&self, slot: usize, level: f64) {
+        self.video_pads[slot].set_property("alpha", level);
+        self.audio_pads[slot].set_property("volume", level);
+    }
+}
+
+/// Name of the inter channel for a player slot
+fn slot_channel(channel: &str, slot: usize) -> String {
+    format!("{}-{}", channel, slot)
+}
+
+/// The item after `index`, wrapping around when looping
+fn following(playlist: &Playlist, index: usize) -> Option<usize> {
+    if index + 1 < playlist.items.len() {
+        Some(index + 1)
+    } else if playlist.looping {
+        Some(0)
+    } else {
+        None
+    }
+}
+
+/// The item before `current`, wrapping around when looping, or the
+/// last item once the playlist has ended
+fn preceding(playlist: &Playlist, current: Option<usize>) -> usize {
+    let count = playlist.items.len();
+
+    match current {
+        Some(0) if playlist.looping => count - 1,
+        Some(0) => 0,
+        Some(current) => current - 1,
+        None => count - 1,
+    }
+}
+
+/// One playlist item playing in its own pipeline into an inter
+/// channel, so it can be prerolled and seeked independently of the
+/// output
+#[derive(Debug)]
+struct Player {
+    /// The item being played
+    item: PlaylistItem,
+    /// The wrapped pipeline
+    pipeline: gst::Pipeline,
+    /// When the item went to air
+    started: Option<Instant>,
+    /// Whether the player reached the end of its media or failed
+    ended: bool,
+    /// Whether the player failed
+    failed: bool,
+}
+
+impl Player {
+    /// Build a player for `item` and preroll it at its in point
+    #[instrument(level = "debug", name = "preparing player", skip(format))]
+    fn new(item: &PlaylistItem, channel: &str, format: &OutputFormat) -> Result<Self, Error> {
+        let pipeline = gst::Pipeline::new();
+
+        let source = make_source(&item.source)?;
+
+        let video_convert = gst::ElementFactory::make("videoconvert").build()?;
+        let scale = gst::ElementFactory::make("videoscale")
+            .property("add-borders", true)
+            .build()?;
+        let rate = gst::ElementFactory::make("videorate").build()?;
+        let caps = gst::ElementFactory::make("capsfilter")
+            .property("caps", video_caps(format))
+            .build()?;
+        let video_sink = gst::ElementFactory::make("intervideosink")
+            .property("channel", channel)
+            .build()?;
+
+        let audio_convert = gst::ElementFactory::make("audioconvert").build()?;
+        let audio_resample = gst::ElementFactory::make("audioresample").build()?;
+        let audio_sink = gst::ElementFactory::make("interaudiosink")
+            .property("channel", channel)
+            .build()?;
+
+        pipeline.add_many([
+            source.upcast_ref::<gst::Element>(),
+            &video_convert,
+            &scale,
+            &rate,
+            &caps,
+            &video_sink,
+            &audio_convert,
+            &audio_resample,
+            &audio_sink,
+        ])?;
+
+        source.link_pads(Some("video"), &video_convert, None)?;
+        gst::Element::link_many([&video_convert, &scale, &rate, &caps, &video_sink])?;
+        source.link_pads(Some("audio"), &audio_convert, None)?;
+        gst::Element::link_many([&audio_convert, &audio_resample, &audio_sink])?;
+
+        pipeline.set_state(gst::State::Paused)?;
+
+        if item.in_ms.is_some() || item.out_ms.is_some() {
+            let start = gst::ClockTime::from_mseconds(item.in_ms.unwrap_or(0));
+            let stop = item.out_ms.map(gst::ClockTime::from_mseconds);
+
+            pipeline.call_async(move |pipeline| {
+                let _ = pipeline.state(Some(PREROLL_TIMEOUT));
+                if let Err(err) = pipeline.seek(
+                    1.0,
+                    gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
+                    gst::SeekType::Set,
+                    Some(start),
+                    if stop.is_some() {
+                        gst::SeekType::Set
+                    } else {
+                        gst::SeekType::None
+                    },
+                    stop,
+                ) {
+                    error!("Failed to seek to in point: {}", err);
+                }
+            });
+        }
+
+        Ok(Self {
+            item: item.clone(),
+            pipeline,
+            started: None,
+            ended: false,
+            failed: false,
+        })
+    }
+
+    /// Put the item on air
+    fn play(&mut self) -> Result<(), Error> {
+        self.pipeline.set_state(gst::State::Playing)?;
+        self.started = Some(Instant::now());
+
+        Ok(())
+    }
+
+    /// Time since the item went to air
+    fn elapsed(&self) -> Duration {
+        self.started
+            .map(|started| started.elapsed())
+            .unwrap_or_default()
+    }
+
+    /// How long the item plays for, when known
+    fn length(&self) -> Option<Duration> {
+        if let Some(duration) = self.item.duration_ms {
+            return Some(Duration::from_millis(duration));
+        }
+
+        let start = self.item.in_ms.unwrap_or(0);
+        let end = self.item.out_ms.or_else(|| {
+            self.pipeline
+                .query_duration::<gst::ClockTime>()
+                .map(|duration| duration.mseconds())
+        })?;
+
+        Some(Duration::from_millis(end.saturating_sub(start)))
+    }
+
+    /// Time until the item ends, when known
+    fn remaining(&self) -> Option<Duration> {
+        self.length()
+            .map(|length| length.saturating_sub(self.elapsed()))
+    }
+
+    /// Take every message off the bus, so none pile up, noting the
+    /// end of the media and failures
+    fn poll(&mut self) {
+        let bus = match self.pipeline.bus() {
+            Some(bus) => bus,
+            None => return,
+        };
+
+        while let Some(msg) = bus.pop() {
+            match msg.view() {
+                gst::MessageView::Eos(_) => self.ended = true,
+                gst::MessageView::Error(err) => {
+                    error!("Playlist item failed: {}", err.error());
+                    self.ended = true;
+                    self.failed = true;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Whether the player reached the end of its media or failed
+    fn finished(&mut self) -> bool {
+        self.poll();
+        self.ended
+    }
+
+    /// Whether the player failed, e.g. while prerolling
+    fn failed(&mut self) -> bool {
+        self.poll();
+        self.failed
+    }
+}
+
+impl Drop for Player {
+    fn drop(&mut self) {
+        let _ = self.pipeline.set_state(gst::State::Null);
+    }
+}
+
+/// A crossfade in progress between the two slots
+#[derive(Debug)]
+struct Fade {
+    /// When the fade began
+    started: Instant,
+    /// How long the fade lasts
+    duration: Duration,
+}
+
+/// Plays a playlist on one output, alternating items between two
+/// player slots so the next one is always prerolled before its take
+#[derive(Debug)]
+pub struct Playout {
+    /// What to play
+    playlist: Playlist,
+    /// The mixing end in the output pipeline
+    program: Program,
+    /// Prefix of the inter channels of each slot
+    channel: String,
+    /// Format the players convert to
+    format: OutputFormat,
+    /// The players in each slot
+    players: [Option<Player>; 2],
+    /// The slot on air
+    active: usize,
+    /// The item on air, `None` once the playlist has ended
+    current: Option<usize>,
+    /// The item prerolled in the standby slot
+    prepared: Option<usize>,
+    /// A crossfade from the standby slot to the active one
+    fade: Option<Fade>,
+}
+
+impl Playout {
+    /// Create a playout feeding `program`, and take the first item
+    pub fn new(
+        playlist: Playlist,
+        program: Program,
+        channel: &str,
+        format: OutputFormat,
+    ) -> Result<Self, Error> {
+        if playlist.items.is_empty() {
+            return Err(anyhow!("Playlist has no items"));
+        }
+
+        let mut playout = Self {
+            playlist,
+            program,
+            channel: channel.to_string(),
+            format,
+            players: [None, None],
+            active: 0,
+            current: None,
+            prepared: None,
+            fade: None,
+        };
+
+        playout.take(0)?;
+
+        Ok(playout)
+    }
+
+    /// The playlist being played
+    pub fn playlist(&self) -> &Playlist {
+        &self.playlist
+    }
+
+    /// The slot not on air
+    fn standby(&self) -> usize {
+        1 - self.active
+    }
+
+    /// Preroll an item in the standby slot
+    fn prepare(&mut self, index: usize) -> Result<(), Error> {
+        let item = self
+            .playlist
+            .items
+            .get(index)
+            .ok_or_else(|| anyhow!("No playlist item {}", index))?;
+        let slot = self.standby();
+
+        self.players[slot] = None;
+        self.players[slot] = Some(Player::new(
+            item,
+            &slot_channel(&self.channel, slot),
+            &self.format,
+        )?);
+        self.prepared = Some(index);
+
+        Ok(())
+    }
+
+    /// Take an item to air with the playlist transition
+    #[instrument(level = "debug", name = "taking item", skip(self))]
+    pub fn take(&mut self, mut index: usize) -> Result<(), Error> {
+        // An item that failed while prerolling is skipped rather than
+        // taken to air
+        if self.prepared == Some(index) {
+            let slot = self.standby();
+            if self.players[slot]
+                .as_mut()
+                .map_or(false, |player| player.failed())
+            {
+                error!("Skipping playlist item {}, which failed to preroll", index);
+                self.players[slot] = None;
+                self.prepared = None;
+
+                index = match following(&self.playlist, index) {
+                    Some(next) if next != index => next,
+                    _ => return Err(anyhow!("Playlist item {} failed to preroll", index)),
+                };
+            }
+        }
+
+        if self.prepared != Some(index) {
+            self.prepare(index)?;
+        }
+
+        // A take during a fade cuts it short
+        if self.fade.take().is_some() {
+            self.finish_fade();
+        }
+
+        let slot = self.standby();
+        if let Some(player) = self.players[slot].as_mut() {
+            player.play()?;
+        }
+
+        self.active = slot;
+        self.current = Some(index);
+        self.prepared = None;
+
+        match self.playlist.transition {
+            Transition::Crossfade { duration_ms } if duration_ms > 0 => {
+                self.fade = Some(Fade {
+                    started: Instant::now(),
+                    duration: Duration::from_millis(duration_ms),
+                });
+            }
+            _ => self.finish_fade(),
+        }
+
+        Ok(())
+    }
+
+    /// Leave only the active slot on air and release the other
+    fn finish_fade(&mut self) {
+        let standby = self.standby();
+
+        self.program.set_level(self.active, 1.0);
+        self.program.set_level(standby, 0.0);
+
+        if self.prepared.is_none() {
+            self.players[standby] = None;
+        }
+    }
+
+    /// Take the next item, or end the playlist
+    pub fn next(&mut self) -> Result<(), Error> {
+        match self
+            .current
+            .and_then(|current| following(&self.playlist, current))
+        {
+            Some(index) => self.take(index),
+            None => {
+                self.end();
+                Ok(())
+            }
+        }
+    }
+
+    /// Take the previous item, wrapping around when looping
+    pub fn previous(&mut self) -> Result<(), Error> {
+        self.take(preceding(&self.playlist, self.current))
+    }
+
+    /// Jump straight to an item
+    pub fn skip(&mut self, index: usize) -> Result<(), Error> {
+        self.take(index)
+    }
+
+    /// Go to black at the end of the playlist
+    fn end(&mut self) {
+        debug!("playlist ended");
+
+        self.fade = None;
+        self.current = None;
+        self.prepared = None;
+        self.players = [None, None];
+        self.program.set_level(0, 0.0);
+        self.program.set_level(1, 0.0);
+    }
+
+    /// Advance the playout, called regularly by the owning node
+    pub fn tick(&mut self) {
+        if let Some(fade) = &self.fade {
+            let progress = fade.started.elapsed().as_secs_f64() / fade.duration.as_secs_f64();
+
+            if progress >= 1.0 {
+                self.fade = None;
+                self.finish_fade();
+            } else {
+                self.program.set_level(self.active, progress);
+                self.program.set_level(self.standby(), 1.0 - progress);
+            }
+        }
+
+        let current = match self.current {
+            Some(current) => current,
+            None => return,
+        };
+
+        let (finished, remaining) = match self.players[self.active].as_mut() {
+            Some(player) => (player.finished(), player.remaining()),
+            None => (true, None),
+        };
+
+        let lead = match self.playlist.transition {
+            Transition::Crossfade { duration_ms } => Duration::from_millis(duration_ms),
+            Transition::Cut => Duration::ZERO,
+        };
+
+        if finished || remaining.map_or(false, |remaining| remaining <= lead) {
+            if let Err(err) = self.next() {
+                error!("Failed to take next playlist item: {}", err);
+                self.end();
+            }
+            return;
+        }
+
+        // Preroll what comes next so the take is instant
+        if self.fade.is_none() && self.prepared.is_none() {
+            if let (Some(remaining), Some(next)) = (remaining, following(&self.playlist, current)) {
+                if remaining <= PREROLL_LEAD + lead {
+                    if let Err(err) = self.prepare(next) {
+                        error!("Failed to prepare playlist item {}: {}", next, err);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Where the playlist is up to
+    pub fn status(&self) -> PlayoutStatus {
+        let player = self.players[self.active].as_ref();
+
+        PlayoutStatus {
+            current: self.current,
+            count: self.playlist.items.len(),
+            elapsed_ms: player
+                .map(|player| player.elapsed().as_millis() as u64)
+                .unwrap_or(0),
+            remaining_ms: player
+                .and_then(|player| player.remaining())
+                .map(|remaining| remaining.as_millis() as u64),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::VideoMode;
+
+    fn playlist(count: usize, looping: bool) -> Playlist {
+        Playlist {
+            items: (0..count)
+                .map(|_| PlaylistItem {
+                    source: VideoMode::TestCard("smpte".to_string()),
+                    duration_ms: Some(1000),
+                    in_ms: None,
+                    out_ms: None,
+                })
+                .collect(),
+            looping,
+            transition: Transition::Cut,
+        }
+    }
+
+    #[test]
+    fn following_wraps_only_when_looping() {
+        let once = playlist(3, false);
+        assert_eq!(following(&once, 0), Some(1));
+        assert_eq!(following(&once, 1), Some(2));
+        assert_eq!(following(&once, 2), None);
+
+        let looping = playlist(3, true);
+        assert_eq!(following(&looping, 1), Some(2));
+        assert_eq!(following(&looping, 2), Some(0));
+
+        let single = playlist(1, true);
+        assert_eq!(following(&single, 0), Some(0));
+        assert_eq!(following(&playlist(1, false), 0), None);
+    }
+
+    #[test]
+    fn preceding_wraps_only_when_looping() {
+        let once = playlist(3, false);
+        assert_eq!(preceding(&once, Some(2)), 1);
+        assert_eq!(preceding(&once, Some(1)), 0);
+        // Stays on the first item
+        assert_eq!(preceding(&once, Some(0)), 0);
+        // Back to the last item after the playlist ended
+        assert_eq!(preceding(&once, None), 2);
+
+        let looping = playlist(3, true);
+        assert_eq!(preceding(&looping, Some(1)), 0);
+        assert_eq!(preceding(&looping, Some(0)), 2);
+        assert_eq!(preceding(&looping, None), 2);
+    }
+}
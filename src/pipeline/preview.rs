@@ -0,0 +1,423 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use actix::{Message, Recipient};
+use actix_web::web::{Bytes, BytesMut};
+use anyhow::{anyhow, Error};
+use gst::prelude::*;
+use gstreamer as gst;
+use gstreamer_app as gst_app;
+use gstreamer_audio as gst_audio;
+use gstreamer_video as gst_video;
+use tracing::warn;
+use uuid::Uuid;
+
+use super::missing_elements;
+
+/// Width of the preview picture
+const PREVIEW_WIDTH: i32 = 640;
+/// Height of the preview picture
+const PREVIEW_HEIGHT: i32 = 360;
+/// Video bitrate of the preview in kbit/s
+const PREVIEW_BITRATE: u32 = 1500;
+/// Frames between keyframes, which bounds how long a new viewer waits
+const PREVIEW_KEYFRAME_INTERVAL: u32 = 25;
+/// Target length of each MP4 fragment
+const FRAGMENT_DURATION: gst::ClockTime = gst::ClockTime::from_mseconds(500);
+/// Elements the preview stream is encoded with, from optional plugins
+const ENCODER_ELEMENTS: [&str; 5] = [
+    "x264enc",
+    "h264parse",
+    "avenc_aac",
+    "aacparse",
+    "isofmp4mux",
+];
+
+/// A chunk of preview stream for a websocket, the 16 byte id of the
+/// device followed by fragmented MP4
+#[derive(Debug)]
+pub struct PreviewDataMessage(pub Bytes);
+
+impl Message for PreviewDataMessage {
+    type Result = ();
+}
+
+/// A viewer of the preview
+#[derive(Debug)]
+struct Subscriber {
+    recipient: Recipient<PreviewDataMessage>,
+    /// Whether the viewer has had the current init segment
+    synced: bool,
+}
+
+/// Who is watching an output, shared between the node and the
+/// preview branch of its pipeline. The branch only encodes while
+/// there are subscribers
+#[derive(Debug)]
+pub struct Preview {
+    /// Device the stream is of, prefixed to every chunk
+    device_id: Uuid,
+    /// Viewers by websocket session
+    subscribers: HashMap<Uuid, Subscriber>,
+    /// The latest init segment, sent to viewers before their first
+    /// fragment
+    header: Option<Bytes>,
+    /// Valves gating the encoders of the current branch
+    valves: Vec<gst::Element>,
+}
+
+impl Preview {
+    pub fn new(device_id: Uuid) -> Self {
+        Self {
+            device_id,
+            subscribers: HashMap::new(),
+            header: None,
+            valves: vec![],
+        }
+    }
+
+    /// Start sending the preview to a websocket session
+    pub fn subscribe(&mut self, session: Uuid, recipient: Recipient<PreviewDataMessage>) {
+        self.subscribers.insert(
+            session,
+            Subscriber {
+                recipient,
+                synced: false,
+            },
+        );
+        self.update_valves();
+    }
+
+    /// Stop sending the preview to a websocket session
+    pub fn unsubscribe(&mut self, session: &Uuid) {
+        if self.subscribers.remove(session).is_some() {
+            self.update_valves();
+        }
+    }
+
+    /// Only let media into the encoders while someone is watching
+    fn update_valves(&self) {
+        let drop = self.subscribers.is_empty();
+
+        for valve in self.valves.iter() {
+            valve.set_property("drop", drop);
+        }
+    }
+
+    /// Take over from the branch of a previous pipeline, whose init
+    /// segment no longer applies
+    fn attach(&mut self, valves: Vec<gst::Element>) {
+        self.valves = valves;
+        self.header = None;
+
+        for subscriber in self.subscribers.values_mut() {
+            subscriber.synced = false;
+        }
+
+        self.update_valves();
+    }
+
+    /// Forward muxer output to every subscriber
+    fn push(&mut self, data: &[u8]) {
+        let (init, fragment) = split_init_segment(data);
+
+        if !init.is_empty() {
+            self.header = Some(self.frame(init));
+
+            for subscriber in self.subscribers.values_mut() {
+                subscriber.synced = false;
+            }
+        }
+
+        if fragment.is_empty() {
+            return;
+        }
+
+        let fragment = self.frame(fragment);
+
+        for subscriber in self.subscribers.values_mut() {
+            // Every fragment starts on a keyframe, so anyone who has
+            // the init segment can join here
+            if !subscriber.synced {
+                match &self.header {
+                    Some(header) => subscriber
+                        .recipient
+                        .do_send(PreviewDataMessage(header.clone())),
+                    None => continue,
+                }
+                subscriber.synced = true;
+            }
+
+            subscriber
+                .recipient
+                .do_send(PreviewDataMessage(fragment.clone()));
+        }
+    }
+
+    /// Prefix a chunk with our device id
+    fn frame(&self, data: &[u8]) -> Bytes {
+        let mut frame = BytesMut::with_capacity(16 + data.len());
+        frame.extend_from_slice(self.device_id.as_bytes());
+        frame.extend_from_slice(data);
+        frame.freeze()
+    }
+}
+
+/// Split muxer output into any leading `ftyp`/`moov` init segment and
+/// the fragments that follow it
+fn split_init_segment(data: &[u8]) -> (&[u8], &[u8]) {
+    let mut offset = 0;
+
+    while offset + 8 <= data.len() {
+        let size = u32::from_be_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        let kind = &data[offset + 4..offset + 8];
+
+        if !matches!(kind, b"ftyp" | b"moov") || size < 8 {
+            break;
+        }
+
+        offset += size;
+    }
+
+    data.split_at(offset.min(data.len()))
+}
+
+/// Build a bin encoding the output to fragmented MP4 for `preview`.
+/// It takes the output on its `video` and `audio` ghost sink pads,
+/// dropping rather than blocking so it can hang off tees on the
+/// on-air path, and encodes nothing while nobody is watching. With a
+/// `monitor` channel the scaled picture and sound are also sent to
+/// inter elements of that name for the multiviewer. Without the
+/// encoders installed only the monitor is fed
+pub fn make_preview(
+    preview: &Arc<Mutex<Preview>>,
+    monitor: Option<&str>,
+) -> Result<gst::Bin, Error> {
+    let bin = gst::Bin::new();
+
+    let video_queue = gst::ElementFactory::make("queue")
+        .property_from_str("leaky", "downstream")
+        .property("max-size-buffers", 3u32)
+        .build()?;
+    let deinterlace = gst::ElementFactory::make("deinterlace").build()?;
+    let video_convert = gst::ElementFactory::make("videoconvert").build()?;
+    let scale = gst::ElementFactory::make("videoscale").build()?;
+    let video_caps = gst::ElementFactory::make("capsfilter")
+        .property(
+            "caps",
+            gst_video::VideoCapsBuilder::new()
+                .format(gst_video::VideoFormat::I420)
+                .width(PREVIEW_WIDTH)
+                .height(PREVIEW_HEIGHT)
+                .pixel_aspect_ratio((1, 1).into())
+                .build(),
+        )
+        .build()?;
+    // Either branch may be left out
+    let video_tee = gst::ElementFactory::make("tee")
+        .property("allow-not-linked", true)
+        .build()?;
+
+    let audio_queue = gst::ElementFactory::make("queue")
+        .property_from_str("leaky", "downstream")
+        .build()?;
+    let audio_convert = gst::ElementFactory::make("audioconvert").build()?;
+    let audio_resample = gst::ElementFactory::make("audioresample").build()?;
+    let audio_caps = gst::ElementFactory::make("capsfilter")
+        .property(
+            "caps",
+            gst_audio::AudioCapsBuilder::new()
+                .rate(48000)
+                .channels(2)
+                .build(),
+        )
+        .build()?;
+    let audio_tee = gst::ElementFactory::make("tee")
+        .property("allow-not-linked", true)
+        .build()?;
+
+    bin.add_many([
+        &video_queue,
+        &deinterlace,
+        &video_convert,
+        &scale,
+        &video_caps,
+        &video_tee,
+        &audio_queue,
+        &audio_convert,
+        &audio_resample,
+        &audio_caps,
+        &audio_tee,
+    ])?;
+    gst::Element::link_many([
+        &video_queue,
+        &deinterlace,
+        &video_convert,
+        &scale,
+        &video_caps,
+        &video_tee,
+    ])?;
+    gst::Element::link_many([
+        &audio_queue,
+        &audio_convert,
+        &audio_resample,
+        &audio_caps,
+        &audio_tee,
+    ])?;
+
+    let missing = missing_elements(&ENCODER_ELEMENTS);
+    let valves = if missing.is_empty() {
+        make_encoder(&bin, preview, &video_tee, &audio_tee)?
+    } else {
+        warn!("Previews unavailable, missing {}", missing.join(", "));
+        vec![]
+    };
+
+    if let Some(channel) = monitor {
+        let video_monitor_queue = gst::ElementFactory::make("queue")
+            .property_from_str("leaky", "downstream")
+            .property("max-size-buffers", 1u32)
+            .build()?;
+        let video_monitor = gst::ElementFactory::make("intervideosink")
+            .property("channel", channel)
+            .build()?;
+        let audio_monitor_queue = gst::ElementFactory::make("queue")
+            .property_from_str("leaky", "downstream")
+            .build()?;
+        let audio_monitor = gst::ElementFactory::make("interaudiosink")
+            .property("channel", channel)
+            .build()?;
+
+        bin.add_many([
+            &video_monitor_queue,
+            &video_monitor,
+            &audio_monitor_queue,
+            &audio_monitor,
+        ])?;
+        gst::Element::link_many([&video_tee, &video_monitor_queue, &video_monitor])?;
+        gst::Element::link_many([&audio_tee, &audio_monitor_queue, &audio_monitor])?;
+    }
+
+    bin.add_pad(
+        &gst::GhostPad::builder_with_target(
+            &video_queue
+                .static_pad("sink")
+                .expect("queue with no sink pad"),
+        )?
+        .name("video")
+        .build(),
+    )?;
+    bin.add_pad(
+        &gst::GhostPad::builder_with_target(
+            &audio_queue
+                .static_pad("sink")
+                .expect("queue with no sink pad"),
+        )?
+        .name("audio")
+        .build(),
+    )?;
+
+    preview.lock().unwrap().attach(valves);
+
+    Ok(bin)
+}
+
+/// Add the encoding half of the preview to `bin`, fed from the
+/// scaled `video_tee` and `audio_tee` and handing fragments to
+/// `preview`. Returns the valves gating it
+fn make_encoder(
+    bin: &gst::Bin,
+    preview: &Arc<Mutex<Preview>>,
+    video_tee: &gst::Element,
+    audio_tee: &gst::Element,
+) -> Result<Vec<gst::Element>, Error> {
+    let video_valve = gst::ElementFactory::make("valve")
+        .property("drop", true)
+        .build()?;
+    let video_encoder = gst::ElementFactory::make("x264enc")
+        .property_from_str("tune", "zerolatency")
+        .property_from_str("speed-preset", "ultrafast")
+        .property("bitrate", PREVIEW_BITRATE)
+        .property("key-int-max", PREVIEW_KEYFRAME_INTERVAL)
+        .build()?;
+    // Constrained baseline plays everywhere Media Source Extensions do
+    let encoded_caps = gst::ElementFactory::make("capsfilter")
+        .property(
+            "caps",
+            gst::Caps::builder("video/x-h264")
+                .field("profile", "constrained-baseline")
+                .build(),
+        )
+        .build()?;
+    let video_parse = gst::ElementFactory::make("h264parse").build()?;
+
+    let audio_valve = gst::ElementFactory::make("valve")
+        .property("drop", true)
+        .build()?;
+    let audio_encoder = gst::ElementFactory::make("avenc_aac").build()?;
+    let audio_parse = gst::ElementFactory::make("aacparse").build()?;
+
+    let mux = gst::ElementFactory::make("isofmp4mux")
+        .property("fragment-duration", FRAGMENT_DURATION)
+        .build()?;
+    let sink = gst::ElementFactory::make("appsink")
+        .property("sync", false)
+        .property("async", false)
+        .property("buffer-list", true)
+        .build()?
+        .downcast::<gst_app::AppSink>()
+        .map_err(|_| anyhow!("appsink is not an AppSink"))?;
+
+    let shared = preview.clone();
+    sink.set_callbacks(
+        gst_app::AppSinkCallbacks::builder()
+            .new_sample(move |sink| {
+                let sample = sink.pull_sample().map_err(|_| gst::FlowError::Eos)?;
+
+                let mut data = vec![];
+                let mut append = |buffer: &gst::BufferRef| -> Result<(), gst::FlowError> {
+                    let map = buffer.map_readable().map_err(|_| gst::FlowError::Error)?;
+                    data.extend_from_slice(map.as_slice());
+                    Ok(())
+                };
+
+                if let Some(list) = sample.buffer_list() {
+                    for buffer in list.iter() {
+                        append(buffer)?;
+                    }
+                } else if let Some(buffer) = sample.buffer() {
+                    append(buffer)?;
+                }
+
+                shared.lock().unwrap().push(&data);
+
+                Ok(gst::FlowSuccess::Ok)
+            })
+            .build(),
+    );
+
+    bin.add_many([
+        &video_valve,
+        &video_encoder,
+        &encoded_caps,
+        &video_parse,
+        &audio_valve,
+        &audio_encoder,
+        &audio_parse,
+        &mux,
+        sink.upcast_ref::<gst::Element>(),
+    ])?;
+
+    gst::Element::link_many([
+        video_tee,
+        &video_valve,
+        &video_encoder,
+        &encoded_caps,
+        &video_parse,
+        &mux,
+    ])?;
+    gst::Element::link_many([audio_tee, &audio_valve, &audio_encoder, &audio_parse, &mux])?;
+    mux.link(&sink)?;
+
+    Ok(vec![video_valve, audio_valve])
+}
@@ -0,0 +1,216 @@
+use anyhow::{anyhow, Error};
+use gst::prelude::*;
+use gstreamer as gst;
+use tracing::{debug, error};
+
+use crate::command::VideoMode;
+
+/// Level of the line-up tone on stills, -18 dBFS per EBU R68
+const TONE_VOLUME: f64 = 0.125;
+/// Frequency of the line-up tone on stills
+const TONE_FREQUENCY: f64 = 1000.0;
+
+/// Set the pattern of a `videotestsrc` by name, checking it first as
+/// setting an unknown one panics
+fn set_pattern(video: &gst::Element, pattern: &str) -> Result<(), Error> {
+    let known = video
+        .find_property("pattern")
+        .and_then(|pspec| gst::glib::EnumClass::with_type(pspec.value_type()))
+        .map_or(false, |patterns| {
+            patterns.value_by_nick(pattern).is_some() || patterns.value_by_name(pattern).is_some()
+        });
+    if !known {
+        return Err(anyhow!("Unknown test pattern {}", pattern));
+    }
+
+    video.set_property_from_str("pattern", pattern);
+
+    Ok(())
+}
+
+/// Build a bin producing the video and audio for `mode`. The bin
+/// exposes `video` and `audio` ghost source pads, left unconstrained
+/// so the caller can convert them to the output format. The bin is
+/// named `source` so it can be found in the pipeline again
+pub fn make_source(mode: &VideoMode) -> Result<gst::Bin, Error> {
+    let bin = gst::Bin::with_name("source");
+
+    let (video, audio) = match mode {
+        VideoMode::TestCard(pattern) => {
+            let video = gst::ElementFactory::make("videotestsrc")
+                .property("is-live", true)
+                .build()?;
+            set_pattern(&video, pattern)?;
+            let audio = gst::ElementFactory::make("audiotestsrc")
+                .property("is-live", true)
+                .build()?;
+
+            bin.add_many([&video, &audio])?;
+
+            (video, audio)
+        }
+        VideoMode::Stream(uri) => {
+            let decodebin = gst::ElementFactory::make("uridecodebin")
+                .property("uri", uri)
+                .build()?;
+            let video = gst::ElementFactory::make("videoconvert").build()?;
+            let audio = gst::ElementFactory::make("audioconvert").build()?;
+
+            bin.add_many([&decodebin, &video, &audio])?;
+            link_decoded(&decodebin, &video, Some(&audio));
+
+            (video, audio)
+        }
+        VideoMode::Still { location, tone } => {
+            let decodebin = gst::ElementFactory::make("uridecodebin")
+                .property("uri", file_uri(location)?)
+                .build()?;
+            let convert = gst::ElementFactory::make("videoconvert").build()?;
+            let video = gst::ElementFactory::make("imagefreeze")
+                .property("is-live", true)
+                .build()?;
+            let audio = gst::ElementFactory::make("audiotestsrc")
+                .property("is-live", true)
+                .property("freq", TONE_FREQUENCY)
+                .property("volume", TONE_VOLUME)
+                .property_from_str("wave", if *tone { "sine" } else { "silence" })
+                .build()?;
+
+            bin.add_many([&decodebin, &convert, &video, &audio])?;
+            convert.link(&video)?;
+            link_decoded(&decodebin, &convert, None);
+
+            (video, audio)
+        }
+        VideoMode::Playlist => {
+            return Err(anyhow!("Playlists have no single source, load one instead"));
+        }
+    };
+
+    bin.add_pad(
+        &gst::GhostPad::builder_with_target(
+            &video
+                .static_pad("src")
+                .expect("video source with no src pad"),
+        )?
+        .name("video")
+        .build(),
+    )?;
+    bin.add_pad(
+        &gst::GhostPad::builder_with_target(
+            &audio
+                .static_pad("src")
+                .expect("audio source with no src pad"),
+        )?
+        .name("audio")
+        .build(),
+    )?;
+
+    Ok(bin)
+}
+
+/// Convert a local path to a `file://` URI, passing through anything
+/// that already is one
+fn file_uri(location: &str) -> Result<String, Error> {
+    if location.contains("://") {
+        return Ok(location.to_string());
+    }
+
+    let path = std::fs::canonicalize(location)
+        .map_err(|err| anyhow!("Invalid still location {}: {}", location, err))?;
+
+    gst::glib::filename_to_uri(&path, None)
+        .map(|uri| uri.to_string())
+        .map_err(|err| anyhow!("Invalid still location {}: {}", location, err))
+}
+
+/// Link the dynamic pads of a decoder to `video` and `audio` as they
+/// appear. Streams without a matching element are left unlinked,
+/// except `audio`, which gets silence if the decoder has no audio
+fn link_decoded(decodebin: &gst::Element, video: &gst::Element, audio: Option<&gst::Element>) {
+    if let Some(audio) = audio {
+        let audio = audio.downgrade();
+
+        decodebin.connect_no_more_pads(move |decodebin| {
+            let audio = match audio.upgrade() {
+                Some(audio) => audio,
+                None => return,
+            };
+            if audio.static_pad("sink").map_or(true, |pad| pad.is_linked()) {
+                return;
+            }
+
+            debug!("no audio stream, playing silence");
+            if let Err(err) = link_silence(decodebin, &audio) {
+                error!("Failed to link silence: {}", err);
+            }
+        });
+    }
+
+    let video = video.downgrade();
+    let audio = audio.map(|audio| audio.downgrade());
+
+    decodebin.connect_pad_added(move |_, pad| {
+        let caps = match pad.current_caps() {
+            Some(caps) => caps,
+            None => pad.query_caps(None),
+        };
+        let name = match caps.structure(0) {
+            Some(s) => s.name().to_string(),
+            None => return,
+        };
+
+        let sink = if name.starts_with("video/") {
+            video.upgrade()
+        } else if name.starts_with("audio/") {
+            audio.as_ref().and_then(|audio| audio.upgrade())
+        } else {
+            None
+        };
+
+        if let Some(sink_pad) = sink.and_then(|sink| sink.static_pad("sink")) {
+            if sink_pad.is_linked() {
+                debug!("ignoring additional {} stream", name);
+                return;
+            }
+
+            if let Err(err) = pad.link(&sink_pad) {
+                error!("Failed to link decoded {} stream: {}", name, err);
+            }
+        }
+    });
+}
+
+/// Feed silence to `audio`, next to `decodebin` in its bin, so a
+/// stream without audio still prerolls
+fn link_silence(decodebin: &gst::Element, audio: &gst::Element) -> Result<(), Error> {
+    let bin = decodebin
+        .parent()
+        .and_then(|parent| parent.downcast::<gst::Bin>().ok())
+        .ok_or_else(|| anyhow!("Decoder is not in a bin"))?;
+
+    let silence = gst::ElementFactory::make("audiotestsrc")
+        .property_from_str("wave", "silence")
+        .build()?;
+    bin.add(&silence)?;
+    silence.link(audio)?;
+    silence.sync_state_with_parent()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_test_pattern_rejected() {
+        gst::init().unwrap();
+
+        let err = make_source(&VideoMode::TestCard("smtpe".to_string())).unwrap_err();
+        assert_eq!(err.to_string(), "Unknown test pattern smtpe");
+
+        assert!(make_source(&VideoMode::TestCard("smpte".to_string())).is_ok());
+        assert!(make_source(&VideoMode::TestCard("GST_VIDEO_TEST_SRC_BALL".to_string())).is_ok());
+    }
+}
@@ -0,0 +1,95 @@
+use anyhow::{anyhow, Error};
+use gst::prelude::*;
+use gstreamer as gst;
+use gstreamer_app as gst_app;
+use gstreamer_video as gst_video;
+
+/// Width of thumbnails
+const THUMBNAIL_WIDTH: i32 = 320;
+/// Height of thumbnails
+const THUMBNAIL_HEIGHT: i32 = 180;
+
+/// Build a bin taking the output video on its `sink` ghost pad and
+/// handing a JPEG snapshot to `on_snapshot` about once a second. The
+/// bin drops rather than blocks, so it can hang off a tee on the
+/// on-air path without affecting it
+pub fn make_thumbnailer<F>(on_snapshot: F) -> Result<gst::Bin, Error>
+where
+    F: Fn(Vec<u8>) + Send + 'static,
+{
+    let bin = gst::Bin::new();
+
+    let queue = gst::ElementFactory::make("queue")
+        .property_from_str("leaky", "downstream")
+        .property("max-size-buffers", 1u32)
+        .property("max-size-bytes", 0u32)
+        .property("max-size-time", 0u64)
+        .build()?;
+    let rate = gst::ElementFactory::make("videorate")
+        .property("drop-only", true)
+        .build()?;
+    let scale = gst::ElementFactory::make("videoscale").build()?;
+    let convert = gst::ElementFactory::make("videoconvert").build()?;
+    let caps = gst::ElementFactory::make("capsfilter")
+        .property(
+            "caps",
+            gst_video::VideoCapsBuilder::new()
+                .width(THUMBNAIL_WIDTH)
+                .height(THUMBNAIL_HEIGHT)
+                .framerate((1, 1).into())
+                .build(),
+        )
+        .build()?;
+    let encoder = gst::ElementFactory::make("jpegenc").build()?;
+    let sink = gst::ElementFactory::make("appsink")
+        .property("sync", false)
+        .property("async", false)
+        .property("max-buffers", 1u32)
+        .property("drop", true)
+        .build()?
+        .downcast::<gst_app::AppSink>()
+        .map_err(|_| anyhow!("appsink is not an AppSink"))?;
+
+    sink.set_callbacks(
+        gst_app::AppSinkCallbacks::builder()
+            .new_sample(move |sink| {
+                let sample = sink.pull_sample().map_err(|_| gst::FlowError::Eos)?;
+                let buffer = sample.buffer().ok_or(gst::FlowError::Error)?;
+                let map = buffer.map_readable().map_err(|_| gst::FlowError::Error)?;
+
+                on_snapshot(map.as_slice().to_vec());
+
+                Ok(gst::FlowSuccess::Ok)
+            })
+            .build(),
+    );
+
+    bin.add_many([
+        &queue,
+        &rate,
+        &scale,
+        &convert,
+        &caps,
+        &encoder,
+        sink.upcast_ref::<gst::Element>(),
+    ])?;
+    gst::Element::link_many([
+        &queue,
+        &rate,
+        &scale,
+        &convert,
+        &caps,
+        &encoder,
+        sink.upcast_ref::<gst::Element>(),
+    ])?;
+
+    bin.add_pad(
+        &gst::GhostPad::builder_with_target(
+            &queue.static_pad("sink").expect("queue with no sink pad"),
+        )?
+        .name("sink")
+        .build(),
+    )?;
+
+    Ok(bin)
+}
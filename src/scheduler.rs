@@ -0,0 +1,590 @@
+use std::collections::{HashMap, VecDeque};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use actix::{
+    Actor, ActorFutureExt, AsyncContext, Context, Handler, Message, MessageResult, SystemService,
+    WrapFuture,
+};
+use anyhow::{anyhow, Error};
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{error, info, instrument};
+use uuid::Uuid;
+
+use crate::command::{Command, CommandResult};
+use crate::config::data_dir;
+use crate::history::DailyHistory;
+use crate::node::{CommandMessage, NodeManager};
+
+/// How often the schedule is checked for due entries
+const TICK_INTERVAL: Duration = Duration::from_secs(1);
+/// How many past runs are kept in memory for the API
+const HISTORY_LENGTH: usize = 1000;
+/// How many daily history files are kept before the oldest are removed
+const HISTORY_DAYS: usize = 90;
+
+/// When a schedule entry runs
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum When {
+    /// Once, at a fixed time
+    Once(DateTime<Utc>),
+    /// Repeatedly, per a cron expression with seconds, in local time,
+    /// e.g. `0 30 6 * * Mon-Fri`
+    Cron(String),
+}
+
+impl When {
+    /// Check a cron expression parses
+    fn validate(&self) -> Result<(), Error> {
+        if let When::Cron(expression) = self {
+            cron::Schedule::from_str(expression)
+                .map_err(|err| anyhow!("Invalid cron expression {}: {}", expression, err))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// What to run and when, as given over the API
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct EntrySpec {
+    #[serde(default)]
+    pub label: String,
+    pub when: When,
+    pub command: Command,
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+}
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+/// An entry in the schedule
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct ScheduleEntry {
+    pub id: Uuid,
+    #[serde(flatten)]
+    pub spec: EntrySpec,
+    /// When the entry last ran
+    pub last_run: Option<DateTime<Utc>>,
+    /// When the entry runs next, absent when it never will
+    #[serde(default)]
+    pub next_run: Option<DateTime<Utc>>,
+}
+
+impl ScheduleEntry {
+    /// Work out when the entry next runs after `after`
+    fn update_next_run(&mut self, after: DateTime<Utc>) {
+        self.next_run = if !self.spec.enabled {
+            None
+        } else {
+            match &self.spec.when {
+                When::Once(at) if self.last_run.is_none() => Some(*at),
+                When::Once(_) => None,
+                When::Cron(expression) => cron::Schedule::from_str(expression)
+                    .ok()
+                    .and_then(|schedule| schedule.after(&after.with_timezone(&Local)).next())
+                    .map(|next| next.with_timezone(&Utc)),
+            }
+        };
+    }
+}
+
+/// A record of a schedule entry running
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct ScheduleRun {
+    pub entry_id: Uuid,
+    pub label: String,
+    pub time: DateTime<Utc>,
+    pub command: Command,
+    pub result: CommandResult,
+}
+
+/// Issues commands to the [`NodeManager`] at scheduled times, keeping
+/// the schedule and a history of runs on disk
+#[derive(Debug)]
+pub struct Scheduler {
+    /// All entries by id
+    entries: HashMap<Uuid, ScheduleEntry>,
+    /// Most recent runs, oldest first
+    history: VecDeque<ScheduleRun>,
+    /// Where entries are persisted
+    schedule_path: PathBuf,
+    /// Where a file of runs is appended each day
+    history_files: DailyHistory,
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self::new(&data_dir())
+    }
+}
+
+impl Actor for Scheduler {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        if let Err(err) = self.load() {
+            error!("Failed to load schedule: {}", err);
+        }
+
+        if self.catch_up(Utc::now()) {
+            self.persist();
+        }
+
+        info!(
+            "Scheduler coming online with {} entries",
+            self.entries.len()
+        );
+
+        ctx.run_interval(TICK_INTERVAL, |act, ctx| act.run_due(ctx));
+    }
+}
+
+impl actix::Supervised for Scheduler {}
+
+impl SystemService for Scheduler {}
+
+impl Scheduler {
+    /// A scheduler keeping its files in `dir`
+    fn new(dir: &Path) -> Self {
+        Self {
+            entries: HashMap::new(),
+            history: VecDeque::new(),
+            schedule_path: dir.join("schedule.json"),
+            history_files: DailyHistory::new(
+                dir.join("schedule-history"),
+                "schedule",
+                HISTORY_DAYS,
+            ),
+        }
+    }
+
+    /// Load entries and recent history from disk
+    fn load(&mut self) -> Result<(), Error> {
+        if self.schedule_path.exists() {
+            let file = std::fs::File::open(&self.schedule_path)?;
+            let entries: Vec<ScheduleEntry> = serde_json::from_reader(BufReader::new(file))?;
+            self.entries = entries.into_iter().map(|entry| (entry.id, entry)).collect();
+        }
+
+        for run in self.history_files.load(HISTORY_LENGTH)? {
+            self.push_history(run);
+        }
+
+        Ok(())
+    }
+
+    /// Write all entries to disk, replacing the previous file
+    fn save(&self) -> Result<(), Error> {
+        if let Some(dir) = self.schedule_path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let mut entries: Vec<&ScheduleEntry> = self.entries.values().collect();
+        entries.sort_by_key(|entry| entry.id);
+
+        let partial = self.schedule_path.with_extension("json.part");
+        serde_json::to_writer_pretty(std::fs::File::create(&partial)?, &entries)?;
+        std::fs::rename(&partial, &self.schedule_path)?;
+
+        Ok(())
+    }
+
+    /// Save, logging rather than failing
+    fn persist(&self) {
+        if let Err(err) = self.save() {
+            error!("Failed to save schedule: {}", err);
+        }
+    }
+
+    fn push_history(&mut self, run: ScheduleRun) {
+        if self.history.len() == HISTORY_LENGTH {
+            self.history.pop_front();
+        }
+        self.history.push_back(run);
+    }
+
+    /// Add a run of `entry` to the history
+    fn record(&mut self, entry: &ScheduleEntry, result: CommandResult) {
+        let run = ScheduleRun {
+            entry_id: entry.id,
+            label: entry.spec.label.clone(),
+            time: Utc::now(),
+            command: entry.spec.command.clone(),
+            result,
+        };
+
+        if let Err(err) = self.history_files.append(run.time, &run) {
+            error!("Failed to write schedule history: {}", err);
+        }
+
+        self.push_history(run);
+    }
+
+    /// Work out when entries next run after a start at `now`. Once
+    /// entries that came due while we were down are not run late, only
+    /// recorded as missed. Returns whether any were
+    fn catch_up(&mut self, now: DateTime<Utc>) -> bool {
+        let missed: Vec<ScheduleEntry> = self
+            .entries
+            .values_mut()
+            .filter_map(|entry| {
+                entry.update_next_run(now);
+                match entry.next_run {
+                    Some(next) if matches!(entry.spec.when, When::Once(_)) && next < now => {
+                        entry.last_run = Some(now);
+                        entry.next_run = None;
+                        Some(entry.clone())
+                    }
+                    _ => None,
+                }
+            })
+            .collect();
+
+        for entry in &missed {
+            self.record(
+                entry,
+                CommandResult::Error(String::from("Missed while stopped")),
+            );
+        }
+
+        !missed.is_empty()
+    }
+
+    /// Add an entry, or replace the entry `id`. A replaced entry keeps
+    /// when it last ran unless it is now to run at other times
+    fn set_entry(
+        &mut self,
+        id: Option<Uuid>,
+        spec: EntrySpec,
+        now: DateTime<Utc>,
+    ) -> Result<ScheduleEntry, Error> {
+        spec.when.validate()?;
+
+        let last_run = match id {
+            Some(id) => {
+                let previous = self
+                    .entries
+                    .get(&id)
+                    .ok_or_else(|| anyhow!("No schedule entry with id {}", id))?;
+                previous
+                    .last_run
+                    .filter(|_| previous.spec.when == spec.when)
+            }
+            None => None,
+        };
+
+        let mut entry = ScheduleEntry {
+            id: id.unwrap_or_else(Uuid::new_v4),
+            spec,
+            last_run,
+            next_run: None,
+        };
+        entry.update_next_run(now);
+
+        self.entries.insert(entry.id, entry.clone());
+
+        Ok(entry)
+    }
+
+    /// Run every entry that has come due
+    fn run_due(&mut self, ctx: &mut Context<Self>) {
+        let now = Utc::now();
+        let due: Vec<Uuid> = self
+            .entries
+            .values()
+            .filter(|entry| entry.next_run.map_or(false, |next| next <= now))
+            .map(|entry| entry.id)
+            .collect();
+
+        if due.is_empty() {
+            return;
+        }
+
+        for id in due {
+            let entry = match self.entries.get_mut(&id) {
+                Some(entry) => {
+                    entry.last_run = Some(now);
+                    entry.update_next_run(now);
+                    entry.clone()
+                }
+                None => continue,
+            };
+
+            self.run(entry, ctx);
+        }
+
+        self.persist();
+    }
+
+    /// Issue the command of an entry and record its result
+    #[instrument(level = "info", name = "running schedule entry", skip(self, entry, ctx), fields(entry_id = %entry.id))]
+    fn run(&mut self, entry: ScheduleEntry, ctx: &mut Context<Self>) {
+        // Made here, so the command is traced under this entry
+        let command = CommandMessage::new(entry.spec.command.clone());
+
+        ctx.spawn(
+            async move { NodeManager::from_registry().send(command).await }
+                .into_actor(self)
+                .map(move |res, act, _ctx| {
+                    let result = match res {
+                        Ok(result) => result,
+                        Err(err) => CommandResult::Error(format!("Internal server error {}", err)),
+                    };
+
+                    act.record(&entry, result);
+                }),
+        );
+    }
+}
+
+/// List the schedule
+#[derive(Debug)]
+pub struct ListScheduleMessage;
+
+impl Message for ListScheduleMessage {
+    type Result = Vec<ScheduleEntry>;
+}
+
+impl Handler<ListScheduleMessage> for Scheduler {
+    type Result = MessageResult<ListScheduleMessage>;
+
+    fn handle(&mut self, _msg: ListScheduleMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        let mut entries: Vec<ScheduleEntry> = self.entries.values().cloned().collect();
+        entries.sort_by_key(|entry| entry.next_run.unwrap_or(DateTime::<Utc>::MAX_UTC));
+
+        MessageResult(entries)
+    }
+}
+
+/// Add an entry to the schedule, or replace one by id
+#[derive(Debug)]
+pub struct SetScheduleEntryMessage {
+    /// The entry to replace, `None` to add a new one
+    pub id: Option<Uuid>,
+    pub spec: EntrySpec,
+}
+
+impl Message for SetScheduleEntryMessage {
+    type Result = Result<ScheduleEntry, Error>;
+}
+
+impl Handler<SetScheduleEntryMessage> for Scheduler {
+    type Result = Result<ScheduleEntry, Error>;
+
+    fn handle(&mut self, msg: SetScheduleEntryMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        let entry = self.set_entry(msg.id, msg.spec, Utc::now())?;
+        self.persist();
+
+        Ok(entry)
+    }
+}
+
+/// Remove an entry from the schedule
+#[derive(Debug)]
+pub struct RemoveScheduleEntryMessage {
+    pub id: Uuid,
+}
+
+impl Message for RemoveScheduleEntryMessage {
+    type Result = Result<(), Error>;
+}
+
+impl Handler<RemoveScheduleEntryMessage> for Scheduler {
+    type Result = Result<(), Error>;
+
+    fn handle(
+        &mut self,
+        msg: RemoveScheduleEntryMessage,
+        _ctx: &mut Context<Self>,
+    ) -> Self::Result {
+        self.entries
+            .remove(&msg.id)
+            .ok_or_else(|| anyhow!("No schedule entry with id {}", msg.id))?;
+        self.persist();
+
+        Ok(())
+    }
+}
+
+/// List recent runs, newest first
+#[derive(Debug)]
+pub struct ScheduleHistoryMessage;
+
+impl Message for ScheduleHistoryMessage {
+    type Result = Vec<ScheduleRun>;
+}
+
+impl Handler<ScheduleHistoryMessage> for Scheduler {
+    type Result = MessageResult<ScheduleHistoryMessage>;
+
+    fn handle(&mut self, _msg: ScheduleHistoryMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(self.history.iter().rev().cloned().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn spec(when: When) -> EntrySpec {
+        EntrySpec {
+            label: "stop".to_string(),
+            when,
+            command: Command::Stop {
+                device_id: Uuid::nil(),
+            },
+            enabled: true,
+        }
+    }
+
+    fn entry(when: When) -> ScheduleEntry {
+        ScheduleEntry {
+            id: Uuid::new_v4(),
+            spec: spec(when),
+            last_run: None,
+            next_run: None,
+        }
+    }
+
+    fn at(hour: u32, minute: u32, second: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 1, hour, minute, second)
+            .unwrap()
+    }
+
+    #[test]
+    fn once_runs_only_until_it_has_run() {
+        let mut once = entry(When::Once(at(12, 0, 0)));
+
+        once.update_next_run(at(11, 0, 0));
+        assert_eq!(once.next_run, Some(at(12, 0, 0)));
+
+        once.last_run = Some(at(12, 0, 0));
+        once.update_next_run(at(12, 0, 0));
+        assert_eq!(once.next_run, None);
+    }
+
+    #[test]
+    fn cron_runs_at_next_match() {
+        // Every minute, so the local time zone makes no difference
+        let mut cron = entry(When::Cron("0 * * * * *".to_string()));
+
+        cron.update_next_run(at(12, 0, 30));
+        assert_eq!(cron.next_run, Some(at(12, 1, 0)));
+
+        cron.update_next_run(at(12, 1, 0));
+        assert_eq!(cron.next_run, Some(at(12, 2, 0)));
+    }
+
+    #[test]
+    fn disabled_entry_never_runs() {
+        for when in [
+            When::Once(at(12, 0, 0)),
+            When::Cron("0 * * * * *".to_string()),
+        ] {
+            let mut disabled = entry(when);
+            disabled.spec.enabled = false;
+            disabled.next_run = Some(at(12, 0, 0));
+
+            disabled.update_next_run(at(11, 0, 0));
+            assert_eq!(disabled.next_run, None);
+        }
+    }
+
+    #[test]
+    fn bad_cron_expression_rejected() {
+        assert!(When::Cron("0 * * * * *".to_string()).validate().is_ok());
+        assert!(When::Cron("every morning".to_string()).validate().is_err());
+        assert!(When::Cron("0 61 * * * *".to_string()).validate().is_err());
+        assert!(When::Once(at(12, 0, 0)).validate().is_ok());
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut scheduler = Scheduler::new(dir.path());
+        assert!(scheduler
+            .set_entry(
+                None,
+                spec(When::Cron("every morning".to_string())),
+                at(11, 0, 0)
+            )
+            .is_err());
+        assert!(scheduler.entries.is_empty());
+    }
+
+    #[test]
+    fn once_missed_while_stopped_is_recorded() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut scheduler = Scheduler::new(dir.path());
+
+        let missed = entry(When::Once(at(12, 0, 0)));
+        let upcoming = entry(When::Once(at(14, 0, 0)));
+        for entry in [&missed, &upcoming] {
+            scheduler.entries.insert(entry.id, entry.clone());
+        }
+
+        assert!(scheduler.catch_up(at(13, 0, 0)));
+
+        let missed = &scheduler.entries[&missed.id];
+        assert_eq!(missed.last_run, Some(at(13, 0, 0)));
+        assert_eq!(missed.next_run, None);
+        let upcoming = &scheduler.entries[&upcoming.id];
+        assert_eq!(upcoming.last_run, None);
+        assert_eq!(upcoming.next_run, Some(at(14, 0, 0)));
+
+        assert_eq!(scheduler.history.len(), 1);
+        assert_eq!(scheduler.history[0].entry_id, missed.id);
+        assert!(matches!(
+            &scheduler.history[0].result,
+            CommandResult::Error(err) if err == "Missed while stopped"
+        ));
+
+        // Nothing more is missed on the next start
+        assert!(!scheduler.catch_up(at(13, 0, 1)));
+
+        let mut restarted = Scheduler::new(dir.path());
+        restarted.load().unwrap();
+        assert_eq!(restarted.history.len(), 1);
+    }
+
+    #[test]
+    fn replaced_entry_keeps_last_run_unless_rescheduled() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut scheduler = Scheduler::new(dir.path());
+
+        let added = scheduler
+            .set_entry(None, spec(When::Once(at(12, 0, 0))), at(11, 0, 0))
+            .unwrap();
+        scheduler.entries.get_mut(&added.id).unwrap().last_run = Some(at(12, 0, 0));
+
+        let mut relabelled = spec(When::Once(at(12, 0, 0)));
+        relabelled.label = "stop output".to_string();
+        let replaced = scheduler
+            .set_entry(Some(added.id), relabelled, at(13, 0, 0))
+            .unwrap();
+        assert_eq!(replaced.last_run, Some(at(12, 0, 0)));
+        assert_eq!(replaced.next_run, None);
+
+        let rescheduled = scheduler
+            .set_entry(Some(added.id), spec(When::Once(at(14, 0, 0))), at(13, 0, 0))
+            .unwrap();
+        assert_eq!(rescheduled.last_run, None);
+        assert_eq!(rescheduled.next_run, Some(at(14, 0, 0)));
+
+        assert!(scheduler
+            .set_entry(
+                Some(Uuid::new_v4()),
+                spec(When::Once(at(14, 0, 0))),
+                at(13, 0, 0)
+            )
+            .is_err());
+    }
+}
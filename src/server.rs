@@ -1,27 +1,121 @@
 use actix::SystemService;
 use actix_cors::Cors;
-use actix_web::{error, web, App, HttpRequest, HttpResponse, HttpServer};
+use actix_web::{error, http::header, web, App, HttpRequest, HttpResponse, HttpServer};
 use actix_web_actors::ws;
+use futures::StreamExt;
+#[cfg(feature = "web-ui")]
 use mime_guess::from_path;
+#[cfg(feature = "web-ui")]
 use rust_embed::Embed;
+use serde::Deserialize;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
 use tracing::{error, info, trace};
+use uuid::Uuid;
 
 use crate::{
+    alarm::{
+        AcknowledgeAlarmMessage, AlarmHistoryMessage, AlarmManager, ListAlarmsMessage,
+        ListShelvesMessage, RemoveShelfMessage, ShelveAlarmMessage, UnshelveAlarmMessage,
+    },
+    command::{
+        BulkAction, Command, CommandResult, InputSource, LogLevel, LoudnessAction, OutputFormat,
+        Target,
+    },
     controller::Controller,
-    node::{NodeManager, StopMessage},
+    detector::{
+        ContentDetector, DetectorConfig, GetDetectorConfigMessage, SetDetectorConfigMessage,
+    },
+    device::DeviceWatcher,
+    health::{self, HealthReport, HealthStatus},
+    logs,
+    loudness::{
+        GetLoudnessConfigMessage, LoudnessConfig, LoudnessHistoryMessage, LoudnessLog,
+        SetLoudnessConfigMessage,
+    },
+    media::{max_upload_size, media_path, InvalidateMediaMessage, ListMediaMessage, MediaLibrary},
+    metrics::{Metrics, RenderMetricsMessage},
+    multiviewer::{GetMultiviewerMessage, Multiviewer, MultiviewerConfig, SetMultiviewerMessage},
+    node::{
+        CommandMessage, GetLevelsMessage, GetThumbnailMessage, ListDevicesMessage, NodeManager,
+        NodeMetricsMessage, StopMessage,
+    },
+    pipeline::graph::{render_svg, GraphDetails, GraphMessage, PipelineGraphs},
+    scheduler::{
+        EntrySpec, ListScheduleMessage, RemoveScheduleEntryMessage, ScheduleHistoryMessage,
+        Scheduler, SetScheduleEntryMessage,
+    },
+    watchdog::{GetWatchdogConfigMessage, SetWatchdogConfigMessage, Watchdog, WatchdogConfig},
 };
 
+/// Address the server listens on unless told otherwise
+const DEFAULT_BIND: &str = "0.0.0.0:3000";
+
+#[cfg(feature = "web-ui")]
 #[derive(Embed)]
 #[folder = "web-ui/dist"]
 struct Asset;
 
+/// Origins other than the server's own that may change things, set
+/// with [`ServerBuilder::allow_origin`]
+#[derive(Clone, Debug, Default)]
+struct AllowedOrigins(Vec<String>);
+
+impl AllowedOrigins {
+    fn contains(&self, origin: &str) -> bool {
+        self.0.iter().any(|allowed| allowed == origin)
+    }
+}
+
+/// Cross-origin access: any origin may read the API, only the allowed
+/// ones may change things
+fn cors(origins: AllowedOrigins) -> Cors {
+    Cors::default()
+        .allowed_origin_fn(move |origin, req| {
+            let method = req
+                .headers()
+                .get(header::ACCESS_CONTROL_REQUEST_METHOD)
+                .and_then(|method| method.to_str().ok())
+                .unwrap_or_else(|| req.method.as_str());
+            matches!(method, "GET" | "HEAD")
+                || origin
+                    .to_str()
+                    .map_or(false, |origin| origins.contains(origin))
+        })
+        .allow_any_method()
+        .allow_any_header()
+}
+
+/// Whether a websocket may be opened from the page that asks for it.
+/// Browsers don't apply CORS to websockets, so the control socket
+/// checks the origin itself: it has to be the server's own or allowed
+fn origin_allowed(req: &HttpRequest, origins: Option<&AllowedOrigins>) -> bool {
+    let origin = match req.headers().get(header::ORIGIN) {
+        Some(origin) => origin,
+        None => return true,
+    };
+    let origin = match origin.to_str() {
+        Ok(origin) => origin,
+        Err(_) => return false,
+    };
+    let host = origin.split_once("://").map_or(origin, |(_, host)| host);
+
+    host == req.connection_info().host()
+        || origins.map_or(false, |origins| origins.contains(origin))
+}
+
 async fn ws(
     path: web::Path<String>,
     req: HttpRequest,
     stream: web::Payload,
+    origins: Option<web::Data<AllowedOrigins>>,
 ) -> Result<HttpResponse, actix_web::Error> {
     match path.as_str() {
         "control" => {
+            if !origin_allowed(&req, origins.as_deref()) {
+                return Err(error::ErrorForbidden("Origin not allowed"));
+            }
+
             trace!("trace creating new controller");
             let controller = Controller::new(&req.connection_info()).map_err(|err| {
                 error!("Failed to create controller: {}", err);
@@ -35,6 +129,704 @@ async fn ws(
     }
 }
 
+/// List the media library, with the issues of playing each file on
+/// the output `device_id`, or on the default format
+async fn list_media(query: web::Query<DeviceQuery>) -> Result<HttpResponse, actix_web::Error> {
+    let format = match query.device_id {
+        Some(device_id) => NodeManager::from_registry()
+            .send(ListDevicesMessage)
+            .await
+            .map_err(error::ErrorInternalServerError)?
+            .into_iter()
+            .find(|device| device.id == device_id)
+            .map(|device| device.format)
+            .ok_or_else(|| error::ErrorNotFound(format!("No output {}", device_id)))?,
+        None => OutputFormat::default(),
+    };
+
+    let media = MediaLibrary::from_registry()
+        .send(ListMediaMessage { format })
+        .await
+        .map_err(error::ErrorInternalServerError)?
+        .map_err(error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok().json(media))
+}
+
+/// Store the request body in the media library, replacing any file
+/// with the same name once the upload completes. Bodies over the
+/// upload limit are refused with 413
+async fn upload_media(
+    name: web::Path<String>,
+    req: HttpRequest,
+    mut body: web::Payload,
+) -> Result<HttpResponse, actix_web::Error> {
+    let path = media_path(&name).map_err(error::ErrorBadRequest)?;
+    let limit = max_upload_size();
+    let too_large =
+        || error::ErrorPayloadTooLarge(format!("Uploads are limited to {} bytes", limit));
+
+    let length = req
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|length| length.to_str().ok())
+        .and_then(|length| length.parse::<u64>().ok());
+    if length.map_or(false, |length| length > limit) {
+        return Err(too_large());
+    }
+
+    // Unique, so uploads of the same name don't write over each other
+    let partial = path.with_file_name(format!(".{}.{}.part", name, Uuid::new_v4()));
+
+    let written = async {
+        let mut file = tokio::fs::File::create(&partial).await?;
+        let mut size = 0;
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk?;
+            size += chunk.len() as u64;
+            if size > limit {
+                return Err(too_large());
+            }
+            file.write_all(&chunk).await?;
+        }
+        file.flush().await?;
+
+        tokio::fs::rename(&partial, &path).await?;
+
+        Ok::<_, actix_web::Error>(())
+    }
+    .await;
+
+    if let Err(err) = written {
+        error!("Failed to upload media {}: {}", name, err);
+        let _ = tokio::fs::remove_file(&partial).await;
+        return Err(err);
+    }
+
+    MediaLibrary::from_registry().do_send(InvalidateMediaMessage {
+        name: name.into_inner(),
+    });
+
+    Ok(HttpResponse::Created().finish())
+}
+
+#[derive(Debug, Deserialize)]
+struct RenameMedia {
+    name: String,
+}
+
+/// Rename a file in the media library
+async fn rename_media(
+    name: web::Path<String>,
+    rename: web::Json<RenameMedia>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let from = media_path(&name).map_err(error::ErrorBadRequest)?;
+    let to = media_path(&rename.name).map_err(error::ErrorBadRequest)?;
+
+    if !from.is_file() {
+        return Err(error::ErrorNotFound(format!("No media named {}", name)));
+    }
+    if to.exists() {
+        return Err(error::ErrorConflict(format!(
+            "Media named {} already exists",
+            rename.name
+        )));
+    }
+
+    tokio::fs::rename(&from, &to).await?;
+
+    let library = MediaLibrary::from_registry();
+    library.do_send(InvalidateMediaMessage {
+        name: name.into_inner(),
+    });
+    library.do_send(InvalidateMediaMessage {
+        name: rename.into_inner().name,
+    });
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Delete a file from the media library
+async fn delete_media(name: web::Path<String>) -> Result<HttpResponse, actix_web::Error> {
+    let path = media_path(&name).map_err(error::ErrorBadRequest)?;
+
+    if !path.is_file() {
+        return Err(error::ErrorNotFound(format!("No media named {}", name)));
+    }
+
+    tokio::fs::remove_file(&path).await?;
+
+    MediaLibrary::from_registry().do_send(InvalidateMediaMessage {
+        name: name.into_inner(),
+    });
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Run a command through the [`NodeManager`], answering with its
+/// result
+async fn run_command(command: Command) -> Result<HttpResponse, actix_web::Error> {
+    let result = NodeManager::from_registry()
+        .send(CommandMessage::new(command))
+        .await
+        .map_err(error::ErrorInternalServerError)?;
+
+    Ok(match result {
+        CommandResult::Error(_) => HttpResponse::BadRequest().json(result),
+        _ => HttpResponse::Ok().json(result),
+    })
+}
+
+/// List device groups
+async fn list_groups() -> Result<HttpResponse, actix_web::Error> {
+    run_command(Command::ListGroups {}).await
+}
+
+/// Create or replace a device group
+async fn set_group(
+    name: web::Path<String>,
+    devices: web::Json<Vec<Uuid>>,
+) -> Result<HttpResponse, actix_web::Error> {
+    run_command(Command::SetGroup {
+        name: name.into_inner(),
+        devices: devices.into_inner(),
+    })
+    .await
+}
+
+/// Remove a device group
+async fn remove_group(name: web::Path<String>) -> Result<HttpResponse, actix_web::Error> {
+    run_command(Command::RemoveGroup {
+        name: name.into_inner(),
+    })
+    .await
+}
+
+#[derive(Debug, Deserialize)]
+struct BulkRequest {
+    target: Target,
+    action: BulkAction,
+}
+
+/// Apply an action to many devices at once
+async fn bulk(request: web::Json<BulkRequest>) -> Result<HttpResponse, actix_web::Error> {
+    let BulkRequest { target, action } = request.into_inner();
+
+    run_command(Command::Bulk { target, action }).await
+}
+
+/// List monitored inputs
+async fn list_inputs() -> Result<HttpResponse, actix_web::Error> {
+    run_command(Command::ListInputs {}).await
+}
+
+#[derive(Debug, Deserialize)]
+struct AddInputRequest {
+    label: String,
+    #[serde(default)]
+    source: InputSource,
+}
+
+/// Start monitoring a new input
+async fn add_input(request: web::Json<AddInputRequest>) -> Result<HttpResponse, actix_web::Error> {
+    let AddInputRequest { label, source } = request.into_inner();
+
+    run_command(Command::AddInput { label, source }).await
+}
+
+/// Stop monitoring an input
+async fn remove_input(id: web::Path<Uuid>) -> Result<HttpResponse, actix_web::Error> {
+    run_command(Command::RemoveInput {
+        device_id: id.into_inner(),
+    })
+    .await
+}
+
+/// The latest thumbnail of a device as a JPEG
+async fn thumbnail(id: web::Path<Uuid>) -> Result<HttpResponse, actix_web::Error> {
+    let id = id.into_inner();
+    let thumbnail = NodeManager::from_registry()
+        .send(GetThumbnailMessage { id })
+        .await
+        .map_err(error::ErrorInternalServerError)?
+        .ok_or_else(|| error::ErrorNotFound(format!("No thumbnail of {} yet", id)))?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("image/jpeg")
+        .insert_header(("Cache-Control", "no-cache"))
+        .body(thumbnail.jpeg))
+}
+
+/// Format of a pipeline graph
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum GraphFormat {
+    #[default]
+    Dot,
+    Svg,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQuery {
+    #[serde(default)]
+    format: GraphFormat,
+    #[serde(default)]
+    details: GraphDetails,
+}
+
+/// The current graph of the pipeline of a device, input or the
+/// multiviewer
+async fn graph(
+    id: web::Path<Uuid>,
+    query: web::Query<GraphQuery>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let id = id.into_inner();
+    let dot = PipelineGraphs::from_registry()
+        .send(GraphMessage {
+            id,
+            details: query.details,
+        })
+        .await
+        .map_err(error::ErrorInternalServerError)?
+        .ok_or_else(|| error::ErrorNotFound(format!("No pipeline for {}", id)))?;
+
+    match query.format {
+        GraphFormat::Dot => Ok(HttpResponse::Ok()
+            .content_type("text/vnd.graphviz")
+            .body(dot)),
+        GraphFormat::Svg => {
+            let svg = web::block(move || render_svg(&dot))
+                .await
+                .map_err(error::ErrorInternalServerError)?
+                .map_err(error::ErrorInternalServerError)?;
+
+            Ok(HttpResponse::Ok().content_type("image/svg+xml").body(svg))
+        }
+    }
+}
+
+/// The latest audio levels of every device
+async fn levels() -> Result<HttpResponse, actix_web::Error> {
+    let levels = NodeManager::from_registry()
+        .send(GetLevelsMessage)
+        .await
+        .map_err(error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok().json(levels))
+}
+
+/// Start, stop or reset the integrated loudness of a device
+async fn control_loudness(
+    id: web::Path<Uuid>,
+    action: web::Json<LoudnessAction>,
+) -> Result<HttpResponse, actix_web::Error> {
+    run_command(Command::Loudness {
+        device_id: id.into_inner(),
+        action: action.into_inner(),
+    })
+    .await
+}
+
+/// Get the loudness alarm thresholds
+async fn get_loudness_config() -> Result<HttpResponse, actix_web::Error> {
+    let config = LoudnessLog::from_registry()
+        .send(GetLoudnessConfigMessage)
+        .await
+        .map_err(error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok().json(config))
+}
+
+/// Replace the loudness alarm thresholds
+async fn set_loudness_config(
+    config: web::Json<LoudnessConfig>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let config = LoudnessLog::from_registry()
+        .send(SetLoudnessConfigMessage {
+            config: config.into_inner(),
+        })
+        .await
+        .map_err(error::ErrorInternalServerError)?
+        .map_err(error::ErrorBadRequest)?;
+
+    Ok(HttpResponse::Ok().json(config))
+}
+
+/// Narrows a listing down to one device
+#[derive(Debug, Deserialize)]
+struct DeviceQuery {
+    device_id: Option<Uuid>,
+}
+
+/// List per-minute loudness summaries, optionally of one device
+async fn loudness_history(
+    query: web::Query<DeviceQuery>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let history = LoudnessLog::from_registry()
+        .send(LoudnessHistoryMessage {
+            device_id: query.device_id,
+        })
+        .await
+        .map_err(error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok().json(history))
+}
+
+/// Get the content detector settings
+async fn get_detector_config() -> Result<HttpResponse, actix_web::Error> {
+    let config = ContentDetector::from_registry()
+        .send(GetDetectorConfigMessage)
+        .await
+        .map_err(error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok().json(config))
+}
+
+/// Replace the content detector settings
+async fn set_detector_config(
+    config: web::Json<DetectorConfig>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let config = ContentDetector::from_registry()
+        .send(SetDetectorConfigMessage {
+            config: config.into_inner(),
+        })
+        .await
+        .map_err(error::ErrorInternalServerError)?
+        .map_err(error::ErrorBadRequest)?;
+
+    Ok(HttpResponse::Ok().json(config))
+}
+
+/// The log filter and GStreamer debug thresholds
+async fn get_logging() -> Result<HttpResponse, actix_web::Error> {
+    run_command(Command::GetLogging {}).await
+}
+
+#[derive(Debug, Deserialize)]
+struct LogFilterRequest {
+    /// In `VIGIL_LOG` syntax
+    filter: String,
+    /// How long for, until changed again when absent
+    #[serde(default)]
+    duration_s: Option<u64>,
+}
+
+/// Change the log filter without restarting
+async fn set_log_filter(
+    request: web::Json<LogFilterRequest>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let LogFilterRequest { filter, duration_s } = request.into_inner();
+
+    run_command(Command::SetLogFilter { filter, duration_s }).await
+}
+
+#[derive(Debug, Deserialize)]
+struct GstDebugRequest {
+    /// In `GST_DEBUG` syntax
+    threshold: String,
+    /// How long for, until changed again when absent
+    #[serde(default)]
+    duration_s: Option<u64>,
+}
+
+/// Change GStreamer debug thresholds without restarting
+async fn set_gst_debug(
+    request: web::Json<GstDebugRequest>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let GstDebugRequest {
+        threshold,
+        duration_s,
+    } = request.into_inner();
+
+    run_command(Command::SetGstDebug {
+        threshold,
+        duration_s,
+    })
+    .await
+}
+
+#[derive(Debug, Deserialize)]
+struct LogsQuery {
+    device_id: Option<Uuid>,
+    /// The least severe records listed
+    #[serde(default)]
+    level: LogLevel,
+    /// How many records at most
+    limit: Option<usize>,
+}
+
+/// List the latest log records, optionally about one device
+async fn list_logs(query: web::Query<LogsQuery>) -> Result<HttpResponse, actix_web::Error> {
+    Ok(HttpResponse::Ok().json(logs::recent(
+        query.device_id,
+        query.level,
+        query.limit.unwrap_or(500),
+    )))
+}
+
+/// Get the stall watchdog settings
+async fn get_watchdog_config() -> Result<HttpResponse, actix_web::Error> {
+    let config = Watchdog::from_registry()
+        .send(GetWatchdogConfigMessage)
+        .await
+        .map_err(error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok().json(config))
+}
+
+/// Replace the stall watchdog settings
+async fn set_watchdog_config(
+    config: web::Json<WatchdogConfig>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let config = Watchdog::from_registry()
+        .send(SetWatchdogConfigMessage {
+            config: config.into_inner(),
+        })
+        .await
+        .map_err(error::ErrorInternalServerError)?
+        .map_err(error::ErrorBadRequest)?;
+
+    Ok(HttpResponse::Ok().json(config))
+}
+
+/// Answer a health check, with a status as bad as the worst
+/// subsystem
+fn health_response(report: HealthReport) -> HttpResponse {
+    match report.status {
+        HealthStatus::Ok => HttpResponse::Ok().json(report),
+        HealthStatus::Degraded | HealthStatus::Down => {
+            HttpResponse::ServiceUnavailable().json(report)
+        }
+    }
+}
+
+/// Whether the process is alive and its actors are answering
+async fn healthz() -> HttpResponse {
+    health_response(health::liveness().await)
+}
+
+/// Whether every started output is running and clocks are synced
+async fn readyz() -> HttpResponse {
+    health_response(health::readiness().await)
+}
+
+/// Scrape the state and counters of every node in the Prometheus
+/// text format
+async fn metrics() -> Result<HttpResponse, actix_web::Error> {
+    let nodes = NodeManager::from_registry()
+        .send(NodeMetricsMessage)
+        .await
+        .map_err(error::ErrorInternalServerError)?;
+    let text = Metrics::from_registry()
+        .send(RenderMetricsMessage { nodes })
+        .await
+        .map_err(error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(text))
+}
+
+/// List the active alarms, most severe first
+async fn list_alarms() -> Result<HttpResponse, actix_web::Error> {
+    let alarms = AlarmManager::from_registry()
+        .send(ListAlarmsMessage)
+        .await
+        .map_err(error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok().json(alarms))
+}
+
+/// List alarms being raised, cleared, acknowledged and shelved,
+/// optionally of one device
+async fn alarm_history(query: web::Query<DeviceQuery>) -> Result<HttpResponse, actix_web::Error> {
+    let history = AlarmManager::from_registry()
+        .send(AlarmHistoryMessage {
+            device_id: query.device_id,
+        })
+        .await
+        .map_err(error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok().json(history))
+}
+
+#[derive(Debug, Deserialize)]
+struct AcknowledgeRequest {
+    /// Who acknowledged the alarm
+    by: String,
+}
+
+/// Acknowledge an alarm on behalf of a user
+async fn acknowledge_alarm(
+    path: web::Path<Uuid>,
+    request: web::Json<AcknowledgeRequest>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let alarm = AlarmManager::from_registry()
+        .send(AcknowledgeAlarmMessage {
+            id: path.into_inner(),
+            by: request.into_inner().by,
+        })
+        .await
+        .map_err(error::ErrorInternalServerError)?
+        .map_err(error::ErrorNotFound)?;
+
+    Ok(HttpResponse::Ok().json(alarm))
+}
+
+#[derive(Debug, Deserialize)]
+struct ShelveRequest {
+    /// Who shelved the alarm
+    by: String,
+    /// How long for, indefinitely when absent
+    #[serde(default)]
+    duration_s: Option<u64>,
+}
+
+/// Set an alarm aside, for a while or until unshelved
+async fn shelve_alarm(
+    path: web::Path<Uuid>,
+    request: web::Json<ShelveRequest>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let request = request.into_inner();
+    let alarm = AlarmManager::from_registry()
+        .send(ShelveAlarmMessage {
+            id: path.into_inner(),
+            by: request.by,
+            duration: request.duration_s.map(Duration::from_secs),
+        })
+        .await
+        .map_err(error::ErrorInternalServerError)?
+        .map_err(error::ErrorNotFound)?;
+
+    Ok(HttpResponse::Ok().json(alarm))
+}
+
+/// Bring back a shelved alarm
+async fn unshelve_alarm(path: web::Path<Uuid>) -> Result<HttpResponse, actix_web::Error> {
+    let alarm = AlarmManager::from_registry()
+        .send(UnshelveAlarmMessage {
+            id: path.into_inner(),
+        })
+        .await
+        .map_err(error::ErrorInternalServerError)?
+        .map_err(error::ErrorNotFound)?;
+
+    Ok(HttpResponse::Ok().json(alarm))
+}
+
+/// List the shelved conditions, including those without an alarm
+async fn list_shelves() -> Result<HttpResponse, actix_web::Error> {
+    let shelves = AlarmManager::from_registry()
+        .send(ListShelvesMessage)
+        .await
+        .map_err(error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok().json(shelves))
+}
+
+/// Remove a shelf, bringing back its alarm if there is one
+async fn remove_shelf(path: web::Path<Uuid>) -> Result<HttpResponse, actix_web::Error> {
+    let shelves = AlarmManager::from_registry()
+        .send(RemoveShelfMessage {
+            id: path.into_inner(),
+        })
+        .await
+        .map_err(error::ErrorInternalServerError)?
+        .map_err(error::ErrorNotFound)?;
+
+    Ok(HttpResponse::Ok().json(shelves))
+}
+
+/// Get the multiviewer layout and output
+async fn get_multiviewer() -> Result<HttpResponse, actix_web::Error> {
+    let info = Multiviewer::from_registry()
+        .send(GetMultiviewerMessage)
+        .await
+        .map_err(error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok().json(info))
+}
+
+/// Replace the multiviewer layout and output
+async fn set_multiviewer(
+    config: web::Json<MultiviewerConfig>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let info = Multiviewer::from_registry()
+        .send(SetMultiviewerMessage {
+            config: config.into_inner(),
+        })
+        .await
+        .map_err(error::ErrorInternalServerError)?
+        .map_err(error::ErrorBadRequest)?;
+
+    Ok(HttpResponse::Ok().json(info))
+}
+
+/// List the schedule
+async fn list_schedule() -> Result<HttpResponse, actix_web::Error> {
+    let entries = Scheduler::from_registry()
+        .send(ListScheduleMessage)
+        .await
+        .map_err(error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok().json(entries))
+}
+
+/// Add an entry to the schedule
+async fn add_schedule_entry(spec: web::Json<EntrySpec>) -> Result<HttpResponse, actix_web::Error> {
+    let entry = Scheduler::from_registry()
+        .send(SetScheduleEntryMessage {
+            id: None,
+            spec: spec.into_inner(),
+        })
+        .await
+        .map_err(error::ErrorInternalServerError)?
+        .map_err(error::ErrorBadRequest)?;
+
+    Ok(HttpResponse::Created().json(entry))
+}
+
+/// Replace an entry in the schedule
+async fn update_schedule_entry(
+    id: web::Path<Uuid>,
+    spec: web::Json<EntrySpec>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let entry = Scheduler::from_registry()
+        .send(SetScheduleEntryMessage {
+            id: Some(id.into_inner()),
+            spec: spec.into_inner(),
+        })
+        .await
+        .map_err(error::ErrorInternalServerError)?
+        .map_err(error::ErrorBadRequest)?;
+
+    Ok(HttpResponse::Ok().json(entry))
+}
+
+/// Remove an entry from the schedule
+async fn remove_schedule_entry(id: web::Path<Uuid>) -> Result<HttpResponse, actix_web::Error> {
+    Scheduler::from_registry()
+        .send(RemoveScheduleEntryMessage {
+            id: id.into_inner(),
+        })
+        .await
+        .map_err(error::ErrorInternalServerError)?
+        .map_err(error::ErrorNotFound)?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// List recent runs of schedule entries
+async fn schedule_history() -> Result<HttpResponse, actix_web::Error> {
+    let history = Scheduler::from_registry()
+        .send(ScheduleHistoryMessage)
+        .await
+        .map_err(error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok().json(history))
+}
+
+#[cfg(feature = "web-ui")]
 fn handle_embedded_file(path: &str) -> HttpResponse {
     match Asset::get(path) {
         Some(content) => HttpResponse::Ok()
@@ -44,30 +836,155 @@ fn handle_embedded_file(path: &str) -> HttpResponse {
     }
 }
 
+#[cfg(feature = "web-ui")]
 async fn index() -> HttpResponse {
     handle_embedded_file("index.html")
 }
 
+#[cfg(feature = "web-ui")]
 async fn dist(path: web::Path<String>) -> HttpResponse {
     handle_embedded_file(&path.as_str())
 }
-pub async fn run() -> Result<(), anyhow::Error> {
-    let server = HttpServer::new(move || {
-        let cors = Cors::default().allow_any_origin().send_wildcard();
 
-        App::new()
-            .wrap(cors)
-            .wrap(actix_web::middleware::Logger::default())
-            .route("/api/{mode:(control)}", web::get().to(ws))
-            .route("/", web::get().to(index))
-            .route("/{_:.*}", web::get().to(dist))
-    });
+/// Register the HTTP API, the control websocket and, with the `web-ui`
+/// feature, the web UI, for serving from an [`App`] of one's own. The
+/// control websocket then only accepts pages served from the same host
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/api/{mode:(control)}", web::get().to(ws))
+        .route("/api/media", web::get().to(list_media))
+        .route("/api/media/{name}", web::put().to(upload_media))
+        .route("/api/media/{name}", web::patch().to(rename_media))
+        .route("/api/media/{name}", web::delete().to(delete_media))
+        .route("/api/groups", web::get().to(list_groups))
+        .route("/api/groups/{name}", web::put().to(set_group))
+        .route("/api/groups/{name}", web::delete().to(remove_group))
+        .route("/api/bulk", web::post().to(bulk))
+        .route("/api/inputs", web::get().to(list_inputs))
+        .route("/api/inputs", web::post().to(add_input))
+        .route("/api/inputs/{id}", web::delete().to(remove_input))
+        .route("/api/devices/{id}/thumbnail", web::get().to(thumbnail))
+        .route("/api/devices/{id}/graph", web::get().to(graph))
+        .route("/api/levels", web::get().to(levels))
+        .route(
+            "/api/devices/{id}/loudness",
+            web::post().to(control_loudness),
+        )
+        .route("/api/loudness/config", web::get().to(get_loudness_config))
+        .route("/api/loudness/config", web::put().to(set_loudness_config))
+        .route("/api/loudness/history", web::get().to(loudness_history))
+        .route("/metrics", web::get().to(metrics))
+        .route("/healthz", web::get().to(healthz))
+        .route("/readyz", web::get().to(readyz))
+        .route("/api/alarms", web::get().to(list_alarms))
+        .route("/api/alarms/history", web::get().to(alarm_history))
+        .route("/api/alarms/shelves", web::get().to(list_shelves))
+        .route("/api/alarms/shelves/{id}", web::delete().to(remove_shelf))
+        .route(
+            "/api/alarms/{id}/acknowledge",
+            web::post().to(acknowledge_alarm),
+        )
+        .route("/api/alarms/{id}/shelve", web::post().to(shelve_alarm))
+        .route("/api/alarms/{id}/shelve", web::delete().to(unshelve_alarm))
+        .route("/api/detectors/config", web::get().to(get_detector_config))
+        .route("/api/detectors/config", web::put().to(set_detector_config))
+        .route("/api/watchdog/config", web::get().to(get_watchdog_config))
+        .route("/api/watchdog/config", web::put().to(set_watchdog_config))
+        .route("/api/logging", web::get().to(get_logging))
+        .route("/api/logging/filter", web::put().to(set_log_filter))
+        .route("/api/logging/gst", web::put().to(set_gst_debug))
+        .route("/api/logs", web::get().to(list_logs))
+        .route("/api/multiviewer", web::get().to(get_multiviewer))
+        .route("/api/multiviewer", web::put().to(set_multiviewer))
+        .route("/api/schedule", web::get().to(list_schedule))
+        .route("/api/schedule", web::post().to(add_schedule_entry))
+        .route("/api/schedule/history", web::get().to(schedule_history))
+        .route("/api/schedule/{id}", web::put().to(update_schedule_entry))
+        .route(
+            "/api/schedule/{id}",
+            web::delete().to(remove_schedule_entry),
+        );
+
+    #[cfg(feature = "web-ui")]
+    cfg.route("/", web::get().to(index))
+        .route("/{_:.*}", web::get().to(dist));
+}
+
+/// Start the services that have to run without waiting for a request:
+/// the scheduler, multiviewer, loudness log, content detector, alarms
+/// and hotplug watcher
+pub fn start_services() {
+    AlarmManager::from_registry();
+    DeviceWatcher::from_registry();
+    Scheduler::from_registry();
+    Multiviewer::from_registry();
+    LoudnessLog::from_registry();
+    ContentDetector::from_registry();
+}
+
+/// Serves vigil over HTTP until the server is stopped, then stops
+/// every node. Has to run within an actix system, after GStreamer was
+/// initialized
+#[derive(Clone, Debug)]
+pub struct ServerBuilder {
+    bind: String,
+    workers: Option<usize>,
+    origins: Vec<String>,
+}
 
-    info!("Starting webserver");
+impl Default for ServerBuilder {
+    fn default() -> Self {
+        Self {
+            bind: String::from(DEFAULT_BIND),
+            workers: None,
+            origins: Vec::new(),
+        }
+    }
+}
+
+impl ServerBuilder {
+    /// Listen on `addr`, such as `127.0.0.1:3000`, instead of every
+    /// interface on port 3000
+    pub fn bind(mut self, addr: impl Into<String>) -> Self {
+        self.bind = addr.into();
+        self
+    }
+
+    /// Handle requests on this many threads instead of one per core
+    pub fn workers(mut self, workers: usize) -> Self {
+        self.workers = Some(workers);
+        self
+    }
 
-    server.bind(format!("0.0.0.0:{}", 3000))?.run().await?;
+    /// Let pages from `origin`, such as `https://ops.example.com`,
+    /// change things and not only read them. Can be repeated
+    pub fn allow_origin(mut self, origin: impl Into<String>) -> Self {
+        self.origins.push(origin.into());
+        self
+    }
+
+    pub async fn run(self) -> Result<(), anyhow::Error> {
+        let origins = AllowedOrigins(self.origins.clone());
+        let mut server = HttpServer::new(move || {
+            App::new()
+                .app_data(web::Data::new(origins.clone()))
+                .wrap(cors(origins.clone()))
+                .wrap(actix_web::middleware::Logger::default())
+                .configure(configure)
+        });
+        if let Some(workers) = self.workers {
+            server = server.workers(workers);
+        }
+
+        start_services();
 
-    let _ = NodeManager::from_registry().send(StopMessage).await;
+        info!("Starting webserver on {}", self.bind);
 
-    Ok(())
+        server.bind(&self.bind)?.run().await?;
+
+        let _ = NodeManager::from_registry()
+            .send(StopMessage::default())
+            .await;
+
+        Ok(())
+    }
 }
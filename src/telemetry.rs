@@ -0,0 +1,72 @@
+use anyhow::Error;
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::trace::Tracer;
+use opentelemetry_sdk::Resource;
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::registry::LookupSpan;
+
+/// Keeps spans being exported, flushing those left when dropped
+#[derive(Debug)]
+pub struct Telemetry {
+    /// Where the exporter runs, apart from the actix system so it can
+    /// flush after the system has stopped
+    runtime: Option<tokio::runtime::Runtime>,
+}
+
+impl Drop for Telemetry {
+    fn drop(&mut self) {
+        opentelemetry::global::shutdown_tracer_provider();
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+/// A layer exporting spans over OTLP to `OTEL_EXPORTER_OTLP_ENDPOINT`,
+/// `None` when it is not set. Spans are named after `OTEL_SERVICE_NAME`,
+/// or vigil
+pub fn layer<S>() -> Result<Option<(OpenTelemetryLayer<S, Tracer>, Telemetry)>, Error>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    let endpoint = match std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT") {
+        Ok(endpoint) if !endpoint.is_empty() => endpoint,
+        _ => return Ok(None),
+    };
+    let service = std::env::var("OTEL_SERVICE_NAME").unwrap_or_else(|_| String::from("vigil"));
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("otlp")
+        .enable_all()
+        .build()?;
+    let tracer = {
+        // The batch exporter spawns its task on the runtime it is
+        // built in
+        let _entered = runtime.enter();
+
+        opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(endpoint),
+            )
+            .with_trace_config(
+                opentelemetry_sdk::trace::config().with_resource(Resource::new(vec![
+                    KeyValue::new("service.name", service),
+                    KeyValue::new("service.version", env!("CARGO_PKG_VERSION")),
+                ])),
+            )
+            .install_batch(opentelemetry_sdk::runtime::Tokio)?
+    };
+
+    Ok(Some((
+        tracing_opentelemetry::layer().with_tracer(tracer),
+        Telemetry {
+            runtime: Some(runtime),
+        },
+    )))
+}
@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use actix::{
+    Actor, AsyncContext, Context, Handler, Message, MessageResult, Recipient, SystemService,
+};
+use anyhow::{anyhow, Error};
+use serde::{Deserialize, Serialize};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::alarm::{AlarmManager, ClearAlarmMessage, RaiseAlarmMessage};
+use crate::command::{AlarmKind, AlarmSource, Severity};
+use crate::config::data_dir;
+
+/// How long a node can go without reporting its flow before its stall
+/// is cleared, as it is no longer being watched
+const FLOW_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Settings of the stall watchdog
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase", default)]
+pub struct WatchdogConfig {
+    pub enabled: bool,
+    /// How long buffers may stop flowing through a playing pipeline
+    /// before it counts as stalled
+    pub stall_ms: u64,
+    /// Whether a stalled node is restarted
+    pub restart: bool,
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            stall_ms: 5000,
+            restart: false,
+        }
+    }
+}
+
+impl WatchdogConfig {
+    /// Check the settings make sense
+    fn validate(&self) -> Result<(), Error> {
+        if self.stall_ms < 500 {
+            return Err(anyhow!("Stall threshold must be at least 500 ms"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Restart a stalled pipeline, sent from [`Watchdog`] to any node
+#[derive(Debug)]
+pub struct RestartMessage;
+
+impl Message for RestartMessage {
+    type Result = ();
+}
+
+/// A node that has reported its flow
+#[derive(Debug)]
+struct Watched {
+    last_seen: Instant,
+    /// Whether the node is stalled and alarmed
+    stalled: bool,
+}
+
+/// Keeps an eye on buffers flowing through every playing node, raising
+/// an alarm for any that stalls and optionally restarting it. A node
+/// is restarted once per stall, so one that cannot recover is not
+/// restarted over and over
+#[derive(Debug)]
+pub struct Watchdog {
+    config: WatchdogConfig,
+    /// Where the settings are persisted
+    config_path: PathBuf,
+    nodes: HashMap<Uuid, Watched>,
+}
+
+impl Default for Watchdog {
+    fn default() -> Self {
+        Self {
+            config: WatchdogConfig::default(),
+            config_path: data_dir().join("watchdog.json"),
+            nodes: HashMap::new(),
+        }
+    }
+}
+
+impl Actor for Watchdog {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        if let Err(err) = self.load() {
+            error!("Failed to load watchdog configuration: {}", err);
+        }
+
+        info!("Watchdog coming online");
+
+        ctx.run_interval(FLOW_TIMEOUT, |act, _| act.expire());
+    }
+}
+
+impl actix::Supervised for Watchdog {}
+
+impl SystemService for Watchdog {}
+
+impl Watchdog {
+    /// Load the settings from disk
+    fn load(&mut self) -> Result<(), Error> {
+        if self.config_path.exists() {
+            let file = std::fs::File::open(&self.config_path)?;
+            self.config = serde_json::from_reader(BufReader::new(file))?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the settings to disk, replacing the previous file
+    fn save(&self) -> Result<(), Error> {
+        if let Some(dir) = self.config_path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let partial = self.config_path.with_extension("json.part");
+        serde_json::to_writer_pretty(std::fs::File::create(&partial)?, &self.config)?;
+        std::fs::rename(&partial, &self.config_path)?;
+
+        Ok(())
+    }
+
+    /// Clear the stall alarm of the node with `id`
+    fn clear(id: Uuid) {
+        AlarmManager::from_registry().do_send(ClearAlarmMessage {
+            kind: AlarmKind::Stall,
+            source: AlarmSource {
+                device_id: Some(id),
+                element: None,
+            },
+        });
+    }
+
+    /// Clear the stalls of nodes that stopped reporting
+    fn expire(&mut self) {
+        self.nodes.retain(|id, node| {
+            let alive = node.last_seen.elapsed() < FLOW_TIMEOUT;
+            if !alive && node.stalled {
+                Self::clear(*id);
+            }
+            alive
+        });
+    }
+}
+
+/// How long buffers have been stuck in a node, sent from any node to
+/// [`Watchdog`] periodically
+#[derive(Debug)]
+pub struct FlowMessage {
+    /// Unique identifier of the node
+    pub id: Uuid,
+    /// What the node is called in alarms
+    pub name: String,
+    /// How bad a stall of the node is
+    pub severity: Severity,
+    /// How long the sink stuck the longest has gone without progress,
+    /// `None` while the node is not expected to flow
+    pub stalled: Option<Duration>,
+    /// Where to restart the node
+    pub restart: Recipient<RestartMessage>,
+}
+
+impl Message for FlowMessage {
+    type Result = ();
+}
+
+impl Handler<FlowMessage> for Watchdog {
+    type Result = ();
+
+    fn handle(&mut self, msg: FlowMessage, _ctx: &mut Context<Self>) {
+        let threshold = Duration::from_millis(self.config.stall_ms);
+        let node = self.nodes.entry(msg.id).or_insert(Watched {
+            last_seen: Instant::now(),
+            stalled: false,
+        });
+        node.last_seen = Instant::now();
+
+        let stalled =
+            self.config.enabled && msg.stalled.map_or(false, |stalled| stalled >= threshold);
+
+        if stalled && !node.stalled {
+            node.stalled = true;
+            warn!("{} stalled for {:?}", msg.name, msg.stalled);
+
+            AlarmManager::from_registry().do_send(RaiseAlarmMessage {
+                kind: AlarmKind::Stall,
+                severity: msg.severity,
+                source: AlarmSource {
+                    device_id: Some(msg.id),
+                    element: None,
+                },
+                message: format!(
+                    "{} stalled, no progress for {} s",
+                    msg.name,
+                    threshold.as_secs_f64()
+                ),
+                since: None,
+            });
+
+            if self.config.restart {
+                info!("Restarting stalled {}", msg.name);
+                msg.restart.do_send(RestartMessage);
+            }
+        } else if !stalled && node.stalled {
+            node.stalled = false;
+            Self::clear(msg.id);
+        }
+    }
+}
+
+/// Get the watchdog settings
+#[derive(Debug)]
+pub struct GetWatchdogConfigMessage;
+
+impl Message for GetWatchdogConfigMessage {
+    type Result = WatchdogConfig;
+}
+
+impl Handler<GetWatchdogConfigMessage> for Watchdog {
+    type Result = MessageResult<GetWatchdogConfigMessage>;
+
+    fn handle(&mut self, _msg: GetWatchdogConfigMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(self.config.clone())
+    }
+}
+
+/// Replace the watchdog settings
+#[derive(Debug)]
+pub struct SetWatchdogConfigMessage {
+    pub config: WatchdogConfig,
+}
+
+impl Message for SetWatchdogConfigMessage {
+    type Result = Result<WatchdogConfig, Error>;
+}
+
+impl Handler<SetWatchdogConfigMessage> for Watchdog {
+    type Result = Result<WatchdogConfig, Error>;
+
+    fn handle(&mut self, msg: SetWatchdogConfigMessage, _ctx: &mut Context<Self>) -> Self::Result {
+        msg.config.validate()?;
+
+        self.config = msg.config;
+        self.save()?;
+
+        Ok(self.config.clone())
+    }
+}
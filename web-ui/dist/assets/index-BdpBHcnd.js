@@ -1,62 +0,0 @@
-(function(){const t=document.createElement("link").relList;if(t&&t.supports&&t.supports("modulepreload"))return;for(const o of document.querySelectorAll('link[rel="modulepreload"]'))r(o);new MutationObserver(o=>{for(const i of o)if(i.type==="childList")for(const l of i.addedNodes)l.tagName==="LINK"&&l.rel==="modulepreload"&&r(l)}).observe(document,{childList:!0,subtree:!0});function n(o){const i={};return o.integrity&&(i.integrity=o.integrity),o.referrerPolicy&&(i.referrerPolicy=o.referrerPolicy),o.crossOrigin==="use-credentials"?i.credentials="include":o.crossOrigin==="anonymous"?i.credentials="omit":i.credentials="same-origin",i}function r(o){if(o.ep)return;o.ep=!0;const i=n(o);fetch(o.href,i)}})();function li(e){return e&&e.__esModule&&Object.prototype.hasOwnProperty.call(e,"default")?e.default:e}var qa={exports:{}},si={},ba={exports:{}},z={};/**
- * @license React
- * react.production.min.js
- *
- * Copyright (c) Facebook, Inc. and its affiliates.
- *
- * This source code is licensed under the MIT license found in the
- * LICENSE file in the root directory of this source tree.
- */var Vr=Symbol.for("react.element"),hp=Symbol.for("react.portal"),mp=Symbol.for("react.fragment"),vp=Symbol.for("react.strict_mode"),yp=Symbol.for("react.profiler"),gp=Symbol.for("react.provider"),wp=Symbol.for("react.context"),Sp=Symbol.for("react.forward_ref"),Ep=Symbol.for("react.suspense"),xp=Symbol.for("react.memo"),kp=Symbol.for("react.lazy"),gu=Symbol.iterator;function Cp(e){return e===null||typeof e!="object"?null:(e=gu&&e[gu]||e["@@iterator"],typeof e=="function"?e:null)}var ec={isMounted:function(){return!1},enqueueForceUpdate:function(){},enqueueReplaceState:function(){},enqueueSetState:function(){}},tc=Object.assign,nc={};function Zn(e,t,n){this.props=e,this.context=t,this.refs=nc,this.updater=n||ec}Zn.prototype.isReactComponent={};Zn.prototype.setState=function(e,t){if(typeof e!="object"&&typeof e!="function"&&e!=null)throw Error("setState(...): takes an object of state variables to update or a function which returns an object of state variables.");this.updater.enqueueSetState(this,e,t,"setState")};Zn.prototype.forceUpdate=function(e){this.updater.enqueueForceUpdate(this,e,"forceUpdate")};function rc(){}rc.prototype=Zn.prototype;function ps(e,t,n){this.props=e,this.context=t,this.refs=nc,this.updater=n||ec}var hs=ps.prototype=new rc;hs.constructor=ps;tc(hs,Zn.prototype);hs.isPureReactComponent=!0;var wu=Array.isArray,oc=Object.prototype.hasOwnProperty,ms={current:null},ic={key:!0,ref:!0,__self:!0,__source:!0};function lc(e,t,n){var r,o={},i=null,l=null;if(t!=null)for(r in t.ref!==void 0&&(l=t.ref),t.key!==void 0&&(i=""+t.key),t)oc.call(t,r)&&!ic.hasOwnProperty(r)&&(o[r]=t[r]);var s=arguments.length-2;if(s===1)o.children=n;else if(1<s){for(var u=Array(s),a=0;a<s;a++)u[a]=arguments[a+2];o.children=u}if(e&&e.defaultProps)for(r in s=e.defaultProps,s)o[r]===void 0&&(o[r]=s[r]);return{$$typeof:Vr,type:e,key:i,ref:l,props:o,_owner:ms.current}}function _p(e,t){return{$$typeof:Vr,type:e.type,key:t,ref:e.ref,props:e.props,_owner:e._owner}}function vs(e){return typeof e=="object"&&e!==null&&e.$$typeof===Vr}function Pp(e){var t={"=":"=0",":":"=2"};return"$"+e.replace(/[=:]/g,function(n){return t[n]})}var Su=/\/+/g;function Ni(e,t){return typeof e=="object"&&e!==null&&e.key!=null?Pp(""+e.key):t.toString(36)}function wo(e,t,n,r,o){var i=typeof e;(i==="undefined"||i==="boolean")&&(e=null);var l=!1;if(e===null)l=!0;else switch(i){case"string":case"number":l=!0;break;case"object":switch(e.$$typeof){case Vr:case hp:l=!0}}if(l)return l=e,o=o(l),e=r===""?"."+Ni(l,0):r,wu(o)?(n="",e!=null&&(n=e.replace(Su,"$&/")+"/"),wo(o,t,n,"",function(a){return a})):o!=null&&(vs(o)&&(o=_p(o,n+(!o.key||l&&l.key===o.key?"":(""+o.key).replace(Su,"$&/")+"/")+e)),t.push(o)),1;if(l=0,r=r===""?".":r+":",wu(e))for(var s=0;s<e.length;s++){i=e[s];var u=r+Ni(i,s);l+=wo(i,t,n,u,o)}else if(u=Cp(e),typeof u=="function")for(e=u.call(e),s=0;!(i=e.next()).done;)i=i.value,u=r+Ni(i,s++),l+=wo(i,t,n,u,o);else if(i==="object")throw t=String(e),Error("Objects are not valid as a React child (found: "+(t==="[object Object]"?"object with keys {"+Object.keys(e).join(", ")+"}":t)+"). If you meant to render a collection of children, use an array instead.");return l}function Jr(e,t,n){if(e==null)return e;var r=[],o=0;return wo(e,r,"","",function(i){return t.call(n,i,o++)}),r}function Rp(e){if(e._status===-1){var t=e._result;t=t(),t.then(function(n){(e._status===0||e._status===-1)&&(e._status=1,e._result=n)},function(n){(e._status===0||e._status===-1)&&(e._status=2,e._result=n)}),e._status===-1&&(e._status=0,e._result=t)}if(e._status===1)return e._result.default;throw e._result}var _e={current:null},So={transition:null},Tp={ReactCurrentDispatcher:_e,ReactCurrentBatchConfig:So,ReactCurrentOwner:ms};function sc(){throw Error("act(...) is not supported in production builds of React.")}z.Children={map:Jr,forEach:function(e,t,n){Jr(e,function(){t.apply(this,arguments)},n)},count:function(e){var t=0;return Jr(e,function(){t++}),t},toArray:function(e){return Jr(e,function(t){return t})||[]},only:function(e){if(!vs(e))throw Error("React.Children.only expected to receive a single React element child.");return e}};z.Component=Zn;z.Fragment=mp;z.Profiler=yp;z.PureComponent=ps;z.StrictMode=vp;z.Suspense=Ep;z.__SECRET_INTERNALS_DO_NOT_USE_OR_YOU_WILL_BE_FIRED=Tp;z.act=sc;z.cloneElement=function(e,t,n){if(e==null)throw Error("React.cloneElement(...): The argument must be a React element, but you passed "+e+".");var r=tc({},e.props),o=e.key,i=e.ref,l=e._owner;if(t!=null){if(t.ref!==void 0&&(i=t.ref,l=ms.current),t.key!==void 0&&(o=""+t.key),e.type&&e.type.defaultProps)var s=e.type.defaultProps;for(u in t)oc.call(t,u)&&!ic.hasOwnProperty(u)&&(r[u]=t[u]===void 0&&s!==void 0?s[u]:t[u])}var u=arguments.length-2;if(u===1)r.children=n;else if(1<u){s=Array(u);for(var a=0;a<u;a++)s[a]=arguments[a+2];r.children=s}return{$$typeof:Vr,type:e.type,key:o,ref:i,props:r,_owner:l}};z.createContext=function(e){return e={$$typeof:wp,_currentValue:e,_currentValue2:e,_threadCount:0,Provider:null,Consumer:null,_defaultValue:null,_globalName:null},e.Provider={$$typeof:gp,_context:e},e.Consumer=e};z.createElement=lc;z.createFactory=function(e){var t=lc.bind(null,e);return t.type=e,t};z.createRef=function(){return{current:null}};z.forwardRef=function(e){return{$$typeof:Sp,render:e}};z.isValidElement=vs;z.lazy=function(e){return{$$typeof:kp,_payload:{_status:-1,_result:e},_init:Rp}};z.memo=function(e,t){return{$$typeof:xp,type:e,compare:t===void 0?null:t}};z.startTransition=function(e){var t=So.transition;So.transition={};try{e()}finally{So.transition=t}};z.unstable_act=sc;z.useCallback=function(e,t){return _e.current.useCallback(e,t)};z.useContext=function(e){return _e.current.useContext(e)};z.useDebugValue=function(){};z.useDeferredValue=function(e){return _e.current.useDeferredValue(e)};z.useEffect=function(e,t){return _e.current.useEffect(e,t)};z.useId=function(){return _e.current.useId()};z.useImperativeHandle=function(e,t,n){return _e.current.useImperativeHandle(e,t,n)};z.useInsertionEffect=function(e,t){return _e.current.useInsertionEffect(e,t)};z.useLayoutEffect=function(e,t){return _e.current.useLayoutEffect(e,t)};z.useMemo=function(e,t){return _e.current.useMemo(e,t)};z.useReducer=function(e,t,n){return _e.current.useReducer(e,t,n)};z.useRef=function(e){return _e.current.useRef(e)};z.useState=function(e){return _e.current.useState(e)};z.useSyncExternalStore=function(e,t,n){return _e.current.useSyncExternalStore(e,t,n)};z.useTransition=function(){return _e.current.useTransition()};z.version="18.3.1";ba.exports=z;var w=ba.exports;const ct=li(w);/**
- * @license React
- * react-jsx-runtime.production.min.js
- *
- * Copyright (c) Facebook, Inc. and its affiliates.
- *
- * This source code is licensed under the MIT license found in the
- * LICENSE file in the root directory of this source tree.
- */var Np=w,Lp=Symbol.for("react.element"),Op=Symbol.for("react.fragment"),Mp=Object.prototype.hasOwnProperty,jp=Np.__SECRET_INTERNALS_DO_NOT_USE_OR_YOU_WILL_BE_FIRED.ReactCurrentOwner,Ip={key:!0,ref:!0,__self:!0,__source:!0};function uc(e,t,n){var r,o={},i=null,l=null;n!==void 0&&(i=""+n),t.key!==void 0&&(i=""+t.key),t.ref!==void 0&&(l=t.ref);for(r in t)Mp.call(t,r)&&!Ip.hasOwnProperty(r)&&(o[r]=t[r]);if(e&&e.defaultProps)for(r in t=e.defaultProps,t)o[r]===void 0&&(o[r]=t[r]);return{$$typeof:Lp,type:e,key:i,ref:l,props:o,_owner:jp.current}}si.Fragment=Op;si.jsx=uc;si.jsxs=uc;qa.exports=si;var _=qa.exports,fl={},ac={exports:{}},He={},cc={exports:{}},fc={};/**
- * @license React
- * scheduler.production.min.js
- *
- * Copyright (c) Facebook, Inc. and its affiliates.
- *
- * This source code is licensed under the MIT license found in the
- * LICENSE file in the root directory of this source tree.
- */(function(e){function t(C,L){var O=C.length;C.push(L);e:for(;0<O;){var F=O-1>>>1,U=C[F];if(0<o(U,L))C[F]=L,C[O]=U,O=F;else break e}}function n(C){return C.length===0?null:C[0]}function r(C){if(C.length===0)return null;var L=C[0],O=C.pop();if(O!==L){C[0]=O;e:for(var F=0,U=C.length,ye=U>>>1;F<ye;){var le=2*(F+1)-1,J=C[le],se=le+1,xe=C[se];if(0>o(J,O))se<U&&0>o(xe,J)?(C[F]=xe,C[se]=O,F=se):(C[F]=J,C[le]=O,F=le);else if(se<U&&0>o(xe,O))C[F]=xe,C[se]=O,F=se;else break e}}return L}function o(C,L){var O=C.sortIndex-L.sortIndex;return O!==0?O:C.id-L.id}if(typeof performance=="object"&&typeof performance.now=="function"){var i=performance;e.unstable_now=function(){return i.now()}}else{var l=Date,s=l.now();e.unstable_now=function(){return l.now()-s}}var u=[],a=[],c=1,h=null,d=3,v=!1,y=!1,g=!1,x=typeof setTimeout=="function"?setTimeout:null,m=typeof clearTimeout=="function"?clearTimeout:null,f=typeof setImmediate<"u"?setImmediate:null;typeof navigator<"u"&&navigator.scheduling!==void 0&&navigator.scheduling.isInputPending!==void 0&&navigator.scheduling.isInputPending.bind(navigator.scheduling);function p(C){for(var L=n(a);L!==null;){if(L.callback===null)r(a);else if(L.startTime<=C)r(a),L.sortIndex=L.expirationTime,t(u,L);else break;L=n(a)}}function S(C){if(g=!1,p(C),!y)if(n(u)!==null)y=!0,X(k);else{var L=n(a);L!==null&&V(S,L.startTime-C)}}function k(C,L){y=!1,g&&(g=!1,m(T),T=-1),v=!0;var O=d;try{for(p(L),h=n(u);h!==null&&(!(h.expirationTime>L)||C&&!B());){var F=h.callback;if(typeof F=="function"){h.callback=null,d=h.priorityLevel;var U=F(h.expirationTime<=L);L=e.unstable_now(),typeof U=="function"?h.callback=U:h===n(u)&&r(u),p(L)}else r(u);h=n(u)}if(h!==null)var ye=!0;else{var le=n(a);le!==null&&V(S,le.startTime-L),ye=!1}return ye}finally{h=null,d=O,v=!1}}var E=!1,P=null,T=-1,D=5,M=-1;function B(){return!(e.unstable_now()-M<D)}function I(){if(P!==null){var C=e.unstable_now();M=C;var L=!0;try{L=P(!0,C)}finally{L?j():(E=!1,P=null)}}else E=!1}var j;if(typeof f=="function")j=function(){f(I)};else if(typeof MessageChannel<"u"){var $=new MessageChannel,ne=$.port2;$.port1.onmessage=I,j=function(){ne.postMessage(null)}}else j=function(){x(I,0)};function X(C){P=C,E||(E=!0,j())}function V(C,L){T=x(function(){C(e.unstable_now())},L)}e.unstable_IdlePriority=5,e.unstable_ImmediatePriority=1,e.unstable_LowPriority=4,e.unstable_NormalPriority=3,e.unstable_Profiling=null,e.unstable_UserBlockingPriority=2,e.unstable_cancelCallback=function(C){C.callback=null},e.unstable_continueExecution=function(){y||v||(y=!0,X(k))},e.unstable_forceFrameRate=function(C){0>C||125<C?console.error("forceFrameRate takes a positive int between 0 and 125, forcing frame rates higher than 125 fps is not supported"):D=0<C?Math.floor(1e3/C):5},e.unstable_getCurrentPriorityLevel=function(){return d},e.unstable_getFirstCallbackNode=function(){return n(u)},e.unstable_next=function(C){switch(d){case 1:case 2:case 3:var L=3;break;default:L=d}var O=d;d=L;try{return C()}finally{d=O}},e.unstable_pauseExecution=function(){},e.unstable_requestPaint=function(){},e.unstable_runWithPriority=function(C,L){switch(C){case 1:case 2:case 3:case 4:case 5:break;default:C=3}var O=d;d=C;try{return L()}finally{d=O}},e.unstable_scheduleCallback=function(C,L,O){var F=e.unstable_now();switch(typeof O=="object"&&O!==null?(O=O.delay,O=typeof O=="number"&&0<O?F+O:F):O=F,C){case 1:var U=-1;break;case 2:U=250;break;case 5:U=1073741823;break;case 4:U=1e4;break;default:U=5e3}return U=O+U,C={id:c++,callback:L,priorityLevel:C,startTime:O,expirationTime:U,sortIndex:-1},O>F?(C.sortIndex=O,t(a,C),n(u)===null&&C===n(a)&&(g?(m(T),T=-1):g=!0,V(S,O-F))):(C.sortIndex=U,t(u,C),y||v||(y=!0,X(k))),C},e.unstable_shouldYield=B,e.unstable_wrapCallback=function(C){var L=d;return function(){var O=d;d=L;try{return C.apply(this,arguments)}finally{d=O}}}})(fc);cc.exports=fc;var Dp=cc.exports;/**
- * @license React
- * react-dom.production.min.js
- *
- * Copyright (c) Facebook, Inc. and its affiliates.
- *
- * This source code is licensed under the MIT license found in the
- * LICENSE file in the root directory of this source tree.
- */var Fp=w,We=Dp;function R(e){for(var t="https://reactjs.org/docs/error-decoder.html?invariant="+e,n=1;n<arguments.length;n++)t+="&args[]="+encodeURIComponent(arguments[n]);return"Minified React error #"+e+"; visit "+t+" for the full message or use the non-minified dev environment for full errors and additional helpful warnings."}var dc=new Set,Pr={};function hn(e,t){Un(e,t),Un(e+"Capture",t)}function Un(e,t){for(Pr[e]=t,e=0;e<t.length;e++)dc.add(t[e])}var Et=!(typeof window>"u"||typeof window.document>"u"||typeof window.document.createElement>"u"),dl=Object.prototype.hasOwnProperty,zp=/^[:A-Z_a-z\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u02FF\u0370-\u037D\u037F-\u1FFF\u200C-\u200D\u2070-\u218F\u2C00-\u2FEF\u3001-\uD7FF\uF900-\uFDCF\uFDF0-\uFFFD][:A-Z_a-z\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u02FF\u0370-\u037D\u037F-\u1FFF\u200C-\u200D\u2070-\u218F\u2C00-\u2FEF\u3001-\uD7FF\uF900-\uFDCF\uFDF0-\uFFFD\-.0-9\u00B7\u0300-\u036F\u203F-\u2040]*$/,Eu={},xu={};function $p(e){return dl.call(xu,e)?!0:dl.call(Eu,e)?!1:zp.test(e)?xu[e]=!0:(Eu[e]=!0,!1)}function Ap(e,t,n,r){if(n!==null&&n.type===0)return!1;switch(typeof t){case"function":case"symbol":return!0;case"boolean":return r?!1:n!==null?!n.acceptsBooleans:(e=e.toLowerCase().slice(0,5),e!=="data-"&&e!=="aria-");default:return!1}}function Bp(e,t,n,r){if(t===null||typeof t>"u"||Ap(e,t,n,r))return!0;if(r)return!1;if(n!==null)switch(n.type){case 3:return!t;case 4:return t===!1;case 5:return isNaN(t);case 6:return isNaN(t)||1>t}return!1}function Pe(e,t,n,r,o,i,l){this.acceptsBooleans=t===2||t===3||t===4,this.attributeName=r,this.attributeNamespace=o,this.mustUseProperty=n,this.propertyName=e,this.type=t,this.sanitizeURL=i,this.removeEmptyString=l}var ve={};"children dangerouslySetInnerHTML defaultValue defaultChecked innerHTML suppressContentEditableWarning suppressHydrationWarning style".split(" ").forEach(function(e){ve[e]=new Pe(e,0,!1,e,null,!1,!1)});[["acceptCharset","accept-charset"],["className","class"],["htmlFor","for"],["httpEquiv","http-equiv"]].forEach(function(e){var t=e[0];ve[t]=new Pe(t,1,!1,e[1],null,!1,!1)});["contentEditable","draggable","spellCheck","value"].forEach(function(e){ve[e]=new Pe(e,2,!1,e.toLowerCase(),null,!1,!1)});["autoReverse","externalResourcesRequired","focusable","preserveAlpha"].forEach(function(e){ve[e]=new Pe(e,2,!1,e,null,!1,!1)});"allowFullScreen async autoFocus autoPlay controls default defer disabled disablePictureInPicture disableRemotePlayback formNoValidate hidden loop noModule noValidate open playsInline readOnly required reversed scoped seamless itemScope".split(" ").forEach(function(e){ve[e]=new Pe(e,3,!1,e.toLowerCase(),null,!1,!1)});["checked","multiple","muted","selected"].forEach(function(e){ve[e]=new Pe(e,3,!0,e,null,!1,!1)});["capture","download"].forEach(function(e){ve[e]=new Pe(e,4,!1,e,null,!1,!1)});["cols","rows","size","span"].forEach(function(e){ve[e]=new Pe(e,6,!1,e,null,!1,!1)});["rowSpan","start"].forEach(function(e){ve[e]=new Pe(e,5,!1,e.toLowerCase(),null,!1,!1)});var ys=/[\-:]([a-z])/g;function gs(e){return e[1].toUpperCase()}"accent-height alignment-baseline arabic-form baseline-shift cap-height clip-path clip-rule color-interpolation color-interpolation-filters color-profile color-rendering dominant-baseline enable-background fill-opacity fill-rule flood-color flood-opacity font-family font-size font-size-adjust font-stretch font-style font-variant font-weight glyph-name glyph-orientation-horizontal glyph-orientation-vertical horiz-adv-x horiz-origin-x image-rendering letter-spacing lighting-color marker-end marker-mid marker-start overline-position overline-thickness paint-order panose-1 pointer-events rendering-intent shape-rendering stop-color stop-opacity strikethrough-position strikethrough-thickness stroke-dasharray stroke-dashoffset stroke-linecap stroke-linejoin stroke-miterlimit stroke-opacity stroke-width text-anchor text-decoration text-rendering underline-position underline-thickness unicode-bidi unicode-range units-per-em v-alphabetic v-hanging v-ideographic v-mathematical vector-effect vert-adv-y vert-origin-x vert-origin-y word-spacing writing-mode xmlns:xlink x-height".split(" ").forEach(function(e){var t=e.replace(ys,gs);ve[t]=new Pe(t,1,!1,e,null,!1,!1)});"xlink:actuate xlink:arcrole xlink:role xlink:show xlink:title xlink:type".split(" ").forEach(function(e){var t=e.replace(ys,gs);ve[t]=new Pe(t,1,!1,e,"http://www.w3.org/1999/xlink",!1,!1)});["xml:base","xml:lang","xml:space"].forEach(function(e){var t=e.replace(ys,gs);ve[t]=new Pe(t,1,!1,e,"http://www.w3.org/XML/1998/namespace",!1,!1)});["tabIndex","crossOrigin"].forEach(function(e){ve[e]=new Pe(e,1,!1,e.toLowerCase(),null,!1,!1)});ve.xlinkHref=new Pe("xlinkHref",1,!1,"xlink:href","http://www.w3.org/1999/xlink",!0,!1);["src","href","action","formAction"].forEach(function(e){ve[e]=new Pe(e,1,!1,e.toLowerCase(),null,!0,!0)});function ws(e,t,n,r){var o=ve.hasOwnProperty(t)?ve[t]:null;(o!==null?o.type!==0:r||!(2<t.length)||t[0]!=="o"&&t[0]!=="O"||t[1]!=="n"&&t[1]!=="N")&&(Bp(t,n,o,r)&&(n=null),r||o===null?$p(t)&&(n===null?e.removeAttribute(t):e.setAttribute(t,""+n)):o.mustUseProperty?e[o.propertyName]=n===null?o.type===3?!1:"":n:(t=o.attributeName,r=o.attributeNamespace,n===null?e.removeAttribute(t):(o=o.type,n=o===3||o===4&&n===!0?"":""+n,r?e.setAttributeNS(r,t,n):e.setAttribute(t,n))))}var Pt=Fp.__SECRET_INTERNALS_DO_NOT_USE_OR_YOU_WILL_BE_FIRED,qr=Symbol.for("react.element"),Sn=Symbol.for("react.portal"),En=Symbol.for("react.fragment"),Ss=Symbol.for("react.strict_mode"),pl=Symbol.for("react.profiler"),pc=Symbol.for("react.provider"),hc=Symbol.for("react.context"),Es=Symbol.for("react.forward_ref"),hl=Symbol.for("react.suspense"),ml=Symbol.for("react.suspense_list"),xs=Symbol.for("react.memo"),Lt=Symbol.for("react.lazy"),mc=Symbol.for("react.offscreen"),ku=Symbol.iterator;function er(e){return e===null||typeof e!="object"?null:(e=ku&&e[ku]||e["@@iterator"],typeof e=="function"?e:null)}var ee=Object.assign,Li;function ar(e){if(Li===void 0)try{throw Error()}catch(n){var t=n.stack.trim().match(/\n( *(at )?)/);Li=t&&t[1]||""}return`
-`+Li+e}var Oi=!1;function Mi(e,t){if(!e||Oi)return"";Oi=!0;var n=Error.prepareStackTrace;Error.prepareStackTrace=void 0;try{if(t)if(t=function(){throw Error()},Object.defineProperty(t.prototype,"props",{set:function(){throw Error()}}),typeof Reflect=="object"&&Reflect.construct){try{Reflect.construct(t,[])}catch(a){var r=a}Reflect.construct(e,[],t)}else{try{t.call()}catch(a){r=a}e.call(t.prototype)}else{try{throw Error()}catch(a){r=a}e()}}catch(a){if(a&&r&&typeof a.stack=="string"){for(var o=a.stack.split(`
-`),i=r.stack.split(`
-`),l=o.length-1,s=i.length-1;1<=l&&0<=s&&o[l]!==i[s];)s--;for(;1<=l&&0<=s;l--,s--)if(o[l]!==i[s]){if(l!==1||s!==1)do if(l--,s--,0>s||o[l]!==i[s]){var u=`
-`+o[l].replace(" at new "," at ");return e.displayName&&u.includes("<anonymous>")&&(u=u.replace("<anonymous>",e.displayName)),u}while(1<=l&&0<=s);break}}}finally{Oi=!1,Error.prepareStackTrace=n}return(e=e?e.displayName||e.name:"")?ar(e):""}function Up(e){switch(e.tag){case 5:return ar(e.type);case 16:return ar("Lazy");case 13:return ar("Suspense");case 19:return ar("SuspenseList");case 0:case 2:case 15:return e=Mi(e.type,!1),e;case 11:return e=Mi(e.type.render,!1),e;case 1:return e=Mi(e.type,!0),e;default:return""}}function vl(e){if(e==null)return null;if(typeof e=="function")return e.displayName||e.name||null;if(typeof e=="string")return e;switch(e){case En:return"Fragment";case Sn:return"Portal";case pl:return"Profiler";case Ss:return"StrictMode";case hl:return"Suspense";case ml:return"SuspenseList"}if(typeof e=="object")switch(e.$$typeof){case hc:return(e.displayName||"Context")+".Consumer";case pc:return(e._context.displayName||"Context")+".Provider";case Es:var t=e.render;return e=e.displayName,e||(e=t.displayName||t.name||"",e=e!==""?"ForwardRef("+e+")":"ForwardRef"),e;case xs:return t=e.displayName||null,t!==null?t:vl(e.type)||"Memo";case Lt:t=e._payload,e=e._init;try{return vl(e(t))}catch{}}return null}function Wp(e){var t=e.type;switch(e.tag){case 24:return"Cache";case 9:return(t.displayName||"Context")+".Consumer";case 10:return(t._context.displayName||"Context")+".Provider";case 18:return"DehydratedFragment";case 11:return e=t.render,e=e.displayName||e.name||"",t.displayName||(e!==""?"ForwardRef("+e+")":"ForwardRef");case 7:return"Fragment";case 5:return t;case 4:return"Portal";case 3:return"Root";case 6:return"Text";case 16:return vl(t);case 8:return t===Ss?"StrictMode":"Mode";case 22:return"Offscreen";case 12:return"Profiler";case 21:return"Scope";case 13:return"Suspense";case 19:return"SuspenseList";case 25:return"TracingMarker";case 1:case 0:case 17:case 2:case 14:case 15:if(typeof t=="function")return t.displayName||t.name||null;if(typeof t=="string")return t}return null}function Qt(e){switch(typeof e){case"boolean":case"number":case"string":case"undefined":return e;case"object":return e;default:return""}}function vc(e){var t=e.type;return(e=e.nodeName)&&e.toLowerCase()==="input"&&(t==="checkbox"||t==="radio")}function Hp(e){var t=vc(e)?"checked":"value",n=Object.getOwnPropertyDescriptor(e.constructor.prototype,t),r=""+e[t];if(!e.hasOwnProperty(t)&&typeof n<"u"&&typeof n.get=="function"&&typeof n.set=="function"){var o=n.get,i=n.set;return Object.defineProperty(e,t,{configurable:!0,get:function(){return o.call(this)},set:function(l){r=""+l,i.call(this,l)}}),Object.defineProperty(e,t,{enumerable:n.enumerable}),{getValue:function(){return r},setValue:function(l){r=""+l},stopTracking:function(){e._valueTracker=null,delete e[t]}}}}function br(e){e._valueTracker||(e._valueTracker=Hp(e))}function yc(e){if(!e)return!1;var t=e._valueTracker;if(!t)return!0;var n=t.getValue(),r="";return e&&(r=vc(e)?e.checked?"true":"false":e.value),e=r,e!==n?(t.setValue(e),!0):!1}function jo(e){if(e=e||(typeof document<"u"?document:void 0),typeof e>"u")return null;try{return e.activeElement||e.body}catch{return e.body}}function yl(e,t){var n=t.checked;return ee({},t,{defaultChecked:void 0,defaultValue:void 0,value:void 0,checked:n??e._wrapperState.initialChecked})}function Cu(e,t){var n=t.defaultValue==null?"":t.defaultValue,r=t.checked!=null?t.checked:t.defaultChecked;n=Qt(t.value!=null?t.value:n),e._wrapperState={initialChecked:r,initialValue:n,controlled:t.type==="checkbox"||t.type==="radio"?t.checked!=null:t.value!=null}}function gc(e,t){t=t.checked,t!=null&&ws(e,"checked",t,!1)}function gl(e,t){gc(e,t);var n=Qt(t.value),r=t.type;if(n!=null)r==="number"?(n===0&&e.value===""||e.value!=n)&&(e.value=""+n):e.value!==""+n&&(e.value=""+n);else if(r==="submit"||r==="reset"){e.removeAttribute("value");return}t.hasOwnProperty("value")?wl(e,t.type,n):t.hasOwnProperty("defaultValue")&&wl(e,t.type,Qt(t.defaultValue)),t.checked==null&&t.defaultChecked!=null&&(e.defaultChecked=!!t.defaultChecked)}function _u(e,t,n){if(t.hasOwnProperty("value")||t.hasOwnProperty("defaultValue")){var r=t.type;if(!(r!=="submit"&&r!=="reset"||t.value!==void 0&&t.value!==null))return;t=""+e._wrapperState.initialValue,n||t===e.value||(e.value=t),e.defaultValue=t}n=e.name,n!==""&&(e.name=""),e.defaultChecked=!!e._wrapperState.initialChecked,n!==""&&(e.name=n)}function wl(e,t,n){(t!=="number"||jo(e.ownerDocument)!==e)&&(n==null?e.defaultValue=""+e._wrapperState.initialValue:e.defaultValue!==""+n&&(e.defaultValue=""+n))}var cr=Array.isArray;function In(e,t,n,r){if(e=e.options,t){t={};for(var o=0;o<n.length;o++)t["$"+n[o]]=!0;for(n=0;n<e.length;n++)o=t.hasOwnProperty("$"+e[n].value),e[n].selected!==o&&(e[n].selected=o),o&&r&&(e[n].defaultSelected=!0)}else{for(n=""+Qt(n),t=null,o=0;o<e.length;o++){if(e[o].value===n){e[o].selected=!0,r&&(e[o].defaultSelected=!0);return}t!==null||e[o].disabled||(t=e[o])}t!==null&&(t.selected=!0)}}function Sl(e,t){if(t.dangerouslySetInnerHTML!=null)throw Error(R(91));return ee({},t,{value:void 0,defaultValue:void 0,children:""+e._wrapperState.initialValue})}function Pu(e,t){var n=t.value;if(n==null){if(n=t.children,t=t.defaultValue,n!=null){if(t!=null)throw Error(R(92));if(cr(n)){if(1<n.length)throw Error(R(93));n=n[0]}t=n}t==null&&(t=""),n=t}e._wrapperState={initialValue:Qt(n)}}function wc(e,t){var n=Qt(t.value),r=Qt(t.defaultValue);n!=null&&(n=""+n,n!==e.value&&(e.value=n),t.defaultValue==null&&e.defaultValue!==n&&(e.defaultValue=n)),r!=null&&(e.defaultValue=""+r)}function Ru(e){var t=e.textContent;t===e._wrapperState.initialValue&&t!==""&&t!==null&&(e.value=t)}function Sc(e){switch(e){case"svg":return"http://www.w3.org/2000/svg";case"math":return"http://www.w3.org/1998/Math/MathML";default:return"http://www.w3.org/1999/xhtml"}}function El(e,t){return e==null||e==="http://www.w3.org/1999/xhtml"?Sc(t):e==="http://www.w3.org/2000/svg"&&t==="foreignObject"?"http://www.w3.org/1999/xhtml":e}var eo,Ec=function(e){return typeof MSApp<"u"&&MSApp.execUnsafeLocalFunction?function(t,n,r,o){MSApp.execUnsafeLocalFunction(function(){return e(t,n,r,o)})}:e}(function(e,t){if(e.namespaceURI!=="http://www.w3.org/2000/svg"||"innerHTML"in e)e.innerHTML=t;else{for(eo=eo||document.createElement("div"),eo.innerHTML="<svg>"+t.valueOf().toString()+"</svg>",t=eo.firstChild;e.firstChild;)e.removeChild(e.firstChild);for(;t.firstChild;)e.appendChild(t.firstChild)}});function Rr(e,t){if(t){var n=e.firstChild;if(n&&n===e.lastChild&&n.nodeType===3){n.nodeValue=t;return}}e.textContent=t}var hr={animationIterationCount:!0,aspectRatio:!0,borderImageOutset:!0,borderImageSlice:!0,borderImageWidth:!0,boxFlex:!0,boxFlexGroup:!0,boxOrdinalGroup:!0,columnCount:!0,columns:!0,flex:!0,flexGrow:!0,flexPositive:!0,flexShrink:!0,flexNegative:!0,flexOrder:!0,gridArea:!0,gridRow:!0,gridRowEnd:!0,gridRowSpan:!0,gridRowStart:!0,gridColumn:!0,gridColumnEnd:!0,gridColumnSpan:!0,gridColumnStart:!0,fontWeight:!0,lineClamp:!0,lineHeight:!0,opacity:!0,order:!0,orphans:!0,tabSize:!0,widows:!0,zIndex:!0,zoom:!0,fillOpacity:!0,floodOpacity:!0,stopOpacity:!0,strokeDasharray:!0,strokeDashoffset:!0,strokeMiterlimit:!0,strokeOpacity:!0,strokeWidth:!0},Vp=["Webkit","ms","Moz","O"];Object.keys(hr).forEach(function(e){Vp.forEach(function(t){t=t+e.charAt(0).toUpperCase()+e.substring(1),hr[t]=hr[e]})});function xc(e,t,n){return t==null||typeof t=="boolean"||t===""?"":n||typeof t!="number"||t===0||hr.hasOwnProperty(e)&&hr[e]?(""+t).trim():t+"px"}function kc(e,t){e=e.style;for(var n in t)if(t.hasOwnProperty(n)){var r=n.indexOf("--")===0,o=xc(n,t[n],r);n==="float"&&(n="cssFloat"),r?e.setProperty(n,o):e[n]=o}}var Kp=ee({menuitem:!0},{area:!0,base:!0,br:!0,col:!0,embed:!0,hr:!0,img:!0,input:!0,keygen:!0,link:!0,meta:!0,param:!0,source:!0,track:!0,wbr:!0});function xl(e,t){if(t){if(Kp[e]&&(t.children!=null||t.dangerouslySetInnerHTML!=null))throw Error(R(137,e));if(t.dangerouslySetInnerHTML!=null){if(t.children!=null)throw Error(R(60));if(typeof t.dangerouslySetInnerHTML!="object"||!("__html"in t.dangerouslySetInnerHTML))throw Error(R(61))}if(t.style!=null&&typeof t.style!="object")throw Error(R(62))}}function kl(e,t){if(e.indexOf("-")===-1)return typeof t.is=="string";switch(e){case"annotation-xml":case"color-profile":case"font-face":case"font-face-src":case"font-face-uri":case"font-face-format":case"font-face-name":case"missing-glyph":return!1;default:return!0}}var Cl=null;function ks(e){return e=e.target||e.srcElement||window,e.correspondingUseElement&&(e=e.correspondingUseElement),e.nodeType===3?e.parentNode:e}var _l=null,Dn=null,Fn=null;function Tu(e){if(e=Gr(e)){if(typeof _l!="function")throw Error(R(280));var t=e.stateNode;t&&(t=di(t),_l(e.stateNode,e.type,t))}}function Cc(e){Dn?Fn?Fn.push(e):Fn=[e]:Dn=e}function _c(){if(Dn){var e=Dn,t=Fn;if(Fn=Dn=null,Tu(e),t)for(e=0;e<t.length;e++)Tu(t[e])}}function Pc(e,t){return e(t)}function Rc(){}var ji=!1;function Tc(e,t,n){if(ji)return e(t,n);ji=!0;try{return Pc(e,t,n)}finally{ji=!1,(Dn!==null||Fn!==null)&&(Rc(),_c())}}function Tr(e,t){var n=e.stateNode;if(n===null)return null;var r=di(n);if(r===null)return null;n=r[t];e:switch(t){case"onClick":case"onClickCapture":case"onDoubleClick":case"onDoubleClickCapture":case"onMouseDown":case"onMouseDownCapture":case"onMouseMove":case"onMouseMoveCapture":case"onMouseUp":case"onMouseUpCapture":case"onMouseEnter":(r=!r.disabled)||(e=e.type,r=!(e==="button"||e==="input"||e==="select"||e==="textarea")),e=!r;break e;default:e=!1}if(e)return null;if(n&&typeof n!="function")throw Error(R(231,t,typeof n));return n}var Pl=!1;if(Et)try{var tr={};Object.defineProperty(tr,"passive",{get:function(){Pl=!0}}),window.addEventListener("test",tr,tr),window.removeEventListener("test",tr,tr)}catch{Pl=!1}function Qp(e,t,n,r,o,i,l,s,u){var a=Array.prototype.slice.call(arguments,3);try{t.apply(n,a)}catch(c){this.onError(c)}}var mr=!1,Io=null,Do=!1,Rl=null,Gp={onError:function(e){mr=!0,Io=e}};function Yp(e,t,n,r,o,i,l,s,u){mr=!1,Io=null,Qp.apply(Gp,arguments)}function Xp(e,t,n,r,o,i,l,s,u){if(Yp.apply(this,arguments),mr){if(mr){var a=Io;mr=!1,Io=null}else throw Error(R(198));Do||(Do=!0,Rl=a)}}function mn(e){var t=e,n=e;if(e.alternate)for(;t.return;)t=t.return;else{e=t;do t=e,t.flags&4098&&(n=t.return),e=t.return;while(e)}return t.tag===3?n:null}function Nc(e){if(e.tag===13){var t=e.memoizedState;if(t===null&&(e=e.alternate,e!==null&&(t=e.memoizedState)),t!==null)return t.dehydrated}return null}function Nu(e){if(mn(e)!==e)throw Error(R(188))}function Zp(e){var t=e.alternate;if(!t){if(t=mn(e),t===null)throw Error(R(188));return t!==e?null:e}for(var n=e,r=t;;){var o=n.return;if(o===null)break;var i=o.alternate;if(i===null){if(r=o.return,r!==null){n=r;continue}break}if(o.child===i.child){for(i=o.child;i;){if(i===n)return Nu(o),e;if(i===r)return Nu(o),t;i=i.sibling}throw Error(R(188))}if(n.return!==r.return)n=o,r=i;else{for(var l=!1,s=o.child;s;){if(s===n){l=!0,n=o,r=i;break}if(s===r){l=!0,r=o,n=i;break}s=s.sibling}if(!l){for(s=i.child;s;){if(s===n){l=!0,n=i,r=o;break}if(s===r){l=!0,r=i,n=o;break}s=s.sibling}if(!l)throw Error(R(189))}}if(n.alternate!==r)throw Error(R(190))}if(n.tag!==3)throw Error(R(188));return n.stateNode.current===n?e:t}function Lc(e){return e=Zp(e),e!==null?Oc(e):null}function Oc(e){if(e.tag===5||e.tag===6)return e;for(e=e.child;e!==null;){var t=Oc(e);if(t!==null)return t;e=e.sibling}return null}var Mc=We.unstable_scheduleCallback,Lu=We.unstable_cancelCallback,Jp=We.unstable_shouldYield,qp=We.unstable_requestPaint,re=We.unstable_now,bp=We.unstable_getCurrentPriorityLevel,Cs=We.unstable_ImmediatePriority,jc=We.unstable_UserBlockingPriority,Fo=We.unstable_NormalPriority,eh=We.unstable_LowPriority,Ic=We.unstable_IdlePriority,ui=null,ft=null;function th(e){if(ft&&typeof ft.onCommitFiberRoot=="function")try{ft.onCommitFiberRoot(ui,e,void 0,(e.current.flags&128)===128)}catch{}}var rt=Math.clz32?Math.clz32:oh,nh=Math.log,rh=Math.LN2;function oh(e){return e>>>=0,e===0?32:31-(nh(e)/rh|0)|0}var to=64,no=4194304;function fr(e){switch(e&-e){case 1:return 1;case 2:return 2;case 4:return 4;case 8:return 8;case 16:return 16;case 32:return 32;case 64:case 128:case 256:case 512:case 1024:case 2048:case 4096:case 8192:case 16384:case 32768:case 65536:case 131072:case 262144:case 524288:case 1048576:case 2097152:return e&4194240;case 4194304:case 8388608:case 16777216:case 33554432:case 67108864:return e&130023424;case 134217728:return 134217728;case 268435456:return 268435456;case 536870912:return 536870912;case 1073741824:return 1073741824;default:return e}}function zo(e,t){var n=e.pendingLanes;if(n===0)return 0;var r=0,o=e.suspendedLanes,i=e.pingedLanes,l=n&268435455;if(l!==0){var s=l&~o;s!==0?r=fr(s):(i&=l,i!==0&&(r=fr(i)))}else l=n&~o,l!==0?r=fr(l):i!==0&&(r=fr(i));if(r===0)return 0;if(t!==0&&t!==r&&!(t&o)&&(o=r&-r,i=t&-t,o>=i||o===16&&(i&4194240)!==0))return t;if(r&4&&(r|=n&16),t=e.entangledLanes,t!==0)for(e=e.entanglements,t&=r;0<t;)n=31-rt(t),o=1<<n,r|=e[n],t&=~o;return r}function ih(e,t){switch(e){case 1:case 2:case 4:return t+250;case 8:case 16:case 32:case 64:case 128:case 256:case 512:case 1024:case 2048:case 4096:case 8192:case 16384:case 32768:case 65536:case 131072:case 262144:case 524288:case 1048576:case 2097152:return t+5e3;case 4194304:case 8388608:case 16777216:case 33554432:case 67108864:return-1;case 134217728:case 268435456:case 536870912:case 1073741824:return-1;default:return-1}}function lh(e,t){for(var n=e.suspendedLanes,r=e.pingedLanes,o=e.expirationTimes,i=e.pendingLanes;0<i;){var l=31-rt(i),s=1<<l,u=o[l];u===-1?(!(s&n)||s&r)&&(o[l]=ih(s,t)):u<=t&&(e.expiredLanes|=s),i&=~s}}function Tl(e){return e=e.pendingLanes&-1073741825,e!==0?e:e&1073741824?1073741824:0}function Dc(){var e=to;return to<<=1,!(to&4194240)&&(to=64),e}function Ii(e){for(var t=[],n=0;31>n;n++)t.push(e);return t}function Kr(e,t,n){e.pendingLanes|=t,t!==536870912&&(e.suspendedLanes=0,e.pingedLanes=0),e=e.eventTimes,t=31-rt(t),e[t]=n}function sh(e,t){var n=e.pendingLanes&~t;e.pendingLanes=t,e.suspendedLanes=0,e.pingedLanes=0,e.expiredLanes&=t,e.mutableReadLanes&=t,e.entangledLanes&=t,t=e.entanglements;var r=e.eventTimes;for(e=e.expirationTimes;0<n;){var o=31-rt(n),i=1<<o;t[o]=0,r[o]=-1,e[o]=-1,n&=~i}}function _s(e,t){var n=e.entangledLanes|=t;for(e=e.entanglements;n;){var r=31-rt(n),o=1<<r;o&t|e[r]&t&&(e[r]|=t),n&=~o}}var W=0;function Fc(e){return e&=-e,1<e?4<e?e&268435455?16:536870912:4:1}var zc,Ps,$c,Ac,Bc,Nl=!1,ro=[],zt=null,$t=null,At=null,Nr=new Map,Lr=new Map,jt=[],uh="mousedown mouseup touchcancel touchend touchstart auxclick dblclick pointercancel pointerdown pointerup dragend dragstart drop compositionend compositionstart keydown keypress keyup input textInput copy cut paste click change contextmenu reset submit".split(" ");function Ou(e,t){switch(e){case"focusin":case"focusout":zt=null;break;case"dragenter":case"dragleave":$t=null;break;case"mouseover":case"mouseout":At=null;break;case"pointerover":case"pointerout":Nr.delete(t.pointerId);break;case"gotpointercapture":case"lostpointercapture":Lr.delete(t.pointerId)}}function nr(e,t,n,r,o,i){return e===null||e.nativeEvent!==i?(e={blockedOn:t,domEventName:n,eventSystemFlags:r,nativeEvent:i,targetContainers:[o]},t!==null&&(t=Gr(t),t!==null&&Ps(t)),e):(e.eventSystemFlags|=r,t=e.targetContainers,o!==null&&t.indexOf(o)===-1&&t.push(o),e)}function ah(e,t,n,r,o){switch(t){case"focusin":return zt=nr(zt,e,t,n,r,o),!0;case"dragenter":return $t=nr($t,e,t,n,r,o),!0;case"mouseover":return At=nr(At,e,t,n,r,o),!0;case"pointerover":var i=o.pointerId;return Nr.set(i,nr(Nr.get(i)||null,e,t,n,r,o)),!0;case"gotpointercapture":return i=o.pointerId,Lr.set(i,nr(Lr.get(i)||null,e,t,n,r,o)),!0}return!1}function Uc(e){var t=rn(e.target);if(t!==null){var n=mn(t);if(n!==null){if(t=n.tag,t===13){if(t=Nc(n),t!==null){e.blockedOn=t,Bc(e.priority,function(){$c(n)});return}}else if(t===3&&n.stateNode.current.memoizedState.isDehydrated){e.blockedOn=n.tag===3?n.stateNode.containerInfo:null;return}}}e.blockedOn=null}function Eo(e){if(e.blockedOn!==null)return!1;for(var t=e.targetContainers;0<t.length;){var n=Ll(e.domEventName,e.eventSystemFlags,t[0],e.nativeEvent);if(n===null){n=e.nativeEvent;var r=new n.constructor(n.type,n);Cl=r,n.target.dispatchEvent(r),Cl=null}else return t=Gr(n),t!==null&&Ps(t),e.blockedOn=n,!1;t.shift()}return!0}function Mu(e,t,n){Eo(e)&&n.delete(t)}function ch(){Nl=!1,zt!==null&&Eo(zt)&&(zt=null),$t!==null&&Eo($t)&&($t=null),At!==null&&Eo(At)&&(At=null),Nr.forEach(Mu),Lr.forEach(Mu)}function rr(e,t){e.blockedOn===t&&(e.blockedOn=null,Nl||(Nl=!0,We.unstable_scheduleCallback(We.unstable_NormalPriority,ch)))}function Or(e){function t(o){return rr(o,e)}if(0<ro.length){rr(ro[0],e);for(var n=1;n<ro.length;n++){var r=ro[n];r.blockedOn===e&&(r.blockedOn=null)}}for(zt!==null&&rr(zt,e),$t!==null&&rr($t,e),At!==null&&rr(At,e),Nr.forEach(t),Lr.forEach(t),n=0;n<jt.length;n++)r=jt[n],r.blockedOn===e&&(r.blockedOn=null);for(;0<jt.length&&(n=jt[0],n.blockedOn===null);)Uc(n),n.blockedOn===null&&jt.shift()}var zn=Pt.ReactCurrentBatchConfig,$o=!0;function fh(e,t,n,r){var o=W,i=zn.transition;zn.transition=null;try{W=1,Rs(e,t,n,r)}finally{W=o,zn.transition=i}}function dh(e,t,n,r){var o=W,i=zn.transition;zn.transition=null;try{W=4,Rs(e,t,n,r)}finally{W=o,zn.transition=i}}function Rs(e,t,n,r){if($o){var o=Ll(e,t,n,r);if(o===null)Vi(e,t,r,Ao,n),Ou(e,r);else if(ah(o,e,t,n,r))r.stopPropagation();else if(Ou(e,r),t&4&&-1<uh.indexOf(e)){for(;o!==null;){var i=Gr(o);if(i!==null&&zc(i),i=Ll(e,t,n,r),i===null&&Vi(e,t,r,Ao,n),i===o)break;o=i}o!==null&&r.stopPropagation()}else Vi(e,t,r,null,n)}}var Ao=null;function Ll(e,t,n,r){if(Ao=null,e=ks(r),e=rn(e),e!==null)if(t=mn(e),t===null)e=null;else if(n=t.tag,n===13){if(e=Nc(t),e!==null)return e;e=null}else if(n===3){if(t.stateNode.current.memoizedState.isDehydrated)return t.tag===3?t.stateNode.containerInfo:null;e=null}else t!==e&&(e=null);return Ao=e,null}function Wc(e){switch(e){case"cancel":case"click":case"close":case"contextmenu":case"copy":case"cut":case"auxclick":case"dblclick":case"dragend":case"dragstart":case"drop":case"focusin":case"focusout":case"input":case"invalid":case"keydown":case"keypress":case"keyup":case"mousedown":case"mouseup":case"paste":case"pause":case"play":case"pointercancel":case"pointerdown":case"pointerup":case"ratechange":case"reset":case"resize":case"seeked":case"submit":case"touchcancel":case"touchend":case"touchstart":case"volumechange":case"change":case"selectionchange":case"textInput":case"compositionstart":case"compositionend":case"compositionupdate":case"beforeblur":case"afterblur":case"beforeinput":case"blur":case"fullscreenchange":case"focus":case"hashchange":case"popstate":case"select":case"selectstart":return 1;case"drag":case"dragenter":case"dragexit":case"dragleave":case"dragover":case"mousemove":case"mouseout":case"mouseover":case"pointermove":case"pointerout":case"pointerover":case"scroll":case"toggle":case"touchmove":case"wheel":case"mouseenter":case"mouseleave":case"pointerenter":case"pointerleave":return 4;case"message":switch(bp()){case Cs:return 1;case jc:return 4;case Fo:case eh:return 16;case Ic:return 536870912;default:return 16}default:return 16}}var Dt=null,Ts=null,xo=null;function Hc(){if(xo)return xo;var e,t=Ts,n=t.length,r,o="value"in Dt?Dt.value:Dt.textContent,i=o.length;for(e=0;e<n&&t[e]===o[e];e++);var l=n-e;for(r=1;r<=l&&t[n-r]===o[i-r];r++);return xo=o.slice(e,1<r?1-r:void 0)}function ko(e){var t=e.keyCode;return"charCode"in e?(e=e.charCode,e===0&&t===13&&(e=13)):e=t,e===10&&(e=13),32<=e||e===13?e:0}function oo(){return!0}function ju(){return!1}function Ve(e){function t(n,r,o,i,l){this._reactName=n,this._targetInst=o,this.type=r,this.nativeEvent=i,this.target=l,this.currentTarget=null;for(var s in e)e.hasOwnProperty(s)&&(n=e[s],this[s]=n?n(i):i[s]);return this.isDefaultPrevented=(i.defaultPrevented!=null?i.defaultPrevented:i.returnValue===!1)?oo:ju,this.isPropagationStopped=ju,this}return ee(t.prototype,{preventDefault:function(){this.defaultPrevented=!0;var n=this.nativeEvent;n&&(n.preventDefault?n.preventDefault():typeof n.returnValue!="unknown"&&(n.returnValue=!1),this.isDefaultPrevented=oo)},stopPropagation:function(){var n=this.nativeEvent;n&&(n.stopPropagation?n.stopPropagation():typeof n.cancelBubble!="unknown"&&(n.cancelBubble=!0),this.isPropagationStopped=oo)},persist:function(){},isPersistent:oo}),t}var Jn={eventPhase:0,bubbles:0,cancelable:0,timeStamp:function(e){return e.timeStamp||Date.now()},defaultPrevented:0,isTrusted:0},Ns=Ve(Jn),Qr=ee({},Jn,{view:0,detail:0}),ph=Ve(Qr),Di,Fi,or,ai=ee({},Qr,{screenX:0,screenY:0,clientX:0,clientY:0,pageX:0,pageY:0,ctrlKey:0,shiftKey:0,altKey:0,metaKey:0,getModifierState:Ls,button:0,buttons:0,relatedTarget:function(e){return e.relatedTarget===void 0?e.fromElement===e.srcElement?e.toElement:e.fromElement:e.relatedTarget},movementX:function(e){return"movementX"in e?e.movementX:(e!==or&&(or&&e.type==="mousemove"?(Di=e.screenX-or.screenX,Fi=e.screenY-or.screenY):Fi=Di=0,or=e),Di)},movementY:function(e){return"movementY"in e?e.movementY:Fi}}),Iu=Ve(ai),hh=ee({},ai,{dataTransfer:0}),mh=Ve(hh),vh=ee({},Qr,{relatedTarget:0}),zi=Ve(vh),yh=ee({},Jn,{animationName:0,elapsedTime:0,pseudoElement:0}),gh=Ve(yh),wh=ee({},Jn,{clipboardData:function(e){return"clipboardData"in e?e.clipboardData:window.clipboardData}}),Sh=Ve(wh),Eh=ee({},Jn,{data:0}),Du=Ve(Eh),xh={Esc:"Escape",Spacebar:" ",Left:"ArrowLeft",Up:"ArrowUp",Right:"ArrowRight",Down:"ArrowDown",Del:"Delete",Win:"OS",Menu:"ContextMenu",Apps:"ContextMenu",Scroll:"ScrollLock",MozPrintableKey:"Unidentified"},kh={8:"Backspace",9:"Tab",12:"Clear",13:"Enter",16:"Shift",17:"Control",18:"Alt",19:"Pause",20:"CapsLock",27:"Escape",32:" ",33:"PageUp",34:"PageDown",35:"End",36:"Home",37:"ArrowLeft",38:"ArrowUp",39:"ArrowRight",40:"ArrowDown",45:"Insert",46:"Delete",112:"F1",113:"F2",114:"F3",115:"F4",116:"F5",117:"F6",118:"F7",119:"F8",120:"F9",121:"F10",122:"F11",123:"F12",144:"NumLock",145:"ScrollLock",224:"Meta"},Ch={Alt:"altKey",Control:"ctrlKey",Meta:"metaKey",Shift:"shiftKey"};function _h(e){var t=this.nativeEvent;return t.getModifierState?t.getModifierState(e):(e=Ch[e])?!!t[e]:!1}function Ls(){return _h}var Ph=ee({},Qr,{key:function(e){if(e.key){var t=xh[e.key]||e.key;if(t!=="Unidentified")return t}return e.type==="keypress"?(e=ko(e),e===13?"Enter":String.fromCharCode(e)):e.type==="keydown"||e.type==="keyup"?kh[e.keyCode]||"Unidentified":""},code:0,location:0,ctrlKey:0,shiftKey:0,altKey:0,metaKey:0,repeat:0,locale:0,getModifierState:Ls,charCode:function(e){return e.type==="keypress"?ko(e):0},keyCode:function(e){return e.type==="keydown"||e.type==="keyup"?e.keyCode:0},which:function(e){return e.type==="keypress"?ko(e):e.type==="keydown"||e.type==="keyup"?e.keyCode:0}}),Rh=Ve(Ph),Th=ee({},ai,{pointerId:0,width:0,height:0,pressure:0,tangentialPressure:0,tiltX:0,tiltY:0,twist:0,pointerType:0,isPrimary:0}),Fu=Ve(Th),Nh=ee({},Qr,{touches:0,targetTouches:0,changedTouches:0,altKey:0,metaKey:0,ctrlKey:0,shiftKey:0,getModifierState:Ls}),Lh=Ve(Nh),Oh=ee({},Jn,{propertyName:0,elapsedTime:0,pseudoElement:0}),Mh=Ve(Oh),jh=ee({},ai,{deltaX:function(e){return"deltaX"in e?e.deltaX:"wheelDeltaX"in e?-e.wheelDeltaX:0},deltaY:function(e){return"deltaY"in e?e.deltaY:"wheelDeltaY"in e?-e.wheelDeltaY:"wheelDelta"in e?-e.wheelDelta:0},deltaZ:0,deltaMode:0}),Ih=Ve(jh),Dh=[9,13,27,32],Os=Et&&"CompositionEvent"in window,vr=null;Et&&"documentMode"in document&&(vr=document.documentMode);var Fh=Et&&"TextEvent"in window&&!vr,Vc=Et&&(!Os||vr&&8<vr&&11>=vr),zu=" ",$u=!1;function Kc(e,t){switch(e){case"keyup":return Dh.indexOf(t.keyCode)!==-1;case"keydown":return t.keyCode!==229;case"keypress":case"mousedown":case"focusout":return!0;default:return!1}}function Qc(e){return e=e.detail,typeof e=="object"&&"data"in e?e.data:null}var xn=!1;function zh(e,t){switch(e){case"compositionend":return Qc(t);case"keypress":return t.which!==32?null:($u=!0,zu);case"textInput":return e=t.data,e===zu&&$u?null:e;default:return null}}function $h(e,t){if(xn)return e==="compositionend"||!Os&&Kc(e,t)?(e=Hc(),xo=Ts=Dt=null,xn=!1,e):null;switch(e){case"paste":return null;case"keypress":if(!(t.ctrlKey||t.altKey||t.metaKey)||t.ctrlKey&&t.altKey){if(t.char&&1<t.char.length)return t.char;if(t.which)return String.fromCharCode(t.which)}return null;case"compositionend":return Vc&&t.locale!=="ko"?null:t.data;default:return null}}var Ah={color:!0,date:!0,datetime:!0,"datetime-local":!0,email:!0,month:!0,number:!0,password:!0,range:!0,search:!0,tel:!0,text:!0,time:!0,url:!0,week:!0};function Au(e){var t=e&&e.nodeName&&e.nodeName.toLowerCase();return t==="input"?!!Ah[e.type]:t==="textarea"}function Gc(e,t,n,r){Cc(r),t=Bo(t,"onChange"),0<t.length&&(n=new Ns("onChange","change",null,n,r),e.push({event:n,listeners:t}))}var yr=null,Mr=null;function Bh(e){of(e,0)}function ci(e){var t=_n(e);if(yc(t))return e}function Uh(e,t){if(e==="change")return t}var Yc=!1;if(Et){var $i;if(Et){var Ai="oninput"in document;if(!Ai){var Bu=document.createElement("div");Bu.setAttribute("oninput","return;"),Ai=typeof Bu.oninput=="function"}$i=Ai}else $i=!1;Yc=$i&&(!document.documentMode||9<document.documentMode)}function Uu(){yr&&(yr.detachEvent("onpropertychange",Xc),Mr=yr=null)}function Xc(e){if(e.propertyName==="value"&&ci(Mr)){var t=[];Gc(t,Mr,e,ks(e)),Tc(Bh,t)}}function Wh(e,t,n){e==="focusin"?(Uu(),yr=t,Mr=n,yr.attachEvent("onpropertychange",Xc)):e==="focusout"&&Uu()}function Hh(e){if(e==="selectionchange"||e==="keyup"||e==="keydown")return ci(Mr)}function Vh(e,t){if(e==="click")return ci(t)}function Kh(e,t){if(e==="input"||e==="change")return ci(t)}function Qh(e,t){return e===t&&(e!==0||1/e===1/t)||e!==e&&t!==t}var it=typeof Object.is=="function"?Object.is:Qh;function jr(e,t){if(it(e,t))return!0;if(typeof e!="object"||e===null||typeof t!="object"||t===null)return!1;var n=Object.keys(e),r=Object.keys(t);if(n.length!==r.length)return!1;for(r=0;r<n.length;r++){var o=n[r];if(!dl.call(t,o)||!it(e[o],t[o]))return!1}return!0}function Wu(e){for(;e&&e.firstChild;)e=e.firstChild;return e}function Hu(e,t){var n=Wu(e);e=0;for(var r;n;){if(n.nodeType===3){if(r=e+n.textContent.length,e<=t&&r>=t)return{node:n,offset:t-e};e=r}e:{for(;n;){if(n.nextSibling){n=n.nextSibling;break e}n=n.parentNode}n=void 0}n=Wu(n)}}function Zc(e,t){return e&&t?e===t?!0:e&&e.nodeType===3?!1:t&&t.nodeType===3?Zc(e,t.parentNode):"contains"in e?e.contains(t):e.compareDocumentPosition?!!(e.compareDocumentPosition(t)&16):!1:!1}function Jc(){for(var e=window,t=jo();t instanceof e.HTMLIFrameElement;){try{var n=typeof t.contentWindow.location.href=="string"}catch{n=!1}if(n)e=t.contentWindow;else break;t=jo(e.document)}return t}function Ms(e){var t=e&&e.nodeName&&e.nodeName.toLowerCase();return t&&(t==="input"&&(e.type==="text"||e.type==="search"||e.type==="tel"||e.type==="url"||e.type==="password")||t==="textarea"||e.contentEditable==="true")}function Gh(e){var t=Jc(),n=e.focusedElem,r=e.selectionRange;if(t!==n&&n&&n.ownerDocument&&Zc(n.ownerDocument.documentElement,n)){if(r!==null&&Ms(n)){if(t=r.start,e=r.end,e===void 0&&(e=t),"selectionStart"in n)n.selectionStart=t,n.selectionEnd=Math.min(e,n.value.length);else if(e=(t=n.ownerDocument||document)&&t.defaultView||window,e.getSelection){e=e.getSelection();var o=n.textContent.length,i=Math.min(r.start,o);r=r.end===void 0?i:Math.min(r.end,o),!e.extend&&i>r&&(o=r,r=i,i=o),o=Hu(n,i);var l=Hu(n,r);o&&l&&(e.rangeCount!==1||e.anchorNode!==o.node||e.anchorOffset!==o.offset||e.focusNode!==l.node||e.focusOffset!==l.offset)&&(t=t.createRange(),t.setStart(o.node,o.offset),e.removeAllRanges(),i>r?(e.addRange(t),e.extend(l.node,l.offset)):(t.setEnd(l.node,l.offset),e.addRange(t)))}}for(t=[],e=n;e=e.parentNode;)e.nodeType===1&&t.push({element:e,left:e.scrollLeft,top:e.scrollTop});for(typeof n.focus=="function"&&n.focus(),n=0;n<t.length;n++)e=t[n],e.element.scrollLeft=e.left,e.element.scrollTop=e.top}}var Yh=Et&&"documentMode"in document&&11>=document.documentMode,kn=null,Ol=null,gr=null,Ml=!1;function Vu(e,t,n){var r=n.window===n?n.document:n.nodeType===9?n:n.ownerDocument;Ml||kn==null||kn!==jo(r)||(r=kn,"selectionStart"in r&&Ms(r)?r={start:r.selectionStart,end:r.selectionEnd}:(r=(r.ownerDocument&&r.ownerDocument.defaultView||window).getSelection(),r={anchorNode:r.anchorNode,anchorOffset:r.anchorOffset,focusNode:r.focusNode,focusOffset:r.focusOffset}),gr&&jr(gr,r)||(gr=r,r=Bo(Ol,"onSelect"),0<r.length&&(t=new Ns("onSelect","select",null,t,n),e.push({event:t,listeners:r}),t.target=kn)))}function io(e,t){var n={};return n[e.toLowerCase()]=t.toLowerCase(),n["Webkit"+e]="webkit"+t,n["Moz"+e]="moz"+t,n}var Cn={animationend:io("Animation","AnimationEnd"),animationiteration:io("Animation","AnimationIteration"),animationstart:io("Animation","AnimationStart"),transitionend:io("Transition","TransitionEnd")},Bi={},qc={};Et&&(qc=document.createElement("div").style,"AnimationEvent"in window||(delete Cn.animationend.animation,delete Cn.animationiteration.animation,delete Cn.animationstart.animation),"TransitionEvent"in window||delete Cn.transitionend.transition);function fi(e){if(Bi[e])return Bi[e];if(!Cn[e])return e;var t=Cn[e],n;for(n in t)if(t.hasOwnProperty(n)&&n in qc)return Bi[e]=t[n];return e}var bc=fi("animationend"),ef=fi("animationiteration"),tf=fi("animationstart"),nf=fi("transitionend"),rf=new Map,Ku="abort auxClick cancel canPlay canPlayThrough click close contextMenu copy cut drag dragEnd dragEnter dragExit dragLeave dragOver dragStart drop durationChange emptied encrypted ended error gotPointerCapture input invalid keyDown keyPress keyUp load loadedData loadedMetadata loadStart lostPointerCapture mouseDown mouseMove mouseOut mouseOver mouseUp paste pause play playing pointerCancel pointerDown pointerMove pointerOut pointerOver pointerUp progress rateChange reset resize seeked seeking stalled submit suspend timeUpdate touchCancel touchEnd touchStart volumeChange scroll toggle touchMove waiting wheel".split(" ");function Yt(e,t){rf.set(e,t),hn(t,[e])}for(var Ui=0;Ui<Ku.length;Ui++){var Wi=Ku[Ui],Xh=Wi.toLowerCase(),Zh=Wi[0].toUpperCase()+Wi.slice(1);Yt(Xh,"on"+Zh)}Yt(bc,"onAnimationEnd");Yt(ef,"onAnimationIteration");Yt(tf,"onAnimationStart");Yt("dblclick","onDoubleClick");Yt("focusin","onFocus");Yt("focusout","onBlur");Yt(nf,"onTransitionEnd");Un("onMouseEnter",["mouseout","mouseover"]);Un("onMouseLeave",["mouseout","mouseover"]);Un("onPointerEnter",["pointerout","pointerover"]);Un("onPointerLeave",["pointerout","pointerover"]);hn("onChange","change click focusin focusout input keydown keyup selectionchange".split(" "));hn("onSelect","focusout contextmenu dragend focusin keydown keyup mousedown mouseup selectionchange".split(" "));hn("onBeforeInput",["compositionend","keypress","textInput","paste"]);hn("onCompositionEnd","compositionend focusout keydown keypress keyup mousedown".split(" "));hn("onCompositionStart","compositionstart focusout keydown keypress keyup mousedown".split(" "));hn("onCompositionUpdate","compositionupdate focusout keydown keypress keyup mousedown".split(" "));var dr="abort canplay canplaythrough durationchange emptied encrypted ended error loadeddata loadedmetadata loadstart pause play playing progress ratechange resize seeked seeking stalled suspend timeupdate volumechange waiting".split(" "),Jh=new Set("cancel close invalid load scroll toggle".split(" ").concat(dr));function Qu(e,t,n){var r=e.type||"unknown-event";e.currentTarget=n,Xp(r,t,void 0,e),e.currentTarget=null}function of(e,t){t=(t&4)!==0;for(var n=0;n<e.length;n++){var r=e[n],o=r.event;r=r.listeners;e:{var i=void 0;if(t)for(var l=r.length-1;0<=l;l--){var s=r[l],u=s.instance,a=s.currentTarget;if(s=s.listener,u!==i&&o.isPropagationStopped())break e;Qu(o,s,a),i=u}else for(l=0;l<r.length;l++){if(s=r[l],u=s.instance,a=s.currentTarget,s=s.listener,u!==i&&o.isPropagationStopped())break e;Qu(o,s,a),i=u}}}if(Do)throw e=Rl,Do=!1,Rl=null,e}function G(e,t){var n=t[zl];n===void 0&&(n=t[zl]=new Set);var r=e+"__bubble";n.has(r)||(lf(t,e,2,!1),n.add(r))}function Hi(e,t,n){var r=0;t&&(r|=4),lf(n,e,r,t)}var lo="_reactListening"+Math.random().toString(36).slice(2);function Ir(e){if(!e[lo]){e[lo]=!0,dc.forEach(function(n){n!=="selectionchange"&&(Jh.has(n)||Hi(n,!1,e),Hi(n,!0,e))});var t=e.nodeType===9?e:e.ownerDocument;t===null||t[lo]||(t[lo]=!0,Hi("selectionchange",!1,t))}}function lf(e,t,n,r){switch(Wc(t)){case 1:var o=fh;break;case 4:o=dh;break;default:o=Rs}n=o.bind(null,t,n,e),o=void 0,!Pl||t!=="touchstart"&&t!=="touchmove"&&t!=="wheel"||(o=!0),r?o!==void 0?e.addEventListener(t,n,{capture:!0,passive:o}):e.addEventListener(t,n,!0):o!==void 0?e.addEventListener(t,n,{passive:o}):e.addEventListener(t,n,!1)}function Vi(e,t,n,r,o){var i=r;if(!(t&1)&&!(t&2)&&r!==null)e:for(;;){if(r===null)return;var l=r.tag;if(l===3||l===4){var s=r.stateNode.containerInfo;if(s===o||s.nodeType===8&&s.parentNode===o)break;if(l===4)for(l=r.return;l!==null;){var u=l.tag;if((u===3||u===4)&&(u=l.stateNode.containerInfo,u===o||u.nodeType===8&&u.parentNode===o))return;l=l.return}for(;s!==null;){if(l=rn(s),l===null)return;if(u=l.tag,u===5||u===6){r=i=l;continue e}s=s.parentNode}}r=r.return}Tc(function(){var a=i,c=ks(n),h=[];e:{var d=rf.get(e);if(d!==void 0){var v=Ns,y=e;switch(e){case"keypress":if(ko(n)===0)break e;case"keydown":case"keyup":v=Rh;break;case"focusin":y="focus",v=zi;break;case"focusout":y="blur",v=zi;break;case"beforeblur":case"afterblur":v=zi;break;case"click":if(n.button===2)break e;case"auxclick":case"dblclick":case"mousedown":case"mousemove":case"mouseup":case"mouseout":case"mouseover":case"contextmenu":v=Iu;break;case"drag":case"dragend":case"dragenter":case"dragexit":case"dragleave":case"dragover":case"dragstart":case"drop":v=mh;break;case"touchcancel":case"touchend":case"touchmove":case"touchstart":v=Lh;break;case bc:case ef:case tf:v=gh;break;case nf:v=Mh;break;case"scroll":v=ph;break;case"wheel":v=Ih;break;case"copy":case"cut":case"paste":v=Sh;break;case"gotpointercapture":case"lostpointercapture":case"pointercancel":case"pointerdown":case"pointermove":case"pointerout":case"pointerover":case"pointerup":v=Fu}var g=(t&4)!==0,x=!g&&e==="scroll",m=g?d!==null?d+"Capture":null:d;g=[];for(var f=a,p;f!==null;){p=f;var S=p.stateNode;if(p.tag===5&&S!==null&&(p=S,m!==null&&(S=Tr(f,m),S!=null&&g.push(Dr(f,S,p)))),x)break;f=f.return}0<g.length&&(d=new v(d,y,null,n,c),h.push({event:d,listeners:g}))}}if(!(t&7)){e:{if(d=e==="mouseover"||e==="pointerover",v=e==="mouseout"||e==="pointerout",d&&n!==Cl&&(y=n.relatedTarget||n.fromElement)&&(rn(y)||y[xt]))break e;if((v||d)&&(d=c.window===c?c:(d=c.ownerDocument)?d.defaultView||d.parentWindow:window,v?(y=n.relatedTarget||n.toElement,v=a,y=y?rn(y):null,y!==null&&(x=mn(y),y!==x||y.tag!==5&&y.tag!==6)&&(y=null)):(v=null,y=a),v!==y)){if(g=Iu,S="onMouseLeave",m="onMouseEnter",f="mouse",(e==="pointerout"||e==="pointerover")&&(g=Fu,S="onPointerLeave",m="onPointerEnter",f="pointer"),x=v==null?d:_n(v),p=y==null?d:_n(y),d=new g(S,f+"leave",v,n,c),d.target=x,d.relatedTarget=p,S=null,rn(c)===a&&(g=new g(m,f+"enter",y,n,c),g.target=p,g.relatedTarget=x,S=g),x=S,v&&y)t:{for(g=v,m=y,f=0,p=g;p;p=yn(p))f++;for(p=0,S=m;S;S=yn(S))p++;for(;0<f-p;)g=yn(g),f--;for(;0<p-f;)m=yn(m),p--;for(;f--;){if(g===m||m!==null&&g===m.alternate)break t;g=yn(g),m=yn(m)}g=null}else g=null;v!==null&&Gu(h,d,v,g,!1),y!==null&&x!==null&&Gu(h,x,y,g,!0)}}e:{if(d=a?_n(a):window,v=d.nodeName&&d.nodeName.toLowerCase(),v==="select"||v==="input"&&d.type==="file")var k=Uh;else if(Au(d))if(Yc)k=Kh;else{k=Hh;var E=Wh}else(v=d.nodeName)&&v.toLowerCase()==="input"&&(d.type==="checkbox"||d.type==="radio")&&(k=Vh);if(k&&(k=k(e,a))){Gc(h,k,n,c);break e}E&&E(e,d,a),e==="focusout"&&(E=d._wrapperState)&&E.controlled&&d.type==="number"&&wl(d,"number",d.value)}switch(E=a?_n(a):window,e){case"focusin":(Au(E)||E.contentEditable==="true")&&(kn=E,Ol=a,gr=null);break;case"focusout":gr=Ol=kn=null;break;case"mousedown":Ml=!0;break;case"contextmenu":case"mouseup":case"dragend":Ml=!1,Vu(h,n,c);break;case"selectionchange":if(Yh)break;case"keydown":case"keyup":Vu(h,n,c)}var P;if(Os)e:{switch(e){case"compositionstart":var T="onCompositionStart";break e;case"compositionend":T="onCompositionEnd";break e;case"compositionupdate":T="onCompositionUpdate";break e}T=void 0}else xn?Kc(e,n)&&(T="onCompositionEnd"):e==="keydown"&&n.keyCode===229&&(T="onCompositionStart");T&&(Vc&&n.locale!=="ko"&&(xn||T!=="onCompositionStart"?T==="onCompositionEnd"&&xn&&(P=Hc()):(Dt=c,Ts="value"in Dt?Dt.value:Dt.textContent,xn=!0)),E=Bo(a,T),0<E.length&&(T=new Du(T,e,null,n,c),h.push({event:T,listeners:E}),P?T.data=P:(P=Qc(n),P!==null&&(T.data=P)))),(P=Fh?zh(e,n):$h(e,n))&&(a=Bo(a,"onBeforeInput"),0<a.length&&(c=new Du("onBeforeInput","beforeinput",null,n,c),h.push({event:c,listeners:a}),c.data=P))}of(h,t)})}function Dr(e,t,n){return{instance:e,listener:t,currentTarget:n}}function Bo(e,t){for(var n=t+"Capture",r=[];e!==null;){var o=e,i=o.stateNode;o.tag===5&&i!==null&&(o=i,i=Tr(e,n),i!=null&&r.unshift(Dr(e,i,o)),i=Tr(e,t),i!=null&&r.push(Dr(e,i,o))),e=e.return}return r}function yn(e){if(e===null)return null;do e=e.return;while(e&&e.tag!==5);return e||null}function Gu(e,t,n,r,o){for(var i=t._reactName,l=[];n!==null&&n!==r;){var s=n,u=s.alternate,a=s.stateNode;if(u!==null&&u===r)break;s.tag===5&&a!==null&&(s=a,o?(u=Tr(n,i),u!=null&&l.unshift(Dr(n,u,s))):o||(u=Tr(n,i),u!=null&&l.push(Dr(n,u,s)))),n=n.return}l.length!==0&&e.push({event:t,listeners:l})}var qh=/\r\n?/g,bh=/\u0000|\uFFFD/g;function Yu(e){return(typeof e=="string"?e:""+e).replace(qh,`
-`).replace(bh,"")}function so(e,t,n){if(t=Yu(t),Yu(e)!==t&&n)throw Error(R(425))}function Uo(){}var jl=null,Il=null;function Dl(e,t){return e==="textarea"||e==="noscript"||typeof t.children=="string"||typeof t.children=="number"||typeof t.dangerouslySetInnerHTML=="object"&&t.dangerouslySetInnerHTML!==null&&t.dangerouslySetInnerHTML.__html!=null}var Fl=typeof setTimeout=="function"?setTimeout:void 0,em=typeof clearTimeout=="function"?clearTimeout:void 0,Xu=typeof Promise=="function"?Promise:void 0,tm=typeof queueMicrotask=="function"?queueMicrotask:typeof Xu<"u"?function(e){return Xu.resolve(null).then(e).catch(nm)}:Fl;function nm(e){setTimeout(function(){throw e})}function Ki(e,t){var n=t,r=0;do{var o=n.nextSibling;if(e.removeChild(n),o&&o.nodeType===8)if(n=o.data,n==="/$"){if(r===0){e.removeChild(o),Or(t);return}r--}else n!=="$"&&n!=="$?"&&n!=="$!"||r++;n=o}while(n);Or(t)}function Bt(e){for(;e!=null;e=e.nextSibling){var t=e.nodeType;if(t===1||t===3)break;if(t===8){if(t=e.data,t==="$"||t==="$!"||t==="$?")break;if(t==="/$")return null}}return e}function Zu(e){e=e.previousSibling;for(var t=0;e;){if(e.nodeType===8){var n=e.data;if(n==="$"||n==="$!"||n==="$?"){if(t===0)return e;t--}else n==="/$"&&t++}e=e.previousSibling}return null}var qn=Math.random().toString(36).slice(2),at="__reactFiber$"+qn,Fr="__reactProps$"+qn,xt="__reactContainer$"+qn,zl="__reactEvents$"+qn,rm="__reactListeners$"+qn,om="__reactHandles$"+qn;function rn(e){var t=e[at];if(t)return t;for(var n=e.parentNode;n;){if(t=n[xt]||n[at]){if(n=t.alternate,t.child!==null||n!==null&&n.child!==null)for(e=Zu(e);e!==null;){if(n=e[at])return n;e=Zu(e)}return t}e=n,n=e.parentNode}return null}function Gr(e){return e=e[at]||e[xt],!e||e.tag!==5&&e.tag!==6&&e.tag!==13&&e.tag!==3?null:e}function _n(e){if(e.tag===5||e.tag===6)return e.stateNode;throw Error(R(33))}function di(e){return e[Fr]||null}var $l=[],Pn=-1;function Xt(e){return{current:e}}function Y(e){0>Pn||(e.current=$l[Pn],$l[Pn]=null,Pn--)}function K(e,t){Pn++,$l[Pn]=e.current,e.current=t}var Gt={},Ee=Xt(Gt),Me=Xt(!1),an=Gt;function Wn(e,t){var n=e.type.contextTypes;if(!n)return Gt;var r=e.stateNode;if(r&&r.__reactInternalMemoizedUnmaskedChildContext===t)return r.__reactInternalMemoizedMaskedChildContext;var o={},i;for(i in n)o[i]=t[i];return r&&(e=e.stateNode,e.__reactInternalMemoizedUnmaskedChildContext=t,e.__reactInternalMemoizedMaskedChildContext=o),o}function je(e){return e=e.childContextTypes,e!=null}function Wo(){Y(Me),Y(Ee)}function Ju(e,t,n){if(Ee.current!==Gt)throw Error(R(168));K(Ee,t),K(Me,n)}function sf(e,t,n){var r=e.stateNode;if(t=t.childContextTypes,typeof r.getChildContext!="function")return n;r=r.getChildContext();for(var o in r)if(!(o in t))throw Error(R(108,Wp(e)||"Unknown",o));return ee({},n,r)}function Ho(e){return e=(e=e.stateNode)&&e.__reactInternalMemoizedMergedChildContext||Gt,an=Ee.current,K(Ee,e),K(Me,Me.current),!0}function qu(e,t,n){var r=e.stateNode;if(!r)throw Error(R(169));n?(e=sf(e,t,an),r.__reactInternalMemoizedMergedChildContext=e,Y(Me),Y(Ee),K(Ee,e)):Y(Me),K(Me,n)}var mt=null,pi=!1,Qi=!1;function uf(e){mt===null?mt=[e]:mt.push(e)}function im(e){pi=!0,uf(e)}function Zt(){if(!Qi&&mt!==null){Qi=!0;var e=0,t=W;try{var n=mt;for(W=1;e<n.length;e++){var r=n[e];do r=r(!0);while(r!==null)}mt=null,pi=!1}catch(o){throw mt!==null&&(mt=mt.slice(e+1)),Mc(Cs,Zt),o}finally{W=t,Qi=!1}}return null}var Rn=[],Tn=0,Vo=null,Ko=0,Ke=[],Qe=0,cn=null,yt=1,gt="";function qt(e,t){Rn[Tn++]=Ko,Rn[Tn++]=Vo,Vo=e,Ko=t}function af(e,t,n){Ke[Qe++]=yt,Ke[Qe++]=gt,Ke[Qe++]=cn,cn=e;var r=yt;e=gt;var o=32-rt(r)-1;r&=~(1<<o),n+=1;var i=32-rt(t)+o;if(30<i){var l=o-o%5;i=(r&(1<<l)-1).toString(32),r>>=l,o-=l,yt=1<<32-rt(t)+o|n<<o|r,gt=i+e}else yt=1<<i|n<<o|r,gt=e}function js(e){e.return!==null&&(qt(e,1),af(e,1,0))}function Is(e){for(;e===Vo;)Vo=Rn[--Tn],Rn[Tn]=null,Ko=Rn[--Tn],Rn[Tn]=null;for(;e===cn;)cn=Ke[--Qe],Ke[Qe]=null,gt=Ke[--Qe],Ke[Qe]=null,yt=Ke[--Qe],Ke[Qe]=null}var Ue=null,Be=null,Z=!1,tt=null;function cf(e,t){var n=Ye(5,null,null,0);n.elementType="DELETED",n.stateNode=t,n.return=e,t=e.deletions,t===null?(e.deletions=[n],e.flags|=16):t.push(n)}function bu(e,t){switch(e.tag){case 5:var n=e.type;return t=t.nodeType!==1||n.toLowerCase()!==t.nodeName.toLowerCase()?null:t,t!==null?(e.stateNode=t,Ue=e,Be=Bt(t.firstChild),!0):!1;case 6:return t=e.pendingProps===""||t.nodeType!==3?null:t,t!==null?(e.stateNode=t,Ue=e,Be=null,!0):!1;case 13:return t=t.nodeType!==8?null:t,t!==null?(n=cn!==null?{id:yt,overflow:gt}:null,e.memoizedState={dehydrated:t,treeContext:n,retryLane:1073741824},n=Ye(18,null,null,0),n.stateNode=t,n.return=e,e.child=n,Ue=e,Be=null,!0):!1;default:return!1}}function Al(e){return(e.mode&1)!==0&&(e.flags&128)===0}function Bl(e){if(Z){var t=Be;if(t){var n=t;if(!bu(e,t)){if(Al(e))throw Error(R(418));t=Bt(n.nextSibling);var r=Ue;t&&bu(e,t)?cf(r,n):(e.flags=e.flags&-4097|2,Z=!1,Ue=e)}}else{if(Al(e))throw Error(R(418));e.flags=e.flags&-4097|2,Z=!1,Ue=e}}}function ea(e){for(e=e.return;e!==null&&e.tag!==5&&e.tag!==3&&e.tag!==13;)e=e.return;Ue=e}function uo(e){if(e!==Ue)return!1;if(!Z)return ea(e),Z=!0,!1;var t;if((t=e.tag!==3)&&!(t=e.tag!==5)&&(t=e.type,t=t!=="head"&&t!=="body"&&!Dl(e.type,e.memoizedProps)),t&&(t=Be)){if(Al(e))throw ff(),Error(R(418));for(;t;)cf(e,t),t=Bt(t.nextSibling)}if(ea(e),e.tag===13){if(e=e.memoizedState,e=e!==null?e.dehydrated:null,!e)throw Error(R(317));e:{for(e=e.nextSibling,t=0;e;){if(e.nodeType===8){var n=e.data;if(n==="/$"){if(t===0){Be=Bt(e.nextSibling);break e}t--}else n!=="$"&&n!=="$!"&&n!=="$?"||t++}e=e.nextSibling}Be=null}}else Be=Ue?Bt(e.stateNode.nextSibling):null;return!0}function ff(){for(var e=Be;e;)e=Bt(e.nextSibling)}function Hn(){Be=Ue=null,Z=!1}function Ds(e){tt===null?tt=[e]:tt.push(e)}var lm=Pt.ReactCurrentBatchConfig;function ir(e,t,n){if(e=n.ref,e!==null&&typeof e!="function"&&typeof e!="object"){if(n._owner){if(n=n._owner,n){if(n.tag!==1)throw Error(R(309));var r=n.stateNode}if(!r)throw Error(R(147,e));var o=r,i=""+e;return t!==null&&t.ref!==null&&typeof t.ref=="function"&&t.ref._stringRef===i?t.ref:(t=function(l){var s=o.refs;l===null?delete s[i]:s[i]=l},t._stringRef=i,t)}if(typeof e!="string")throw Error(R(284));if(!n._owner)throw Error(R(290,e))}return e}function ao(e,t){throw e=Object.prototype.toString.call(t),Error(R(31,e==="[object Object]"?"object with keys {"+Object.keys(t).join(", ")+"}":e))}function ta(e){var t=e._init;return t(e._payload)}function df(e){function t(m,f){if(e){var p=m.deletions;p===null?(m.deletions=[f],m.flags|=16):p.push(f)}}function n(m,f){if(!e)return null;for(;f!==null;)t(m,f),f=f.sibling;return null}function r(m,f){for(m=new Map;f!==null;)f.key!==null?m.set(f.key,f):m.set(f.index,f),f=f.sibling;return m}function o(m,f){return m=Vt(m,f),m.index=0,m.sibling=null,m}function i(m,f,p){return m.index=p,e?(p=m.alternate,p!==null?(p=p.index,p<f?(m.flags|=2,f):p):(m.flags|=2,f)):(m.flags|=1048576,f)}function l(m){return e&&m.alternate===null&&(m.flags|=2),m}function s(m,f,p,S){return f===null||f.tag!==6?(f=bi(p,m.mode,S),f.return=m,f):(f=o(f,p),f.return=m,f)}function u(m,f,p,S){var k=p.type;return k===En?c(m,f,p.props.children,S,p.key):f!==null&&(f.elementType===k||typeof k=="object"&&k!==null&&k.$$typeof===Lt&&ta(k)===f.type)?(S=o(f,p.props),S.ref=ir(m,f,p),S.return=m,S):(S=Lo(p.type,p.key,p.props,null,m.mode,S),S.ref=ir(m,f,p),S.return=m,S)}function a(m,f,p,S){return f===null||f.tag!==4||f.stateNode.containerInfo!==p.containerInfo||f.stateNode.implementation!==p.implementation?(f=el(p,m.mode,S),f.return=m,f):(f=o(f,p.children||[]),f.return=m,f)}function c(m,f,p,S,k){return f===null||f.tag!==7?(f=un(p,m.mode,S,k),f.return=m,f):(f=o(f,p),f.return=m,f)}function h(m,f,p){if(typeof f=="string"&&f!==""||typeof f=="number")return f=bi(""+f,m.mode,p),f.return=m,f;if(typeof f=="object"&&f!==null){switch(f.$$typeof){case qr:return p=Lo(f.type,f.key,f.props,null,m.mode,p),p.ref=ir(m,null,f),p.return=m,p;case Sn:return f=el(f,m.mode,p),f.return=m,f;case Lt:var S=f._init;return h(m,S(f._payload),p)}if(cr(f)||er(f))return f=un(f,m.mode,p,null),f.return=m,f;ao(m,f)}return null}function d(m,f,p,S){var k=f!==null?f.key:null;if(typeof p=="string"&&p!==""||typeof p=="number")return k!==null?null:s(m,f,""+p,S);if(typeof p=="object"&&p!==null){switch(p.$$typeof){case qr:return p.key===k?u(m,f,p,S):null;case Sn:return p.key===k?a(m,f,p,S):null;case Lt:return k=p._init,d(m,f,k(p._payload),S)}if(cr(p)||er(p))return k!==null?null:c(m,f,p,S,null);ao(m,p)}return null}function v(m,f,p,S,k){if(typeof S=="string"&&S!==""||typeof S=="number")return m=m.get(p)||null,s(f,m,""+S,k);if(typeof S=="object"&&S!==null){switch(S.$$typeof){case qr:return m=m.get(S.key===null?p:S.key)||null,u(f,m,S,k);case Sn:return m=m.get(S.key===null?p:S.key)||null,a(f,m,S,k);case Lt:var E=S._init;return v(m,f,p,E(S._payload),k)}if(cr(S)||er(S))return m=m.get(p)||null,c(f,m,S,k,null);ao(f,S)}return null}function y(m,f,p,S){for(var k=null,E=null,P=f,T=f=0,D=null;P!==null&&T<p.length;T++){P.index>T?(D=P,P=null):D=P.sibling;var M=d(m,P,p[T],S);if(M===null){P===null&&(P=D);break}e&&P&&M.alternate===null&&t(m,P),f=i(M,f,T),E===null?k=M:E.sibling=M,E=M,P=D}if(T===p.length)return n(m,P),Z&&qt(m,T),k;if(P===null){for(;T<p.length;T++)P=h(m,p[T],S),P!==null&&(f=i(P,f,T),E===null?k=P:E.sibling=P,E=P);return Z&&qt(m,T),k}for(P=r(m,P);T<p.length;T++)D=v(P,m,T,p[T],S),D!==null&&(e&&D.alternate!==null&&P.delete(D.key===null?T:D.key),f=i(D,f,T),E===null?k=D:E.sibling=D,E=D);return e&&P.forEach(function(B){return t(m,B)}),Z&&qt(m,T),k}function g(m,f,p,S){var k=er(p);if(typeof k!="function")throw Error(R(150));if(p=k.call(p),p==null)throw Error(R(151));for(var E=k=null,P=f,T=f=0,D=null,M=p.next();P!==null&&!M.done;T++,M=p.next()){P.index>T?(D=P,P=null):D=P.sibling;var B=d(m,P,M.value,S);if(B===null){P===null&&(P=D);break}e&&P&&B.alternate===null&&t(m,P),f=i(B,f,T),E===null?k=B:E.sibling=B,E=B,P=D}if(M.done)return n(m,P),Z&&qt(m,T),k;if(P===null){for(;!M.done;T++,M=p.next())M=h(m,M.value,S),M!==null&&(f=i(M,f,T),E===null?k=M:E.sibling=M,E=M);return Z&&qt(m,T),k}for(P=r(m,P);!M.done;T++,M=p.next())M=v(P,m,T,M.value,S),M!==null&&(e&&M.alternate!==null&&P.delete(M.key===null?T:M.key),f=i(M,f,T),E===null?k=M:E.sibling=M,E=M);return e&&P.forEach(function(I){return t(m,I)}),Z&&qt(m,T),k}function x(m,f,p,S){if(typeof p=="object"&&p!==null&&p.type===En&&p.key===null&&(p=p.props.children),typeof p=="object"&&p!==null){switch(p.$$typeof){case qr:e:{for(var k=p.key,E=f;E!==null;){if(E.key===k){if(k=p.type,k===En){if(E.tag===7){n(m,E.sibling),f=o(E,p.props.children),f.return=m,m=f;break e}}else if(E.elementType===k||typeof k=="object"&&k!==null&&k.$$typeof===Lt&&ta(k)===E.type){n(m,E.sibling),f=o(E,p.props),f.ref=ir(m,E,p),f.return=m,m=f;break e}n(m,E);break}else t(m,E);E=E.sibling}p.type===En?(f=un(p.props.children,m.mode,S,p.key),f.return=m,m=f):(S=Lo(p.type,p.key,p.props,null,m.mode,S),S.ref=ir(m,f,p),S.return=m,m=S)}return l(m);case Sn:e:{for(E=p.key;f!==null;){if(f.key===E)if(f.tag===4&&f.stateNode.containerInfo===p.containerInfo&&f.stateNode.implementation===p.implementation){n(m,f.sibling),f=o(f,p.children||[]),f.return=m,m=f;break e}else{n(m,f);break}else t(m,f);f=f.sibling}f=el(p,m.mode,S),f.return=m,m=f}return l(m);case Lt:return E=p._init,x(m,f,E(p._payload),S)}if(cr(p))return y(m,f,p,S);if(er(p))return g(m,f,p,S);ao(m,p)}return typeof p=="string"&&p!==""||typeof p=="number"?(p=""+p,f!==null&&f.tag===6?(n(m,f.sibling),f=o(f,p),f.return=m,m=f):(n(m,f),f=bi(p,m.mode,S),f.return=m,m=f),l(m)):n(m,f)}return x}var Vn=df(!0),pf=df(!1),Qo=Xt(null),Go=null,Nn=null,Fs=null;function zs(){Fs=Nn=Go=null}function $s(e){var t=Qo.current;Y(Qo),e._currentValue=t}function Ul(e,t,n){for(;e!==null;){var r=e.alternate;if((e.childLanes&t)!==t?(e.childLanes|=t,r!==null&&(r.childLanes|=t)):r!==null&&(r.childLanes&t)!==t&&(r.childLanes|=t),e===n)break;e=e.return}}function $n(e,t){Go=e,Fs=Nn=null,e=e.dependencies,e!==null&&e.firstContext!==null&&(e.lanes&t&&(Oe=!0),e.firstContext=null)}function Ze(e){var t=e._currentValue;if(Fs!==e)if(e={context:e,memoizedValue:t,next:null},Nn===null){if(Go===null)throw Error(R(308));Nn=e,Go.dependencies={lanes:0,firstContext:e}}else Nn=Nn.next=e;return t}var on=null;function As(e){on===null?on=[e]:on.push(e)}function hf(e,t,n,r){var o=t.interleaved;return o===null?(n.next=n,As(t)):(n.next=o.next,o.next=n),t.interleaved=n,kt(e,r)}function kt(e,t){e.lanes|=t;var n=e.alternate;for(n!==null&&(n.lanes|=t),n=e,e=e.return;e!==null;)e.childLanes|=t,n=e.alternate,n!==null&&(n.childLanes|=t),n=e,e=e.return;return n.tag===3?n.stateNode:null}var Ot=!1;function Bs(e){e.updateQueue={baseState:e.memoizedState,firstBaseUpdate:null,lastBaseUpdate:null,shared:{pending:null,interleaved:null,lanes:0},effects:null}}function mf(e,t){e=e.updateQueue,t.updateQueue===e&&(t.updateQueue={baseState:e.baseState,firstBaseUpdate:e.firstBaseUpdate,lastBaseUpdate:e.lastBaseUpdate,shared:e.shared,effects:e.effects})}function wt(e,t){return{eventTime:e,lane:t,tag:0,payload:null,callback:null,next:null}}function Ut(e,t,n){var r=e.updateQueue;if(r===null)return null;if(r=r.shared,A&2){var o=r.pending;return o===null?t.next=t:(t.next=o.next,o.next=t),r.pending=t,kt(e,n)}return o=r.interleaved,o===null?(t.next=t,As(r)):(t.next=o.next,o.next=t),r.interleaved=t,kt(e,n)}function Co(e,t,n){if(t=t.updateQueue,t!==null&&(t=t.shared,(n&4194240)!==0)){var r=t.lanes;r&=e.pendingLanes,n|=r,t.lanes=n,_s(e,n)}}function na(e,t){var n=e.updateQueue,r=e.alternate;if(r!==null&&(r=r.updateQueue,n===r)){var o=null,i=null;if(n=n.firstBaseUpdate,n!==null){do{var l={eventTime:n.eventTime,lane:n.lane,tag:n.tag,payload:n.payload,callback:n.callback,next:null};i===null?o=i=l:i=i.next=l,n=n.next}while(n!==null);i===null?o=i=t:i=i.next=t}else o=i=t;n={baseState:r.baseState,firstBaseUpdate:o,lastBaseUpdate:i,shared:r.shared,effects:r.effects},e.updateQueue=n;return}e=n.lastBaseUpdate,e===null?n.firstBaseUpdate=t:e.next=t,n.lastBaseUpdate=t}function Yo(e,t,n,r){var o=e.updateQueue;Ot=!1;var i=o.firstBaseUpdate,l=o.lastBaseUpdate,s=o.shared.pending;if(s!==null){o.shared.pending=null;var u=s,a=u.next;u.next=null,l===null?i=a:l.next=a,l=u;var c=e.alternate;c!==null&&(c=c.updateQueue,s=c.lastBaseUpdate,s!==l&&(s===null?c.firstBaseUpdate=a:s.next=a,c.lastBaseUpdate=u))}if(i!==null){var h=o.baseState;l=0,c=a=u=null,s=i;do{var d=s.lane,v=s.eventTime;if((r&d)===d){c!==null&&(c=c.next={eventTime:v,lane:0,tag:s.tag,payload:s.payload,callback:s.callback,next:null});e:{var y=e,g=s;switch(d=t,v=n,g.tag){case 1:if(y=g.payload,typeof y=="function"){h=y.call(v,h,d);break e}h=y;break e;case 3:y.flags=y.flags&-65537|128;case 0:if(y=g.payload,d=typeof y=="function"?y.call(v,h,d):y,d==null)break e;h=ee({},h,d);break e;case 2:Ot=!0}}s.callback!==null&&s.lane!==0&&(e.flags|=64,d=o.effects,d===null?o.effects=[s]:d.push(s))}else v={eventTime:v,lane:d,tag:s.tag,payload:s.payload,callback:s.callback,next:null},c===null?(a=c=v,u=h):c=c.next=v,l|=d;if(s=s.next,s===null){if(s=o.shared.pending,s===null)break;d=s,s=d.next,d.next=null,o.lastBaseUpdate=d,o.shared.pending=null}}while(!0);if(c===null&&(u=h),o.baseState=u,o.firstBaseUpdate=a,o.lastBaseUpdate=c,t=o.shared.interleaved,t!==null){o=t;do l|=o.lane,o=o.next;while(o!==t)}else i===null&&(o.shared.lanes=0);dn|=l,e.lanes=l,e.memoizedState=h}}function ra(e,t,n){if(e=t.effects,t.effects=null,e!==null)for(t=0;t<e.length;t++){var r=e[t],o=r.callback;if(o!==null){if(r.callback=null,r=n,typeof o!="function")throw Error(R(191,o));o.call(r)}}}var Yr={},dt=Xt(Yr),zr=Xt(Yr),$r=Xt(Yr);function ln(e){if(e===Yr)throw Error(R(174));return e}function Us(e,t){switch(K($r,t),K(zr,e),K(dt,Yr),e=t.nodeType,e){case 9:case 11:t=(t=t.documentElement)?t.namespaceURI:El(null,"");break;default:e=e===8?t.parentNode:t,t=e.namespaceURI||null,e=e.tagName,t=El(t,e)}Y(dt),K(dt,t)}function Kn(){Y(dt),Y(zr),Y($r)}function vf(e){ln($r.current);var t=ln(dt.current),n=El(t,e.type);t!==n&&(K(zr,e),K(dt,n))}function Ws(e){zr.current===e&&(Y(dt),Y(zr))}var q=Xt(0);function Xo(e){for(var t=e;t!==null;){if(t.tag===13){var n=t.memoizedState;if(n!==null&&(n=n.dehydrated,n===null||n.data==="$?"||n.data==="$!"))return t}else if(t.tag===19&&t.memoizedProps.revealOrder!==void 0){if(t.flags&128)return t}else if(t.child!==null){t.child.return=t,t=t.child;continue}if(t===e)break;for(;t.sibling===null;){if(t.return===null||t.return===e)return null;t=t.return}t.sibling.return=t.return,t=t.sibling}return null}var Gi=[];function Hs(){for(var e=0;e<Gi.length;e++)Gi[e]._workInProgressVersionPrimary=null;Gi.length=0}var _o=Pt.ReactCurrentDispatcher,Yi=Pt.ReactCurrentBatchConfig,fn=0,b=null,ae=null,fe=null,Zo=!1,wr=!1,Ar=0,sm=0;function ge(){throw Error(R(321))}function Vs(e,t){if(t===null)return!1;for(var n=0;n<t.length&&n<e.length;n++)if(!it(e[n],t[n]))return!1;return!0}function Ks(e,t,n,r,o,i){if(fn=i,b=t,t.memoizedState=null,t.updateQueue=null,t.lanes=0,_o.current=e===null||e.memoizedState===null?fm:dm,e=n(r,o),wr){i=0;do{if(wr=!1,Ar=0,25<=i)throw Error(R(301));i+=1,fe=ae=null,t.updateQueue=null,_o.current=pm,e=n(r,o)}while(wr)}if(_o.current=Jo,t=ae!==null&&ae.next!==null,fn=0,fe=ae=b=null,Zo=!1,t)throw Error(R(300));return e}function Qs(){var e=Ar!==0;return Ar=0,e}function st(){var e={memoizedState:null,baseState:null,baseQueue:null,queue:null,next:null};return fe===null?b.memoizedState=fe=e:fe=fe.next=e,fe}function Je(){if(ae===null){var e=b.alternate;e=e!==null?e.memoizedState:null}else e=ae.next;var t=fe===null?b.memoizedState:fe.next;if(t!==null)fe=t,ae=e;else{if(e===null)throw Error(R(310));ae=e,e={memoizedState:ae.memoizedState,baseState:ae.baseState,baseQueue:ae.baseQueue,queue:ae.queue,next:null},fe===null?b.memoizedState=fe=e:fe=fe.next=e}return fe}function Br(e,t){return typeof t=="function"?t(e):t}function Xi(e){var t=Je(),n=t.queue;if(n===null)throw Error(R(311));n.lastRenderedReducer=e;var r=ae,o=r.baseQueue,i=n.pending;if(i!==null){if(o!==null){var l=o.next;o.next=i.next,i.next=l}r.baseQueue=o=i,n.pending=null}if(o!==null){i=o.next,r=r.baseState;var s=l=null,u=null,a=i;do{var c=a.lane;if((fn&c)===c)u!==null&&(u=u.next={lane:0,action:a.action,hasEagerState:a.hasEagerState,eagerState:a.eagerState,next:null}),r=a.hasEagerState?a.eagerState:e(r,a.action);else{var h={lane:c,action:a.action,hasEagerState:a.hasEagerState,eagerState:a.eagerState,next:null};u===null?(s=u=h,l=r):u=u.next=h,b.lanes|=c,dn|=c}a=a.next}while(a!==null&&a!==i);u===null?l=r:u.next=s,it(r,t.memoizedState)||(Oe=!0),t.memoizedState=r,t.baseState=l,t.baseQueue=u,n.lastRenderedState=r}if(e=n.interleaved,e!==null){o=e;do i=o.lane,b.lanes|=i,dn|=i,o=o.next;while(o!==e)}else o===null&&(n.lanes=0);return[t.memoizedState,n.dispatch]}function Zi(e){var t=Je(),n=t.queue;if(n===null)throw Error(R(311));n.lastRenderedReducer=e;var r=n.dispatch,o=n.pending,i=t.memoizedState;if(o!==null){n.pending=null;var l=o=o.next;do i=e(i,l.action),l=l.next;while(l!==o);it(i,t.memoizedState)||(Oe=!0),t.memoizedState=i,t.baseQueue===null&&(t.baseState=i),n.lastRenderedState=i}return[i,r]}function yf(){}function gf(e,t){var n=b,r=Je(),o=t(),i=!it(r.memoizedState,o);if(i&&(r.memoizedState=o,Oe=!0),r=r.queue,Gs(Ef.bind(null,n,r,e),[e]),r.getSnapshot!==t||i||fe!==null&&fe.memoizedState.tag&1){if(n.flags|=2048,Ur(9,Sf.bind(null,n,r,o,t),void 0,null),de===null)throw Error(R(349));fn&30||wf(n,t,o)}return o}function wf(e,t,n){e.flags|=16384,e={getSnapshot:t,value:n},t=b.updateQueue,t===null?(t={lastEffect:null,stores:null},b.updateQueue=t,t.stores=[e]):(n=t.stores,n===null?t.stores=[e]:n.push(e))}function Sf(e,t,n,r){t.value=n,t.getSnapshot=r,xf(t)&&kf(e)}function Ef(e,t,n){return n(function(){xf(t)&&kf(e)})}function xf(e){var t=e.getSnapshot;e=e.value;try{var n=t();return!it(e,n)}catch{return!0}}function kf(e){var t=kt(e,1);t!==null&&ot(t,e,1,-1)}function oa(e){var t=st();return typeof e=="function"&&(e=e()),t.memoizedState=t.baseState=e,e={pending:null,interleaved:null,lanes:0,dispatch:null,lastRenderedReducer:Br,lastRenderedState:e},t.queue=e,e=e.dispatch=cm.bind(null,b,e),[t.memoizedState,e]}function Ur(e,t,n,r){return e={tag:e,create:t,destroy:n,deps:r,next:null},t=b.updateQueue,t===null?(t={lastEffect:null,stores:null},b.updateQueue=t,t.lastEffect=e.next=e):(n=t.lastEffect,n===null?t.lastEffect=e.next=e:(r=n.next,n.next=e,e.next=r,t.lastEffect=e)),e}function Cf(){return Je().memoizedState}function Po(e,t,n,r){var o=st();b.flags|=e,o.memoizedState=Ur(1|t,n,void 0,r===void 0?null:r)}function hi(e,t,n,r){var o=Je();r=r===void 0?null:r;var i=void 0;if(ae!==null){var l=ae.memoizedState;if(i=l.destroy,r!==null&&Vs(r,l.deps)){o.memoizedState=Ur(t,n,i,r);return}}b.flags|=e,o.memoizedState=Ur(1|t,n,i,r)}function ia(e,t){return Po(8390656,8,e,t)}function Gs(e,t){return hi(2048,8,e,t)}function _f(e,t){return hi(4,2,e,t)}function Pf(e,t){return hi(4,4,e,t)}function Rf(e,t){if(typeof t=="function")return e=e(),t(e),function(){t(null)};if(t!=null)return e=e(),t.current=e,function(){t.current=null}}function Tf(e,t,n){return n=n!=null?n.concat([e]):null,hi(4,4,Rf.bind(null,t,e),n)}function Ys(){}function Nf(e,t){var n=Je();t=t===void 0?null:t;var r=n.memoizedState;return r!==null&&t!==null&&Vs(t,r[1])?r[0]:(n.memoizedState=[e,t],e)}function Lf(e,t){var n=Je();t=t===void 0?null:t;var r=n.memoizedState;return r!==null&&t!==null&&Vs(t,r[1])?r[0]:(e=e(),n.memoizedState=[e,t],e)}function Of(e,t,n){return fn&21?(it(n,t)||(n=Dc(),b.lanes|=n,dn|=n,e.baseState=!0),t):(e.baseState&&(e.baseState=!1,Oe=!0),e.memoizedState=n)}function um(e,t){var n=W;W=n!==0&&4>n?n:4,e(!0);var r=Yi.transition;Yi.transition={};try{e(!1),t()}finally{W=n,Yi.transition=r}}function Mf(){return Je().memoizedState}function am(e,t,n){var r=Ht(e);if(n={lane:r,action:n,hasEagerState:!1,eagerState:null,next:null},jf(e))If(t,n);else if(n=hf(e,t,n,r),n!==null){var o=Ce();ot(n,e,r,o),Df(n,t,r)}}function cm(e,t,n){var r=Ht(e),o={lane:r,action:n,hasEagerState:!1,eagerState:null,next:null};if(jf(e))If(t,o);else{var i=e.alternate;if(e.lanes===0&&(i===null||i.lanes===0)&&(i=t.lastRenderedReducer,i!==null))try{var l=t.lastRenderedState,s=i(l,n);if(o.hasEagerState=!0,o.eagerState=s,it(s,l)){var u=t.interleaved;u===null?(o.next=o,As(t)):(o.next=u.next,u.next=o),t.interleaved=o;return}}catch{}finally{}n=hf(e,t,o,r),n!==null&&(o=Ce(),ot(n,e,r,o),Df(n,t,r))}}function jf(e){var t=e.alternate;return e===b||t!==null&&t===b}function If(e,t){wr=Zo=!0;var n=e.pending;n===null?t.next=t:(t.next=n.next,n.next=t),e.pending=t}function Df(e,t,n){if(n&4194240){var r=t.lanes;r&=e.pendingLanes,n|=r,t.lanes=n,_s(e,n)}}var Jo={readContext:Ze,useCallback:ge,useContext:ge,useEffect:ge,useImperativeHandle:ge,useInsertionEffect:ge,useLayoutEffect:ge,useMemo:ge,useReducer:ge,useRef:ge,useState:ge,useDebugValue:ge,useDeferredValue:ge,useTransition:ge,useMutableSource:ge,useSyncExternalStore:ge,useId:ge,unstable_isNewReconciler:!1},fm={readContext:Ze,useCallback:function(e,t){return st().memoizedState=[e,t===void 0?null:t],e},useContext:Ze,useEffect:ia,useImperativeHandle:function(e,t,n){return n=n!=null?n.concat([e]):null,Po(4194308,4,Rf.bind(null,t,e),n)},useLayoutEffect:function(e,t){return Po(4194308,4,e,t)},useInsertionEffect:function(e,t){return Po(4,2,e,t)},useMemo:function(e,t){var n=st();return t=t===void 0?null:t,e=e(),n.memoizedState=[e,t],e},useReducer:function(e,t,n){var r=st();return t=n!==void 0?n(t):t,r.memoizedState=r.baseState=t,e={pending:null,interleaved:null,lanes:0,dispatch:null,lastRenderedReducer:e,lastRenderedState:t},r.queue=e,e=e.dispatch=am.bind(null,b,e),[r.memoizedState,e]},useRef:function(e){var t=st();return e={current:e},t.memoizedState=e},useState:oa,useDebugValue:Ys,useDeferredValue:function(e){return st().memoizedState=e},useTransition:function(){var e=oa(!1),t=e[0];return e=um.bind(null,e[1]),st().memoizedState=e,[t,e]},useMutableSource:function(){},useSyncExternalStore:function(e,t,n){var r=b,o=st();if(Z){if(n===void 0)throw Error(R(407));n=n()}else{if(n=t(),de===null)throw Error(R(349));fn&30||wf(r,t,n)}o.memoizedState=n;var i={value:n,getSnapshot:t};return o.queue=i,ia(Ef.bind(null,r,i,e),[e]),r.flags|=2048,Ur(9,Sf.bind(null,r,i,n,t),void 0,null),n},useId:function(){var e=st(),t=de.identifierPrefix;if(Z){var n=gt,r=yt;n=(r&~(1<<32-rt(r)-1)).toString(32)+n,t=":"+t+"R"+n,n=Ar++,0<n&&(t+="H"+n.toString(32)),t+=":"}else n=sm++,t=":"+t+"r"+n.toString(32)+":";return e.memoizedState=t},unstable_isNewReconciler:!1},dm={readContext:Ze,useCallback:Nf,useContext:Ze,useEffect:Gs,useImperativeHandle:Tf,useInsertionEffect:_f,useLayoutEffect:Pf,useMemo:Lf,useReducer:Xi,useRef:Cf,useState:function(){return Xi(Br)},useDebugValue:Ys,useDeferredValue:function(e){var t=Je();return Of(t,ae.memoizedState,e)},useTransition:function(){var e=Xi(Br)[0],t=Je().memoizedState;return[e,t]},useMutableSource:yf,useSyncExternalStore:gf,useId:Mf,unstable_isNewReconciler:!1},pm={readContext:Ze,useCallback:Nf,useContext:Ze,useEffect:Gs,useImperativeHandle:Tf,useInsertionEffect:_f,useLayoutEffect:Pf,useMemo:Lf,useReducer:Zi,useRef:Cf,useState:function(){return Zi(Br)},useDebugValue:Ys,useDeferredValue:function(e){var t=Je();return ae===null?t.memoizedState=e:Of(t,ae.memoizedState,e)},useTransition:function(){var e=Zi(Br)[0],t=Je().memoizedState;return[e,t]},useMutableSource:yf,useSyncExternalStore:gf,useId:Mf,unstable_isNewReconciler:!1};function be(e,t){if(e&&e.defaultProps){t=ee({},t),e=e.defaultProps;for(var n in e)t[n]===void 0&&(t[n]=e[n]);return t}return t}function Wl(e,t,n,r){t=e.memoizedState,n=n(r,t),n=n==null?t:ee({},t,n),e.memoizedState=n,e.lanes===0&&(e.updateQueue.baseState=n)}var mi={isMounted:function(e){return(e=e._reactInternals)?mn(e)===e:!1},enqueueSetState:function(e,t,n){e=e._reactInternals;var r=Ce(),o=Ht(e),i=wt(r,o);i.payload=t,n!=null&&(i.callback=n),t=Ut(e,i,o),t!==null&&(ot(t,e,o,r),Co(t,e,o))},enqueueReplaceState:function(e,t,n){e=e._reactInternals;var r=Ce(),o=Ht(e),i=wt(r,o);i.tag=1,i.payload=t,n!=null&&(i.callback=n),t=Ut(e,i,o),t!==null&&(ot(t,e,o,r),Co(t,e,o))},enqueueForceUpdate:function(e,t){e=e._reactInternals;var n=Ce(),r=Ht(e),o=wt(n,r);o.tag=2,t!=null&&(o.callback=t),t=Ut(e,o,r),t!==null&&(ot(t,e,r,n),Co(t,e,r))}};function la(e,t,n,r,o,i,l){return e=e.stateNode,typeof e.shouldComponentUpdate=="function"?e.shouldComponentUpdate(r,i,l):t.prototype&&t.prototype.isPureReactComponent?!jr(n,r)||!jr(o,i):!0}function Ff(e,t,n){var r=!1,o=Gt,i=t.contextType;return typeof i=="object"&&i!==null?i=Ze(i):(o=je(t)?an:Ee.current,r=t.contextTypes,i=(r=r!=null)?Wn(e,o):Gt),t=new t(n,i),e.memoizedState=t.state!==null&&t.state!==void 0?t.state:null,t.updater=mi,e.stateNode=t,t._reactInternals=e,r&&(e=e.stateNode,e.__reactInternalMemoizedUnmaskedChildContext=o,e.__reactInternalMemoizedMaskedChildContext=i),t}function sa(e,t,n,r){e=t.state,typeof t.componentWillReceiveProps=="function"&&t.componentWillReceiveProps(n,r),typeof t.UNSAFE_componentWillReceiveProps=="function"&&t.UNSAFE_componentWillReceiveProps(n,r),t.state!==e&&mi.enqueueReplaceState(t,t.state,null)}function Hl(e,t,n,r){var o=e.stateNode;o.props=n,o.state=e.memoizedState,o.refs={},Bs(e);var i=t.contextType;typeof i=="object"&&i!==null?o.context=Ze(i):(i=je(t)?an:Ee.current,o.context=Wn(e,i)),o.state=e.memoizedState,i=t.getDerivedStateFromProps,typeof i=="function"&&(Wl(e,t,i,n),o.state=e.memoizedState),typeof t.getDerivedStateFromProps=="function"||typeof o.getSnapshotBeforeUpdate=="function"||typeof o.UNSAFE_componentWillMount!="function"&&typeof o.componentWillMount!="function"||(t=o.state,typeof o.componentWillMount=="function"&&o.componentWillMount(),typeof o.UNSAFE_componentWillMount=="function"&&o.UNSAFE_componentWillMount(),t!==o.state&&mi.enqueueReplaceState(o,o.state,null),Yo(e,n,o,r),o.state=e.memoizedState),typeof o.componentDidMount=="function"&&(e.flags|=4194308)}function Qn(e,t){try{var n="",r=t;do n+=Up(r),r=r.return;while(r);var o=n}catch(i){o=`
-Error generating stack: `+i.message+`
-`+i.stack}return{value:e,source:t,stack:o,digest:null}}function Ji(e,t,n){return{value:e,source:null,stack:n??null,digest:t??null}}function Vl(e,t){try{console.error(t.value)}catch(n){setTimeout(function(){throw n})}}var hm=typeof WeakMap=="function"?WeakMap:Map;function zf(e,t,n){n=wt(-1,n),n.tag=3,n.payload={element:null};var r=t.value;return n.callback=function(){bo||(bo=!0,es=r),Vl(e,t)},n}function $f(e,t,n){n=wt(-1,n),n.tag=3;var r=e.type.getDerivedStateFromError;if(typeof r=="function"){var o=t.value;n.payload=function(){return r(o)},n.callback=function(){Vl(e,t)}}var i=e.stateNode;return i!==null&&typeof i.componentDidCatch=="function"&&(n.callback=function(){Vl(e,t),typeof r!="function"&&(Wt===null?Wt=new Set([this]):Wt.add(this));var l=t.stack;this.componentDidCatch(t.value,{componentStack:l!==null?l:""})}),n}function ua(e,t,n){var r=e.pingCache;if(r===null){r=e.pingCache=new hm;var o=new Set;r.set(t,o)}else o=r.get(t),o===void 0&&(o=new Set,r.set(t,o));o.has(n)||(o.add(n),e=Tm.bind(null,e,t,n),t.then(e,e))}function aa(e){do{var t;if((t=e.tag===13)&&(t=e.memoizedState,t=t!==null?t.dehydrated!==null:!0),t)return e;e=e.return}while(e!==null);return null}function ca(e,t,n,r,o){return e.mode&1?(e.flags|=65536,e.lanes=o,e):(e===t?e.flags|=65536:(e.flags|=128,n.flags|=131072,n.flags&=-52805,n.tag===1&&(n.alternate===null?n.tag=17:(t=wt(-1,1),t.tag=2,Ut(n,t,1))),n.lanes|=1),e)}var mm=Pt.ReactCurrentOwner,Oe=!1;function ke(e,t,n,r){t.child=e===null?pf(t,null,n,r):Vn(t,e.child,n,r)}function fa(e,t,n,r,o){n=n.render;var i=t.ref;return $n(t,o),r=Ks(e,t,n,r,i,o),n=Qs(),e!==null&&!Oe?(t.updateQueue=e.updateQueue,t.flags&=-2053,e.lanes&=~o,Ct(e,t,o)):(Z&&n&&js(t),t.flags|=1,ke(e,t,r,o),t.child)}function da(e,t,n,r,o){if(e===null){var i=n.type;return typeof i=="function"&&!nu(i)&&i.defaultProps===void 0&&n.compare===null&&n.defaultProps===void 0?(t.tag=15,t.type=i,Af(e,t,i,r,o)):(e=Lo(n.type,null,r,t,t.mode,o),e.ref=t.ref,e.return=t,t.child=e)}if(i=e.child,!(e.lanes&o)){var l=i.memoizedProps;if(n=n.compare,n=n!==null?n:jr,n(l,r)&&e.ref===t.ref)return Ct(e,t,o)}return t.flags|=1,e=Vt(i,r),e.ref=t.ref,e.return=t,t.child=e}function Af(e,t,n,r,o){if(e!==null){var i=e.memoizedProps;if(jr(i,r)&&e.ref===t.ref)if(Oe=!1,t.pendingProps=r=i,(e.lanes&o)!==0)e.flags&131072&&(Oe=!0);else return t.lanes=e.lanes,Ct(e,t,o)}return Kl(e,t,n,r,o)}function Bf(e,t,n){var r=t.pendingProps,o=r.children,i=e!==null?e.memoizedState:null;if(r.mode==="hidden")if(!(t.mode&1))t.memoizedState={baseLanes:0,cachePool:null,transitions:null},K(On,$e),$e|=n;else{if(!(n&1073741824))return e=i!==null?i.baseLanes|n:n,t.lanes=t.childLanes=1073741824,t.memoizedState={baseLanes:e,cachePool:null,transitions:null},t.updateQueue=null,K(On,$e),$e|=e,null;t.memoizedState={baseLanes:0,cachePool:null,transitions:null},r=i!==null?i.baseLanes:n,K(On,$e),$e|=r}else i!==null?(r=i.baseLanes|n,t.memoizedState=null):r=n,K(On,$e),$e|=r;return ke(e,t,o,n),t.child}function Uf(e,t){var n=t.ref;(e===null&&n!==null||e!==null&&e.ref!==n)&&(t.flags|=512,t.flags|=2097152)}function Kl(e,t,n,r,o){var i=je(n)?an:Ee.current;return i=Wn(t,i),$n(t,o),n=Ks(e,t,n,r,i,o),r=Qs(),e!==null&&!Oe?(t.updateQueue=e.updateQueue,t.flags&=-2053,e.lanes&=~o,Ct(e,t,o)):(Z&&r&&js(t),t.flags|=1,ke(e,t,n,o),t.child)}function pa(e,t,n,r,o){if(je(n)){var i=!0;Ho(t)}else i=!1;if($n(t,o),t.stateNode===null)Ro(e,t),Ff(t,n,r),Hl(t,n,r,o),r=!0;else if(e===null){var l=t.stateNode,s=t.memoizedProps;l.props=s;var u=l.context,a=n.contextType;typeof a=="object"&&a!==null?a=Ze(a):(a=je(n)?an:Ee.current,a=Wn(t,a));var c=n.getDerivedStateFromProps,h=typeof c=="function"||typeof l.getSnapshotBeforeUpdate=="function";h||typeof l.UNSAFE_componentWillReceiveProps!="function"&&typeof l.componentWillReceiveProps!="function"||(s!==r||u!==a)&&sa(t,l,r,a),Ot=!1;var d=t.memoizedState;l.state=d,Yo(t,r,l,o),u=t.memoizedState,s!==r||d!==u||Me.current||Ot?(typeof c=="function"&&(Wl(t,n,c,r),u=t.memoizedState),(s=Ot||la(t,n,s,r,d,u,a))?(h||typeof l.UNSAFE_componentWillMount!="function"&&typeof l.componentWillMount!="function"||(typeof l.componentWillMount=="function"&&l.componentWillMount(),typeof l.UNSAFE_componentWillMount=="function"&&l.UNSAFE_componentWillMount()),typeof l.componentDidMount=="function"&&(t.flags|=4194308)):(typeof l.componentDidMount=="function"&&(t.flags|=4194308),t.memoizedProps=r,t.memoizedState=u),l.props=r,l.state=u,l.context=a,r=s):(typeof l.componentDidMount=="function"&&(t.flags|=4194308),r=!1)}else{l=t.stateNode,mf(e,t),s=t.memoizedProps,a=t.type===t.elementType?s:be(t.type,s),l.props=a,h=t.pendingProps,d=l.context,u=n.contextType,typeof u=="object"&&u!==null?u=Ze(u):(u=je(n)?an:Ee.current,u=Wn(t,u));var v=n.getDerivedStateFromProps;(c=typeof v=="function"||typeof l.getSnapshotBeforeUpdate=="function")||typeof l.UNSAFE_componentWillReceiveProps!="function"&&typeof l.componentWillReceiveProps!="function"||(s!==h||d!==u)&&sa(t,l,r,u),Ot=!1,d=t.memoizedState,l.state=d,Yo(t,r,l,o);var y=t.memoizedState;s!==h||d!==y||Me.current||Ot?(typeof v=="function"&&(Wl(t,n,v,r),y=t.memoizedState),(a=Ot||la(t,n,a,r,d,y,u)||!1)?(c||typeof l.UNSAFE_componentWillUpdate!="function"&&typeof l.componentWillUpdate!="function"||(typeof l.componentWillUpdate=="function"&&l.componentWillUpdate(r,y,u),typeof l.UNSAFE_componentWillUpdate=="function"&&l.UNSAFE_componentWillUpdate(r,y,u)),typeof l.componentDidUpdate=="function"&&(t.flags|=4),typeof l.getSnapshotBeforeUpdate=="function"&&(t.flags|=1024)):(typeof l.componentDidUpdate!="function"||s===e.memoizedProps&&d===e.memoizedState||(t.flags|=4),typeof l.getSnapshotBeforeUpdate!="function"||s===e.memoizedProps&&d===e.memoizedState||(t.flags|=1024),t.memoizedProps=r,t.memoizedState=y),l.props=r,l.state=y,l.context=u,r=a):(typeof l.componentDidUpdate!="function"||s===e.memoizedProps&&d===e.memoizedState||(t.flags|=4),typeof l.getSnapshotBeforeUpdate!="function"||s===e.memoizedProps&&d===e.memoizedState||(t.flags|=1024),r=!1)}return Ql(e,t,n,r,i,o)}function Ql(e,t,n,r,o,i){Uf(e,t);var l=(t.flags&128)!==0;if(!r&&!l)return o&&qu(t,n,!1),Ct(e,t,i);r=t.stateNode,mm.current=t;var s=l&&typeof n.getDerivedStateFromError!="function"?null:r.render();return t.flags|=1,e!==null&&l?(t.child=Vn(t,e.child,null,i),t.child=Vn(t,null,s,i)):ke(e,t,s,i),t.memoizedState=r.state,o&&qu(t,n,!0),t.child}function Wf(e){var t=e.stateNode;t.pendingContext?Ju(e,t.pendingContext,t.pendingContext!==t.context):t.context&&Ju(e,t.context,!1),Us(e,t.containerInfo)}function ha(e,t,n,r,o){return Hn(),Ds(o),t.flags|=256,ke(e,t,n,r),t.child}var Gl={dehydrated:null,treeContext:null,retryLane:0};function Yl(e){return{baseLanes:e,cachePool:null,transitions:null}}function Hf(e,t,n){var r=t.pendingProps,o=q.current,i=!1,l=(t.flags&128)!==0,s;if((s=l)||(s=e!==null&&e.memoizedState===null?!1:(o&2)!==0),s?(i=!0,t.flags&=-129):(e===null||e.memoizedState!==null)&&(o|=1),K(q,o&1),e===null)return Bl(t),e=t.memoizedState,e!==null&&(e=e.dehydrated,e!==null)?(t.mode&1?e.data==="$!"?t.lanes=8:t.lanes=1073741824:t.lanes=1,null):(l=r.children,e=r.fallback,i?(r=t.mode,i=t.child,l={mode:"hidden",children:l},!(r&1)&&i!==null?(i.childLanes=0,i.pendingProps=l):i=gi(l,r,0,null),e=un(e,r,n,null),i.return=t,e.return=t,i.sibling=e,t.child=i,t.child.memoizedState=Yl(n),t.memoizedState=Gl,e):Xs(t,l));if(o=e.memoizedState,o!==null&&(s=o.dehydrated,s!==null))return vm(e,t,l,r,s,o,n);if(i){i=r.fallback,l=t.mode,o=e.child,s=o.sibling;var u={mode:"hidden",children:r.children};return!(l&1)&&t.child!==o?(r=t.child,r.childLanes=0,r.pendingProps=u,t.deletions=null):(r=Vt(o,u),r.subtreeFlags=o.subtreeFlags&14680064),s!==null?i=Vt(s,i):(i=un(i,l,n,null),i.flags|=2),i.return=t,r.return=t,r.sibling=i,t.child=r,r=i,i=t.child,l=e.child.memoizedState,l=l===null?Yl(n):{baseLanes:l.baseLanes|n,cachePool:null,transitions:l.transitions},i.memoizedState=l,i.childLanes=e.childLanes&~n,t.memoizedState=Gl,r}return i=e.child,e=i.sibling,r=Vt(i,{mode:"visible",children:r.children}),!(t.mode&1)&&(r.lanes=n),r.return=t,r.sibling=null,e!==null&&(n=t.deletions,n===null?(t.deletions=[e],t.flags|=16):n.push(e)),t.child=r,t.memoizedState=null,r}function Xs(e,t){return t=gi({mode:"visible",children:t},e.mode,0,null),t.return=e,e.child=t}function co(e,t,n,r){return r!==null&&Ds(r),Vn(t,e.child,null,n),e=Xs(t,t.pendingProps.children),e.flags|=2,t.memoizedState=null,e}function vm(e,t,n,r,o,i,l){if(n)return t.flags&256?(t.flags&=-257,r=Ji(Error(R(422))),co(e,t,l,r)):t.memoizedState!==null?(t.child=e.child,t.flags|=128,null):(i=r.fallback,o=t.mode,r=gi({mode:"visible",children:r.children},o,0,null),i=un(i,o,l,null),i.flags|=2,r.return=t,i.return=t,r.sibling=i,t.child=r,t.mode&1&&Vn(t,e.child,null,l),t.child.memoizedState=Yl(l),t.memoizedState=Gl,i);if(!(t.mode&1))return co(e,t,l,null);if(o.data==="$!"){if(r=o.nextSibling&&o.nextSibling.dataset,r)var s=r.dgst;return r=s,i=Error(R(419)),r=Ji(i,r,void 0),co(e,t,l,r)}if(s=(l&e.childLanes)!==0,Oe||s){if(r=de,r!==null){switch(l&-l){case 4:o=2;break;case 16:o=8;break;case 64:case 128:case 256:case 512:case 1024:case 2048:case 4096:case 8192:case 16384:case 32768:case 65536:case 131072:case 262144:case 524288:case 1048576:case 2097152:case 4194304:case 8388608:case 16777216:case 33554432:case 67108864:o=32;break;case 536870912:o=268435456;break;default:o=0}o=o&(r.suspendedLanes|l)?0:o,o!==0&&o!==i.retryLane&&(i.retryLane=o,kt(e,o),ot(r,e,o,-1))}return tu(),r=Ji(Error(R(421))),co(e,t,l,r)}return o.data==="$?"?(t.flags|=128,t.child=e.child,t=Nm.bind(null,e),o._reactRetry=t,null):(e=i.treeContext,Be=Bt(o.nextSibling),Ue=t,Z=!0,tt=null,e!==null&&(Ke[Qe++]=yt,Ke[Qe++]=gt,Ke[Qe++]=cn,yt=e.id,gt=e.overflow,cn=t),t=Xs(t,r.children),t.flags|=4096,t)}function ma(e,t,n){e.lanes|=t;var r=e.alternate;r!==null&&(r.lanes|=t),Ul(e.return,t,n)}function qi(e,t,n,r,o){var i=e.memoizedState;i===null?e.memoizedState={isBackwards:t,rendering:null,renderingStartTime:0,last:r,tail:n,tailMode:o}:(i.isBackwards=t,i.rendering=null,i.renderingStartTime=0,i.last=r,i.tail=n,i.tailMode=o)}function Vf(e,t,n){var r=t.pendingProps,o=r.revealOrder,i=r.tail;if(ke(e,t,r.children,n),r=q.current,r&2)r=r&1|2,t.flags|=128;else{if(e!==null&&e.flags&128)e:for(e=t.child;e!==null;){if(e.tag===13)e.memoizedState!==null&&ma(e,n,t);else if(e.tag===19)ma(e,n,t);else if(e.child!==null){e.child.return=e,e=e.child;continue}if(e===t)break e;for(;e.sibling===null;){if(e.return===null||e.return===t)break e;e=e.return}e.sibling.return=e.return,e=e.sibling}r&=1}if(K(q,r),!(t.mode&1))t.memoizedState=null;else switch(o){case"forwards":for(n=t.child,o=null;n!==null;)e=n.alternate,e!==null&&Xo(e)===null&&(o=n),n=n.sibling;n=o,n===null?(o=t.child,t.child=null):(o=n.sibling,n.sibling=null),qi(t,!1,o,n,i);break;case"backwards":for(n=null,o=t.child,t.child=null;o!==null;){if(e=o.alternate,e!==null&&Xo(e)===null){t.child=o;break}e=o.sibling,o.sibling=n,n=o,o=e}qi(t,!0,n,null,i);break;case"together":qi(t,!1,null,null,void 0);break;default:t.memoizedState=null}return t.child}function Ro(e,t){!(t.mode&1)&&e!==null&&(e.alternate=null,t.alternate=null,t.flags|=2)}function Ct(e,t,n){if(e!==null&&(t.dependencies=e.dependencies),dn|=t.lanes,!(n&t.childLanes))return null;if(e!==null&&t.child!==e.child)throw Error(R(153));if(t.child!==null){for(e=t.child,n=Vt(e,e.pendingProps),t.child=n,n.return=t;e.sibling!==null;)e=e.sibling,n=n.sibling=Vt(e,e.pendingProps),n.return=t;n.sibling=null}return t.child}function ym(e,t,n){switch(t.tag){case 3:Wf(t),Hn();break;case 5:vf(t);break;case 1:je(t.type)&&Ho(t);break;case 4:Us(t,t.stateNode.containerInfo);break;case 10:var r=t.type._context,o=t.memoizedProps.value;K(Qo,r._currentValue),r._currentValue=o;break;case 13:if(r=t.memoizedState,r!==null)return r.dehydrated!==null?(K(q,q.current&1),t.flags|=128,null):n&t.child.childLanes?Hf(e,t,n):(K(q,q.current&1),e=Ct(e,t,n),e!==null?e.sibling:null);K(q,q.current&1);break;case 19:if(r=(n&t.childLanes)!==0,e.flags&128){if(r)return Vf(e,t,n);t.flags|=128}if(o=t.memoizedState,o!==null&&(o.rendering=null,o.tail=null,o.lastEffect=null),K(q,q.current),r)break;return null;case 22:case 23:return t.lanes=0,Bf(e,t,n)}return Ct(e,t,n)}var Kf,Xl,Qf,Gf;Kf=function(e,t){for(var n=t.child;n!==null;){if(n.tag===5||n.tag===6)e.appendChild(n.stateNode);else if(n.tag!==4&&n.child!==null){n.child.return=n,n=n.child;continue}if(n===t)break;for(;n.sibling===null;){if(n.return===null||n.return===t)return;n=n.return}n.sibling.return=n.return,n=n.sibling}};Xl=function(){};Qf=function(e,t,n,r){var o=e.memoizedProps;if(o!==r){e=t.stateNode,ln(dt.current);var i=null;switch(n){case"input":o=yl(e,o),r=yl(e,r),i=[];break;case"select":o=ee({},o,{value:void 0}),r=ee({},r,{value:void 0}),i=[];break;case"textarea":o=Sl(e,o),r=Sl(e,r),i=[];break;default:typeof o.onClick!="function"&&typeof r.onClick=="function"&&(e.onclick=Uo)}xl(n,r);var l;n=null;for(a in o)if(!r.hasOwnProperty(a)&&o.hasOwnProperty(a)&&o[a]!=null)if(a==="style"){var s=o[a];for(l in s)s.hasOwnProperty(l)&&(n||(n={}),n[l]="")}else a!=="dangerouslySetInnerHTML"&&a!=="children"&&a!=="suppressContentEditableWarning"&&a!=="suppressHydrationWarning"&&a!=="autoFocus"&&(Pr.hasOwnProperty(a)?i||(i=[]):(i=i||[]).push(a,null));for(a in r){var u=r[a];if(s=o!=null?o[a]:void 0,r.hasOwnProperty(a)&&u!==s&&(u!=null||s!=null))if(a==="style")if(s){for(l in s)!s.hasOwnProperty(l)||u&&u.hasOwnProperty(l)||(n||(n={}),n[l]="");for(l in u)u.hasOwnProperty(l)&&s[l]!==u[l]&&(n||(n={}),n[l]=u[l])}else n||(i||(i=[]),i.push(a,n)),n=u;else a==="dangerouslySetInnerHTML"?(u=u?u.__html:void 0,s=s?s.__html:void 0,u!=null&&s!==u&&(i=i||[]).push(a,u)):a==="children"?typeof u!="string"&&typeof u!="number"||(i=i||[]).push(a,""+u):a!=="suppressContentEditableWarning"&&a!=="suppressHydrationWarning"&&(Pr.hasOwnProperty(a)?(u!=null&&a==="onScroll"&&G("scroll",e),i||s===u||(i=[])):(i=i||[]).push(a,u))}n&&(i=i||[]).push("style",n);var a=i;(t.updateQueue=a)&&(t.flags|=4)}};Gf=function(e,t,n,r){n!==r&&(t.flags|=4)};function lr(e,t){if(!Z)switch(e.tailMode){case"hidden":t=e.tail;for(var n=null;t!==null;)t.alternate!==null&&(n=t),t=t.sibling;n===null?e.tail=null:n.sibling=null;break;case"collapsed":n=e.tail;for(var r=null;n!==null;)n.alternate!==null&&(r=n),n=n.sibling;r===null?t||e.tail===null?e.tail=null:e.tail.sibling=null:r.sibling=null}}function we(e){var t=e.alternate!==null&&e.alternate.child===e.child,n=0,r=0;if(t)for(var o=e.child;o!==null;)n|=o.lanes|o.childLanes,r|=o.subtreeFlags&14680064,r|=o.flags&14680064,o.return=e,o=o.sibling;else for(o=e.child;o!==null;)n|=o.lanes|o.childLanes,r|=o.subtreeFlags,r|=o.flags,o.return=e,o=o.sibling;return e.subtreeFlags|=r,e.childLanes=n,t}function gm(e,t,n){var r=t.pendingProps;switch(Is(t),t.tag){case 2:case 16:case 15:case 0:case 11:case 7:case 8:case 12:case 9:case 14:return we(t),null;case 1:return je(t.type)&&Wo(),we(t),null;case 3:return r=t.stateNode,Kn(),Y(Me),Y(Ee),Hs(),r.pendingContext&&(r.context=r.pendingContext,r.pendingContext=null),(e===null||e.child===null)&&(uo(t)?t.flags|=4:e===null||e.memoizedState.isDehydrated&&!(t.flags&256)||(t.flags|=1024,tt!==null&&(rs(tt),tt=null))),Xl(e,t),we(t),null;case 5:Ws(t);var o=ln($r.current);if(n=t.type,e!==null&&t.stateNode!=null)Qf(e,t,n,r,o),e.ref!==t.ref&&(t.flags|=512,t.flags|=2097152);else{if(!r){if(t.stateNode===null)throw Error(R(166));return we(t),null}if(e=ln(dt.current),uo(t)){r=t.stateNode,n=t.type;var i=t.memoizedProps;switch(r[at]=t,r[Fr]=i,e=(t.mode&1)!==0,n){case"dialog":G("cancel",r),G("close",r);break;case"iframe":case"object":case"embed":G("load",r);break;case"video":case"audio":for(o=0;o<dr.length;o++)G(dr[o],r);break;case"source":G("error",r);break;case"img":case"image":case"link":G("error",r),G("load",r);break;case"details":G("toggle",r);break;case"input":Cu(r,i),G("invalid",r);break;case"select":r._wrapperState={wasMultiple:!!i.multiple},G("invalid",r);break;case"textarea":Pu(r,i),G("invalid",r)}xl(n,i),o=null;for(var l in i)if(i.hasOwnProperty(l)){var s=i[l];l==="children"?typeof s=="string"?r.textContent!==s&&(i.suppressHydrationWarning!==!0&&so(r.textContent,s,e),o=["children",s]):typeof s=="number"&&r.textContent!==""+s&&(i.suppressHydrationWarning!==!0&&so(r.textContent,s,e),o=["children",""+s]):Pr.hasOwnProperty(l)&&s!=null&&l==="onScroll"&&G("scroll",r)}switch(n){case"input":br(r),_u(r,i,!0);break;case"textarea":br(r),Ru(r);break;case"select":case"option":break;default:typeof i.onClick=="function"&&(r.onclick=Uo)}r=o,t.updateQueue=r,r!==null&&(t.flags|=4)}else{l=o.nodeType===9?o:o.ownerDocument,e==="http://www.w3.org/1999/xhtml"&&(e=Sc(n)),e==="http://www.w3.org/1999/xhtml"?n==="script"?(e=l.createElement("div"),e.innerHTML="<script><\/script>",e=e.removeChild(e.firstChild)):typeof r.is=="string"?e=l.createElement(n,{is:r.is}):(e=l.createElement(n),n==="select"&&(l=e,r.multiple?l.multiple=!0:r.size&&(l.size=r.size))):e=l.createElementNS(e,n),e[at]=t,e[Fr]=r,Kf(e,t,!1,!1),t.stateNode=e;e:{switch(l=kl(n,r),n){case"dialog":G("cancel",e),G("close",e),o=r;break;case"iframe":case"object":case"embed":G("load",e),o=r;break;case"video":case"audio":for(o=0;o<dr.length;o++)G(dr[o],e);o=r;break;case"source":G("error",e),o=r;break;case"img":case"image":case"link":G("error",e),G("load",e),o=r;break;case"details":G("toggle",e),o=r;break;case"input":Cu(e,r),o=yl(e,r),G("invalid",e);break;case"option":o=r;break;case"select":e._wrapperState={wasMultiple:!!r.multiple},o=ee({},r,{value:void 0}),G("invalid",e);break;case"textarea":Pu(e,r),o=Sl(e,r),G("invalid",e);break;default:o=r}xl(n,o),s=o;for(i in s)if(s.hasOwnProperty(i)){var u=s[i];i==="style"?kc(e,u):i==="dangerouslySetInnerHTML"?(u=u?u.__html:void 0,u!=null&&Ec(e,u)):i==="children"?typeof u=="string"?(n!=="textarea"||u!=="")&&Rr(e,u):typeof u=="number"&&Rr(e,""+u):i!=="suppressContentEditableWarning"&&i!=="suppressHydrationWarning"&&i!=="autoFocus"&&(Pr.hasOwnProperty(i)?u!=null&&i==="onScroll"&&G("scroll",e):u!=null&&ws(e,i,u,l))}switch(n){case"input":br(e),_u(e,r,!1);break;case"textarea":br(e),Ru(e);break;case"option":r.value!=null&&e.setAttribute("value",""+Qt(r.value));break;case"select":e.multiple=!!r.multiple,i=r.value,i!=null?In(e,!!r.multiple,i,!1):r.defaultValue!=null&&In(e,!!r.multiple,r.defaultValue,!0);break;default:typeof o.onClick=="function"&&(e.onclick=Uo)}switch(n){case"button":case"input":case"select":case"textarea":r=!!r.autoFocus;break e;case"img":r=!0;break e;default:r=!1}}r&&(t.flags|=4)}t.ref!==null&&(t.flags|=512,t.flags|=2097152)}return we(t),null;case 6:if(e&&t.stateNode!=null)Gf(e,t,e.memoizedProps,r);else{if(typeof r!="string"&&t.stateNode===null)throw Error(R(166));if(n=ln($r.current),ln(dt.current),uo(t)){if(r=t.stateNode,n=t.memoizedProps,r[at]=t,(i=r.nodeValue!==n)&&(e=Ue,e!==null))switch(e.tag){case 3:so(r.nodeValue,n,(e.mode&1)!==0);break;case 5:e.memoizedProps.suppressHydrationWarning!==!0&&so(r.nodeValue,n,(e.mode&1)!==0)}i&&(t.flags|=4)}else r=(n.nodeType===9?n:n.ownerDocument).createTextNode(r),r[at]=t,t.stateNode=r}return we(t),null;case 13:if(Y(q),r=t.memoizedState,e===null||e.memoizedState!==null&&e.memoizedState.dehydrated!==null){if(Z&&Be!==null&&t.mode&1&&!(t.flags&128))ff(),Hn(),t.flags|=98560,i=!1;else if(i=uo(t),r!==null&&r.dehydrated!==null){if(e===null){if(!i)throw Error(R(318));if(i=t.memoizedState,i=i!==null?i.dehydrated:null,!i)throw Error(R(317));i[at]=t}else Hn(),!(t.flags&128)&&(t.memoizedState=null),t.flags|=4;we(t),i=!1}else tt!==null&&(rs(tt),tt=null),i=!0;if(!i)return t.flags&65536?t:null}return t.flags&128?(t.lanes=n,t):(r=r!==null,r!==(e!==null&&e.memoizedState!==null)&&r&&(t.child.flags|=8192,t.mode&1&&(e===null||q.current&1?ce===0&&(ce=3):tu())),t.updateQueue!==null&&(t.flags|=4),we(t),null);case 4:return Kn(),Xl(e,t),e===null&&Ir(t.stateNode.containerInfo),we(t),null;case 10:return $s(t.type._context),we(t),null;case 17:return je(t.type)&&Wo(),we(t),null;case 19:if(Y(q),i=t.memoizedState,i===null)return we(t),null;if(r=(t.flags&128)!==0,l=i.rendering,l===null)if(r)lr(i,!1);else{if(ce!==0||e!==null&&e.flags&128)for(e=t.child;e!==null;){if(l=Xo(e),l!==null){for(t.flags|=128,lr(i,!1),r=l.updateQueue,r!==null&&(t.updateQueue=r,t.flags|=4),t.subtreeFlags=0,r=n,n=t.child;n!==null;)i=n,e=r,i.flags&=14680066,l=i.alternate,l===null?(i.childLanes=0,i.lanes=e,i.child=null,i.subtreeFlags=0,i.memoizedProps=null,i.memoizedState=null,i.updateQueue=null,i.dependencies=null,i.stateNode=null):(i.childLanes=l.childLanes,i.lanes=l.lanes,i.child=l.child,i.subtreeFlags=0,i.deletions=null,i.memoizedProps=l.memoizedProps,i.memoizedState=l.memoizedState,i.updateQueue=l.updateQueue,i.type=l.type,e=l.dependencies,i.dependencies=e===null?null:{lanes:e.lanes,firstContext:e.firstContext}),n=n.sibling;return K(q,q.current&1|2),t.child}e=e.sibling}i.tail!==null&&re()>Gn&&(t.flags|=128,r=!0,lr(i,!1),t.lanes=4194304)}else{if(!r)if(e=Xo(l),e!==null){if(t.flags|=128,r=!0,n=e.updateQueue,n!==null&&(t.updateQueue=n,t.flags|=4),lr(i,!0),i.tail===null&&i.tailMode==="hidden"&&!l.alternate&&!Z)return we(t),null}else 2*re()-i.renderingStartTime>Gn&&n!==1073741824&&(t.flags|=128,r=!0,lr(i,!1),t.lanes=4194304);i.isBackwards?(l.sibling=t.child,t.child=l):(n=i.last,n!==null?n.sibling=l:t.child=l,i.last=l)}return i.tail!==null?(t=i.tail,i.rendering=t,i.tail=t.sibling,i.renderingStartTime=re(),t.sibling=null,n=q.current,K(q,r?n&1|2:n&1),t):(we(t),null);case 22:case 23:return eu(),r=t.memoizedState!==null,e!==null&&e.memoizedState!==null!==r&&(t.flags|=8192),r&&t.mode&1?$e&1073741824&&(we(t),t.subtreeFlags&6&&(t.flags|=8192)):we(t),null;case 24:return null;case 25:return null}throw Error(R(156,t.tag))}function wm(e,t){switch(Is(t),t.tag){case 1:return je(t.type)&&Wo(),e=t.flags,e&65536?(t.flags=e&-65537|128,t):null;case 3:return Kn(),Y(Me),Y(Ee),Hs(),e=t.flags,e&65536&&!(e&128)?(t.flags=e&-65537|128,t):null;case 5:return Ws(t),null;case 13:if(Y(q),e=t.memoizedState,e!==null&&e.dehydrated!==null){if(t.alternate===null)throw Error(R(340));Hn()}return e=t.flags,e&65536?(t.flags=e&-65537|128,t):null;case 19:return Y(q),null;case 4:return Kn(),null;case 10:return $s(t.type._context),null;case 22:case 23:return eu(),null;case 24:return null;default:return null}}var fo=!1,Se=!1,Sm=typeof WeakSet=="function"?WeakSet:Set,N=null;function Ln(e,t){var n=e.ref;if(n!==null)if(typeof n=="function")try{n(null)}catch(r){te(e,t,r)}else n.current=null}function Zl(e,t,n){try{n()}catch(r){te(e,t,r)}}var va=!1;function Em(e,t){if(jl=$o,e=Jc(),Ms(e)){if("selectionStart"in e)var n={start:e.selectionStart,end:e.selectionEnd};else e:{n=(n=e.ownerDocument)&&n.defaultView||window;var r=n.getSelection&&n.getSelection();if(r&&r.rangeCount!==0){n=r.anchorNode;var o=r.anchorOffset,i=r.focusNode;r=r.focusOffset;try{n.nodeType,i.nodeType}catch{n=null;break e}var l=0,s=-1,u=-1,a=0,c=0,h=e,d=null;t:for(;;){for(var v;h!==n||o!==0&&h.nodeType!==3||(s=l+o),h!==i||r!==0&&h.nodeType!==3||(u=l+r),h.nodeType===3&&(l+=h.nodeValue.length),(v=h.firstChild)!==null;)d=h,h=v;for(;;){if(h===e)break t;if(d===n&&++a===o&&(s=l),d===i&&++c===r&&(u=l),(v=h.nextSibling)!==null)break;h=d,d=h.parentNode}h=v}n=s===-1||u===-1?null:{start:s,end:u}}else n=null}n=n||{start:0,end:0}}else n=null;for(Il={focusedElem:e,selectionRange:n},$o=!1,N=t;N!==null;)if(t=N,e=t.child,(t.subtreeFlags&1028)!==0&&e!==null)e.return=t,N=e;else for(;N!==null;){t=N;try{var y=t.alternate;if(t.flags&1024)switch(t.tag){case 0:case 11:case 15:break;case 1:if(y!==null){var g=y.memoizedProps,x=y.memoizedState,m=t.stateNode,f=m.getSnapshotBeforeUpdate(t.elementType===t.type?g:be(t.type,g),x);m.__reactInternalSnapshotBeforeUpdate=f}break;case 3:var p=t.stateNode.containerInfo;p.nodeType===1?p.textContent="":p.nodeType===9&&p.documentElement&&p.removeChild(p.documentElement);break;case 5:case 6:case 4:case 17:break;default:throw Error(R(163))}}catch(S){te(t,t.return,S)}if(e=t.sibling,e!==null){e.return=t.return,N=e;break}N=t.return}return y=va,va=!1,y}function Sr(e,t,n){var r=t.updateQueue;if(r=r!==null?r.lastEffect:null,r!==null){var o=r=r.next;do{if((o.tag&e)===e){var i=o.destroy;o.destroy=void 0,i!==void 0&&Zl(t,n,i)}o=o.next}while(o!==r)}}function vi(e,t){if(t=t.updateQueue,t=t!==null?t.lastEffect:null,t!==null){var n=t=t.next;do{if((n.tag&e)===e){var r=n.create;n.destroy=r()}n=n.next}while(n!==t)}}function Jl(e){var t=e.ref;if(t!==null){var n=e.stateNode;switch(e.tag){case 5:e=n;break;default:e=n}typeof t=="function"?t(e):t.current=e}}function Yf(e){var t=e.alternate;t!==null&&(e.alternate=null,Yf(t)),e.child=null,e.deletions=null,e.sibling=null,e.tag===5&&(t=e.stateNode,t!==null&&(delete t[at],delete t[Fr],delete t[zl],delete t[rm],delete t[om])),e.stateNode=null,e.return=null,e.dependencies=null,e.memoizedProps=null,e.memoizedState=null,e.pendingProps=null,e.stateNode=null,e.updateQueue=null}function Xf(e){return e.tag===5||e.tag===3||e.tag===4}function ya(e){e:for(;;){for(;e.sibling===null;){if(e.return===null||Xf(e.return))return null;e=e.return}for(e.sibling.return=e.return,e=e.sibling;e.tag!==5&&e.tag!==6&&e.tag!==18;){if(e.flags&2||e.child===null||e.tag===4)continue e;e.child.return=e,e=e.child}if(!(e.flags&2))return e.stateNode}}function ql(e,t,n){var r=e.tag;if(r===5||r===6)e=e.stateNode,t?n.nodeType===8?n.parentNode.insertBefore(e,t):n.insertBefore(e,t):(n.nodeType===8?(t=n.parentNode,t.insertBefore(e,n)):(t=n,t.appendChild(e)),n=n._reactRootContainer,n!=null||t.onclick!==null||(t.onclick=Uo));else if(r!==4&&(e=e.child,e!==null))for(ql(e,t,n),e=e.sibling;e!==null;)ql(e,t,n),e=e.sibling}function bl(e,t,n){var r=e.tag;if(r===5||r===6)e=e.stateNode,t?n.insertBefore(e,t):n.appendChild(e);else if(r!==4&&(e=e.child,e!==null))for(bl(e,t,n),e=e.sibling;e!==null;)bl(e,t,n),e=e.sibling}var he=null,et=!1;function Nt(e,t,n){for(n=n.child;n!==null;)Zf(e,t,n),n=n.sibling}function Zf(e,t,n){if(ft&&typeof ft.onCommitFiberUnmount=="function")try{ft.onCommitFiberUnmount(ui,n)}catch{}switch(n.tag){case 5:Se||Ln(n,t);case 6:var r=he,o=et;he=null,Nt(e,t,n),he=r,et=o,he!==null&&(et?(e=he,n=n.stateNode,e.nodeType===8?e.parentNode.removeChild(n):e.removeChild(n)):he.removeChild(n.stateNode));break;case 18:he!==null&&(et?(e=he,n=n.stateNode,e.nodeType===8?Ki(e.parentNode,n):e.nodeType===1&&Ki(e,n),Or(e)):Ki(he,n.stateNode));break;case 4:r=he,o=et,he=n.stateNode.containerInfo,et=!0,Nt(e,t,n),he=r,et=o;break;case 0:case 11:case 14:case 15:if(!Se&&(r=n.updateQueue,r!==null&&(r=r.lastEffect,r!==null))){o=r=r.next;do{var i=o,l=i.destroy;i=i.tag,l!==void 0&&(i&2||i&4)&&Zl(n,t,l),o=o.next}while(o!==r)}Nt(e,t,n);break;case 1:if(!Se&&(Ln(n,t),r=n.stateNode,typeof r.componentWillUnmount=="function"))try{r.props=n.memoizedProps,r.state=n.memoizedState,r.componentWillUnmount()}catch(s){te(n,t,s)}Nt(e,t,n);break;case 21:Nt(e,t,n);break;case 22:n.mode&1?(Se=(r=Se)||n.memoizedState!==null,Nt(e,t,n),Se=r):Nt(e,t,n);break;default:Nt(e,t,n)}}function ga(e){var t=e.updateQueue;if(t!==null){e.updateQueue=null;var n=e.stateNode;n===null&&(n=e.stateNode=new Sm),t.forEach(function(r){var o=Lm.bind(null,e,r);n.has(r)||(n.add(r),r.then(o,o))})}}function qe(e,t){var n=t.deletions;if(n!==null)for(var r=0;r<n.length;r++){var o=n[r];try{var i=e,l=t,s=l;e:for(;s!==null;){switch(s.tag){case 5:he=s.stateNode,et=!1;break e;case 3:he=s.stateNode.containerInfo,et=!0;break e;case 4:he=s.stateNode.containerInfo,et=!0;break e}s=s.return}if(he===null)throw Error(R(160));Zf(i,l,o),he=null,et=!1;var u=o.alternate;u!==null&&(u.return=null),o.return=null}catch(a){te(o,t,a)}}if(t.subtreeFlags&12854)for(t=t.child;t!==null;)Jf(t,e),t=t.sibling}function Jf(e,t){var n=e.alternate,r=e.flags;switch(e.tag){case 0:case 11:case 14:case 15:if(qe(t,e),lt(e),r&4){try{Sr(3,e,e.return),vi(3,e)}catch(g){te(e,e.return,g)}try{Sr(5,e,e.return)}catch(g){te(e,e.return,g)}}break;case 1:qe(t,e),lt(e),r&512&&n!==null&&Ln(n,n.return);break;case 5:if(qe(t,e),lt(e),r&512&&n!==null&&Ln(n,n.return),e.flags&32){var o=e.stateNode;try{Rr(o,"")}catch(g){te(e,e.return,g)}}if(r&4&&(o=e.stateNode,o!=null)){var i=e.memoizedProps,l=n!==null?n.memoizedProps:i,s=e.type,u=e.updateQueue;if(e.updateQueue=null,u!==null)try{s==="input"&&i.type==="radio"&&i.name!=null&&gc(o,i),kl(s,l);var a=kl(s,i);for(l=0;l<u.length;l+=2){var c=u[l],h=u[l+1];c==="style"?kc(o,h):c==="dangerouslySetInnerHTML"?Ec(o,h):c==="children"?Rr(o,h):ws(o,c,h,a)}switch(s){case"input":gl(o,i);break;case"textarea":wc(o,i);break;case"select":var d=o._wrapperState.wasMultiple;o._wrapperState.wasMultiple=!!i.multiple;var v=i.value;v!=null?In(o,!!i.multiple,v,!1):d!==!!i.multiple&&(i.defaultValue!=null?In(o,!!i.multiple,i.defaultValue,!0):In(o,!!i.multiple,i.multiple?[]:"",!1))}o[Fr]=i}catch(g){te(e,e.return,g)}}break;case 6:if(qe(t,e),lt(e),r&4){if(e.stateNode===null)throw Error(R(162));o=e.stateNode,i=e.memoizedProps;try{o.nodeValue=i}catch(g){te(e,e.return,g)}}break;case 3:if(qe(t,e),lt(e),r&4&&n!==null&&n.memoizedState.isDehydrated)try{Or(t.containerInfo)}catch(g){te(e,e.return,g)}break;case 4:qe(t,e),lt(e);break;case 13:qe(t,e),lt(e),o=e.child,o.flags&8192&&(i=o.memoizedState!==null,o.stateNode.isHidden=i,!i||o.alternate!==null&&o.alternate.memoizedState!==null||(qs=re())),r&4&&ga(e);break;case 22:if(c=n!==null&&n.memoizedState!==null,e.mode&1?(Se=(a=Se)||c,qe(t,e),Se=a):qe(t,e),lt(e),r&8192){if(a=e.memoizedState!==null,(e.stateNode.isHidden=a)&&!c&&e.mode&1)for(N=e,c=e.child;c!==null;){for(h=N=c;N!==null;){switch(d=N,v=d.child,d.tag){case 0:case 11:case 14:case 15:Sr(4,d,d.return);break;case 1:Ln(d,d.return);var y=d.stateNode;if(typeof y.componentWillUnmount=="function"){r=d,n=d.return;try{t=r,y.props=t.memoizedProps,y.state=t.memoizedState,y.componentWillUnmount()}catch(g){te(r,n,g)}}break;case 5:Ln(d,d.return);break;case 22:if(d.memoizedState!==null){Sa(h);continue}}v!==null?(v.return=d,N=v):Sa(h)}c=c.sibling}e:for(c=null,h=e;;){if(h.tag===5){if(c===null){c=h;try{o=h.stateNode,a?(i=o.style,typeof i.setProperty=="function"?i.setProperty("display","none","important"):i.display="none"):(s=h.stateNode,u=h.memoizedProps.style,l=u!=null&&u.hasOwnProperty("display")?u.display:null,s.style.display=xc("display",l))}catch(g){te(e,e.return,g)}}}else if(h.tag===6){if(c===null)try{h.stateNode.nodeValue=a?"":h.memoizedProps}catch(g){te(e,e.return,g)}}else if((h.tag!==22&&h.tag!==23||h.memoizedState===null||h===e)&&h.child!==null){h.child.return=h,h=h.child;continue}if(h===e)break e;for(;h.sibling===null;){if(h.return===null||h.return===e)break e;c===h&&(c=null),h=h.return}c===h&&(c=null),h.sibling.return=h.return,h=h.sibling}}break;case 19:qe(t,e),lt(e),r&4&&ga(e);break;case 21:break;default:qe(t,e),lt(e)}}function lt(e){var t=e.flags;if(t&2){try{e:{for(var n=e.return;n!==null;){if(Xf(n)){var r=n;break e}n=n.return}throw Error(R(160))}switch(r.tag){case 5:var o=r.stateNode;r.flags&32&&(Rr(o,""),r.flags&=-33);var i=ya(e);bl(e,i,o);break;case 3:case 4:var l=r.stateNode.containerInfo,s=ya(e);ql(e,s,l);break;default:throw Error(R(161))}}catch(u){te(e,e.return,u)}e.flags&=-3}t&4096&&(e.flags&=-4097)}function xm(e,t,n){N=e,qf(e)}function qf(e,t,n){for(var r=(e.mode&1)!==0;N!==null;){var o=N,i=o.child;if(o.tag===22&&r){var l=o.memoizedState!==null||fo;if(!l){var s=o.alternate,u=s!==null&&s.memoizedState!==null||Se;s=fo;var a=Se;if(fo=l,(Se=u)&&!a)for(N=o;N!==null;)l=N,u=l.child,l.tag===22&&l.memoizedState!==null?Ea(o):u!==null?(u.return=l,N=u):Ea(o);for(;i!==null;)N=i,qf(i),i=i.sibling;N=o,fo=s,Se=a}wa(e)}else o.subtreeFlags&8772&&i!==null?(i.return=o,N=i):wa(e)}}function wa(e){for(;N!==null;){var t=N;if(t.flags&8772){var n=t.alternate;try{if(t.flags&8772)switch(t.tag){case 0:case 11:case 15:Se||vi(5,t);break;case 1:var r=t.stateNode;if(t.flags&4&&!Se)if(n===null)r.componentDidMount();else{var o=t.elementType===t.type?n.memoizedProps:be(t.type,n.memoizedProps);r.componentDidUpdate(o,n.memoizedState,r.__reactInternalSnapshotBeforeUpdate)}var i=t.updateQueue;i!==null&&ra(t,i,r);break;case 3:var l=t.updateQueue;if(l!==null){if(n=null,t.child!==null)switch(t.child.tag){case 5:n=t.child.stateNode;break;case 1:n=t.child.stateNode}ra(t,l,n)}break;case 5:var s=t.stateNode;if(n===null&&t.flags&4){n=s;var u=t.memoizedProps;switch(t.type){case"button":case"input":case"select":case"textarea":u.autoFocus&&n.focus();break;case"img":u.src&&(n.src=u.src)}}break;case 6:break;case 4:break;case 12:break;case 13:if(t.memoizedState===null){var a=t.alternate;if(a!==null){var c=a.memoizedState;if(c!==null){var h=c.dehydrated;h!==null&&Or(h)}}}break;case 19:case 17:case 21:case 22:case 23:case 25:break;default:throw Error(R(163))}Se||t.flags&512&&Jl(t)}catch(d){te(t,t.return,d)}}if(t===e){N=null;break}if(n=t.sibling,n!==null){n.return=t.return,N=n;break}N=t.return}}function Sa(e){for(;N!==null;){var t=N;if(t===e){N=null;break}var n=t.sibling;if(n!==null){n.return=t.return,N=n;break}N=t.return}}function Ea(e){for(;N!==null;){var t=N;try{switch(t.tag){case 0:case 11:case 15:var n=t.return;try{vi(4,t)}catch(u){te(t,n,u)}break;case 1:var r=t.stateNode;if(typeof r.componentDidMount=="function"){var o=t.return;try{r.componentDidMount()}catch(u){te(t,o,u)}}var i=t.return;try{Jl(t)}catch(u){te(t,i,u)}break;case 5:var l=t.return;try{Jl(t)}catch(u){te(t,l,u)}}}catch(u){te(t,t.return,u)}if(t===e){N=null;break}var s=t.sibling;if(s!==null){s.return=t.return,N=s;break}N=t.return}}var km=Math.ceil,qo=Pt.ReactCurrentDispatcher,Zs=Pt.ReactCurrentOwner,Xe=Pt.ReactCurrentBatchConfig,A=0,de=null,ie=null,me=0,$e=0,On=Xt(0),ce=0,Wr=null,dn=0,yi=0,Js=0,Er=null,Ne=null,qs=0,Gn=1/0,ht=null,bo=!1,es=null,Wt=null,po=!1,Ft=null,ei=0,xr=0,ts=null,To=-1,No=0;function Ce(){return A&6?re():To!==-1?To:To=re()}function Ht(e){return e.mode&1?A&2&&me!==0?me&-me:lm.transition!==null?(No===0&&(No=Dc()),No):(e=W,e!==0||(e=window.event,e=e===void 0?16:Wc(e.type)),e):1}function ot(e,t,n,r){if(50<xr)throw xr=0,ts=null,Error(R(185));Kr(e,n,r),(!(A&2)||e!==de)&&(e===de&&(!(A&2)&&(yi|=n),ce===4&&It(e,me)),Ie(e,r),n===1&&A===0&&!(t.mode&1)&&(Gn=re()+500,pi&&Zt()))}function Ie(e,t){var n=e.callbackNode;lh(e,t);var r=zo(e,e===de?me:0);if(r===0)n!==null&&Lu(n),e.callbackNode=null,e.callbackPriority=0;else if(t=r&-r,e.callbackPriority!==t){if(n!=null&&Lu(n),t===1)e.tag===0?im(xa.bind(null,e)):uf(xa.bind(null,e)),tm(function(){!(A&6)&&Zt()}),n=null;else{switch(Fc(r)){case 1:n=Cs;break;case 4:n=jc;break;case 16:n=Fo;break;case 536870912:n=Ic;break;default:n=Fo}n=ld(n,bf.bind(null,e))}e.callbackPriority=t,e.callbackNode=n}}function bf(e,t){if(To=-1,No=0,A&6)throw Error(R(327));var n=e.callbackNode;if(An()&&e.callbackNode!==n)return null;var r=zo(e,e===de?me:0);if(r===0)return null;if(r&30||r&e.expiredLanes||t)t=ti(e,r);else{t=r;var o=A;A|=2;var i=td();(de!==e||me!==t)&&(ht=null,Gn=re()+500,sn(e,t));do try{Pm();break}catch(s){ed(e,s)}while(!0);zs(),qo.current=i,A=o,ie!==null?t=0:(de=null,me=0,t=ce)}if(t!==0){if(t===2&&(o=Tl(e),o!==0&&(r=o,t=ns(e,o))),t===1)throw n=Wr,sn(e,0),It(e,r),Ie(e,re()),n;if(t===6)It(e,r);else{if(o=e.current.alternate,!(r&30)&&!Cm(o)&&(t=ti(e,r),t===2&&(i=Tl(e),i!==0&&(r=i,t=ns(e,i))),t===1))throw n=Wr,sn(e,0),It(e,r),Ie(e,re()),n;switch(e.finishedWork=o,e.finishedLanes=r,t){case 0:case 1:throw Error(R(345));case 2:bt(e,Ne,ht);break;case 3:if(It(e,r),(r&130023424)===r&&(t=qs+500-re(),10<t)){if(zo(e,0)!==0)break;if(o=e.suspendedLanes,(o&r)!==r){Ce(),e.pingedLanes|=e.suspendedLanes&o;break}e.timeoutHandle=Fl(bt.bind(null,e,Ne,ht),t);break}bt(e,Ne,ht);break;case 4:if(It(e,r),(r&4194240)===r)break;for(t=e.eventTimes,o=-1;0<r;){var l=31-rt(r);i=1<<l,l=t[l],l>o&&(o=l),r&=~i}if(r=o,r=re()-r,r=(120>r?120:480>r?480:1080>r?1080:1920>r?1920:3e3>r?3e3:4320>r?4320:1960*km(r/1960))-r,10<r){e.timeoutHandle=Fl(bt.bind(null,e,Ne,ht),r);break}bt(e,Ne,ht);break;case 5:bt(e,Ne,ht);break;default:throw Error(R(329))}}}return Ie(e,re()),e.callbackNode===n?bf.bind(null,e):null}function ns(e,t){var n=Er;return e.current.memoizedState.isDehydrated&&(sn(e,t).flags|=256),e=ti(e,t),e!==2&&(t=Ne,Ne=n,t!==null&&rs(t)),e}function rs(e){Ne===null?Ne=e:Ne.push.apply(Ne,e)}function Cm(e){for(var t=e;;){if(t.flags&16384){var n=t.updateQueue;if(n!==null&&(n=n.stores,n!==null))for(var r=0;r<n.length;r++){var o=n[r],i=o.getSnapshot;o=o.value;try{if(!it(i(),o))return!1}catch{return!1}}}if(n=t.child,t.subtreeFlags&16384&&n!==null)n.return=t,t=n;else{if(t===e)break;for(;t.sibling===null;){if(t.return===null||t.return===e)return!0;t=t.return}t.sibling.return=t.return,t=t.sibling}}return!0}function It(e,t){for(t&=~Js,t&=~yi,e.suspendedLanes|=t,e.pingedLanes&=~t,e=e.expirationTimes;0<t;){var n=31-rt(t),r=1<<n;e[n]=-1,t&=~r}}function xa(e){if(A&6)throw Error(R(327));An();var t=zo(e,0);if(!(t&1))return Ie(e,re()),null;var n=ti(e,t);if(e.tag!==0&&n===2){var r=Tl(e);r!==0&&(t=r,n=ns(e,r))}if(n===1)throw n=Wr,sn(e,0),It(e,t),Ie(e,re()),n;if(n===6)throw Error(R(345));return e.finishedWork=e.current.alternate,e.finishedLanes=t,bt(e,Ne,ht),Ie(e,re()),null}function bs(e,t){var n=A;A|=1;try{return e(t)}finally{A=n,A===0&&(Gn=re()+500,pi&&Zt())}}function pn(e){Ft!==null&&Ft.tag===0&&!(A&6)&&An();var t=A;A|=1;var n=Xe.transition,r=W;try{if(Xe.transition=null,W=1,e)return e()}finally{W=r,Xe.transition=n,A=t,!(A&6)&&Zt()}}function eu(){$e=On.current,Y(On)}function sn(e,t){e.finishedWork=null,e.finishedLanes=0;var n=e.timeoutHandle;if(n!==-1&&(e.timeoutHandle=-1,em(n)),ie!==null)for(n=ie.return;n!==null;){var r=n;switch(Is(r),r.tag){case 1:r=r.type.childContextTypes,r!=null&&Wo();break;case 3:Kn(),Y(Me),Y(Ee),Hs();break;case 5:Ws(r);break;case 4:Kn();break;case 13:Y(q);break;case 19:Y(q);break;case 10:$s(r.type._context);break;case 22:case 23:eu()}n=n.return}if(de=e,ie=e=Vt(e.current,null),me=$e=t,ce=0,Wr=null,Js=yi=dn=0,Ne=Er=null,on!==null){for(t=0;t<on.length;t++)if(n=on[t],r=n.interleaved,r!==null){n.interleaved=null;var o=r.next,i=n.pending;if(i!==null){var l=i.next;i.next=o,r.next=l}n.pending=r}on=null}return e}function ed(e,t){do{var n=ie;try{if(zs(),_o.current=Jo,Zo){for(var r=b.memoizedState;r!==null;){var o=r.queue;o!==null&&(o.pending=null),r=r.next}Zo=!1}if(fn=0,fe=ae=b=null,wr=!1,Ar=0,Zs.current=null,n===null||n.return===null){ce=1,Wr=t,ie=null;break}e:{var i=e,l=n.return,s=n,u=t;if(t=me,s.flags|=32768,u!==null&&typeof u=="object"&&typeof u.then=="function"){var a=u,c=s,h=c.tag;if(!(c.mode&1)&&(h===0||h===11||h===15)){var d=c.alternate;d?(c.updateQueue=d.updateQueue,c.memoizedState=d.memoizedState,c.lanes=d.lanes):(c.updateQueue=null,c.memoizedState=null)}var v=aa(l);if(v!==null){v.flags&=-257,ca(v,l,s,i,t),v.mode&1&&ua(i,a,t),t=v,u=a;var y=t.updateQueue;if(y===null){var g=new Set;g.add(u),t.updateQueue=g}else y.add(u);break e}else{if(!(t&1)){ua(i,a,t),tu();break e}u=Error(R(426))}}else if(Z&&s.mode&1){var x=aa(l);if(x!==null){!(x.flags&65536)&&(x.flags|=256),ca(x,l,s,i,t),Ds(Qn(u,s));break e}}i=u=Qn(u,s),ce!==4&&(ce=2),Er===null?Er=[i]:Er.push(i),i=l;do{switch(i.tag){case 3:i.flags|=65536,t&=-t,i.lanes|=t;var m=zf(i,u,t);na(i,m);break e;case 1:s=u;var f=i.type,p=i.stateNode;if(!(i.flags&128)&&(typeof f.getDerivedStateFromError=="function"||p!==null&&typeof p.componentDidCatch=="function"&&(Wt===null||!Wt.has(p)))){i.flags|=65536,t&=-t,i.lanes|=t;var S=$f(i,s,t);na(i,S);break e}}i=i.return}while(i!==null)}rd(n)}catch(k){t=k,ie===n&&n!==null&&(ie=n=n.return);continue}break}while(!0)}function td(){var e=qo.current;return qo.current=Jo,e===null?Jo:e}function tu(){(ce===0||ce===3||ce===2)&&(ce=4),de===null||!(dn&268435455)&&!(yi&268435455)||It(de,me)}function ti(e,t){var n=A;A|=2;var r=td();(de!==e||me!==t)&&(ht=null,sn(e,t));do try{_m();break}catch(o){ed(e,o)}while(!0);if(zs(),A=n,qo.current=r,ie!==null)throw Error(R(261));return de=null,me=0,ce}function _m(){for(;ie!==null;)nd(ie)}function Pm(){for(;ie!==null&&!Jp();)nd(ie)}function nd(e){var t=id(e.alternate,e,$e);e.memoizedProps=e.pendingProps,t===null?rd(e):ie=t,Zs.current=null}function rd(e){var t=e;do{var n=t.alternate;if(e=t.return,t.flags&32768){if(n=wm(n,t),n!==null){n.flags&=32767,ie=n;return}if(e!==null)e.flags|=32768,e.subtreeFlags=0,e.deletions=null;else{ce=6,ie=null;return}}else if(n=gm(n,t,$e),n!==null){ie=n;return}if(t=t.sibling,t!==null){ie=t;return}ie=t=e}while(t!==null);ce===0&&(ce=5)}function bt(e,t,n){var r=W,o=Xe.transition;try{Xe.transition=null,W=1,Rm(e,t,n,r)}finally{Xe.transition=o,W=r}return null}function Rm(e,t,n,r){do An();while(Ft!==null);if(A&6)throw Error(R(327));n=e.finishedWork;var o=e.finishedLanes;if(n===null)return null;if(e.finishedWork=null,e.finishedLanes=0,n===e.current)throw Error(R(177));e.callbackNode=null,e.callbackPriority=0;var i=n.lanes|n.childLanes;if(sh(e,i),e===de&&(ie=de=null,me=0),!(n.subtreeFlags&2064)&&!(n.flags&2064)||po||(po=!0,ld(Fo,function(){return An(),null})),i=(n.flags&15990)!==0,n.subtreeFlags&15990||i){i=Xe.transition,Xe.transition=null;var l=W;W=1;var s=A;A|=4,Zs.current=null,Em(e,n),Jf(n,e),Gh(Il),$o=!!jl,Il=jl=null,e.current=n,xm(n),qp(),A=s,W=l,Xe.transition=i}else e.current=n;if(po&&(po=!1,Ft=e,ei=o),i=e.pendingLanes,i===0&&(Wt=null),th(n.stateNode),Ie(e,re()),t!==null)for(r=e.onRecoverableError,n=0;n<t.length;n++)o=t[n],r(o.value,{componentStack:o.stack,digest:o.digest});if(bo)throw bo=!1,e=es,es=null,e;return ei&1&&e.tag!==0&&An(),i=e.pendingLanes,i&1?e===ts?xr++:(xr=0,ts=e):xr=0,Zt(),null}function An(){if(Ft!==null){var e=Fc(ei),t=Xe.transition,n=W;try{if(Xe.transition=null,W=16>e?16:e,Ft===null)var r=!1;else{if(e=Ft,Ft=null,ei=0,A&6)throw Error(R(331));var o=A;for(A|=4,N=e.current;N!==null;){var i=N,l=i.child;if(N.flags&16){var s=i.deletions;if(s!==null){for(var u=0;u<s.length;u++){var a=s[u];for(N=a;N!==null;){var c=N;switch(c.tag){case 0:case 11:case 15:Sr(8,c,i)}var h=c.child;if(h!==null)h.return=c,N=h;else for(;N!==null;){c=N;var d=c.sibling,v=c.return;if(Yf(c),c===a){N=null;break}if(d!==null){d.return=v,N=d;break}N=v}}}var y=i.alternate;if(y!==null){var g=y.child;if(g!==null){y.child=null;do{var x=g.sibling;g.sibling=null,g=x}while(g!==null)}}N=i}}if(i.subtreeFlags&2064&&l!==null)l.return=i,N=l;else e:for(;N!==null;){if(i=N,i.flags&2048)switch(i.tag){case 0:case 11:case 15:Sr(9,i,i.return)}var m=i.sibling;if(m!==null){m.return=i.return,N=m;break e}N=i.return}}var f=e.current;for(N=f;N!==null;){l=N;var p=l.child;if(l.subtreeFlags&2064&&p!==null)p.return=l,N=p;else e:for(l=f;N!==null;){if(s=N,s.flags&2048)try{switch(s.tag){case 0:case 11:case 15:vi(9,s)}}catch(k){te(s,s.return,k)}if(s===l){N=null;break e}var S=s.sibling;if(S!==null){S.return=s.return,N=S;break e}N=s.return}}if(A=o,Zt(),ft&&typeof ft.onPostCommitFiberRoot=="function")try{ft.onPostCommitFiberRoot(ui,e)}catch{}r=!0}return r}finally{W=n,Xe.transition=t}}return!1}function ka(e,t,n){t=Qn(n,t),t=zf(e,t,1),e=Ut(e,t,1),t=Ce(),e!==null&&(Kr(e,1,t),Ie(e,t))}function te(e,t,n){if(e.tag===3)ka(e,e,n);else for(;t!==null;){if(t.tag===3){ka(t,e,n);break}else if(t.tag===1){var r=t.stateNode;if(typeof t.type.getDerivedStateFromError=="function"||typeof r.componentDidCatch=="function"&&(Wt===null||!Wt.has(r))){e=Qn(n,e),e=$f(t,e,1),t=Ut(t,e,1),e=Ce(),t!==null&&(Kr(t,1,e),Ie(t,e));break}}t=t.return}}function Tm(e,t,n){var r=e.pingCache;r!==null&&r.delete(t),t=Ce(),e.pingedLanes|=e.suspendedLanes&n,de===e&&(me&n)===n&&(ce===4||ce===3&&(me&130023424)===me&&500>re()-qs?sn(e,0):Js|=n),Ie(e,t)}function od(e,t){t===0&&(e.mode&1?(t=no,no<<=1,!(no&130023424)&&(no=4194304)):t=1);var n=Ce();e=kt(e,t),e!==null&&(Kr(e,t,n),Ie(e,n))}function Nm(e){var t=e.memoizedState,n=0;t!==null&&(n=t.retryLane),od(e,n)}function Lm(e,t){var n=0;switch(e.tag){case 13:var r=e.stateNode,o=e.memoizedState;o!==null&&(n=o.retryLane);break;case 19:r=e.stateNode;break;default:throw Error(R(314))}r!==null&&r.delete(t),od(e,n)}var id;id=function(e,t,n){if(e!==null)if(e.memoizedProps!==t.pendingProps||Me.current)Oe=!0;else{if(!(e.lanes&n)&&!(t.flags&128))return Oe=!1,ym(e,t,n);Oe=!!(e.flags&131072)}else Oe=!1,Z&&t.flags&1048576&&af(t,Ko,t.index);switch(t.lanes=0,t.tag){case 2:var r=t.type;Ro(e,t),e=t.pendingProps;var o=Wn(t,Ee.current);$n(t,n),o=Ks(null,t,r,e,o,n);var i=Qs();return t.flags|=1,typeof o=="object"&&o!==null&&typeof o.render=="function"&&o.$$typeof===void 0?(t.tag=1,t.memoizedState=null,t.updateQueue=null,je(r)?(i=!0,Ho(t)):i=!1,t.memoizedState=o.state!==null&&o.state!==void 0?o.state:null,Bs(t),o.updater=mi,t.stateNode=o,o._reactInternals=t,Hl(t,r,e,n),t=Ql(null,t,r,!0,i,n)):(t.tag=0,Z&&i&&js(t),ke(null,t,o,n),t=t.child),t;case 16:r=t.elementType;e:{switch(Ro(e,t),e=t.pendingProps,o=r._init,r=o(r._payload),t.type=r,o=t.tag=Mm(r),e=be(r,e),o){case 0:t=Kl(null,t,r,e,n);break e;case 1:t=pa(null,t,r,e,n);break e;case 11:t=fa(null,t,r,e,n);break e;case 14:t=da(null,t,r,be(r.type,e),n);break e}throw Error(R(306,r,""))}return t;case 0:return r=t.type,o=t.pendingProps,o=t.elementType===r?o:be(r,o),Kl(e,t,r,o,n);case 1:return r=t.type,o=t.pendingProps,o=t.elementType===r?o:be(r,o),pa(e,t,r,o,n);case 3:e:{if(Wf(t),e===null)throw Error(R(387));r=t.pendingProps,i=t.memoizedState,o=i.element,mf(e,t),Yo(t,r,null,n);var l=t.memoizedState;if(r=l.element,i.isDehydrated)if(i={element:r,isDehydrated:!1,cache:l.cache,pendingSuspenseBoundaries:l.pendingSuspenseBoundaries,transitions:l.transitions},t.updateQueue.baseState=i,t.memoizedState=i,t.flags&256){o=Qn(Error(R(423)),t),t=ha(e,t,r,n,o);break e}else if(r!==o){o=Qn(Error(R(424)),t),t=ha(e,t,r,n,o);break e}else for(Be=Bt(t.stateNode.containerInfo.firstChild),Ue=t,Z=!0,tt=null,n=pf(t,null,r,n),t.child=n;n;)n.flags=n.flags&-3|4096,n=n.sibling;else{if(Hn(),r===o){t=Ct(e,t,n);break e}ke(e,t,r,n)}t=t.child}return t;case 5:return vf(t),e===null&&Bl(t),r=t.type,o=t.pendingProps,i=e!==null?e.memoizedProps:null,l=o.children,Dl(r,o)?l=null:i!==null&&Dl(r,i)&&(t.flags|=32),Uf(e,t),ke(e,t,l,n),t.child;case 6:return e===null&&Bl(t),null;case 13:return Hf(e,t,n);case 4:return Us(t,t.stateNode.containerInfo),r=t.pendingProps,e===null?t.child=Vn(t,null,r,n):ke(e,t,r,n),t.child;case 11:return r=t.type,o=t.pendingProps,o=t.elementType===r?o:be(r,o),fa(e,t,r,o,n);case 7:return ke(e,t,t.pendingProps,n),t.child;case 8:return ke(e,t,t.pendingProps.children,n),t.child;case 12:return ke(e,t,t.pendingProps.children,n),t.child;case 10:e:{if(r=t.type._context,o=t.pendingProps,i=t.memoizedProps,l=o.value,K(Qo,r._currentValue),r._currentValue=l,i!==null)if(it(i.value,l)){if(i.children===o.children&&!Me.current){t=Ct(e,t,n);break e}}else for(i=t.child,i!==null&&(i.return=t);i!==null;){var s=i.dependencies;if(s!==null){l=i.child;for(var u=s.firstContext;u!==null;){if(u.context===r){if(i.tag===1){u=wt(-1,n&-n),u.tag=2;var a=i.updateQueue;if(a!==null){a=a.shared;var c=a.pending;c===null?u.next=u:(u.next=c.next,c.next=u),a.pending=u}}i.lanes|=n,u=i.alternate,u!==null&&(u.lanes|=n),Ul(i.return,n,t),s.lanes|=n;break}u=u.next}}else if(i.tag===10)l=i.type===t.type?null:i.child;else if(i.tag===18){if(l=i.return,l===null)throw Error(R(341));l.lanes|=n,s=l.alternate,s!==null&&(s.lanes|=n),Ul(l,n,t),l=i.sibling}else l=i.child;if(l!==null)l.return=i;else for(l=i;l!==null;){if(l===t){l=null;break}if(i=l.sibling,i!==null){i.return=l.return,l=i;break}l=l.return}i=l}ke(e,t,o.children,n),t=t.child}return t;case 9:return o=t.type,r=t.pendingProps.children,$n(t,n),o=Ze(o),r=r(o),t.flags|=1,ke(e,t,r,n),t.child;case 14:return r=t.type,o=be(r,t.pendingProps),o=be(r.type,o),da(e,t,r,o,n);case 15:return Af(e,t,t.type,t.pendingProps,n);case 17:return r=t.type,o=t.pendingProps,o=t.elementType===r?o:be(r,o),Ro(e,t),t.tag=1,je(r)?(e=!0,Ho(t)):e=!1,$n(t,n),Ff(t,r,o),Hl(t,r,o,n),Ql(null,t,r,!0,e,n);case 19:return Vf(e,t,n);case 22:return Bf(e,t,n)}throw Error(R(156,t.tag))};function ld(e,t){return Mc(e,t)}function Om(e,t,n,r){this.tag=e,this.key=n,this.sibling=this.child=this.return=this.stateNode=this.type=this.elementType=null,this.index=0,this.ref=null,this.pendingProps=t,this.dependencies=this.memoizedState=this.updateQueue=this.memoizedProps=null,this.mode=r,this.subtreeFlags=this.flags=0,this.deletions=null,this.childLanes=this.lanes=0,this.alternate=null}function Ye(e,t,n,r){return new Om(e,t,n,r)}function nu(e){return e=e.prototype,!(!e||!e.isReactComponent)}function Mm(e){if(typeof e=="function")return nu(e)?1:0;if(e!=null){if(e=e.$$typeof,e===Es)return 11;if(e===xs)return 14}return 2}function Vt(e,t){var n=e.alternate;return n===null?(n=Ye(e.tag,t,e.key,e.mode),n.elementType=e.elementType,n.type=e.type,n.stateNode=e.stateNode,n.alternate=e,e.alternate=n):(n.pendingProps=t,n.type=e.type,n.flags=0,n.subtreeFlags=0,n.deletions=null),n.flags=e.flags&14680064,n.childLanes=e.childLanes,n.lanes=e.lanes,n.child=e.child,n.memoizedProps=e.memoizedProps,n.memoizedState=e.memoizedState,n.updateQueue=e.updateQueue,t=e.dependencies,n.dependencies=t===null?null:{lanes:t.lanes,firstContext:t.firstContext},n.sibling=e.sibling,n.index=e.index,n.ref=e.ref,n}function Lo(e,t,n,r,o,i){var l=2;if(r=e,typeof e=="function")nu(e)&&(l=1);else if(typeof e=="string")l=5;else e:switch(e){case En:return un(n.children,o,i,t);case Ss:l=8,o|=8;break;case pl:return e=Ye(12,n,t,o|2),e.elementType=pl,e.lanes=i,e;case hl:return e=Ye(13,n,t,o),e.elementType=hl,e.lanes=i,e;case ml:return e=Ye(19,n,t,o),e.elementType=ml,e.lanes=i,e;case mc:return gi(n,o,i,t);default:if(typeof e=="object"&&e!==null)switch(e.$$typeof){case pc:l=10;break e;case hc:l=9;break e;case Es:l=11;break e;case xs:l=14;break e;case Lt:l=16,r=null;break e}throw Error(R(130,e==null?e:typeof e,""))}return t=Ye(l,n,t,o),t.elementType=e,t.type=r,t.lanes=i,t}function un(e,t,n,r){return e=Ye(7,e,r,t),e.lanes=n,e}function gi(e,t,n,r){return e=Ye(22,e,r,t),e.elementType=mc,e.lanes=n,e.stateNode={isHidden:!1},e}function bi(e,t,n){return e=Ye(6,e,null,t),e.lanes=n,e}function el(e,t,n){return t=Ye(4,e.children!==null?e.children:[],e.key,t),t.lanes=n,t.stateNode={containerInfo:e.containerInfo,pendingChildren:null,implementation:e.implementation},t}function jm(e,t,n,r,o){this.tag=t,this.containerInfo=e,this.finishedWork=this.pingCache=this.current=this.pendingChildren=null,this.timeoutHandle=-1,this.callbackNode=this.pendingContext=this.context=null,this.callbackPriority=0,this.eventTimes=Ii(0),this.expirationTimes=Ii(-1),this.entangledLanes=this.finishedLanes=this.mutableReadLanes=this.expiredLanes=this.pingedLanes=this.suspendedLanes=this.pendingLanes=0,this.entanglements=Ii(0),this.identifierPrefix=r,this.onRecoverableError=o,this.mutableSourceEagerHydrationData=null}function ru(e,t,n,r,o,i,l,s,u){return e=new jm(e,t,n,s,u),t===1?(t=1,i===!0&&(t|=8)):t=0,i=Ye(3,null,null,t),e.current=i,i.stateNode=e,i.memoizedState={element:r,isDehydrated:n,cache:null,transitions:null,pendingSuspenseBoundaries:null},Bs(i),e}function Im(e,t,n){var r=3<arguments.length&&arguments[3]!==void 0?arguments[3]:null;return{$$typeof:Sn,key:r==null?null:""+r,children:e,containerInfo:t,implementation:n}}function sd(e){if(!e)return Gt;e=e._reactInternals;e:{if(mn(e)!==e||e.tag!==1)throw Error(R(170));var t=e;do{switch(t.tag){case 3:t=t.stateNode.context;break e;case 1:if(je(t.type)){t=t.stateNode.__reactInternalMemoizedMergedChildContext;break e}}t=t.return}while(t!==null);throw Error(R(171))}if(e.tag===1){var n=e.type;if(je(n))return sf(e,n,t)}return t}function ud(e,t,n,r,o,i,l,s,u){return e=ru(n,r,!0,e,o,i,l,s,u),e.context=sd(null),n=e.current,r=Ce(),o=Ht(n),i=wt(r,o),i.callback=t??null,Ut(n,i,o),e.current.lanes=o,Kr(e,o,r),Ie(e,r),e}function wi(e,t,n,r){var o=t.current,i=Ce(),l=Ht(o);return n=sd(n),t.context===null?t.context=n:t.pendingContext=n,t=wt(i,l),t.payload={element:e},r=r===void 0?null:r,r!==null&&(t.callback=r),e=Ut(o,t,l),e!==null&&(ot(e,o,l,i),Co(e,o,l)),l}function ni(e){if(e=e.current,!e.child)return null;switch(e.child.tag){case 5:return e.child.stateNode;default:return e.child.stateNode}}function Ca(e,t){if(e=e.memoizedState,e!==null&&e.dehydrated!==null){var n=e.retryLane;e.retryLane=n!==0&&n<t?n:t}}function ou(e,t){Ca(e,t),(e=e.alternate)&&Ca(e,t)}function Dm(){return null}var ad=typeof reportError=="function"?reportError:function(e){console.error(e)};function iu(e){this._internalRoot=e}Si.prototype.render=iu.prototype.render=function(e){var t=this._internalRoot;if(t===null)throw Error(R(409));wi(e,t,null,null)};Si.prototype.unmount=iu.prototype.unmount=function(){var e=this._internalRoot;if(e!==null){this._internalRoot=null;var t=e.containerInfo;pn(function(){wi(null,e,null,null)}),t[xt]=null}};function Si(e){this._internalRoot=e}Si.prototype.unstable_scheduleHydration=function(e){if(e){var t=Ac();e={blockedOn:null,target:e,priority:t};for(var n=0;n<jt.length&&t!==0&&t<jt[n].priority;n++);jt.splice(n,0,e),n===0&&Uc(e)}};function lu(e){return!(!e||e.nodeType!==1&&e.nodeType!==9&&e.nodeType!==11)}function Ei(e){return!(!e||e.nodeType!==1&&e.nodeType!==9&&e.nodeType!==11&&(e.nodeType!==8||e.nodeValue!==" react-mount-point-unstable "))}function _a(){}function Fm(e,t,n,r,o){if(o){if(typeof r=="function"){var i=r;r=function(){var a=ni(l);i.call(a)}}var l=ud(t,r,e,0,null,!1,!1,"",_a);return e._reactRootContainer=l,e[xt]=l.current,Ir(e.nodeType===8?e.parentNode:e),pn(),l}for(;o=e.lastChild;)e.removeChild(o);if(typeof r=="function"){var s=r;r=function(){var a=ni(u);s.call(a)}}var u=ru(e,0,!1,null,null,!1,!1,"",_a);return e._reactRootContainer=u,e[xt]=u.current,Ir(e.nodeType===8?e.parentNode:e),pn(function(){wi(t,u,n,r)}),u}function xi(e,t,n,r,o){var i=n._reactRootContainer;if(i){var l=i;if(typeof o=="function"){var s=o;o=function(){var u=ni(l);s.call(u)}}wi(t,l,e,o)}else l=Fm(n,t,e,o,r);return ni(l)}zc=function(e){switch(e.tag){case 3:var t=e.stateNode;if(t.current.memoizedState.isDehydrated){var n=fr(t.pendingLanes);n!==0&&(_s(t,n|1),Ie(t,re()),!(A&6)&&(Gn=re()+500,Zt()))}break;case 13:pn(function(){var r=kt(e,1);if(r!==null){var o=Ce();ot(r,e,1,o)}}),ou(e,1)}};Ps=function(e){if(e.tag===13){var t=kt(e,134217728);if(t!==null){var n=Ce();ot(t,e,134217728,n)}ou(e,134217728)}};$c=function(e){if(e.tag===13){var t=Ht(e),n=kt(e,t);if(n!==null){var r=Ce();ot(n,e,t,r)}ou(e,t)}};Ac=function(){return W};Bc=function(e,t){var n=W;try{return W=e,t()}finally{W=n}};_l=function(e,t,n){switch(t){case"input":if(gl(e,n),t=n.name,n.type==="radio"&&t!=null){for(n=e;n.parentNode;)n=n.parentNode;for(n=n.querySelectorAll("input[name="+JSON.stringify(""+t)+'][type="radio"]'),t=0;t<n.length;t++){var r=n[t];if(r!==e&&r.form===e.form){var o=di(r);if(!o)throw Error(R(90));yc(r),gl(r,o)}}}break;case"textarea":wc(e,n);break;case"select":t=n.value,t!=null&&In(e,!!n.multiple,t,!1)}};Pc=bs;Rc=pn;var zm={usingClientEntryPoint:!1,Events:[Gr,_n,di,Cc,_c,bs]},sr={findFiberByHostInstance:rn,bundleType:0,version:"18.3.1",rendererPackageName:"react-dom"},$m={bundleType:sr.bundleType,version:sr.version,rendererPackageName:sr.rendererPackageName,rendererConfig:sr.rendererConfig,overrideHookState:null,overrideHookStateDeletePath:null,overrideHookStateRenamePath:null,overrideProps:null,overridePropsDeletePath:null,overridePropsRenamePath:null,setErrorHandler:null,setSuspenseHandler:null,scheduleUpdate:null,currentDispatcherRef:Pt.ReactCurrentDispatcher,findHostInstanceByFiber:function(e){return e=Lc(e),e===null?null:e.stateNode},findFiberByHostInstance:sr.findFiberByHostInstance||Dm,findHostInstancesForRefresh:null,scheduleRefresh:null,scheduleRoot:null,setRefreshHandler:null,getCurrentFiber:null,reconcilerVersion:"18.3.1-next-f1338f8080-20240426"};if(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__<"u"){var ho=__REACT_DEVTOOLS_GLOBAL_HOOK__;if(!ho.isDisabled&&ho.supportsFiber)try{ui=ho.inject($m),ft=ho}catch{}}He.__SECRET_INTERNALS_DO_NOT_USE_OR_YOU_WILL_BE_FIRED=zm;He.createPortal=function(e,t){var n=2<arguments.length&&arguments[2]!==void 0?arguments[2]:null;if(!lu(t))throw Error(R(200));return Im(e,t,null,n)};He.createRoot=function(e,t){if(!lu(e))throw Error(R(299));var n=!1,r="",o=ad;return t!=null&&(t.unstable_strictMode===!0&&(n=!0),t.identifierPrefix!==void 0&&(r=t.identifierPrefix),t.onRecoverableError!==void 0&&(o=t.onRecoverableError)),t=ru(e,1,!1,null,null,n,!1,r,o),e[xt]=t.current,Ir(e.nodeType===8?e.parentNode:e),new iu(t)};He.findDOMNode=function(e){if(e==null)return null;if(e.nodeType===1)return e;var t=e._reactInternals;if(t===void 0)throw typeof e.render=="function"?Error(R(188)):(e=Object.keys(e).join(","),Error(R(268,e)));return e=Lc(t),e=e===null?null:e.stateNode,e};He.flushSync=function(e){return pn(e)};He.hydrate=function(e,t,n){if(!Ei(t))throw Error(R(200));return xi(null,e,t,!0,n)};He.hydrateRoot=function(e,t,n){if(!lu(e))throw Error(R(405));var r=n!=null&&n.hydratedSources||null,o=!1,i="",l=ad;if(n!=null&&(n.unstable_strictMode===!0&&(o=!0),n.identifierPrefix!==void 0&&(i=n.identifierPrefix),n.onRecoverableError!==void 0&&(l=n.onRecoverableError)),t=ud(t,null,e,1,n??null,o,!1,i,l),e[xt]=t.current,Ir(e),r)for(e=0;e<r.length;e++)n=r[e],o=n._getVersion,o=o(n._source),t.mutableSourceEagerHydrationData==null?t.mutableSourceEagerHydrationData=[n,o]:t.mutableSourceEagerHydrationData.push(n,o);return new Si(t)};He.render=function(e,t,n){if(!Ei(t))throw Error(R(200));return xi(null,e,t,!1,n)};He.unmountComponentAtNode=function(e){if(!Ei(e))throw Error(R(40));return e._reactRootContainer?(pn(function(){xi(null,null,e,!1,function(){e._reactRootContainer=null,e[xt]=null})}),!0):!1};He.unstable_batchedUpdates=bs;He.unstable_renderSubtreeIntoContainer=function(e,t,n,r){if(!Ei(n))throw Error(R(200));if(e==null||e._reactInternals===void 0)throw Error(R(38));return xi(e,t,n,!1,r)};He.version="18.3.1-next-f1338f8080-20240426";function cd(){if(!(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__>"u"||typeof __REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE!="function"))try{__REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE(cd)}catch(e){console.error(e)}}cd(),ac.exports=He;var su=ac.exports;const Mn=li(su);var Pa=su;fl.createRoot=Pa.createRoot,fl.hydrateRoot=Pa.hydrateRoot;const Ra="pushstate",Ta="popstate",fd="beforeunload",dd=e=>(e.preventDefault(),e.returnValue=""),Am=()=>{removeEventListener(fd,dd,{capture:!0})};function pd(e){let t=e.getLocation();const n=new Set;let r=[];const o=()=>{t=e.getLocation(),n.forEach(l=>l())},i=async l=>{var s;if(typeof document<"u"&&r.length){for(const u of r)if(!await u()){(s=e.onBlocked)==null||s.call(e,o);return}}l()};return{get location(){return t},subscribe:l=>(n.add(l),()=>{n.delete(l)}),push:(l,s)=>{s=kr(s),i(()=>{e.pushState(l,s),o()})},replace:(l,s)=>{s=kr(s),i(()=>{e.replaceState(l,s),o()})},go:l=>{i(()=>{e.go(l),o()})},back:()=>{i(()=>{e.back(),o()})},forward:()=>{i(()=>{e.forward(),o()})},createHref:l=>e.createHref(l),block:l=>(r.push(l),r.length===1&&addEventListener(fd,dd,{capture:!0}),()=>{r=r.filter(s=>s!==l),r.length||Am()}),flush:()=>{var l;return(l=e.flush)==null?void 0:l.call(e)},destroy:()=>{var l;return(l=e.destroy)==null?void 0:l.call(e)},notify:o}}function kr(e){return e||(e={}),{...e,key:hd()}}function Bm(e){const t=typeof document<"u"?window:void 0,n=t.history.pushState,r=t.history.replaceState,o=g=>g,i=()=>os(`${t.location.pathname}${t.location.search}${t.location.hash}`,t.history.state);let l=i(),s;const u=()=>l;let a,c;const h=()=>{if(!a)return;(a.isPush?n:r).call(t.history,a.state,"",a.href),a=void 0,c=void 0,s=void 0},d=(g,x,m)=>{const f=o(x);c||(s=l),l=os(x,m),a={href:f,state:m,isPush:(a==null?void 0:a.isPush)||g==="push"},c||(c=Promise.resolve().then(()=>h()))},v=()=>{l=i(),y.notify()},y=pd({getLocation:u,pushState:(g,x)=>d("push",g,x),replaceState:(g,x)=>d("replace",g,x),back:()=>t.history.back(),forward:()=>t.history.forward(),go:g=>t.history.go(g),createHref:g=>o(g),flush:h,destroy:()=>{t.history.pushState=n,t.history.replaceState=r,t.removeEventListener(Ra,v),t.removeEventListener(Ta,v)},onBlocked:g=>{s&&l!==s&&(l=s,g())}});return t.addEventListener(Ra,v),t.addEventListener(Ta,v),t.history.pushState=function(...g){const x=n.apply(t.history,g);return v(),x},t.history.replaceState=function(...g){const x=r.apply(t.history,g);return v(),x},y}function Um(e={initialEntries:["/"]}){const t=e.initialEntries;let n=e.initialIndex??t.length-1,r={key:hd()};return pd({getLocation:()=>os(t[n],r),pushState:(i,l)=>{r=l,t.push(i),n=Math.max(t.length-1,0)},replaceState:(i,l)=>{r=l,t[n]=i},back:()=>{r=kr(r),n--},forward:()=>{r=kr(r),n=Math.min(n+1,t.length-1)},go:i=>{r=kr(r),n=Math.min(Math.max(n+i,0),t.length-1)},createHref:i=>i})}function os(e,t){const n=e.indexOf("#"),r=e.indexOf("?");return{href:e,pathname:e.substring(0,n>0?r>0?Math.min(n,r):n:r>0?r:e.length),hash:n>-1?e.substring(n):"",search:r>-1?e.slice(r,n===-1?void 0:n):"",state:t||{}}}function hd(){return(Math.random()+1).toString(36).substring(7)}var Wm="Invariant failed";function Le(e,t){if(!e)throw new Error(Wm)}const tl=w.createContext(null);function md(){return typeof document>"u"?tl:window.__TSR_ROUTER_CONTEXT__?window.__TSR_ROUTER_CONTEXT__:(window.__TSR_ROUTER_CONTEXT__=tl,tl)}function Jt(e){const t=w.useContext(md());return e==null||e.warn,t}var vd={exports:{}},yd={},gd={exports:{}},wd={};/**
- * @license React
- * use-sync-external-store-shim.production.min.js
- *
- * Copyright (c) Facebook, Inc. and its affiliates.
- *
- * This source code is licensed under the MIT license found in the
- * LICENSE file in the root directory of this source tree.
- */var Yn=w;function Hm(e,t){return e===t&&(e!==0||1/e===1/t)||e!==e&&t!==t}var Vm=typeof Object.is=="function"?Object.is:Hm,Km=Yn.useState,Qm=Yn.useEffect,Gm=Yn.useLayoutEffect,Ym=Yn.useDebugValue;function Xm(e,t){var n=t(),r=Km({inst:{value:n,getSnapshot:t}}),o=r[0].inst,i=r[1];return Gm(function(){o.value=n,o.getSnapshot=t,nl(o)&&i({inst:o})},[e,n,t]),Qm(function(){return nl(o)&&i({inst:o}),e(function(){nl(o)&&i({inst:o})})},[e]),Ym(n),n}function nl(e){var t=e.getSnapshot;e=e.value;try{var n=t();return!Vm(e,n)}catch{return!0}}function Zm(e,t){return t()}var Jm=typeof window>"u"||typeof window.document>"u"||typeof window.document.createElement>"u"?Zm:Xm;wd.useSyncExternalStore=Yn.useSyncExternalStore!==void 0?Yn.useSyncExternalStore:Jm;gd.exports=wd;var qm=gd.exports;/**
- * @license React
- * use-sync-external-store-shim/with-selector.production.min.js
- *
- * Copyright (c) Facebook, Inc. and its affiliates.
- *
- * This source code is licensed under the MIT license found in the
- * LICENSE file in the root directory of this source tree.
- */var ki=w,bm=qm;function ev(e,t){return e===t&&(e!==0||1/e===1/t)||e!==e&&t!==t}var tv=typeof Object.is=="function"?Object.is:ev,nv=bm.useSyncExternalStore,rv=ki.useRef,ov=ki.useEffect,iv=ki.useMemo,lv=ki.useDebugValue;yd.useSyncExternalStoreWithSelector=function(e,t,n,r,o){var i=rv(null);if(i.current===null){var l={hasValue:!1,value:null};i.current=l}else l=i.current;i=iv(function(){function u(v){if(!a){if(a=!0,c=v,v=r(v),o!==void 0&&l.hasValue){var y=l.value;if(o(y,v))return h=y}return h=v}if(y=h,tv(c,v))return y;var g=r(v);return o!==void 0&&o(y,g)?y:(c=v,h=g)}var a=!1,c,h,d=n===void 0?null:n;return[function(){return u(t())},d===null?void 0:function(){return u(d())}]},[t,n,r,o]);var s=nv(e,i[0],i[1]);return ov(function(){l.hasValue=!0,l.value=s},[s]),lv(s),s};vd.exports=yd;var sv=vd.exports,uv=class{constructor(e,t){this.listeners=new Set,this._batching=!1,this._flushing=0,this._nextPriority=null,this.subscribe=n=>{var o,i;this.listeners.add(n);const r=(i=(o=this.options)==null?void 0:o.onSubscribe)==null?void 0:i.call(o,n,this);return()=>{this.listeners.delete(n),r==null||r()}},this.setState=(n,r)=>{var l,s,u,a,c;const o=this.state;this.state=(l=this.options)!=null&&l.updateFn?this.options.updateFn(o)(n):n(o);const i=(r==null?void 0:r.priority)??((s=this.options)==null?void 0:s.defaultPriority)??"high";this._nextPriority===null?this._nextPriority=i:this._nextPriority==="high"?this._nextPriority=i:this._nextPriority=((u=this.options)==null?void 0:u.defaultPriority)??"high",(c=(a=this.options)==null?void 0:a.onUpdate)==null||c.call(a,{priority:this._nextPriority}),this._flush()},this._flush=()=>{if(this._batching)return;const n=++this._flushing;this.listeners.forEach(r=>{this._flushing===n&&r({priority:this._nextPriority??"high"})})},this.batch=n=>{if(this._batching)return n();this._batching=!0,n(),this._batching=!1,this._flush()},this.state=e,this.options=t}};function av(e,t=n=>n){return sv.useSyncExternalStoreWithSelector(e.subscribe,()=>e.state,()=>e.state,t,cv)}function cv(e,t){if(Object.is(e,t))return!0;if(typeof e!="object"||e===null||typeof t!="object"||t===null)return!1;const n=Object.keys(e);if(n.length!==Object.keys(t).length)return!1;for(let r=0;r<n.length;r++)if(!Object.prototype.hasOwnProperty.call(t,n[r])||!Object.is(e[n[r]],t[n[r]]))return!1;return!0}const Ae="__root__";function fv(e,t){let n,r,o,i="";for(n in e)if((o=e[n])!==void 0)if(Array.isArray(o))for(r=0;r<o.length;r++)i&&(i+="&"),i+=encodeURIComponent(n)+"="+encodeURIComponent(o[r]);else i&&(i+="&"),i+=encodeURIComponent(n)+"="+encodeURIComponent(o);return""+i}function Na(e){if(!e)return"";const t=decodeURIComponent(e);return t==="false"?!1:t==="true"?!0:+t*0===0&&+t+""===t?+t:t}function dv(e,t){let n,r;const o={},i=e.split("&");for(;n=i.shift();){const l=n.indexOf("=");if(l!==-1){r=n.slice(0,l);const s=n.slice(l+1);o[r]!==void 0?o[r]=[].concat(o[r],Na(s)):o[r]=Na(s)}else r=n,o[r]=""}return o}const pv=mv(JSON.parse),hv=vv(JSON.stringify,JSON.parse);function mv(e){return t=>{t.substring(0,1)==="?"&&(t=t.substring(1));const n=dv(t);for(const r in n){const o=n[r];if(typeof o=="string")try{n[r]=e(o)}catch{}}return n}}function vv(e,t){function n(r){if(typeof r=="object"&&r!==null)try{return e(r)}catch{}else if(typeof r=="string"&&typeof t=="function")try{return t(r),e(r)}catch{}return r}return r=>{r={...r},Object.keys(r).forEach(i=>{const l=r[i];typeof l>"u"||l===void 0?delete r[i]:r[i]=n(l)});const o=fv(r).toString();return o?`?${o}`:""}}function nn(e){return e[e.length-1]}function yv(e){return typeof e=="function"}function jn(e,t){return yv(e)?e(t):e}function Cr(e,t){return t.reduce((n,r)=>(n[r]=e[r],n),{})}function pt(e,t){if(e===t)return e;const n=t,r=Oa(e)&&Oa(n);if(r||ri(e)&&ri(n)){const o=r?e:Object.keys(e),i=o.length,l=r?n:Object.keys(n),s=l.length,u=r?[]:{};let a=0;for(let c=0;c<s;c++){const h=r?c:l[c];(!r&&o.includes(h)||r)&&e[h]===void 0&&n[h]===void 0?(u[h]=void 0,a++):(u[h]=pt(e[h],n[h]),u[h]===e[h]&&e[h]!==void 0&&a++)}return i===s&&a===i?e:u}return n}function ri(e){if(!La(e))return!1;const t=e.constructor;if(typeof t>"u")return!0;const n=t.prototype;return!(!La(n)||!n.hasOwnProperty("isPrototypeOf"))}function La(e){return Object.prototype.toString.call(e)==="[object Object]"}function Oa(e){return Array.isArray(e)&&e.length===Object.keys(e).length}function Bn(e,t,n=!1){if(e===t)return!0;if(typeof e!=typeof t)return!1;if(ri(e)&&ri(t)){const r=Object.keys(e),o=Object.keys(t);return!n&&r.length!==o.length?!1:!o.some(i=>!(i in e)||!Bn(e[i],t[i],n))}return Array.isArray(e)&&Array.isArray(t)?!e.some((r,o)=>!Bn(r,t[o],n)):!1}const mo=typeof window<"u"?w.useLayoutEffect:w.useEffect;function gv(e){return e.replace(/\\/g,"\\\\").replace(/'/g,"\\'").replace(/"/g,'\\"')}function Ma(e){return e.endsWith("/")&&e!=="/"?e.slice(0,-1):e}function wv(e,t){return Ma(e)===Ma(t)}function _r(e){let t,n;const r=new Promise((o,i)=>{t=o,n=i});return r.status="pending",r.resolve=()=>{r.status="resolved",t(),e==null||e()},r.reject=o=>{r.status="rejected",n(o)},r}function ja(e){const t=w.useRef({value:e,prev:null}),n=t.current.value;return e!==n&&(t.current={value:e,prev:n}),t.current.prev}function uu(e){const t=e.errorComponent??Ci;return _.jsx(Sv,{getResetKey:e.getResetKey,onCatch:e.onCatch,children:({error:n,reset:r})=>n?w.createElement(t,{error:n,reset:r}):e.children})}class Sv extends w.Component{constructor(){super(...arguments),this.state={error:null}}static getDerivedStateFromProps(t){return{resetKey:t.getResetKey()}}static getDerivedStateFromError(t){return{error:t}}reset(){this.setState({error:null})}componentDidUpdate(t,n){n.error&&n.resetKey!==this.state.resetKey&&this.reset()}componentDidCatch(t,n){this.props.onCatch&&this.props.onCatch(t,n)}render(){return this.props.children({error:this.state.error,reset:()=>{this.reset()}})}}function Ci({error:e}){const[t,n]=w.useState(!1);return _.jsxs("div",{style:{padding:".5rem",maxWidth:"100%"},children:[_.jsxs("div",{style:{display:"flex",alignItems:"center",gap:".5rem"},children:[_.jsx("strong",{style:{fontSize:"1rem"},children:"Something went wrong!"}),_.jsx("button",{style:{appearance:"none",fontSize:".6em",border:"1px solid currentColor",padding:".1rem .2rem",fontWeight:"bold",borderRadius:".25rem"},onClick:()=>n(r=>!r),children:t?"Hide Error":"Show Error"})]}),_.jsx("div",{style:{height:".25rem"}}),t?_.jsx("div",{children:_.jsx("pre",{style:{fontSize:".7em",border:"1px solid red",borderRadius:".25rem",padding:".3rem",color:"red",overflow:"auto"},children:e.message?_.jsx("code",{children:e.message}):null})}):null]})}function De(e){const t=Jt({warn:(e==null?void 0:e.router)===void 0});return av(((e==null?void 0:e.router)||t).__store,e==null?void 0:e.select)}function ut(e){return!!(e!=null&&e.isNotFound)}function Ev(e){const t=De({select:n=>`not-found-${n.location.pathname}-${n.status}`});return _.jsx(uu,{getResetKey:()=>t,onCatch:(n,r)=>{var o;if(ut(n))(o=e.onCatch)==null||o.call(e,n,r);else throw n},errorComponent:({error:n})=>{var r;return(r=e.fallback)==null?void 0:r.call(e,n)},children:e.children})}function xv(){return _.jsx("p",{children:"Not Found"})}function en(e){return!!(e!=null&&e.isRedirect)}function Ia(e){return!!(e!=null&&e.isRedirect)&&e.href}function kv(){const e=Jt(),t=w.useRef({router:e,mounted:!1}),n=De({select:a=>Cr(a,["isLoading","location","resolvedLocation","isTransitioning"])}),[r,o]=w.useTransition(),i=De({select:a=>a.matches.some(c=>c.status==="pending")}),l=ja(n.isLoading),s=n.isLoading||r||i,u=ja(s);return e.startReactTransition=o,mo(()=>{const a=e.history.subscribe(e.load),c=e.buildLocation({to:e.latestLocation.pathname,search:!0,params:!0,hash:!0,state:!0});return e.state.location.href!==c.href&&e.commitLocation({...c,replace:!0}),()=>{a()}},[e,e.history]),mo(()=>{if(window.__TSR_DEHYDRATED__||t.current.router===e&&t.current.mounted)return;t.current={router:e,mounted:!0},(async()=>{try{await e.load()}catch(c){console.error(c)}})()},[e]),mo(()=>{if(l&&!n.isLoading){const a=e.state.location,c=e.state.resolvedLocation,h=c.href!==a.href;e.emit({type:"onLoad",fromLocation:c,toLocation:a,pathChanged:h})}},[l,e,n.isLoading]),mo(()=>{if(u&&!s){const a=e.state.location,c=e.state.resolvedLocation,h=c.href!==a.href;if(e.emit({type:"onResolved",fromLocation:c,toLocation:a,pathChanged:h}),e.__store.setState(d=>({...d,status:"idle",resolvedLocation:d.location})),document.querySelector&&e.state.location.hash!==""){const d=document.getElementById(e.state.location.hash);d&&d.scrollIntoView()}}},[s,u,e]),null}const _i=w.createContext(void 0);function Cv(){const e=Jt(),t=e.options.defaultPendingComponent?_.jsx(e.options.defaultPendingComponent,{}):null,n=e.state.matches.length?Oo:w.Suspense,r=_.jsxs(n,{fallback:t,children:[_.jsx(kv,{}),_.jsx(_v,{})]});return e.options.InnerWrap?_.jsx(e.options.InnerWrap,{children:r}):r}function _v(){const e=De({select:n=>{var r;return(r=n.matches[0])==null?void 0:r.id}}),t=De({select:n=>n.resolvedLocation.state.key});return _.jsx(_i.Provider,{value:e,children:_.jsx(uu,{getResetKey:()=>t,errorComponent:Ci,onCatch:n=>{n.message||n.toString()},children:e?_.jsx(Sd,{matchId:e}):null})})}function Oo(e){return _.jsx(_.Fragment,{children:e.children})}function Sd({matchId:e}){var t,n;const r=Jt(),o=De({select:g=>{var x;return(x=g.matches.find(m=>m.id===e))==null?void 0:x.routeId}});Le(o);const i=r.routesById[o],l=i.options.pendingComponent??r.options.defaultPendingComponent,s=l?_.jsx(l,{}):null,u=i.options.errorComponent??r.options.defaultErrorComponent,a=i.options.onCatch??r.options.defaultOnCatch,c=i.isRoot?i.options.notFoundComponent??((t=r.options.notFoundRoute)==null?void 0:t.options.component):i.options.notFoundComponent,h=(!i.isRoot||i.options.wrapInSuspense)&&(i.options.wrapInSuspense??l??((n=i.options.errorComponent)==null?void 0:n.preload))?w.Suspense:Oo,d=u?uu:Oo,v=c?Ev:Oo,y=De({select:g=>g.resolvedLocation.state.key});return _.jsx(_i.Provider,{value:e,children:_.jsx(h,{fallback:s,children:_.jsx(d,{getResetKey:()=>y,errorComponent:u||Ci,onCatch:(g,x)=>{if(ut(g))throw g;a==null||a(g,x)},children:_.jsx(v,{fallback:g=>{if(!c||g.routeId&&g.routeId!==o||!g.routeId&&!i.isRoot)throw g;return w.createElement(c,g)},children:_.jsx(Pv,{matchId:e})})})})})}function Pv({matchId:e}){var t,n;const r=Jt(),o=De({select:u=>{var a;return(a=u.matches.find(c=>c.id===e))==null?void 0:a.routeId}}),i=r.routesById[o],l=De({select:u=>Cr(u.matches.find(a=>a.id===e),["id","status","error","loadPromise","minPendingPromise"])}),s=(i.options.errorComponent??r.options.defaultErrorComponent)||Ci;if(l.status==="notFound"){let u;return Da(l.error)?u=(((t=r.options.errorSerializer)==null?void 0:t.deserialize)??Fa)(l.error.data):u=l.error,Le(ut(u)),xd(r,i,u)}if(l.status==="redirected")throw Le(en(l.error)),l.loadPromise;if(l.status==="error"){if(r.isServer)return _.jsx(s,{error:l.error,info:{componentStack:""}});throw Da(l.error)?(((n=r.options.errorSerializer)==null?void 0:n.deserialize)??Fa)(l.error.data):l.error}if(l.status==="pending"){const u=i.options.pendingMinMs??r.options.defaultPendingMinMs;throw u&&!l.minPendingPromise&&(l.minPendingPromise=_r(),r.isServer||(Promise.resolve().then(()=>{r.__store.setState(a=>({...a,matches:a.matches.map(c=>c.id===l.id?{...c,minPendingPromise:_r()}:c)}))}),setTimeout(()=>{r.__store.setState(a=>({...a,matches:a.matches.map(c=>{var h;return c.id===l.id?{...c,minPendingPromise:((h=c.minPendingPromise)==null||h.resolve(),void 0)}:c})}))},u))),l.loadPromise}if(l.status==="success"){const u=i.options.component??r.options.defaultComponent;return u?_.jsx(u,{}):_.jsx(Ed,{})}Le(!1)}const Ed=w.memo(function(){const t=Jt(),n=w.useContext(_i),r=De({select:a=>{var c;return(c=a.matches.find(h=>h.id===n))==null?void 0:c.routeId}}),o=t.routesById[r],{parentGlobalNotFound:i}=De({select:a=>{const h=a.matches.find(d=>d.id===n);return Le(h),{parentGlobalNotFound:h.globalNotFound}}}),l=De({select:a=>{var c;const h=a.matches,d=h.findIndex(v=>v.id===n);return(c=h[d+1])==null?void 0:c.id}});if(i)return xd(t,o,void 0);if(!l)return null;const s=_.jsx(Sd,{matchId:l}),u=t.options.defaultPendingComponent?_.jsx(t.options.defaultPendingComponent,{}):null;return n===Ae?_.jsx(w.Suspense,{fallback:u,children:s}):s});function xd(e,t,n){return t.options.notFoundComponent?_.jsx(t.options.notFoundComponent,{data:n}):e.options.defaultNotFoundComponent?_.jsx(e.options.defaultNotFoundComponent,{data:n}):_.jsx(xv,{})}function Da(e){return!(typeof e=="object"&&e&&"data"in e)||!("__isServerError"in e&&e.__isServerError)||!(typeof e.data=="object"&&e.data)?!1:e.__isServerError===!0}function Fa(e){if("name"in e&&"message"in e){const t=new Error(e.message);return t.name=e.name,t}return e.data}function Rv({router:e,children:t,...n}){e.update({...e.options,...n,context:{...e.options.context,...n.context}});const r=md(),o=_.jsx(r.Provider,{value:e,children:t});return e.options.Wrap?_.jsx(e.options.Wrap,{children:o}):o}function Tv({router:e,...t}){return _.jsx(Rv,{router:e,...t,children:_.jsx(Cv,{})})}function vo(e,t){return[...e.cachedMatches,...e.pendingMatches??[],...e.matches].find(n=>n.id===t)}function Kt(e){return Pi(e.filter(Boolean).join("/"))}function Pi(e){return e.replace(/\/{2,}/g,"/")}function au(e){return e==="/"?e:e.replace(/^\/{1,}/,"")}function Mo(e){return e==="/"?e:e.replace(/\/{1,}$/,"")}function Nv(e){return Mo(au(e))}function Lv({basepath:e,base:t,to:n,trailingSlash:r="never"}){var o,i;t=t.replace(new RegExp(`^${e}`),"/"),n=n.replace(new RegExp(`^${e}`),"/");let l=Xn(t);const s=Xn(n);l.length>1&&((o=nn(l))==null?void 0:o.value)==="/"&&l.pop(),s.forEach((a,c)=>{a.value==="/"?c?c===s.length-1&&l.push(a):l=[a]:a.value===".."?l.pop():a.value==="."||l.push(a)}),l.length>1&&(((i=nn(l))==null?void 0:i.value)==="/"?r==="never"&&l.pop():r==="always"&&l.push({type:"pathname",value:"/"}));const u=Kt([e,...l.map(a=>a.value)]);return Pi(u)}function Xn(e){if(!e)return[];e=Pi(e);const t=[];if(e.slice(0,1)==="/"&&(e=e.substring(1),t.push({type:"pathname",value:"/"})),!e)return t;const n=e.split("/").filter(Boolean);return t.push(...n.map(r=>r==="$"||r==="*"?{type:"wildcard",value:r}:r.charAt(0)==="$"?{type:"param",value:r}:{type:"pathname",value:r})),e.slice(-1)==="/"&&(e=e.substring(1),t.push({type:"pathname",value:"/"})),t}function rl({path:e,params:t,leaveWildcards:n,leaveParams:r}){const o=Xn(e);return Kt(o.map(i=>{if(i.type==="wildcard"){const l=t._splat;return n?`${i.value}${l??""}`:l}if(i.type==="param"){if(r){const l=t[i.value];return`${i.value}${l??""}`}return t[i.value.substring(1)]??"undefined"}return i.value}))}function ol(e,t,n){const r=Ov(e,t,n);if(!(n.to&&!r))return r??{}}function za(e,t){return e!="/"?t.replace(e,""):t}function Ov(e,t,n){t=za(e,t);const r=za(e,`${n.to??"$"}`),o=Xn(t),i=Xn(r);t.startsWith("/")||o.unshift({type:"pathname",value:"/"}),r.startsWith("/")||i.unshift({type:"pathname",value:"/"});const l={};return(()=>{for(let u=0;u<Math.max(o.length,i.length);u++){const a=o[u],c=i[u],h=u>=o.length-1,d=u>=i.length-1;if(c){if(c.type==="wildcard"){if(a!=null&&a.value){const v=decodeURI(Kt(o.slice(u).map(y=>y.value)));return l["*"]=v,l._splat=v,!0}return!1}if(c.type==="pathname"){if(c.value==="/"&&!(a!=null&&a.value))return!0;if(a){if(n.caseSensitive){if(c.value!==a.value)return!1}else if(c.value.toLowerCase()!==a.value.toLowerCase())return!1}}if(!a)return!1;if(c.type==="param"){if(a.value==="/")return!1;a.value.charAt(0)!=="$"&&(l[c.value.substring(1)]=decodeURIComponent(a.value))}}if(!h&&d)return l["**"]=Kt(o.slice(u+1).map(v=>v.value)),!!n.fuzzy&&(c==null?void 0:c.value)!=="/"}return!0})()?l:void 0}const Mv=["component","errorComponent","pendingComponent","notFoundComponent"];function jv(e){return new Iv(e)}class Iv{constructor(t){this.tempLocationKey=`${Math.round(Math.random()*1e7)}`,this.resetNextScroll=!0,this.shouldViewTransition=void 0,this.latestLoadPromise=Promise.resolve(),this.subscribers=new Set,this.injectedHtml=[],this.isServer=typeof document>"u",this.startReactTransition=n=>n(),this.update=n=>{n.notFoundRoute&&console.warn("The notFoundRoute API is deprecated and will be removed in the next major version. See https://tanstack.com/router/v1/docs/guide/not-found-errors#migrating-from-notfoundroute for more info.");const r=this.options;this.options={...this.options,...n},(!this.basepath||n.basepath&&n.basepath!==r.basepath)&&(n.basepath===void 0||n.basepath===""||n.basepath==="/"?this.basepath="/":this.basepath=`/${Nv(n.basepath)}`),(!this.history||this.options.history&&this.options.history!==this.history)&&(this.history=this.options.history??(typeof document<"u"?Bm():Um({initialEntries:[this.options.basepath||"/"]})),this.latestLocation=this.parseLocation()),this.options.routeTree!==this.routeTree&&(this.routeTree=this.options.routeTree,this.buildRouteTree()),this.__store||(this.__store=new uv(zv(this.latestLocation),{onUpdate:()=>{this.__store.state={...this.state,cachedMatches:this.state.cachedMatches.filter(o=>!["redirected"].includes(o.status))}}}))},this.buildRouteTree=()=>{this.routesById={},this.routesByPath={};const n=this.options.notFoundRoute;n&&(n.init({originalIndex:99999999999}),this.routesById[n.id]=n);const r=l=>{l.forEach((s,u)=>{s.init({originalIndex:u});const a=this.routesById[s.id];if(Le(!a,`Duplicate routes found with id: ${String(s.id)}`),this.routesById[s.id]=s,!s.isRoot&&s.path){const h=Mo(s.fullPath);(!this.routesByPath[h]||s.fullPath.endsWith("/"))&&(this.routesByPath[h]=s)}const c=s.children;c!=null&&c.length&&r(c)})};r([this.routeTree]);const o=[];Object.values(this.routesById).forEach((l,s)=>{var u;if(l.isRoot||!l.path)return;const a=au(l.fullPath),c=Xn(a);for(;c.length>1&&((u=c[0])==null?void 0:u.value)==="/";)c.shift();const h=c.map(d=>d.value==="/"?.75:d.type==="param"?.5:d.type==="wildcard"?.25:1);o.push({child:l,trimmed:a,parsed:c,index:s,scores:h})}),this.flatRoutes=o.sort((l,s)=>{const u=Math.min(l.scores.length,s.scores.length);for(let a=0;a<u;a++)if(l.scores[a]!==s.scores[a])return s.scores[a]-l.scores[a];if(l.scores.length!==s.scores.length)return s.scores.length-l.scores.length;for(let a=0;a<u;a++)if(l.parsed[a].value!==s.parsed[a].value)return l.parsed[a].value>s.parsed[a].value?1:-1;return l.index-s.index}).map((l,s)=>(l.child.rank=s,l.child))},this.subscribe=(n,r)=>{const o={eventType:n,fn:r};return this.subscribers.add(o),()=>{this.subscribers.delete(o)}},this.emit=n=>{this.subscribers.forEach(r=>{r.eventType===n.type&&r.fn(n)})},this.checkLatest=n=>{if(this.latestLoadPromise!==n)throw this.latestLoadPromise},this.parseLocation=n=>{const r=({pathname:s,search:u,hash:a,state:c})=>{const h=this.options.parseSearch(u),d=this.options.stringifySearch(h);return{pathname:s,searchStr:d,search:pt(n==null?void 0:n.search,h),hash:a.split("#").reverse()[0]??"",href:`${s}${d}${a}`,state:pt(n==null?void 0:n.state,c)}},o=r(this.history.location),{__tempLocation:i,__tempKey:l}=o.state;if(i&&(!l||l===this.tempLocationKey)){const s=r(i);return s.state.key=o.state.key,delete s.state.__tempLocation,{...s,maskedLocation:o}}return o},this.resolvePathWithBase=(n,r)=>Lv({basepath:this.basepath,base:n,to:Pi(r),trailingSlash:this.options.trailingSlash}),this.matchRoutes=(n,r,o)=>{let i={};const l=this.flatRoutes.find(v=>{const y=ol(this.basepath,Mo(n),{to:v.fullPath,caseSensitive:v.options.caseSensitive??this.options.caseSensitive,fuzzy:!0});return y?(i=y,!0):!1});let s=l||this.routesById[Ae];const u=[s];let a=!1;for((l?l.path!=="/"&&i["**"]:Mo(n))&&(this.options.notFoundRoute?u.push(this.options.notFoundRoute):a=!0);s.parentRoute;)s=s.parentRoute,u.unshift(s);const c=(()=>{if(a){if(this.options.notFoundMode!=="root")for(let v=u.length-1;v>=0;v--){const y=u[v];if(y.children)return y.id}return Ae}})(),h=u.map(v=>{let y;if(v.options.parseParams)try{const g=v.options.parseParams(i);Object.assign(i,g)}catch(g){if(y=new Fv(g.message,{cause:g}),o!=null&&o.throwOnError)throw y;return y}}),d=[];return u.forEach((v,y)=>{var g,x,m,f,p,S,k,E,P,T;const D=d[y-1],[M,B]=(()=>{const L=(D==null?void 0:D.search)??r;try{const O=typeof v.options.validateSearch=="object"?v.options.validateSearch.parse:v.options.validateSearch,F=(O==null?void 0:O(L))??{};return[{...L,...F},void 0]}catch(O){const F=new Dv(O.message,{cause:O});if(o!=null&&o.throwOnError)throw F;return[L,F]}})(),I=((x=(g=v.options).loaderDeps)==null?void 0:x.call(g,{search:M}))??"",j=I?JSON.stringify(I):"",$=rl({path:v.fullPath,params:i}),ne=rl({path:v.id,params:i,leaveWildcards:!0})+j,X=vo(this.state,ne),V=this.state.matches.find(L=>L.id===ne)?"stay":"enter";let C;if(X)C={...X,cause:V,params:i};else{const L=v.options.loader||v.options.beforeLoad?"pending":"success",O=_r();L==="success"&&O.resolve(),C={id:ne,routeId:v.id,params:i,pathname:Kt([this.basepath,$]),updatedAt:Date.now(),search:{},searchError:void 0,status:"pending",isFetching:!1,error:void 0,paramsError:h[y],loaderPromise:Promise.resolve(),loadPromise:O,routeContext:void 0,context:void 0,abortController:new AbortController,fetchCount:0,cause:V,loaderDeps:I,invalid:!1,preload:!1,links:(f=(m=v.options).links)==null?void 0:f.call(m),scripts:(S=(p=v.options).scripts)==null?void 0:S.call(p),staticData:v.options.staticData||{}}}C.status==="success"&&(C.meta=(E=(k=v.options).meta)==null?void 0:E.call(k,{matches:d,params:C.params,loaderData:C.loaderData}),C.headers=(T=(P=v.options).headers)==null?void 0:T.call(P,{loaderData:C.loaderData})),o!=null&&o.preload||(C.globalNotFound=c===v.id),C.search=pt(C.search,M),C.searchError=B,d.push(C)}),d},this.cancelMatch=n=>{var r;(r=vo(this.state,n))==null||r.abortController.abort()},this.cancelMatches=()=>{var n;(n=this.state.pendingMatches)==null||n.forEach(r=>{this.cancelMatch(r.id)})},this.buildLocation=n=>{const r=(i={},l)=>{var s,u,a,c;let h=this.latestLocation.pathname,d=i.fromSearch||this.latestLocation.search;const v=this.matchRoutes(this.latestLocation.pathname,d);h=((s=v.find(j=>j.id===i.from))==null?void 0:s.pathname)||h,d=((u=nn(v))==null?void 0:u.search)||this.latestLocation.search;const y=l==null?void 0:l.filter(j=>v.find($=>$.routeId===j.routeId)),g=this.routesById[(a=y==null?void 0:y.find(j=>j.pathname===h))==null?void 0:a.routeId];let x=i.to?this.resolvePathWithBase(h,`${i.to}`):this.resolvePathWithBase(h,(g==null?void 0:g.to)??h);const m={...(c=nn(v))==null?void 0:c.params};let f=(i.params??!0)===!0?m:{...m,...jn(i.params,m)};Object.keys(f).length>0&&(l==null||l.map(j=>this.looseRoutesById[j.routeId].options.stringifyParams).filter(Boolean).forEach(j=>{f={...f,...j(f)}})),Object.keys(f).forEach(j=>{["*","_splat"].includes(j)?f[j]=encodeURI(f[j]):f[j]=encodeURIComponent(f[j])}),x=rl({path:x,params:f??{},leaveWildcards:!1,leaveParams:n.leaveParams});const p=(y==null?void 0:y.map(j=>this.looseRoutesById[j.routeId].options.preSearchFilters??[]).flat().filter(Boolean))??[],S=(y==null?void 0:y.map(j=>this.looseRoutesById[j.routeId].options.postSearchFilters??[]).flat().filter(Boolean))??[],k=p.length?p.reduce((j,$)=>$(j),d):d,E=i.search===!0?k:i.search?jn(i.search,k):p.length?k:{},P=S.length?S.reduce((j,$)=>$(j),E):E,T=pt(d,P),D=this.options.stringifySearch(T),M=i.hash===!0?this.latestLocation.hash:i.hash?jn(i.hash,this.latestLocation.hash):void 0,B=M?`#${M}`:"";let I=i.state===!0?this.latestLocation.state:i.state?jn(i.state,this.latestLocation.state):{};return I=pt(this.latestLocation.state,I),{pathname:x,search:T,searchStr:D,state:I,hash:M??"",href:`${x}${D}${B}`,unmaskOnReload:i.unmaskOnReload}},o=(i={},l)=>{var s;const u=r(i);let a=l?r(l):void 0;if(!a){let y={};const g=(s=this.options.routeMasks)==null?void 0:s.find(x=>{const m=ol(this.basepath,u.pathname,{to:x.from,caseSensitive:!1,fuzzy:!1});return m?(y=m,!0):!1});g&&(l={...Cr(n,["from"]),...g,params:y},a=r(l))}const c=this.matchRoutes(u.pathname,u.search),h=a?this.matchRoutes(a.pathname,a.search):void 0,d=a?r(l,h):void 0,v=r(i,c);return d&&(v.maskedLocation=d),v};return n.mask?o(n,{...Cr(n,["from"]),...n.mask}):o(n)},this.commitLocation=async({startTransition:n,viewTransition:r,...o})=>{const i=()=>{o.state.key=this.latestLocation.state.key;const s=Bn(o.state,this.latestLocation.state);return delete o.state.key,s};if(this.latestLocation.href===o.href&&i())this.load();else{let{maskedLocation:s,...u}=o;s&&(u={...s,state:{...s.state,__tempKey:void 0,__tempLocation:{...u,search:u.searchStr,state:{...u.state,__tempKey:void 0,__tempLocation:void 0,key:void 0}}}},(u.unmaskOnReload??this.options.unmaskOnReload??!1)&&(u.state.__tempKey=this.tempLocationKey)),this.shouldViewTransition=r,this.history[o.replace?"replace":"push"](u.href,u.state)}return this.resetNextScroll=o.resetScroll??!0,this.latestLoadPromise},this.buildAndCommitLocation=({replace:n,resetScroll:r,startTransition:o,viewTransition:i,...l}={})=>{const s=this.buildLocation(l);return this.commitLocation({...s,startTransition:o,viewTransition:i,replace:n,resetScroll:r})},this.navigate=({from:n,to:r,...o})=>{const i=String(r);let l;try{new URL(`${i}`),l=!0}catch{}return Le(!l),this.buildAndCommitLocation({...o,from:n,to:r})},this.load=async()=>{if(this.latestLocation=this.parseLocation(this.latestLocation),this.state.location===this.latestLocation)return;const n=_r();this.latestLoadPromise=n;let r,o;return this.startReactTransition(async()=>{try{const i=this.latestLocation,l=this.state.resolvedLocation,s=l.href!==i.href;this.cancelMatches(),this.emit({type:"onBeforeLoad",fromLocation:l,toLocation:i,pathChanged:s});let u;this.__store.batch(()=>{u=this.matchRoutes(i.pathname,i.search),this.__store.setState(a=>({...a,status:"pending",isLoading:!0,location:i,pendingMatches:u,cachedMatches:a.cachedMatches.filter(c=>!u.find(h=>h.id===c.id))}))}),await this.loadMatches({matches:u,location:i,checkLatest:()=>this.checkLatest(n),onReady:async()=>{await this.startViewTransition(async()=>{let a,c,h;this.__store.batch(()=>{this.__store.setState(d=>{const v=d.matches,y=d.pendingMatches||d.matches;return a=v.filter(g=>!y.find(x=>x.id===g.id)),c=y.filter(g=>!v.find(x=>x.id===g.id)),h=v.filter(g=>y.find(x=>x.id===g.id)),{...d,isLoading:!1,matches:y,pendingMatches:void 0,cachedMatches:[...d.cachedMatches,...a.filter(g=>g.status!=="error")]}}),this.cleanCache()}),[[a,"onLeave"],[c,"onEnter"],[h,"onStay"]].forEach(([d,v])=>{d.forEach(y=>{var g,x;(x=(g=this.looseRoutesById[y.routeId].options)[v])==null||x.call(g,y)})})})}})}catch(i){Ia(i)?(r=i,this.isServer||(this.navigate({...i,replace:!0}),this.load())):ut(i)&&(o=i),this.__store.setState(l=>({...l,statusCode:r!=null&&r.statusCode||o?404:l.matches.some(s=>s.status==="error")?500:200,redirect:r}))}n.resolve()}),this.latestLoadPromise},this.startViewTransition=async n=>{var r,o;const i=this.shouldViewTransition??this.options.defaultViewTransition;delete this.shouldViewTransition,(o=(r=i&&typeof document<"u"?document:void 0)==null?void 0:r.startViewTransition)!=null&&o.call(r,n)||n()},this.loadMatches=async({checkLatest:n,location:r,matches:o,preload:i,onReady:l})=>{let s,u=!1;const a=async()=>{u||(u=!0,await(l==null?void 0:l()))};!this.isServer&&!this.state.matches.length&&a();const c=(d,v,y)=>{var g;let x;const m=(g=this.state.pendingMatches)==null?void 0:g.find(S=>S.id===d),f=this.state.matches.find(S=>S.id===d),p=m?"pendingMatches":f?"matches":"cachedMatches";return this.__store.setState(S=>{var k;return{...S,[p]:(k=S[p])==null?void 0:k.map(E=>E.id===d?x=v(E):E)}}),x},h=(d,v)=>{if(Ia(v))throw v;if(en(v)||ut(v)){if(c(d.id,y=>({...y,status:en(v)?"redirected":ut(v)?"notFound":"error",isFetching:!1,error:v})),v.routeId||(v.routeId=d.routeId),en(v))throw u=!0,v=this.resolveRedirect(v),v;if(ut(v))throw this.handleNotFound(o,v),v}};try{await new Promise((d,v)=>{(async()=>{var y,g;try{for(let[f,p]of o.entries()){const S=o[f-1],k=this.looseRoutesById[p.routeId],E=new AbortController;let P=p.loadPromise;const T=k.options.pendingMs??this.options.defaultPendingMs;if(!!(l&&!this.isServer&&!i&&(k.options.loader||k.options.beforeLoad)&&typeof T=="number"&&T!==1/0&&(k.options.pendingComponent??this.options.defaultPendingComponent))&&setTimeout(()=>{try{n(),a()}catch{}},T),p.isFetching)continue;const M=P.resolve;P=_r(M),o[f]=p=c(p.id,I=>({...I,isFetching:"beforeLoad",loadPromise:P}));const B=(I,j)=>{var $,ne;if(I instanceof Promise)throw I;I.routerCode=j,s=s??f,h(p,I);try{(ne=($=k.options).onError)==null||ne.call($,I)}catch(X){I=X,h(p,I)}o[f]=p=c(p.id,()=>({...p,error:I,status:"error",updatedAt:Date.now(),abortController:new AbortController}))};p.paramsError&&B(p.paramsError,"PARSE_PARAMS"),p.searchError&&B(p.searchError,"VALIDATE_SEARCH");try{const I=(S==null?void 0:S.context)??this.options.context??{};o[f]=p=c(p.id,()=>({...p,routeContext:pt(p.routeContext,I),abortController:E}));const j=await((g=(y=k.options).beforeLoad)==null?void 0:g.call(y,{search:p.search,abortController:E,params:p.params,preload:!!i,context:I,location:r,navigate:ne=>this.navigate({...ne,from:p.pathname}),buildLocation:this.buildLocation,cause:i?"preload":p.cause}))??{};n(),(en(j)||ut(j))&&B(j,"BEFORE_LOAD");const $={...I,...j};o[f]=p={...p,routeContext:pt(p.routeContext,j),context:pt(p.context,$),abortController:E},c(p.id,()=>p)}catch(I){B(I,"BEFORE_LOAD");break}}n();const x=o.slice(0,s),m=[];x.forEach((f,p)=>{const S=async()=>{const k=m[p-1],E=this.looseRoutesById[f.routeId],P={params:f.params,deps:f.loaderDeps,preload:!!i,parentMatchPromise:k,abortController:f.abortController,context:f.context,location:r,navigate:$=>this.navigate({...$,from:f.pathname}),cause:i?"preload":f.cause,route:E},T=async()=>{var $,ne,X,V,C,L,O,F,U;const ye=vo(this.state,f.id);let le=Promise.resolve(),J=Promise.resolve(),se=ye.loaderPromise;const xe=async()=>{const ue=vo(this.state,f.id);ue!=null&&ue.minPendingPromise&&(await ue.minPendingPromise,n(),c(ue.id,Re=>({...Re,minPendingPromise:void 0})))};try{f.isFetching==="beforeLoad"&&(o[p]=f=c(f.id,Te=>({...Te,isFetching:"loader",fetchCount:f.fetchCount+1})),le=(($=E.lazyFn)==null?void 0:$.call(E).then(Te=>{Object.assign(E.options,Te.options)}))||Promise.resolve(),J=le.then(()=>Promise.all(Mv.map(async Te=>{const H=E.options[Te];H!=null&&H.preload&&await H.preload()}))),await le,n(),se=(X=(ne=E.options).loader)==null?void 0:X.call(ne,P),o[p]=f=c(f.id,Te=>({...Te,loaderPromise:se})));const ue=await se;n(),h(f,ue),await xe(),n();const Re=(C=(V=E.options).meta)==null?void 0:C.call(V,{matches:o,params:f.params,loaderData:ue}),ze=(O=(L=E.options).headers)==null?void 0:O.call(L,{loaderData:ue});o[p]=f=c(f.id,Te=>({...Te,error:void 0,status:"success",isFetching:!1,updatedAt:Date.now(),loaderData:ue,meta:Re,headers:ze}))}catch(ue){n();let Re=ue;await xe(),n(),h(f,ue);try{(U=(F=E.options).onError)==null||U.call(F,ue)}catch(ze){Re=ze,h(f,ze)}o[p]=f=c(f.id,ze=>({...ze,error:Re,status:"error",isFetching:!1}))}await J,n(),f.loadPromise.resolve()},D=Date.now()-f.updatedAt,M=i?E.options.preloadStaleTime??this.options.defaultPreloadStaleTime??3e4:E.options.staleTime??this.options.defaultStaleTime??0,B=E.options.shouldReload,I=typeof B=="function"?B(P):B;o[p]=f={...f,preload:!!i&&!this.state.matches.find($=>$.id===f.id)};const j=async()=>{try{await T()}catch($){n(),h(f,$)}};if(f.status==="success"&&(f.invalid||(I??D>M))){(async()=>{try{await j()}catch{}})();return}f.status!=="success"&&await j()};m.push(S())}),await Promise.all(m),n(),d()}catch(x){v(x)}})()}),await a()}catch(d){if(en(d)||ut(d))throw ut(d)&&!i&&await a(),d}return o},this.invalidate=()=>{const n=r=>({...r,invalid:!0,...r.status==="error"?{status:"pending"}:{}});return this.__store.setState(r=>{var o;return{...r,matches:r.matches.map(n),cachedMatches:r.cachedMatches.map(n),pendingMatches:(o=r.pendingMatches)==null?void 0:o.map(n)}}),this.load()},this.resolveRedirect=n=>{const r=n;return r.href||(r.href=this.buildLocation(r).href),r},this.cleanCache=()=>{this.__store.setState(n=>({...n,cachedMatches:n.cachedMatches.filter(r=>{const o=this.looseRoutesById[r.routeId];if(!o.options.loader)return!1;const i=(r.preload?o.options.preloadGcTime??this.options.defaultPreloadGcTime:o.options.gcTime??this.options.defaultGcTime)??5*60*1e3;return r.status!=="error"&&Date.now()-r.updatedAt<i})}))},this.preloadRoute=async n=>{const r=this.buildLocation(n);let o=this.matchRoutes(r.pathname,r.search,{throwOnError:!0,preload:!0});const i=Object.fromEntries([...this.state.matches,...this.state.pendingMatches??[],...this.state.cachedMatches].map(a=>[a.id,!0]));this.__store.batch(()=>{o.forEach(a=>{i[a.id]||this.__store.setState(c=>({...c,cachedMatches:[...c.cachedMatches,a]}))})});const l=nn(o),s=nn(this.state.matches),u=nn(this.state.pendingMatches??[]);if(!(l&&((s==null?void 0:s.id)===l.id||(u==null?void 0:u.id)===l.id)))try{return o=await this.loadMatches({matches:o,location:r,preload:!0,checkLatest:()=>{}}),o}catch(a){if(en(a))return await this.preloadRoute({fromSearch:r.search,from:r.pathname,...a});console.error(a);return}},this.matchRoute=(n,r)=>{const o={...n,to:n.to?this.resolvePathWithBase(n.from||"",n.to):void 0,params:n.params||{},leaveParams:!0},i=this.buildLocation(o);if(r!=null&&r.pending&&this.state.status!=="pending")return!1;const l=r!=null&&r.pending?this.latestLocation:this.state.resolvedLocation,s=ol(this.basepath,l.pathname,{...r,to:i.pathname});return!s||n.params&&!Bn(s,n.params,!0)?!1:s&&((r==null?void 0:r.includeSearch)??!0)?Bn(l.search,i.search,!0)?s:!1:s},this.injectHtml=async n=>{this.injectedHtml.push(n)},this.registeredDeferredsIds=new Map,this.registeredDeferreds=new WeakMap,this.getDeferred=n=>{const r=this.registeredDeferredsIds.get(n);if(r)return this.registeredDeferreds.get(r)},this.dehydrateData=(n,r)=>{if(typeof document>"u"){const o=typeof n=="string"?n:JSON.stringify(n);return this.injectHtml(async()=>{const i=`__TSR_DEHYDRATED__${o}`,l=typeof r=="function"?await r():r;return`<script id='${i}' suppressHydrationWarning>
-  window["__TSR_DEHYDRATED__${gv(o)}"] = ${JSON.stringify(this.options.transformer.stringify(l))}
-<\/script>`}),()=>this.hydrateData(n)}return()=>{}},this.hydrateData=n=>{if(typeof document<"u"){const r=typeof n=="string"?n:JSON.stringify(n);return this.options.transformer.parse(window[`__TSR_DEHYDRATED__${r}`])}},this.dehydrate=()=>{var n;const r=((n=this.options.errorSerializer)==null?void 0:n.serialize)??$v;return{state:{dehydratedMatches:this.state.matches.map(o=>({...Cr(o,["id","status","updatedAt","loaderData"]),error:o.error?{data:r(o.error),__isServerError:!0}:void 0}))}}},this.hydrate=async n=>{var r,o,i;let l=n;typeof document<"u"&&(l=(r=window.__TSR_DEHYDRATED__)==null?void 0:r.data),Le(l);const s=this.options.transformer.parse(l);this.dehydratedData=s.payload,(i=(o=this.options).hydrate)==null||i.call(o,s.payload);const u=s.router.state,a=this.matchRoutes(this.state.location.pathname,this.state.location.search).map((c,h,d)=>{var v,y,g,x,m,f;const p=u.dehydratedMatches.find(E=>E.id===c.id);Le(p,`Could not find a client-side match for dehydrated match with id: ${c.id}!`);const S=this.looseRoutesById[c.routeId],k=p.status==="notFound"||p.status==="redirected"?{}:{meta:(y=(v=S.options).meta)==null?void 0:y.call(v,{matches:d,params:c.params,loaderData:p.loaderData}),links:(x=(g=S.options).links)==null?void 0:x.call(g),scripts:(f=(m=S.options).scripts)==null?void 0:f.call(m)};return{...c,...p,...k}});this.__store.setState(c=>({...c,matches:a}))},this.handleNotFound=(n,r)=>{const o=Object.fromEntries(n.map(s=>[s.routeId,s]));let i=(r.global?this.looseRoutesById[Ae]:this.looseRoutesById[r.routeId])||this.looseRoutesById[Ae];for(;!i.options.notFoundComponent&&!this.options.defaultNotFoundComponent&&i.id!==Ae;)i=i.parentRoute,Le(i);const l=o[i.id];Le(l,"Could not find match for route: "+i.id),Object.assign(l,{status:"notFound",error:r,isFetching:!1})},this.hasNotFoundMatch=()=>this.__store.state.matches.some(n=>n.status==="notFound"||n.globalNotFound),this.update({defaultPreloadDelay:50,defaultPendingMs:1e3,defaultPendingMinMs:500,context:void 0,...t,stringifySearch:t.stringifySearch??hv,parseSearch:t.parseSearch??pv,transformer:t.transformer??JSON}),typeof document<"u"&&(window.__TSR__ROUTER__=this)}get state(){return this.__store.state}get looseRoutesById(){return this.routesById}}class Dv extends Error{}class Fv extends Error{}function zv(e){return{isLoading:!1,isTransitioning:!1,status:"idle",resolvedLocation:{...e},location:e,matches:[],pendingMatches:[],cachedMatches:[],statusCode:200}}function $v(e){return e instanceof Error?{name:e.name,message:e.message}:{data:e}}function _t(e){const t=w.useContext(_i);return De({select:r=>{const o=r.matches.find(i=>e.from?e.from===i.routeId:i.id===t);return Le(o,`Could not find ${e.from?`an active match from "${e.from}"`:"a nearest match!"}`),e.select?e.select(o):o}})}function kd(e){return _t({...e,select:t=>typeof e.select=="function"?e.select(t.loaderDeps):t.loaderDeps})}function Cd(e){return _t({...e,select:t=>typeof e.select=="function"?e.select(t.loaderData):t.loaderData})}function _d(e){return _t({...e,select:t=>e.select?e.select(t.params):t.params})}function Pd(e){return _t({...e,select:t=>e.select?e.select(t.search):t.search})}function Rd(e){const t=Jt();return w.useCallback(n=>t.navigate({...n,from:n.to?t.state.resolvedLocation.pathname:void 0}),[t])}let Td=class{constructor(t){this.init=n=>{var r,o;this.originalIndex=n.originalIndex;const i=this.options,l=!(i!=null&&i.path)&&!(i!=null&&i.id);this.parentRoute=(o=(r=this.options)==null?void 0:r.getParentRoute)==null?void 0:o.call(r),l?this.path=Ae:Le(this.parentRoute);let s=l?Ae:i.path;s&&s!=="/"&&(s=au(s));const u=(i==null?void 0:i.id)||s;let a=l?Ae:Kt([this.parentRoute.id===Ae?"":this.parentRoute.id,u]);s===Ae&&(s="/"),a!==Ae&&(a=Kt(["/",a]));const c=a===Ae?"/":Kt([this.parentRoute.fullPath,s]);this.path=s,this.id=a,this.fullPath=c,this.to=c},this.addChildren=n=>(this.children=Array.isArray(n)?n:Object.values(n),this),this.updateLoader=n=>(Object.assign(this.options,n),this),this.update=n=>(Object.assign(this.options,n),this),this.lazy=n=>(this.lazyFn=n,this),this.useMatch=n=>_t({...n,from:this.id}),this.useRouteContext=n=>_t({...n,from:this.id,select:r=>n!=null&&n.select?n.select(r.context):r.context}),this.useSearch=n=>Pd({...n,from:this.id}),this.useParams=n=>_d({...n,from:this.id}),this.useLoaderDeps=n=>kd({...n,from:this.id}),this.useLoaderData=n=>Cd({...n,from:this.id}),this.useNavigate=()=>Rd({from:this.id}),this.options=t||{},this.isRoot=!(t!=null&&t.getParentRoute),Le(!(t!=null&&t.id&&(t!=null&&t.path))),this.$$typeof=Symbol.for("react.memo")}};function Av(e){return new Td(e)}class Bv extends Td{constructor(t){super(t)}}function Uv(e){return new Bv(e)}function Wv(e){return new Hv(e,{silent:!0}).createRoute}class Hv{constructor(t,n){this.path=t,this.createRoute=r=>{this.silent;const o=Av(r);return o.isRoot=!1,o},this.silent=n==null?void 0:n.silent}}class Vv{constructor(t){this.useMatch=n=>_t({select:n==null?void 0:n.select,from:this.options.id}),this.useRouteContext=n=>_t({from:this.options.id,select:r=>n!=null&&n.select?n.select(r.context):r.context}),this.useSearch=n=>Pd({...n,from:this.options.id}),this.useParams=n=>_d({...n,from:this.options.id}),this.useLoaderDeps=n=>kd({...n,from:this.options.id}),this.useLoaderData=n=>Cd({...n,from:this.options.id}),this.useNavigate=()=>Rd({from:this.options.id}),this.options=t,this.$$typeof=Symbol.for("react.memo")}}function Vg(e){const t=Qv(e);return n=>new Vv({id:t,...n})}const Kv=/\(.+\)/g;function Qv(e){return e.replaceAll(Kv,"").replaceAll("//","/")}const Gv="Error preloading route! ☝️";function Yv(e){const t=Jt(),n=_t({strict:!1,select:H=>H.pathname}),[r,o]=w.useState(!1),{activeProps:i=()=>({className:"active"}),inactiveProps:l=()=>({}),activeOptions:s,hash:u,search:a,params:c,to:h,state:d,mask:v,preload:y,preloadDelay:g,replace:x,startTransition:m,resetScroll:f,viewTransition:p,children:S,target:k,disabled:E,style:P,className:T,onClick:D,onFocus:M,onMouseEnter:B,onMouseLeave:I,onTouchStart:j,...$}=e,ne={...e.to&&{from:n},...e};let X="internal";try{new URL(`${h}`),X="external"}catch{}const V=t.buildLocation(ne),C=y??t.options.defaultPreload,L=g??t.options.defaultPreloadDelay??0,O=De({select:H=>{const oe=H.location.pathname.split("/"),Q=V.pathname.split("/").every((dp,pp)=>dp===oe[pp]),Zr=s!=null&&s.exact?wv(H.location.pathname,V.pathname):Q,bn=s!=null&&s.includeHash?H.location.hash===V.hash:!0,fp=(s==null?void 0:s.includeSearch)??!0?Bn(H.location.search,V.search,!(s!=null&&s.exact)):!0;return Zr&&bn&&fp}});if(X==="external")return{...$,type:X,href:h,...S&&{children:S},...k&&{target:k},...E&&{disabled:E},...P&&{style:P},...T&&{className:T},...D&&{onClick:D},...M&&{onFocus:M},...B&&{onMouseEnter:B},...I&&{onMouseLeave:I},...j&&{onTouchStart:j}};const F=H=>{if(!E&&!Zv(H)&&!H.defaultPrevented&&(!k||k==="_self")&&H.button===0){H.preventDefault(),su.flushSync(()=>{o(!0)});const oe=t.subscribe("onResolved",()=>{oe(),o(!1)});t.commitLocation({...V,replace:x,resetScroll:f,startTransition:m,viewTransition:p})}},U=()=>{t.preloadRoute(ne).catch(H=>{console.warn(H),console.warn(Gv)})},ye=H=>{E||C&&U()},le=ye,J=H=>{if(E)return;const oe=H.target||{};if(C){if(oe.preloadTimeout)return;oe.preloadTimeout=setTimeout(()=>{oe.preloadTimeout=null,U()},L)}},se=H=>{if(E)return;const oe=H.target||{};oe.preloadTimeout&&(clearTimeout(oe.preloadTimeout),oe.preloadTimeout=null)},xe=H=>oe=>{var Tt;(Tt=oe.persist)==null||Tt.call(oe),H.filter(Boolean).forEach(Q=>{oe.defaultPrevented||Q(oe)})},ue=O?jn(i,{})??{}:{},Re=O?{}:jn(l,{}),ze=[T,ue.className,Re.className].filter(Boolean).join(" "),Te={...P,...ue.style,...Re.style};return{...ue,...Re,...$,href:E?void 0:V.maskedLocation?t.history.createHref(V.maskedLocation.href):t.history.createHref(V.href),onClick:xe([D,F]),onFocus:xe([M,ye]),onMouseEnter:xe([B,J]),onMouseLeave:xe([I,se]),onTouchStart:xe([j,le]),disabled:!!E,target:k,...Object.keys(Te).length&&{style:Te},...ze&&{className:ze},...E&&{role:"link","aria-disabled":!0},...O&&{"data-status":"active","aria-current":"page"},...r&&{"data-transitioning":"transitioning"}}}const Xv=w.forwardRef((e,t)=>{const{_asChild:n,...r}=e,{type:o,...i}=Yv(r),l=typeof r.children=="function"?r.children({isActive:i["data-status"]==="active"}):r.children;return typeof n>"u"&&delete i.disabled,w.createElement(n||"a",{...i,ref:t},l)});function Zv(e){return!!(e.metaKey||e.altKey||e.ctrlKey||e.shiftKey)}const Jv="modulepreload",qv=function(e){return"/"+e},$a={},bv=function(t,n,r){let o=Promise.resolve();if(n&&n.length>0){document.getElementsByTagName("link");const i=document.querySelector("meta[property=csp-nonce]"),l=(i==null?void 0:i.nonce)||(i==null?void 0:i.getAttribute("nonce"));o=Promise.all(n.map(s=>{if(s=qv(s),s in $a)return;$a[s]=!0;const u=s.endsWith(".css"),a=u?'[rel="stylesheet"]':"";if(document.querySelector(`link[href="${s}"]${a}`))return;const c=document.createElement("link");if(c.rel=u?"stylesheet":Jv,u||(c.as="script",c.crossOrigin=""),c.href=s,l&&c.setAttribute("nonce",l),document.head.appendChild(c),u)return new Promise((h,d)=>{c.addEventListener("load",h),c.addEventListener("error",()=>d(new Error(`Unable to preload CSS for ${s}`)))})}))}return o.then(()=>t()).catch(i=>{const l=new Event("vite:preloadError",{cancelable:!0});if(l.payload=i,window.dispatchEvent(l),!l.defaultPrevented)throw i})};var Nd={exports:{}};/*!
-	Copyright (c) 2018 Jed Watson.
-	Licensed under the MIT License (MIT), see
-	http://jedwatson.github.io/classnames
-*/(function(e){(function(){var t={}.hasOwnProperty;function n(){for(var i="",l=0;l<arguments.length;l++){var s=arguments[l];s&&(i=o(i,r(s)))}return i}function r(i){if(typeof i=="string"||typeof i=="number")return i;if(typeof i!="object")return"";if(Array.isArray(i))return n.apply(null,i);if(i.toString!==Object.prototype.toString&&!i.toString.toString().includes("[native code]"))return i.toString();var l="";for(var s in i)t.call(i,s)&&i[s]&&(l=o(l,s));return l}function o(i,l){return l?i?i+" "+l:i+l:i}e.exports?(n.default=n,e.exports=n):window.classNames=n})()})(Nd);var ey=Nd.exports;const pe=li(ey),ty=["xxl","xl","lg","md","sm","xs"],ny="xs",ry=w.createContext({prefixes:{},breakpoints:ty,minBreakpoint:ny});function Fe(e,t){const{prefixes:n}=w.useContext(ry);return e||n[t]||t}const is=w.forwardRef(({bsPrefix:e,fluid:t=!1,as:n="div",className:r,...o},i)=>{const l=Fe(e,"container"),s=typeof t=="string"?`-${t}`:"-fluid";return _.jsx(n,{ref:i,...o,className:pe(r,t?`${l}${s}`:l)})});is.displayName="Container";var Aa={exports:{}},ls={exports:{}};(function(e,t){Object.defineProperty(t,"__esModule",{value:!0}),t.default=n;function n(r){function o(l,s,u,a,c,h){var d=a||"<<anonymous>>",v=h||u;if(s[u]==null)return l?new Error("Required "+c+" `"+v+"` was not specified "+("in `"+d+"`.")):null;for(var y=arguments.length,g=Array(y>6?y-6:0),x=6;x<y;x++)g[x-6]=arguments[x];return r.apply(void 0,[s,u,d,c,v].concat(g))}var i=o.bind(null,!1);return i.isRequired=o.bind(null,!0),i}e.exports=t.default})(ls,ls.exports);var oy=ls.exports;(function(e,t){Object.defineProperty(t,"__esModule",{value:!0}),t.default=i;var n=oy,r=o(n);function o(l){return l&&l.__esModule?l:{default:l}}function i(){for(var l=arguments.length,s=Array(l),u=0;u<l;u++)s[u]=arguments[u];function a(){for(var c=arguments.length,h=Array(c),d=0;d<c;d++)h[d]=arguments[d];var v=null;return s.forEach(function(y){if(v==null){var g=y.apply(void 0,h);g!=null&&(v=g)}}),v}return(0,r.default)(a)}e.exports=t.default})(Aa,Aa.exports);function ss(){return ss=Object.assign?Object.assign.bind():function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},ss.apply(this,arguments)}function Ld(e,t){if(e==null)return{};var n={};for(var r in e)if(Object.prototype.hasOwnProperty.call(e,r)){if(t.indexOf(r)>=0)continue;n[r]=e[r]}return n}function Ba(e){return"default"+e.charAt(0).toUpperCase()+e.substr(1)}function iy(e){var t=ly(e,"string");return typeof t=="symbol"?t:String(t)}function ly(e,t){if(typeof e!="object"||e===null)return e;var n=e[Symbol.toPrimitive];if(n!==void 0){var r=n.call(e,t);if(typeof r!="object")return r;throw new TypeError("@@toPrimitive must return a primitive value.")}return String(e)}function sy(e,t,n){var r=w.useRef(e!==void 0),o=w.useState(t),i=o[0],l=o[1],s=e!==void 0,u=r.current;return r.current=s,!s&&u&&i!==t&&l(t),[s?e:i,w.useCallback(function(a){for(var c=arguments.length,h=new Array(c>1?c-1:0),d=1;d<c;d++)h[d-1]=arguments[d];n&&n.apply(void 0,[a].concat(h)),l(a)},[n])]}function Od(e,t){return Object.keys(t).reduce(function(n,r){var o,i=n,l=i[Ba(r)],s=i[r],u=Ld(i,[Ba(r),r].map(iy)),a=t[r],c=sy(s,l,e[a]),h=c[0],d=c[1];return ss({},u,(o={},o[r]=h,o[a]=d,o))},e)}function us(e,t){return us=Object.setPrototypeOf?Object.setPrototypeOf.bind():function(r,o){return r.__proto__=o,r},us(e,t)}function uy(e,t){e.prototype=Object.create(t.prototype),e.prototype.constructor=e,us(e,t)}var ay=Function.prototype.bind.call(Function.prototype.call,[].slice);function tn(e,t){return ay(e.querySelectorAll(t))}function cy(){const[,e]=w.useReducer(t=>!t,!1);return e}const Ua=e=>!e||typeof e=="function"?e:t=>{e.current=t};function fy(e,t){const n=Ua(e),r=Ua(t);return o=>{n&&n(o),r&&r(o)}}function Xr(e,t){return w.useMemo(()=>fy(e,t),[e,t])}const cu=w.createContext(null);cu.displayName="NavContext";const dy=w.createContext(null),fu=(e,t=null)=>e!=null?String(e):t||null,oi=dy,Md=w.createContext(null),py="data-rr-ui-",hy="rrUi";function Ri(e){return`${py}${e}`}function my(e){return`${hy}${e}`}function vy(e){const t=w.useRef(e);return w.useEffect(()=>{t.current=e},[e]),t}function Ge(e){const t=vy(e);return w.useCallback(function(...n){return t.current&&t.current(...n)},[t])}const yy=["as","disabled"];function gy(e,t){if(e==null)return{};var n={},r=Object.keys(e),o,i;for(i=0;i<r.length;i++)o=r[i],!(t.indexOf(o)>=0)&&(n[o]=e[o]);return n}function wy(e){return!e||e.trim()==="#"}function jd({tagName:e,disabled:t,href:n,target:r,rel:o,role:i,onClick:l,tabIndex:s=0,type:u}){e||(n!=null||r!=null||o!=null?e="a":e="button");const a={tagName:e};if(e==="button")return[{type:u||"button",disabled:t},a];const c=d=>{if((t||e==="a"&&wy(n))&&d.preventDefault(),t){d.stopPropagation();return}l==null||l(d)},h=d=>{d.key===" "&&(d.preventDefault(),c(d))};return e==="a"&&(n||(n="#"),t&&(n=void 0)),[{role:i??"button",disabled:void 0,tabIndex:t?void 0:s,href:n,target:e==="a"?r:void 0,"aria-disabled":t||void 0,rel:e==="a"?o:void 0,onClick:c,onKeyDown:h},a]}const Id=w.forwardRef((e,t)=>{let{as:n,disabled:r}=e,o=gy(e,yy);const[i,{tagName:l}]=jd(Object.assign({tagName:n,disabled:r},o));return _.jsx(l,Object.assign({},o,i,{ref:t}))});Id.displayName="Button";const Sy=["as","active","eventKey"];function Ey(e,t){if(e==null)return{};var n={},r=Object.keys(e),o,i;for(i=0;i<r.length;i++)o=r[i],!(t.indexOf(o)>=0)&&(n[o]=e[o]);return n}function Dd({key:e,onClick:t,active:n,id:r,role:o,disabled:i}){const l=w.useContext(oi),s=w.useContext(cu),u=w.useContext(Md);let a=n;const c={role:o};if(s){!o&&s.role==="tablist"&&(c.role="tab");const h=s.getControllerId(e??null),d=s.getControlledId(e??null);c[Ri("event-key")]=e,c.id=h||r,a=n==null&&e!=null?s.activeKey===e:n,(a||!(u!=null&&u.unmountOnExit)&&!(u!=null&&u.mountOnEnter))&&(c["aria-controls"]=d)}return c.role==="tab"&&(c["aria-selected"]=a,a||(c.tabIndex=-1),i&&(c.tabIndex=-1,c["aria-disabled"]=!0)),c.onClick=Ge(h=>{i||(t==null||t(h),e!=null&&l&&!h.isPropagationStopped()&&l(e,h))}),[c,{isActive:a}]}const Fd=w.forwardRef((e,t)=>{let{as:n=Id,active:r,eventKey:o}=e,i=Ey(e,Sy);const[l,s]=Dd(Object.assign({key:fu(o,i.href),active:r},i));return l[Ri("active")]=s.isActive,_.jsx(n,Object.assign({},i,l,{ref:t}))});Fd.displayName="NavItem";const xy=Fd,ky=["as","onSelect","activeKey","role","onKeyDown"];function Cy(e,t){if(e==null)return{};var n={},r=Object.keys(e),o,i;for(i=0;i<r.length;i++)o=r[i],!(t.indexOf(o)>=0)&&(n[o]=e[o]);return n}const Wa=()=>{},Ha=Ri("event-key"),zd=w.forwardRef((e,t)=>{let{as:n="div",onSelect:r,activeKey:o,role:i,onKeyDown:l}=e,s=Cy(e,ky);const u=cy(),a=w.useRef(!1),c=w.useContext(oi),h=w.useContext(Md);let d,v;h&&(i=i||"tablist",o=h.activeKey,d=h.getControlledId,v=h.getControllerId);const y=w.useRef(null),g=p=>{const S=y.current;if(!S)return null;const k=tn(S,`[${Ha}]:not([aria-disabled=true])`),E=S.querySelector("[aria-selected=true]");if(!E||E!==document.activeElement)return null;const P=k.indexOf(E);if(P===-1)return null;let T=P+p;return T>=k.length&&(T=0),T<0&&(T=k.length-1),k[T]},x=(p,S)=>{p!=null&&(r==null||r(p,S),c==null||c(p,S))},m=p=>{if(l==null||l(p),!h)return;let S;switch(p.key){case"ArrowLeft":case"ArrowUp":S=g(-1);break;case"ArrowRight":case"ArrowDown":S=g(1);break;default:return}S&&(p.preventDefault(),x(S.dataset[my("EventKey")]||null,p),a.current=!0,u())};w.useEffect(()=>{if(y.current&&a.current){const p=y.current.querySelector(`[${Ha}][aria-selected=true]`);p==null||p.focus()}a.current=!1});const f=Xr(t,y);return _.jsx(oi.Provider,{value:x,children:_.jsx(cu.Provider,{value:{role:i,activeKey:fu(o),getControlledId:d||Wa,getControllerId:v||Wa},children:_.jsx(n,Object.assign({},s,{onKeyDown:m,ref:f,role:i}))})})});zd.displayName="Nav";const _y=Object.assign(zd,{Item:xy}),vn=w.createContext(null);vn.displayName="NavbarContext";const $d=w.createContext(null);$d.displayName="CardHeaderContext";const Ad=w.forwardRef(({className:e,bsPrefix:t,as:n="div",...r},o)=>(t=Fe(t,"nav-item"),_.jsx(n,{ref:o,className:pe(e,t),...r})));Ad.displayName="NavItem";function Py(){const e=w.useRef(!0),t=w.useRef(()=>e.current);return w.useEffect(()=>(e.current=!0,()=>{e.current=!1}),[]),t.current}function Ry(e){const t=w.useRef(null);return w.useEffect(()=>{t.current=e}),t.current}const Ty=typeof global<"u"&&global.navigator&&global.navigator.product==="ReactNative",Ny=typeof document<"u",as=Ny||Ty?w.useLayoutEffect:w.useEffect,Ly=["onKeyDown"];function Oy(e,t){if(e==null)return{};var n={},r=Object.keys(e),o,i;for(i=0;i<r.length;i++)o=r[i],!(t.indexOf(o)>=0)&&(n[o]=e[o]);return n}function My(e){return!e||e.trim()==="#"}const Bd=w.forwardRef((e,t)=>{let{onKeyDown:n}=e,r=Oy(e,Ly);const[o]=jd(Object.assign({tagName:"a"},r)),i=Ge(l=>{o.onKeyDown(l),n==null||n(l)});return My(r.href)||r.role==="button"?_.jsx("a",Object.assign({ref:t},r,o,{onKeyDown:i})):_.jsx("a",Object.assign({ref:t},r,{onKeyDown:n}))});Bd.displayName="Anchor";const Ud=w.forwardRef(({bsPrefix:e,className:t,as:n=Bd,active:r,eventKey:o,disabled:i=!1,...l},s)=>{e=Fe(e,"nav-link");const[u,a]=Dd({key:fu(o,l.href),active:r,disabled:i,...l});return _.jsx(n,{...l,...u,ref:s,disabled:i,className:pe(t,e,i&&"disabled",a.isActive&&"active")})});Ud.displayName="NavLink";const Wd=w.forwardRef((e,t)=>{const{as:n="div",bsPrefix:r,variant:o,fill:i=!1,justify:l=!1,navbar:s,navbarScroll:u,className:a,activeKey:c,...h}=Od(e,{activeKey:"onSelect"}),d=Fe(r,"nav");let v,y,g=!1;const x=w.useContext(vn),m=w.useContext($d);return x?(v=x.bsPrefix,g=s??!0):m&&({cardHeaderBsPrefix:y}=m),_.jsx(_y,{as:n,ref:t,activeKey:c,className:pe(a,{[d]:!g,[`${v}-nav`]:g,[`${v}-nav-scroll`]:g&&u,[`${y}-${o}`]:!!y,[`${d}-${o}`]:!!o,[`${d}-fill`]:i,[`${d}-justified`]:l}),...h})});Wd.displayName="Nav";const Va=Object.assign(Wd,{Item:Ad,Link:Ud}),Hd=w.forwardRef(({bsPrefix:e,className:t,as:n,...r},o)=>{e=Fe(e,"navbar-brand");const i=n||(r.href?"a":"span");return _.jsx(i,{...r,ref:o,className:pe(t,e)})});Hd.displayName="NavbarBrand";function du(e){return e&&e.ownerDocument||document}function jy(e){var t=du(e);return t&&t.defaultView||window}function Iy(e,t){return jy(e).getComputedStyle(e,t)}var Dy=/([A-Z])/g;function Fy(e){return e.replace(Dy,"-$1").toLowerCase()}var zy=/^ms-/;function yo(e){return Fy(e).replace(zy,"-ms-")}var $y=/^((translate|rotate|scale)(X|Y|Z|3d)?|matrix(3d)?|perspective|skew(X|Y)?)$/i;function Ay(e){return!!(e&&$y.test(e))}function St(e,t){var n="",r="";if(typeof t=="string")return e.style.getPropertyValue(yo(t))||Iy(e).getPropertyValue(yo(t));Object.keys(t).forEach(function(o){var i=t[o];!i&&i!==0?e.style.removeProperty(yo(o)):Ay(o)?r+=o+"("+i+") ":n+=yo(o)+": "+i+";"}),r&&(n+="transform: "+r+";"),e.style.cssText+=";"+n}var Vd={exports:{}},By="SECRET_DO_NOT_PASS_THIS_OR_YOU_WILL_BE_FIRED",Uy=By,Wy=Uy;function Kd(){}function Qd(){}Qd.resetWarningCache=Kd;var Hy=function(){function e(r,o,i,l,s,u){if(u!==Wy){var a=new Error("Calling PropTypes validators directly is not supported by the `prop-types` package. Use PropTypes.checkPropTypes() to call them. Read more at http://fb.me/use-check-prop-types");throw a.name="Invariant Violation",a}}e.isRequired=e;function t(){return e}var n={array:e,bigint:e,bool:e,func:e,number:e,object:e,string:e,symbol:e,any:e,arrayOf:t,element:e,elementType:e,instanceOf:t,node:e,objectOf:t,oneOf:t,oneOfType:t,shape:t,exact:t,checkPropTypes:Qd,resetWarningCache:Kd};return n.PropTypes=n,n};Vd.exports=Hy();var Vy=Vd.exports;const il=li(Vy),Ka={disabled:!1},Gd=ct.createContext(null);var Ky=function(t){return t.scrollTop},pr="unmounted",Mt="exited",nt="entering",vt="entered",Hr="exiting",Rt=function(e){uy(t,e);function t(r,o){var i;i=e.call(this,r,o)||this;var l=o,s=l&&!l.isMounting?r.enter:r.appear,u;return i.appearStatus=null,r.in?s?(u=Mt,i.appearStatus=nt):u=vt:r.unmountOnExit||r.mountOnEnter?u=pr:u=Mt,i.state={status:u},i.nextCallback=null,i}t.getDerivedStateFromProps=function(o,i){var l=o.in;return l&&i.status===pr?{status:Mt}:null};var n=t.prototype;return n.componentDidMount=function(){this.updateStatus(!0,this.appearStatus)},n.componentDidUpdate=function(o){var i=null;if(o!==this.props){var l=this.state.status;this.props.in?l!==nt&&l!==vt&&(i=nt):(l===nt||l===vt)&&(i=Hr)}this.updateStatus(!1,i)},n.componentWillUnmount=function(){this.cancelNextCallback()},n.getTimeouts=function(){var o=this.props.timeout,i,l,s;return i=l=s=o,o!=null&&typeof o!="number"&&(i=o.exit,l=o.enter,s=o.appear!==void 0?o.appear:l),{exit:i,enter:l,appear:s}},n.updateStatus=function(o,i){if(o===void 0&&(o=!1),i!==null)if(this.cancelNextCallback(),i===nt){if(this.props.unmountOnExit||this.props.mountOnEnter){var l=this.props.nodeRef?this.props.nodeRef.current:Mn.findDOMNode(this);l&&Ky(l)}this.performEnter(o)}else this.performExit();else this.props.unmountOnExit&&this.state.status===Mt&&this.setState({status:pr})},n.performEnter=function(o){var i=this,l=this.props.enter,s=this.context?this.context.isMounting:o,u=this.props.nodeRef?[s]:[Mn.findDOMNode(this),s],a=u[0],c=u[1],h=this.getTimeouts(),d=s?h.appear:h.enter;if(!o&&!l||Ka.disabled){this.safeSetState({status:vt},function(){i.props.onEntered(a)});return}this.props.onEnter(a,c),this.safeSetState({status:nt},function(){i.props.onEntering(a,c),i.onTransitionEnd(d,function(){i.safeSetState({status:vt},function(){i.props.onEntered(a,c)})})})},n.performExit=function(){var o=this,i=this.props.exit,l=this.getTimeouts(),s=this.props.nodeRef?void 0:Mn.findDOMNode(this);if(!i||Ka.disabled){this.safeSetState({status:Mt},function(){o.props.onExited(s)});return}this.props.onExit(s),this.safeSetState({status:Hr},function(){o.props.onExiting(s),o.onTransitionEnd(l.exit,function(){o.safeSetState({status:Mt},function(){o.props.onExited(s)})})})},n.cancelNextCallback=function(){this.nextCallback!==null&&(this.nextCallback.cancel(),this.nextCallback=null)},n.safeSetState=function(o,i){i=this.setNextCallback(i),this.setState(o,i)},n.setNextCallback=function(o){var i=this,l=!0;return this.nextCallback=function(s){l&&(l=!1,i.nextCallback=null,o(s))},this.nextCallback.cancel=function(){l=!1},this.nextCallback},n.onTransitionEnd=function(o,i){this.setNextCallback(i);var l=this.props.nodeRef?this.props.nodeRef.current:Mn.findDOMNode(this),s=o==null&&!this.props.addEndListener;if(!l||s){setTimeout(this.nextCallback,0);return}if(this.props.addEndListener){var u=this.props.nodeRef?[this.nextCallback]:[l,this.nextCallback],a=u[0],c=u[1];this.props.addEndListener(a,c)}o!=null&&setTimeout(this.nextCallback,o)},n.render=function(){var o=this.state.status;if(o===pr)return null;var i=this.props,l=i.children;i.in,i.mountOnEnter,i.unmountOnExit,i.appear,i.enter,i.exit,i.timeout,i.addEndListener,i.onEnter,i.onEntering,i.onEntered,i.onExit,i.onExiting,i.onExited,i.nodeRef;var s=Ld(i,["children","in","mountOnEnter","unmountOnExit","appear","enter","exit","timeout","addEndListener","onEnter","onEntering","onEntered","onExit","onExiting","onExited","nodeRef"]);return ct.createElement(Gd.Provider,{value:null},typeof l=="function"?l(o,s):ct.cloneElement(ct.Children.only(l),s))},t}(ct.Component);Rt.contextType=Gd;Rt.propTypes={};function gn(){}Rt.defaultProps={in:!1,mountOnEnter:!1,unmountOnExit:!1,appear:!1,enter:!0,exit:!0,onEnter:gn,onEntering:gn,onEntered:gn,onExit:gn,onExiting:gn,onExited:gn};Rt.UNMOUNTED=pr;Rt.EXITED=Mt;Rt.ENTERING=nt;Rt.ENTERED=vt;Rt.EXITING=Hr;const Ti=!!(typeof window<"u"&&window.document&&window.document.createElement);var cs=!1,fs=!1;try{var ll={get passive(){return cs=!0},get once(){return fs=cs=!0}};Ti&&(window.addEventListener("test",ll,ll),window.removeEventListener("test",ll,!0))}catch{}function Qy(e,t,n,r){if(r&&typeof r!="boolean"&&!fs){var o=r.once,i=r.capture,l=n;!fs&&o&&(l=n.__once||function s(u){this.removeEventListener(t,s,i),n.call(this,u)},n.__once=l),e.addEventListener(t,l,cs?r:i)}e.addEventListener(t,n,r)}function Gy(e,t,n,r){var o=r&&typeof r!="boolean"?r.capture:r;e.removeEventListener(t,n,o),n.__once&&e.removeEventListener(t,n.__once,o)}function ii(e,t,n,r){return Qy(e,t,n,r),function(){Gy(e,t,n,r)}}function Yy(e,t,n,r){if(r===void 0&&(r=!0),e){var o=document.createEvent("HTMLEvents");o.initEvent(t,n,r),e.dispatchEvent(o)}}function Xy(e){var t=St(e,"transitionDuration")||"",n=t.indexOf("ms")===-1?1e3:1;return parseFloat(t)*n}function Zy(e,t,n){n===void 0&&(n=5);var r=!1,o=setTimeout(function(){r||Yy(e,"transitionend",!0)},t+n),i=ii(e,"transitionend",function(){r=!0},{once:!0});return function(){clearTimeout(o),i()}}function Jy(e,t,n,r){n==null&&(n=Xy(e)||0);var o=Zy(e,n,r),i=ii(e,"transitionend",t);return function(){o(),i()}}function Qa(e,t){const n=St(e,t)||"",r=n.indexOf("ms")===-1?1e3:1;return parseFloat(n)*r}function pu(e,t){const n=Qa(e,"transitionDuration"),r=Qa(e,"transitionDelay"),o=Jy(e,i=>{i.target===e&&(o(),t(i))},n+r)}function ur(...e){return e.filter(t=>t!=null).reduce((t,n)=>{if(typeof n!="function")throw new Error("Invalid Argument Type, must only provide functions, undefined, or null.");return t===null?n:function(...o){t.apply(this,o),n.apply(this,o)}},null)}function Yd(e){e.offsetHeight}function qy(e){return e&&"setState"in e?Mn.findDOMNode(e):e??null}const hu=ct.forwardRef(({onEnter:e,onEntering:t,onEntered:n,onExit:r,onExiting:o,onExited:i,addEndListener:l,children:s,childRef:u,...a},c)=>{const h=w.useRef(null),d=Xr(h,u),v=E=>{d(qy(E))},y=E=>P=>{E&&h.current&&E(h.current,P)},g=w.useCallback(y(e),[e]),x=w.useCallback(y(t),[t]),m=w.useCallback(y(n),[n]),f=w.useCallback(y(r),[r]),p=w.useCallback(y(o),[o]),S=w.useCallback(y(i),[i]),k=w.useCallback(y(l),[l]);return _.jsx(Rt,{ref:c,...a,onEnter:g,onEntered:m,onEntering:x,onExit:f,onExited:S,onExiting:p,addEndListener:k,nodeRef:h,children:typeof s=="function"?(E,P)=>s(E,{...P,ref:v}):ct.cloneElement(s,{ref:v})})}),by={height:["marginTop","marginBottom"],width:["marginLeft","marginRight"]};function eg(e,t){const n=`offset${e[0].toUpperCase()}${e.slice(1)}`,r=t[n],o=by[e];return r+parseInt(St(t,o[0]),10)+parseInt(St(t,o[1]),10)}const tg={[Mt]:"collapse",[Hr]:"collapsing",[nt]:"collapsing",[vt]:"collapse show"},ng=ct.forwardRef(({onEnter:e,onEntering:t,onEntered:n,onExit:r,onExiting:o,className:i,children:l,dimension:s="height",in:u=!1,timeout:a=300,mountOnEnter:c=!1,unmountOnExit:h=!1,appear:d=!1,getDimensionValue:v=eg,...y},g)=>{const x=typeof s=="function"?s():s,m=w.useMemo(()=>ur(E=>{E.style[x]="0"},e),[x,e]),f=w.useMemo(()=>ur(E=>{const P=`scroll${x[0].toUpperCase()}${x.slice(1)}`;E.style[x]=`${E[P]}px`},t),[x,t]),p=w.useMemo(()=>ur(E=>{E.style[x]=null},n),[x,n]),S=w.useMemo(()=>ur(E=>{E.style[x]=`${v(x,E)}px`,Yd(E)},r),[r,v,x]),k=w.useMemo(()=>ur(E=>{E.style[x]=null},o),[x,o]);return _.jsx(hu,{ref:g,addEndListener:pu,...y,"aria-expanded":y.role?u:null,onEnter:m,onEntering:f,onEntered:p,onExit:S,onExiting:k,childRef:l.ref,in:u,timeout:a,mountOnEnter:c,unmountOnExit:h,appear:d,children:(E,P)=>ct.cloneElement(l,{...P,className:pe(i,l.props.className,tg[E],x==="width"&&"collapse-horizontal")})})}),Xd=w.forwardRef(({children:e,bsPrefix:t,...n},r)=>{t=Fe(t,"navbar-collapse");const o=w.useContext(vn);return _.jsx(ng,{in:!!(o&&o.expanded),...n,children:_.jsx("div",{ref:r,className:t,children:e})})});Xd.displayName="NavbarCollapse";const Zd=w.forwardRef(({bsPrefix:e,className:t,children:n,label:r="Toggle navigation",as:o="button",onClick:i,...l},s)=>{e=Fe(e,"navbar-toggler");const{onToggle:u,expanded:a}=w.useContext(vn)||{},c=Ge(h=>{i&&i(h),u&&u()});return o==="button"&&(l.type="button"),_.jsx(o,{...l,ref:s,onClick:c,"aria-label":r,className:pe(t,e,!a&&"collapsed"),children:n||_.jsx("span",{className:`${e}-icon`})})});Zd.displayName="NavbarToggle";const ds=new WeakMap,Ga=(e,t)=>{if(!e||!t)return;const n=ds.get(t)||new Map;ds.set(t,n);let r=n.get(e);return r||(r=t.matchMedia(e),r.refCount=0,n.set(r.media,r)),r};function rg(e,t=typeof window>"u"?void 0:window){const n=Ga(e,t),[r,o]=w.useState(()=>n?n.matches:!1);return as(()=>{let i=Ga(e,t);if(!i)return o(!1);let l=ds.get(t);const s=()=>{o(i.matches)};return i.refCount++,i.addListener(s),s(),()=>{i.removeListener(s),i.refCount--,i.refCount<=0&&(l==null||l.delete(i.media)),i=void 0}},[e]),r}function og(e){const t=Object.keys(e);function n(s,u){return s===u?u:s?`${s} and ${u}`:u}function r(s){return t[Math.min(t.indexOf(s)+1,t.length-1)]}function o(s){const u=r(s);let a=e[u];return typeof a=="number"?a=`${a-.2}px`:a=`calc(${a} - 0.2px)`,`(max-width: ${a})`}function i(s){let u=e[s];return typeof u=="number"&&(u=`${u}px`),`(min-width: ${u})`}function l(s,u,a){let c;typeof s=="object"?(c=s,a=u,u=!0):(u=u||!0,c={[s]:u});let h=w.useMemo(()=>Object.entries(c).reduce((d,[v,y])=>((y==="up"||y===!0)&&(d=n(d,i(v))),(y==="down"||y===!0)&&(d=n(d,o(v))),d),""),[JSON.stringify(c)]);return rg(h,a)}return l}const ig=og({xs:0,sm:576,md:768,lg:992,xl:1200,xxl:1400});function sl(e){e===void 0&&(e=du());try{var t=e.activeElement;return!t||!t.nodeName?null:t}catch{return e.body}}function Ya(e,t){if(e.contains)return e.contains(t);if(e.compareDocumentPosition)return e===t||!!(e.compareDocumentPosition(t)&16)}function lg(e){const t=w.useRef(e);return t.current=e,t}function sg(e){const t=lg(e);w.useEffect(()=>()=>t.current(),[])}function ug(e=document){const t=e.defaultView;return Math.abs(t.innerWidth-e.documentElement.clientWidth)}const Xa=Ri("modal-open");class mu{constructor({ownerDocument:t,handleContainerOverflow:n=!0,isRTL:r=!1}={}){this.handleContainerOverflow=n,this.isRTL=r,this.modals=[],this.ownerDocument=t}getScrollbarWidth(){return ug(this.ownerDocument)}getElement(){return(this.ownerDocument||document).body}setModalAttributes(t){}removeModalAttributes(t){}setContainerStyle(t){const n={overflow:"hidden"},r=this.isRTL?"paddingLeft":"paddingRight",o=this.getElement();t.style={overflow:o.style.overflow,[r]:o.style[r]},t.scrollBarWidth&&(n[r]=`${parseInt(St(o,r)||"0",10)+t.scrollBarWidth}px`),o.setAttribute(Xa,""),St(o,n)}reset(){[...this.modals].forEach(t=>this.remove(t))}removeContainerStyle(t){const n=this.getElement();n.removeAttribute(Xa),Object.assign(n.style,t.style)}add(t){let n=this.modals.indexOf(t);return n!==-1||(n=this.modals.length,this.modals.push(t),this.setModalAttributes(t),n!==0)||(this.state={scrollBarWidth:this.getScrollbarWidth(),style:{}},this.handleContainerOverflow&&this.setContainerStyle(this.state)),n}remove(t){const n=this.modals.indexOf(t);n!==-1&&(this.modals.splice(n,1),!this.modals.length&&this.handleContainerOverflow&&this.removeContainerStyle(this.state),this.removeModalAttributes(t))}isTopModal(t){return!!this.modals.length&&this.modals[this.modals.length-1]===t}}const Jd=w.createContext(Ti?window:void 0);Jd.Provider;function vu(){return w.useContext(Jd)}const ul=(e,t)=>Ti?e==null?(t||du()).body:(typeof e=="function"&&(e=e()),e&&"current"in e&&(e=e.current),e&&("nodeType"in e||e.getBoundingClientRect)?e:null):null;function ag(e,t){const n=vu(),[r,o]=w.useState(()=>ul(e,n==null?void 0:n.document));if(!r){const i=ul(e);i&&o(i)}return w.useEffect(()=>{},[t,r]),w.useEffect(()=>{const i=ul(e);i!==r&&o(i)},[e,r]),r}function cg({children:e,in:t,onExited:n,mountOnEnter:r,unmountOnExit:o}){const i=w.useRef(null),l=w.useRef(t),s=Ge(n);w.useEffect(()=>{t?l.current=!0:s(i.current)},[t,s]);const u=Xr(i,e.ref),a=w.cloneElement(e,{ref:u});return t?a:o||!l.current&&r?null:a}function fg(e){return e.code==="Escape"||e.keyCode===27}function dg(){const e=w.version.split(".");return{major:+e[0],minor:+e[1],patch:+e[2]}}const pg=["onEnter","onEntering","onEntered","onExit","onExiting","onExited","addEndListener","children"];function hg(e,t){if(e==null)return{};var n={},r=Object.keys(e),o,i;for(i=0;i<r.length;i++)o=r[i],!(t.indexOf(o)>=0)&&(n[o]=e[o]);return n}function mg(e){let{onEnter:t,onEntering:n,onEntered:r,onExit:o,onExiting:i,onExited:l,addEndListener:s,children:u}=e,a=hg(e,pg);const{major:c}=dg(),h=c>=19?u.props.ref:u.ref,d=w.useRef(null),v=Xr(d,typeof u=="function"?null:h),y=E=>P=>{E&&d.current&&E(d.current,P)},g=w.useCallback(y(t),[t]),x=w.useCallback(y(n),[n]),m=w.useCallback(y(r),[r]),f=w.useCallback(y(o),[o]),p=w.useCallback(y(i),[i]),S=w.useCallback(y(l),[l]),k=w.useCallback(y(s),[s]);return Object.assign({},a,{nodeRef:d},t&&{onEnter:g},n&&{onEntering:x},r&&{onEntered:m},o&&{onExit:f},i&&{onExiting:p},l&&{onExited:S},s&&{addEndListener:k},{children:typeof u=="function"?(E,P)=>u(E,Object.assign({},P,{ref:v})):w.cloneElement(u,{ref:v})})}const vg=["component"];function yg(e,t){if(e==null)return{};var n={},r=Object.keys(e),o,i;for(i=0;i<r.length;i++)o=r[i],!(t.indexOf(o)>=0)&&(n[o]=e[o]);return n}const gg=w.forwardRef((e,t)=>{let{component:n}=e,r=yg(e,vg);const o=mg(r);return _.jsx(n,Object.assign({ref:t},o))});function wg({in:e,onTransition:t}){const n=w.useRef(null),r=w.useRef(!0),o=Ge(t);return as(()=>{if(!n.current)return;let i=!1;return o({in:e,element:n.current,initial:r.current,isStale:()=>i}),()=>{i=!0}},[e,o]),as(()=>(r.current=!1,()=>{r.current=!0}),[]),n}function Sg({children:e,in:t,onExited:n,onEntered:r,transition:o}){const[i,l]=w.useState(!t);t&&i&&l(!1);const s=wg({in:!!t,onTransition:a=>{const c=()=>{a.isStale()||(a.in?r==null||r(a.element,a.initial):(l(!0),n==null||n(a.element)))};Promise.resolve(o(a)).then(c,h=>{throw a.in||l(!0),h})}}),u=Xr(s,e.ref);return i&&!t?null:w.cloneElement(e,{ref:u})}function Za(e,t,n){return e?_.jsx(gg,Object.assign({},n,{component:e})):t?_.jsx(Sg,Object.assign({},n,{transition:t})):_.jsx(cg,Object.assign({},n))}const Eg=["show","role","className","style","children","backdrop","keyboard","onBackdropClick","onEscapeKeyDown","transition","runTransition","backdropTransition","runBackdropTransition","autoFocus","enforceFocus","restoreFocus","restoreFocusOptions","renderDialog","renderBackdrop","manager","container","onShow","onHide","onExit","onExited","onExiting","onEnter","onEntering","onEntered"];function xg(e,t){if(e==null)return{};var n={},r=Object.keys(e),o,i;for(i=0;i<r.length;i++)o=r[i],!(t.indexOf(o)>=0)&&(n[o]=e[o]);return n}let al;function kg(e){return al||(al=new mu({ownerDocument:e==null?void 0:e.document})),al}function Cg(e){const t=vu(),n=e||kg(t),r=w.useRef({dialog:null,backdrop:null});return Object.assign(r.current,{add:()=>n.add(r.current),remove:()=>n.remove(r.current),isTopModal:()=>n.isTopModal(r.current),setDialogRef:w.useCallback(o=>{r.current.dialog=o},[]),setBackdropRef:w.useCallback(o=>{r.current.backdrop=o},[])})}const qd=w.forwardRef((e,t)=>{let{show:n=!1,role:r="dialog",className:o,style:i,children:l,backdrop:s=!0,keyboard:u=!0,onBackdropClick:a,onEscapeKeyDown:c,transition:h,runTransition:d,backdropTransition:v,runBackdropTransition:y,autoFocus:g=!0,enforceFocus:x=!0,restoreFocus:m=!0,restoreFocusOptions:f,renderDialog:p,renderBackdrop:S=Q=>_.jsx("div",Object.assign({},Q)),manager:k,container:E,onShow:P,onHide:T=()=>{},onExit:D,onExited:M,onExiting:B,onEnter:I,onEntering:j,onEntered:$}=e,ne=xg(e,Eg);const X=vu(),V=ag(E),C=Cg(k),L=Py(),O=Ry(n),[F,U]=w.useState(!n),ye=w.useRef(null);w.useImperativeHandle(t,()=>C,[C]),Ti&&!O&&n&&(ye.current=sl(X==null?void 0:X.document)),n&&F&&U(!1);const le=Ge(()=>{if(C.add(),ze.current=ii(document,"keydown",ue),Re.current=ii(document,"focus",()=>setTimeout(se),!0),P&&P(),g){var Q,Zr;const bn=sl((Q=(Zr=C.dialog)==null?void 0:Zr.ownerDocument)!=null?Q:X==null?void 0:X.document);C.dialog&&bn&&!Ya(C.dialog,bn)&&(ye.current=bn,C.dialog.focus())}}),J=Ge(()=>{if(C.remove(),ze.current==null||ze.current(),Re.current==null||Re.current(),m){var Q;(Q=ye.current)==null||Q.focus==null||Q.focus(f),ye.current=null}});w.useEffect(()=>{!n||!V||le()},[n,V,le]),w.useEffect(()=>{F&&J()},[F,J]),sg(()=>{J()});const se=Ge(()=>{if(!x||!L()||!C.isTopModal())return;const Q=sl(X==null?void 0:X.document);C.dialog&&Q&&!Ya(C.dialog,Q)&&C.dialog.focus()}),xe=Ge(Q=>{Q.target===Q.currentTarget&&(a==null||a(Q),s===!0&&T())}),ue=Ge(Q=>{u&&fg(Q)&&C.isTopModal()&&(c==null||c(Q),Q.defaultPrevented||T())}),Re=w.useRef(),ze=w.useRef(),Te=(...Q)=>{U(!0),M==null||M(...Q)};if(!V)return null;const H=Object.assign({role:r,ref:C.setDialogRef,"aria-modal":r==="dialog"?!0:void 0},ne,{style:i,className:o,tabIndex:-1});let oe=p?p(H):_.jsx("div",Object.assign({},H,{children:w.cloneElement(l,{role:"document"})}));oe=Za(h,d,{unmountOnExit:!0,mountOnEnter:!0,appear:!0,in:!!n,onExit:D,onExiting:B,onExited:Te,onEnter:I,onEntering:j,onEntered:$,children:oe});let Tt=null;return s&&(Tt=S({ref:C.setBackdropRef,onClick:xe}),Tt=Za(v,y,{in:!!n,appear:!0,mountOnEnter:!0,unmountOnExit:!0,children:Tt})),_.jsx(_.Fragment,{children:Mn.createPortal(_.jsxs(_.Fragment,{children:[Tt,oe]}),V)})});qd.displayName="Modal";const _g=Object.assign(qd,{Manager:mu}),Pg={[nt]:"show",[vt]:"show"},bd=w.forwardRef(({className:e,children:t,transitionClasses:n={},onEnter:r,...o},i)=>{const l={in:!1,timeout:300,mountOnEnter:!1,unmountOnExit:!1,appear:!1,...o},s=w.useCallback((u,a)=>{Yd(u),r==null||r(u,a)},[r]);return _.jsx(hu,{ref:i,addEndListener:pu,...l,onEnter:s,childRef:t.ref,children:(u,a)=>w.cloneElement(t,{...a,className:pe("fade",e,t.props.className,Pg[u],n[u])})})});bd.displayName="Fade";const ep=w.forwardRef(({className:e,bsPrefix:t,as:n="div",...r},o)=>(t=Fe(t,"offcanvas-body"),_.jsx(n,{ref:o,className:pe(e,t),...r})));ep.displayName="OffcanvasBody";const Rg={[nt]:"show",[vt]:"show"},tp=w.forwardRef(({bsPrefix:e,className:t,children:n,in:r=!1,mountOnEnter:o=!1,unmountOnExit:i=!1,appear:l=!1,...s},u)=>(e=Fe(e,"offcanvas"),_.jsx(hu,{ref:u,addEndListener:pu,in:r,mountOnEnter:o,unmountOnExit:i,appear:l,...s,childRef:n.ref,children:(a,c)=>w.cloneElement(n,{...c,className:pe(t,n.props.className,(a===nt||a===Hr)&&`${e}-toggling`,Rg[a])})})));tp.displayName="OffcanvasToggling";const np=w.createContext({onHide(){}}),Tg={"aria-label":il.string,onClick:il.func,variant:il.oneOf(["white"])},yu=w.forwardRef(({className:e,variant:t,"aria-label":n="Close",...r},o)=>_.jsx("button",{ref:o,type:"button",className:pe("btn-close",t&&`btn-close-${t}`,e),"aria-label":n,...r}));yu.displayName="CloseButton";yu.propTypes=Tg;const Ng=w.forwardRef(({closeLabel:e="Close",closeVariant:t,closeButton:n=!1,onHide:r,children:o,...i},l)=>{const s=w.useContext(np),u=Ge(()=>{s==null||s.onHide(),r==null||r()});return _.jsxs("div",{ref:l,...i,children:[o,n&&_.jsx(yu,{"aria-label":e,variant:t,onClick:u})]})}),rp=w.forwardRef(({bsPrefix:e,className:t,closeLabel:n="Close",closeButton:r=!1,...o},i)=>(e=Fe(e,"offcanvas-header"),_.jsx(Ng,{ref:i,...o,className:pe(t,e),closeLabel:n,closeButton:r})));rp.displayName="OffcanvasHeader";const Lg=e=>w.forwardRef((t,n)=>_.jsx("div",{...t,ref:n,className:pe(t.className,e)})),Og=Lg("h5"),op=w.forwardRef(({className:e,bsPrefix:t,as:n=Og,...r},o)=>(t=Fe(t,"offcanvas-title"),_.jsx(n,{ref:o,className:pe(e,t),...r})));op.displayName="OffcanvasTitle";function Mg(e,t){return e.classList?e.classList.contains(t):(" "+(e.className.baseVal||e.className)+" ").indexOf(" "+t+" ")!==-1}function jg(e,t){e.classList?e.classList.add(t):Mg(e,t)||(typeof e.className=="string"?e.className=e.className+" "+t:e.setAttribute("class",(e.className&&e.className.baseVal||"")+" "+t))}function Ja(e,t){return e.replace(new RegExp("(^|\\s)"+t+"(?:\\s|$)","g"),"$1").replace(/\s+/g," ").replace(/^\s*|\s*$/g,"")}function Ig(e,t){e.classList?e.classList.remove(t):typeof e.className=="string"?e.className=Ja(e.className,t):e.setAttribute("class",Ja(e.className&&e.className.baseVal||"",t))}const wn={FIXED_CONTENT:".fixed-top, .fixed-bottom, .is-fixed, .sticky-top",STICKY_CONTENT:".sticky-top",NAVBAR_TOGGLER:".navbar-toggler"};class ip extends mu{adjustAndStore(t,n,r){const o=n.style[t];n.dataset[t]=o,St(n,{[t]:`${parseFloat(St(n,t))+r}px`})}restore(t,n){const r=n.dataset[t];r!==void 0&&(delete n.dataset[t],St(n,{[t]:r}))}setContainerStyle(t){super.setContainerStyle(t);const n=this.getElement();if(jg(n,"modal-open"),!t.scrollBarWidth)return;const r=this.isRTL?"paddingLeft":"paddingRight",o=this.isRTL?"marginLeft":"marginRight";tn(n,wn.FIXED_CONTENT).forEach(i=>this.adjustAndStore(r,i,t.scrollBarWidth)),tn(n,wn.STICKY_CONTENT).forEach(i=>this.adjustAndStore(o,i,-t.scrollBarWidth)),tn(n,wn.NAVBAR_TOGGLER).forEach(i=>this.adjustAndStore(o,i,t.scrollBarWidth))}removeContainerStyle(t){super.removeContainerStyle(t);const n=this.getElement();Ig(n,"modal-open");const r=this.isRTL?"paddingLeft":"paddingRight",o=this.isRTL?"marginLeft":"marginRight";tn(n,wn.FIXED_CONTENT).forEach(i=>this.restore(r,i)),tn(n,wn.STICKY_CONTENT).forEach(i=>this.restore(o,i)),tn(n,wn.NAVBAR_TOGGLER).forEach(i=>this.restore(o,i))}}let cl;function Dg(e){return cl||(cl=new ip(e)),cl}function Fg(e){return _.jsx(tp,{...e})}function zg(e){return _.jsx(bd,{...e})}const lp=w.forwardRef(({bsPrefix:e,className:t,children:n,"aria-labelledby":r,placement:o="start",responsive:i,show:l=!1,backdrop:s=!0,keyboard:u=!0,scroll:a=!1,onEscapeKeyDown:c,onShow:h,onHide:d,container:v,autoFocus:y=!0,enforceFocus:g=!0,restoreFocus:x=!0,restoreFocusOptions:m,onEntered:f,onExit:p,onExiting:S,onEnter:k,onEntering:E,onExited:P,backdropClassName:T,manager:D,renderStaticNode:M=!1,...B},I)=>{const j=w.useRef();e=Fe(e,"offcanvas");const{onToggle:$}=w.useContext(vn)||{},[ne,X]=w.useState(!1),V=ig(i||"xs","up");w.useEffect(()=>{X(i?l&&!V:l)},[l,i,V]);const C=Ge(()=>{$==null||$(),d==null||d()}),L=w.useMemo(()=>({onHide:C}),[C]);function O(){return D||(a?(j.current||(j.current=new ip({handleContainerOverflow:!1})),j.current):Dg())}const F=(J,...se)=>{J&&(J.style.visibility="visible"),k==null||k(J,...se)},U=(J,...se)=>{J&&(J.style.visibility=""),P==null||P(...se)},ye=w.useCallback(J=>_.jsx("div",{...J,className:pe(`${e}-backdrop`,T)}),[T,e]),le=J=>_.jsx("div",{...J,...B,className:pe(t,i?`${e}-${i}`:e,`${e}-${o}`),"aria-labelledby":r,children:n});return _.jsxs(_.Fragment,{children:[!ne&&(i||M)&&le({}),_.jsx(np.Provider,{value:L,children:_.jsx(_g,{show:ne,ref:I,backdrop:s,container:v,keyboard:u,autoFocus:y,enforceFocus:g&&!a,restoreFocus:x,restoreFocusOptions:m,onEscapeKeyDown:c,onShow:h,onHide:C,onEnter:F,onEntering:E,onEntered:f,onExit:p,onExiting:S,onExited:U,manager:O(),transition:Fg,backdropTransition:zg,renderBackdrop:ye,renderDialog:le})})]})});lp.displayName="Offcanvas";const $g=Object.assign(lp,{Body:ep,Header:rp,Title:op}),sp=w.forwardRef((e,t)=>{const n=w.useContext(vn);return _.jsx($g,{ref:t,show:!!(n!=null&&n.expanded),...e,renderStaticNode:!0})});sp.displayName="NavbarOffcanvas";const up=w.forwardRef(({className:e,bsPrefix:t,as:n="span",...r},o)=>(t=Fe(t,"navbar-text"),_.jsx(n,{ref:o,className:pe(e,t),...r})));up.displayName="NavbarText";const ap=w.forwardRef((e,t)=>{const{bsPrefix:n,expand:r=!0,variant:o="light",bg:i,fixed:l,sticky:s,className:u,as:a="nav",expanded:c,onToggle:h,onSelect:d,collapseOnSelect:v=!1,...y}=Od(e,{expanded:"onToggle"}),g=Fe(n,"navbar"),x=w.useCallback((...p)=>{d==null||d(...p),v&&c&&(h==null||h(!1))},[d,v,c,h]);y.role===void 0&&a!=="nav"&&(y.role="navigation");let m=`${g}-expand`;typeof r=="string"&&(m=`${m}-${r}`);const f=w.useMemo(()=>({onToggle:()=>h==null?void 0:h(!c),bsPrefix:g,expanded:!!c,expand:r}),[g,c,r,h]);return _.jsx(vn.Provider,{value:f,children:_.jsx(oi.Provider,{value:x,children:_.jsx(a,{ref:t,...y,className:pe(u,g,r&&m,o&&`${g}-${o}`,i&&`bg-${i}`,s&&`sticky-${s}`,l&&`fixed-${l}`)})})})});ap.displayName="Navbar";const go=Object.assign(ap,{Brand:Hd,Collapse:Xd,Offcanvas:sp,Text:up,Toggle:Zd}),cp=Uv({component:()=>_.jsxs(_.Fragment,{children:[_.jsx(go,{expand:"lg",className:"bg-body-tertiary",children:_.jsxs(is,{children:[_.jsx(go.Brand,{children:"VIGIL"}),_.jsx(go.Toggle,{"aria-controls":"basic-navbar-nav"}),_.jsx(go.Collapse,{id:"basic-navbar-nav",children:_.jsx(Va,{className:"me-auto",children:_.jsx(Va.Link,{children:_.jsx(Xv,{to:"/",children:"Home"})})})})]})}),_.jsx(is,{className:"my-5",children:_.jsx(Ed,{})})]})}),Ag=Wv("/")(),Bg=Ag.update({path:"/",getParentRoute:()=>cp}).lazy(()=>bv(()=>import("./index.lazy-BFForo0j.js"),[]).then(e=>e.Route)),Ug=cp.addChildren({IndexLazyRoute:Bg}),Wg=jv({routeTree:Ug});fl.createRoot(document.getElementById("root")).render(_.jsx(ct.StrictMode,{children:_.jsx(Tv,{router:Wg})}));export{ct as R,jd as a,Vg as b,pe as c,li as g,_ as j,w as r,Fe as u};
@@ -0,0 +1,131 @@
+import Badge from "react-bootstrap/Badge";
+import Button from "react-bootstrap/Button";
+import ButtonGroup from "react-bootstrap/ButtonGroup";
+import Table from "react-bootstrap/Table";
+import { Alarm, Client, Severity, ShelvedCondition } from "./client";
+
+/// How long the shelve button sets an alarm aside for, in seconds
+const SHELVE_DURATION = 3600;
+
+const variant: Record<Severity, string> = {
+    info: "info",
+    warning: "warning",
+    major: "danger",
+    critical: "dark",
+};
+
+/// Who is acting on alarms, asked for once and remembered
+const operator = () => {
+    let name = localStorage.getItem("operator");
+    if (!name) {
+        name = window.prompt("Your name, for the alarm log") || "operator";
+        localStorage.setItem("operator", name);
+    }
+    return name;
+};
+
+const time = (value: string | null) => (value === null ? "--" : new Date(value).toLocaleTimeString());
+
+/// The active alarms, with acknowledgement and shelving
+export function Alarms({ alarms }: { alarms: Alarm[] }) {
+    if (alarms.length === 0) {
+        return null;
+    }
+
+    return (
+        <Table bordered size="sm">
+            <thead>
+                <tr>
+                    <th>Severity</th>
+                    <th>Alarm</th>
+                    <th>Source</th>
+                    <th>Raised</th>
+                    <th>Cleared</th>
+                    <th>Count</th>
+                    <th>Control</th>
+                </tr>
+            </thead>
+            <tbody>
+                {alarms.map((alarm) => (
+                    <tr key={alarm.id} style={{ opacity: alarm.shelved ? 0.5 : 1 }}>
+                        <td>
+                            <Badge bg={variant[alarm.severity]}>{alarm.severity}</Badge>
+                        </td>
+                        <td>
+                            {alarm.acknowledged === null ? <strong>{alarm.message}</strong> : alarm.message}
+                        </td>
+                        <td>{alarm.source.element ?? "--"}</td>
+                        <td>{time(alarm.raised)}</td>
+                        <td>{time(alarm.cleared)}</td>
+                        <td>{alarm.count}</td>
+                        <td>
+                            <ButtonGroup size="sm">
+                                <Button
+                                    variant="primary"
+                                    disabled={alarm.acknowledged !== null}
+                                    title={alarm.acknowledged ? `By ${alarm.acknowledged.by}` : undefined}
+                                    onClick={() => Client.shared.acknowledgeAlarm(alarm.id, operator())}
+                                >
+                                    Acknowledge
+                                </Button>
+                                {alarm.shelved ? (
+                                    <Button variant="secondary" onClick={() => Client.shared.unshelveAlarm(alarm.id)}>
+                                        Unshelve
+                                    </Button>
+                                ) : (
+                                    <Button
+                                        variant="outline-secondary"
+                                        onClick={() => Client.shared.shelveAlarm(alarm.id, operator(), SHELVE_DURATION)}
+                                    >
+                                        Shelve 1h
+                                    </Button>
+                                )}
+                            </ButtonGroup>
+                        </td>
+                    </tr>
+                ))}
+            </tbody>
+        </Table>
+    );
+}
+
+const kindName = (kind: ShelvedCondition["kind"]) =>
+    typeof kind === "string" ? kind : Object.entries(kind).map(([key, value]) => `${key} ${value}`).join(", ");
+
+/// The shelved conditions, including those whose alarms have gone
+export function Shelves({ shelves }: { shelves: ShelvedCondition[] }) {
+    if (shelves.length === 0) {
+        return null;
+    }
+
+    return (
+        <Table bordered size="sm">
+            <thead>
+                <tr>
+                    <th>Shelved</th>
+                    <th>Source</th>
+                    <th>By</th>
+                    <th>Since</th>
+                    <th>Until</th>
+                    <th>Control</th>
+                </tr>
+            </thead>
+            <tbody>
+                {shelves.map(({ kind, source, shelf }) => (
+                    <tr key={shelf.id}>
+                        <td>{kindName(kind)}</td>
+                        <td>{source.element ?? "--"}</td>
+                        <td>{shelf.by}</td>
+                        <td>{time(shelf.at)}</td>
+                        <td>{time(shelf.until)}</td>
+                        <td>
+                            <Button size="sm" variant="secondary" onClick={() => Client.shared.removeShelf(shelf.id)}>
+                                Unshelve
+                            </Button>
+                        </td>
+                    </tr>
+                ))}
+            </tbody>
+        </Table>
+    );
+}
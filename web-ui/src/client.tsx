@@ -18,31 +18,236 @@ enum State {
     Ready,
 }
 
+type Overlay =
+    | "actionsafe"
+    | "titlesafe"
+    | "centrecross"
+    | "aspect4x3"
+    | "aspect14x9"
+    | "aspect239"
+    | "grid";
+
+type VideoMode =
+    | { testcard: string }
+    | { stream: string }
+    | { still: { location: string; tone: boolean } }
+    | "playlist";
+
+interface PlaylistItem {
+    source: VideoMode;
+    duration_ms?: number;
+    in_ms?: number;
+    out_ms?: number;
+}
+
+interface Playlist {
+    items: PlaylistItem[];
+    loop?: boolean;
+    transition?: "cut" | { crossfade: { duration_ms: number } };
+}
+
+interface PlayoutStatus {
+    current: number | null;
+    count: number;
+    elapsed_ms: number;
+    remaining_ms: number | null;
+}
+
+interface TransportStatus {
+    position_ms: number | null;
+    duration_ms: number | null;
+    looping: boolean;
+}
+
+interface OutputFormat {
+    width: number;
+    height: number;
+    framerate: [number, number];
+    interlaced: boolean;
+}
+
+type Target = "all" | { group: string } | { devices: string[] };
+
+type BulkAction = "start" | "stop" | { setpattern: string } | { setformat: OutputFormat };
+
+export interface ChannelLevel {
+    peak: number;
+    rms: number;
+}
+
+export type LoudnessAlarm = "truepeak" | "shortterm" | "integrated";
+
+export interface ProgramLoudness {
+    channels: number[];
+    momentary: number | null;
+    short_term: number | null;
+    integrated: number | null;
+    range: number | null;
+    true_peak: number | null;
+    recent_true_peak: number | null;
+    integrating: boolean;
+    since: string | null;
+    alarms: LoudnessAlarm[];
+}
+
+export interface FrameStats {
+    dropped: number;
+    duplicated: number;
+    late: number;
+    underruns: number;
+}
+
+export type ContentFault = "black" | "freeze" | "silence" | "tone";
+
+export type Severity = "info" | "warning" | "major" | "critical";
+
+export interface Alarm {
+    id: string;
+    kind: string | Record<string, string>;
+    severity: Severity;
+    source: { device_id: string | null; element: string | null };
+    message: string;
+    raised: string;
+    cleared: string | null;
+    count: number;
+    acknowledged: { by: string; at: string } | null;
+    shelved: Shelf | null;
+}
+
+export interface Shelf {
+    id: string;
+    by: string;
+    at: string;
+    until: string | null;
+}
+
+/// A condition set aside, which may outlive its alarm
+export interface ShelvedCondition {
+    kind: string | Record<string, string>;
+    source: { device_id: string | null; element: string | null };
+    shelf: Shelf;
+}
+
+export type LogLevel = "error" | "warn" | "info" | "debug" | "trace";
+
+export interface LogRecord {
+    time: string;
+    level: LogLevel;
+    target: string;
+    message: string;
+    device_id: string | null;
+    fields: Record<string, unknown>;
+}
+
+/// How many log records are kept in the browser
+const LOG_LIMIT = 500;
+
 interface Device {
     id: string;
     device_num: number;
     state: State;
+    format: OutputFormat;
+    mode: VideoMode;
+    overlays: Overlay[];
+    playout: PlayoutStatus | null;
+    transport: TransportStatus | null;
+    loudness: ProgramLoudness[];
+    frames: FrameStats;
+}
+
+type InputSource = "simulated" | { decklink: number };
+
+export interface Input {
+    id: string;
+    label: string;
+    source: InputSource;
+    state: State;
+    signal: boolean;
+    format: OutputFormat | null;
+    mode: string | null;
+    timecode: string | null;
+    audio_channels: number | null;
+    errors: number;
+    last_error: string | null;
+    loudness: ProgramLoudness[];
 }
 
 interface ClientState {
     connected: boolean;
     devices: Device[];
+    inputs: Input[];
+    thumbnails: Record<string, string>;
+    levels: Record<string, ChannelLevel[]>;
+    loudness: Record<string, ProgramLoudness[]>;
+    alarms: Alarm[];
+    shelves: ShelvedCondition[];
+    logs: LogRecord[];
     setConnnected: (by: boolean) => void;
     setDevices: (devices: Device[]) => void;
+    setInputs: (inputs: Input[]) => void;
+    setInput: (input: Input) => void;
+    setThumbnail: (device_id: string, updated: string) => void;
+    setLevels: (levels: Record<string, ChannelLevel[]>) => void;
+    setLoudness: (device_id: string, programs: ProgramLoudness[]) => void;
+    setAlarms: (alarms: Alarm[]) => void;
+    setAlarm: (alarm: Alarm) => void;
+    setShelves: (shelves: ShelvedCondition[]) => void;
+    setLogs: (logs: LogRecord[]) => void;
+    addLog: (record: LogRecord) => void;
 }
 
 export const useClientState = create<ClientState>((set) => ({
     connected: false,
     devices: [],
+    inputs: [],
+    thumbnails: {},
+    levels: {},
+    loudness: {},
+    alarms: [],
+    shelves: [],
+    logs: [],
     setConnnected: (connected: boolean) => set({ connected }),
     setDevices: (devices: Device[]) => set({ devices }),
+    setInputs: (inputs: Input[]) => set({ inputs }),
+    setInput: (input: Input) =>
+        set((state) => ({ inputs: state.inputs.map((existing) => (existing.id === input.id ? input : existing)) })),
+    setThumbnail: (device_id: string, updated: string) =>
+        set((state) => ({ thumbnails: { ...state.thumbnails, [device_id]: updated } })),
+    setLevels: (levels: Record<string, ChannelLevel[]>) => set({ levels }),
+    setLoudness: (device_id: string, programs: ProgramLoudness[]) =>
+        set((state) => ({ loudness: { ...state.loudness, [device_id]: programs } })),
+    setAlarms: (alarms: Alarm[]) => set({ alarms }),
+    setAlarm: (alarm: Alarm) =>
+        set((state) => {
+            const others = state.alarms.filter((existing) => existing.id !== alarm.id);
+            // Alarms leave the list once they have cleared and been seen
+            const active = alarm.cleared === null || alarm.acknowledged === null;
+            return { alarms: active ? [alarm, ...others] : others };
+        }),
+    setShelves: (shelves: ShelvedCondition[]) => set({ shelves }),
+    setLogs: (logs: LogRecord[]) => set({ logs }),
+    addLog: (record: LogRecord) => set((state) => ({ logs: [...state.logs, record].slice(-LOG_LIMIT) })),
 }));
 
+export const thumbnailUrl = (device_id: string, updated: string) =>
+    `http://${window.location.hostname}:3000/api/devices/${device_id}/thumbnail?t=${encodeURIComponent(updated)}`;
+
+type PreviewListener = (chunk: Uint8Array) => void;
+
+/// Formats the 16 byte device id prefixed to preview chunks
+const formatUuid = (bytes: Uint8Array) => {
+    const hex = Array.from(bytes, (byte) => byte.toString(16).padStart(2, "0")).join("");
+    return `${hex.slice(0, 8)}-${hex.slice(8, 12)}-${hex.slice(12, 16)}-${hex.slice(16, 20)}-${hex.slice(20)}`;
+};
+
 export class Client {
     static shared: Client = new Client();
     url = `ws://${window.location.hostname}:3000/api/control`;
     ws: WebSocket | undefined;
     reconnectTimer: number | undefined;
+    previewListeners: Map<string, PreviewListener> = new Map();
+    /// The log being tailed, if any, followed again after reconnecting
+    logTail: { device_id: string | null } | undefined;
 
     connect() {
         if (this.ws) {
@@ -50,6 +255,7 @@ export class Client {
         }
 
         this.ws = new WebSocket(this.url);
+        (this.ws as globalThis.WebSocket).binaryType = "arraybuffer";
         this.ws.onopen = this.onOpen.bind(this);
         this.ws.onmessage = this.onMessage.bind(this);
         this.ws.onclose = this.onClose.bind(this);
@@ -59,6 +265,11 @@ export class Client {
         console.log("Connected to server");
         useClientState.getState().setConnnected(true);
         clearTimeout(this.reconnectTimer);
+        this.listAlarms();
+        this.listShelves();
+        if (this.logTail) {
+            this.tailLogs(this.logTail.device_id);
+        }
     }
 
     onClose() {
@@ -68,6 +279,12 @@ export class Client {
     }
 
     onMessage(event: MessageEvent) {
+        if (event.data instanceof ArrayBuffer) {
+            const data = new Uint8Array(event.data);
+            const listener = this.previewListeners.get(formatUuid(data.subarray(0, 16)));
+            return listener?.(data.subarray(16));
+        }
+
         let message;
         try {
             message = JSON.parse(event.data);
@@ -81,6 +298,49 @@ export class Client {
             devices = devices.sort(({ device_num: a }, { device_num: b }) => a - b);
             return useClientState.getState().setDevices(devices);
         }
+
+        if (Object.prototype.hasOwnProperty.call(message.result, "inputs")) {
+            return useClientState.getState().setInputs(message.result.inputs);
+        }
+
+        if (Object.prototype.hasOwnProperty.call(message.result, "input")) {
+            return useClientState.getState().setInput(message.result.input);
+        }
+
+        if (Object.prototype.hasOwnProperty.call(message.result, "metering")) {
+            return useClientState.getState().setLevels(message.result.metering);
+        }
+
+        if (Object.prototype.hasOwnProperty.call(message.result, "thumbnail")) {
+            const { device_id, updated } = message.result.thumbnail;
+            return useClientState.getState().setThumbnail(device_id, updated);
+        }
+
+        if (Object.prototype.hasOwnProperty.call(message.result, "alarms")) {
+            return useClientState.getState().setAlarms(message.result.alarms);
+        }
+
+        if (Object.prototype.hasOwnProperty.call(message.result, "alarm")) {
+            const alarm = message.result.alarm as Alarm;
+            const existing = useClientState.getState().alarms.find(({ id }) => id === alarm.id);
+            if ((existing?.shelved?.id ?? null) !== (alarm.shelved?.id ?? null)) {
+                this.listShelves();
+            }
+            return useClientState.getState().setAlarm(alarm);
+        }
+
+        if (Object.prototype.hasOwnProperty.call(message.result, "shelves")) {
+            return useClientState.getState().setShelves(message.result.shelves);
+        }
+
+        if (Object.prototype.hasOwnProperty.call(message.result, "log")) {
+            return useClientState.getState().addLog(message.result.log);
+        }
+
+        if (Object.prototype.hasOwnProperty.call(message.result, "loudness")) {
+            const { device_id, programs } = message.result.loudness;
+            return useClientState.getState().setLoudness(device_id, programs);
+        }
     }
 
     send(message: string) {
@@ -109,6 +369,271 @@ export class Client {
         );
     }
 
+    setOverlay(device_id: string, overlay: Overlay, enabled: boolean) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { setoverlay: { device_id, overlay, enabled } },
+            }),
+        );
+    }
+
+    setMode(device_id: string, mode: VideoMode) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { setmode: { device_id, mode } },
+            }),
+        );
+    }
+
+    loadPlaylist(device_id: string, playlist: Playlist) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { loadplaylist: { device_id, playlist } },
+            }),
+        );
+    }
+
+    next(device_id: string) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { next: { device_id } },
+            }),
+        );
+    }
+
+    previous(device_id: string) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { previous: { device_id } },
+            }),
+        );
+    }
+
+    skip(device_id: string, index: number) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { skip: { device_id, index } },
+            }),
+        );
+    }
+
+    pause(device_id: string) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { pause: { device_id } },
+            }),
+        );
+    }
+
+    resume(device_id: string) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { resume: { device_id } },
+            }),
+        );
+    }
+
+    seek(device_id: string, position: { time: number } | { frame: number }) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { seek: { device_id, position } },
+            }),
+        );
+    }
+
+    loop(device_id: string, enabled: boolean) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { loop: { device_id, enabled } },
+            }),
+        );
+    }
+
+    cue(device_id: string) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { cue: { device_id } },
+            }),
+        );
+    }
+
+    setFormat(device_id: string, format: OutputFormat) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { setformat: { device_id, format } },
+            }),
+        );
+    }
+
+    bulk(target: Target, action: BulkAction) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { bulk: { target, action } },
+            }),
+        );
+    }
+
+    setGroup(name: string, devices: string[]) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { setgroup: { name, devices } },
+            }),
+        );
+    }
+
+    removeGroup(name: string) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { removegroup: { name } },
+            }),
+        );
+    }
+
+    addInput(label: string, source: InputSource) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { addinput: { label, source } },
+            }),
+        );
+    }
+
+    removeInput(device_id: string) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { removeinput: { device_id } },
+            }),
+        );
+    }
+
+    /// Starts, stops or resets the integrated loudness of a device
+    loudness(device_id: string, action: "start" | "stop" | "reset") {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { loudness: { device_id, action } },
+            }),
+        );
+    }
+
+    listAlarms() {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { listalarms: {} },
+            }),
+        );
+    }
+
+    acknowledgeAlarm(alarm_id: string, by: string) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { acknowledgealarm: { alarm_id, by } },
+            }),
+        );
+    }
+
+    /// Shelves an alarm, for `duration_s` seconds or until unshelved
+    shelveAlarm(alarm_id: string, by: string, duration_s?: number) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { shelvealarm: { alarm_id, by, duration_s } },
+            }),
+        );
+    }
+
+    unshelveAlarm(alarm_id: string) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { unshelvealarm: { alarm_id } },
+            }),
+        );
+    }
+
+    listShelves() {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { listshelves: {} },
+            }),
+        );
+    }
+
+    /// Removes a shelf, whether or not its condition has an alarm
+    removeShelf(shelf_id: string) {
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { removeshelf: { shelf_id } },
+            }),
+        );
+    }
+
+    /// Starts streaming fragmented MP4 of a device's output to `listener`
+    watchPreview(device_id: string, listener: PreviewListener) {
+        this.previewListeners.set(device_id, listener);
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { preview: { device_id, enabled: true } },
+            }),
+        );
+    }
+
+    unwatchPreview(device_id: string) {
+        this.previewListeners.delete(device_id);
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { preview: { device_id, enabled: false } },
+            }),
+        );
+    }
+
+    /// Loads the latest log records, then follows new ones, of one
+    /// device or of all when `device_id` is null
+    async tailLogs(device_id: string | null) {
+        this.logTail = { device_id };
+        const query = device_id ? `?device_id=${device_id}` : "";
+        const response = await fetch(`http://${window.location.hostname}:3000/api/logs${query}`);
+        useClientState.getState().setLogs(await response.json());
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { taillogs: { device_id, enabled: true } },
+            }),
+        );
+    }
+
+    untailLogs() {
+        this.logTail = undefined;
+        this.send(
+            JSON.stringify({
+                id: uuidv4().toString(),
+                command: { taillogs: { enabled: false } },
+            }),
+        );
+    }
+
     sync() {
         this.send(
             JSON.stringify({
@@ -0,0 +1,73 @@
+import { useEffect, useState } from "react";
+import Badge from "react-bootstrap/Badge";
+import Form from "react-bootstrap/Form";
+import Table from "react-bootstrap/Table";
+import { Client, LogLevel, useClientState } from "./client";
+
+const variant: Record<LogLevel, string> = {
+    error: "danger",
+    warn: "warning",
+    info: "info",
+    debug: "secondary",
+    trace: "light",
+};
+
+/// The live log, of every device or of the one picked from `devices`
+export function Logs({ devices }: { devices: { id: string; label: string }[] }) {
+    const logs = useClientState((state) => state.logs);
+    const [deviceId, setDeviceId] = useState<string | null>(null);
+
+    useEffect(() => {
+        Client.shared.tailLogs(deviceId);
+        return () => Client.shared.untailLogs();
+    }, [deviceId]);
+
+    const labelOf = (device_id: string | null) =>
+        devices.find((device) => device.id === device_id)?.label ?? "--";
+
+    return (
+        <div>
+            <Form.Select
+                size="sm"
+                value={deviceId ?? ""}
+                onChange={(event) => setDeviceId(event.target.value || null)}
+            >
+                <option value="">All devices</option>
+                {devices.map((device) => (
+                    <option key={device.id} value={device.id}>
+                        {device.label}
+                    </option>
+                ))}
+            </Form.Select>
+            <div style={{ maxHeight: 400, overflowY: "auto" }}>
+                <Table bordered size="sm">
+                    <thead>
+                        <tr>
+                            <th>Time</th>
+                            <th>Level</th>
+                            <th>Device</th>
+                            <th>Message</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {logs
+                            .slice()
+                            .reverse()
+                            .map((record, index) => (
+                                <tr key={`${record.time}-${index}`}>
+                                    <td>{new Date(record.time).toLocaleTimeString()}</td>
+                                    <td>
+                                        <Badge bg={variant[record.level]}>{record.level}</Badge>
+                                    </td>
+                                    <td>{labelOf(record.device_id)}</td>
+                                    <td title={`${record.target} ${JSON.stringify(record.fields)}`}>
+                                        {record.message}
+                                    </td>
+                                </tr>
+                            ))}
+                    </tbody>
+                </Table>
+            </div>
+        </div>
+    );
+}
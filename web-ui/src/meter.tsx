@@ -0,0 +1,72 @@
+import { Alarm, ChannelLevel, ProgramLoudness } from "./client";
+
+/// Level at the bottom of the meter, in dBFS
+const FLOOR = -60;
+
+const colour = (db: number) => (db > -9 ? "#dc3545" : db > -18 ? "#ffc107" : "#198754");
+
+/// Horizontal peak meters, one bar per channel
+export function Meter({ levels }: { levels: ChannelLevel[] }) {
+    return (
+        <div style={{ width: 120 }}>
+            {levels.map(({ peak }, channel) => (
+                <div key={channel} style={{ height: 6, marginBottom: 2, background: "#212529" }}>
+                    <div
+                        style={{
+                            height: "100%",
+                            width: `${Math.max(0, Math.min(1, (peak - FLOOR) / -FLOOR)) * 100}%`,
+                            background: colour(peak),
+                        }}
+                    />
+                </div>
+            ))}
+        </div>
+    );
+}
+
+const lufs = (value: number | null) => (value === null ? "--" : value.toFixed(1));
+
+/// EBU R128 readings of each program, red where a threshold is crossed
+export function Loudness({ programs }: { programs: ProgramLoudness[] }) {
+    return (
+        <div style={{ fontFamily: "monospace", fontSize: "0.75rem" }}>
+            {programs.map((program, index) => (
+                <div key={index}>
+                    <span>M {lufs(program.momentary)} </span>
+                    <span style={{ color: program.alarms.includes("shortterm") ? "#dc3545" : undefined }}>
+                        S {lufs(program.short_term)}{" "}
+                    </span>
+                    <span style={{ color: program.alarms.includes("integrated") ? "#dc3545" : undefined }}>
+                        I {lufs(program.integrated)}
+                        {program.integrating ? "" : " (held)"}{" "}
+                    </span>
+                    <span style={{ color: program.alarms.includes("truepeak") ? "#dc3545" : undefined }}>
+                        TP {lufs(program.true_peak)}
+                    </span>
+                </div>
+            ))}
+        </div>
+    );
+}
+
+const faultLabel = ({ kind, source }: Alarm) => {
+    const fault = typeof kind === "string" ? kind : kind.content;
+    return source.element === null ? fault : `${fault} ${source.element.replace("channel ", "ch")}`;
+};
+
+/// Badges for the content alarms raised on a device
+export function Detections({ alarms }: { alarms: Alarm[] }) {
+    return (
+        <div>
+            {alarms.map((alarm) => (
+                <span
+                    key={alarm.id}
+                    className="badge bg-warning text-dark me-1"
+                    title={`Since ${new Date(alarm.raised).toLocaleTimeString()}`}
+                >
+                    {faultLabel(alarm)}
+                </span>
+            ))}
+        </div>
+    );
+}
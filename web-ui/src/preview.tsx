@@ -0,0 +1,76 @@
+import { useEffect, useRef } from "react";
+import { Client } from "./client";
+
+/// Codecs of the preview stream, H.264 constrained baseline and AAC-LC
+const MIME_TYPE = 'video/mp4; codecs="avc1.42E01E, mp4a.40.2"';
+/// How far behind live playback may fall before skipping ahead, in seconds
+const MAX_LATENCY = 1.5;
+
+/// Whether a chunk starts with an init segment, meaning the output
+/// pipeline was rebuilt and playback has to start over
+const isInitSegment = (chunk: Uint8Array) =>
+    chunk.length >= 8 && String.fromCharCode(...chunk.subarray(4, 8)) === "ftyp";
+
+/// Plays the live preview of an output while mounted
+export function Preview({ deviceId }: { deviceId: string }) {
+    const video = useRef<HTMLVideoElement>(null);
+
+    useEffect(() => {
+        const element = video.current;
+        if (!element) {
+            return;
+        }
+
+        let mediaSource: MediaSource | undefined;
+        let sourceBuffer: SourceBuffer | undefined;
+        let pending: Uint8Array[] = [];
+
+        const flush = () => {
+            if (!sourceBuffer || sourceBuffer.updating || pending.length === 0) {
+                return;
+            }
+            sourceBuffer.appendBuffer(pending.shift()!);
+
+            const buffered = sourceBuffer.buffered;
+            if (buffered.length > 0 && buffered.end(buffered.length - 1) - element.currentTime > MAX_LATENCY) {
+                element.currentTime = buffered.end(buffered.length - 1) - 0.2;
+            }
+        };
+
+        const reset = () => {
+            if (mediaSource) {
+                URL.revokeObjectURL(element.src);
+            }
+            sourceBuffer = undefined;
+            pending = [];
+            mediaSource = new MediaSource();
+            const source = mediaSource;
+            source.addEventListener("sourceopen", () => {
+                sourceBuffer = source.addSourceBuffer(MIME_TYPE);
+                sourceBuffer.mode = "segments";
+                sourceBuffer.addEventListener("updateend", flush);
+                flush();
+            });
+            element.src = URL.createObjectURL(source);
+            element.play().catch(() => undefined);
+        };
+
+        Client.shared.watchPreview(deviceId, (chunk) => {
+            if (isInitSegment(chunk)) {
+                reset();
+            }
+            pending.push(chunk.slice());
+            flush();
+        });
+
+        return () => {
+            Client.shared.unwatchPreview(deviceId);
+            if (mediaSource) {
+                URL.revokeObjectURL(element.src);
+            }
+            element.removeAttribute("src");
+        };
+    }, [deviceId]);
+
+    return <video ref={video} width={640} height={360} controls muted autoPlay playsInline />;
+}
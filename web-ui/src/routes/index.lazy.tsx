@@ -3,8 +3,12 @@ import Button from "react-bootstrap/Button";
 import Table from "react-bootstrap/Table";
 import Badge from "react-bootstrap/Badge";
 import ButtonGroup from "react-bootstrap/ButtonGroup";
-import { useEffect } from "react";
-import { Client, useClientState } from "../client";
+import { useEffect, useState } from "react";
+import { Alarms, Shelves } from "../alarms";
+import { Client, thumbnailUrl, useClientState } from "../client";
+import { Logs } from "../logs";
+import { Detections, Loudness, Meter } from "../meter";
+import { Preview } from "../preview";
 
 export const Route = createLazyFileRoute("/")({
     component: Index,
@@ -23,16 +27,47 @@ function Index() {
         Client.shared.stop(device_id);
     };
 
-    const { devices } = useClientState();
+    const { devices, inputs, thumbnails, levels, loudness, alarms, shelves } = useClientState();
+    // Content alarms of a device still raised
+    const alarmsOf = (device_id: string) =>
+        alarms.filter(
+            ({ kind, source, cleared }) =>
+                typeof kind !== "string" && "content" in kind && source.device_id === device_id && cleared === null,
+        );
+    const [previewing, setPreviewing] = useState<string | null>(null);
+
+    const toggleMultiviewer = async () => {
+        if (previewing !== null) {
+            return setPreviewing(null);
+        }
+        const response = await fetch(`http://${window.location.hostname}:3000/api/multiviewer`);
+        const { id } = await response.json();
+        setPreviewing(id);
+    };
 
     return (
         <div className="p-2">
+            <ButtonGroup className="mb-2">
+                <Button variant="primary" onClick={() => Client.shared.bulk("all", "start")}>
+                    Start all
+                </Button>
+                <Button variant="danger" onClick={() => Client.shared.bulk("all", "stop")}>
+                    Stop all
+                </Button>
+                <Button variant="secondary" onClick={toggleMultiviewer}>
+                    Multiviewer
+                </Button>
+            </ButtonGroup>
+            <Alarms alarms={alarms} />
+            <Shelves shelves={shelves} />
             <Table bordered hover>
                 <thead>
                     <tr>
                         <th>#</th>
+                        <th>Output</th>
                         <th>Device id</th>
                         <th>State</th>
+                        <th>Audio</th>
                         <th>Control</th>
                     </tr>
                 </thead>
@@ -41,9 +76,30 @@ function Index() {
                         return (
                             <tr>
                                 <td>{device.device_num}</td>
+                                <td>
+                                    {thumbnails[device.id] && (
+                                        <img
+                                            src={thumbnailUrl(device.id, thumbnails[device.id])}
+                                            width={160}
+                                            height={90}
+                                            alt={`Output ${device.device_num}`}
+                                        />
+                                    )}
+                                </td>
                                 <td>{device.id}</td>
                                 <td>
                                     <Badge bg="secondary">{device.state}</Badge>
+                                    <Detections alarms={alarmsOf(device.id)} />
+                                    <div>
+                                        <small title="Dropped / late / underruns / duplicated frames">
+                                            {device.frames.dropped} / {device.frames.late} / {device.frames.underruns} /{" "}
+                                            {device.frames.duplicated}
+                                        </small>
+                                    </div>
+                                </td>
+                                <td>
+                                    <Meter levels={levels[device.id] ?? []} />
+                                    <Loudness programs={loudness[device.id] ?? []} />
                                 </td>
                                 <td>
                                     <ButtonGroup>
@@ -53,6 +109,19 @@ function Index() {
                                         <Button variant="danger" onClick={() => stop(device.id)}>
                                             Stop
                                         </Button>
+                                        <Button
+                                            variant="secondary"
+                                            active={previewing === device.id}
+                                            onClick={() => setPreviewing(previewing === device.id ? null : device.id)}
+                                        >
+                                            Preview
+                                        </Button>
+                                        <Button
+                                            variant="outline-secondary"
+                                            onClick={() => Client.shared.loudness(device.id, "reset")}
+                                        >
+                                            Reset loudness
+                                        </Button>
                                     </ButtonGroup>
                                 </td>
                             </tr>
@@ -60,6 +129,75 @@ function Index() {
                     })}
                 </tbody>
             </Table>
+            <h5>Inputs</h5>
+            <Table bordered hover>
+                <thead>
+                    <tr>
+                        <th>Input</th>
+                        <th>Picture</th>
+                        <th>Signal</th>
+                        <th>Timecode</th>
+                        <th>Audio</th>
+                        <th>Errors</th>
+                        <th>Control</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {inputs.map((input) => (
+                        <tr key={input.id}>
+                            <td>{input.label}</td>
+                            <td>
+                                {input.signal && thumbnails[input.id] && (
+                                    <img
+                                        src={thumbnailUrl(input.id, thumbnails[input.id])}
+                                        width={160}
+                                        height={90}
+                                        alt={input.label}
+                                    />
+                                )}
+                            </td>
+                            <td>
+                                <Badge bg={input.signal ? "success" : "danger"}>
+                                    {input.signal ? (input.mode ?? "present") : "no signal"}
+                                </Badge>
+                                <Detections alarms={alarmsOf(input.id)} />
+                            </td>
+                            <td>{input.timecode ?? "--"}</td>
+                            <td>
+                                {input.audio_channels !== null && <div>{input.audio_channels} channels</div>}
+                                <Meter levels={levels[input.id] ?? []} />
+                                <Loudness programs={loudness[input.id] ?? []} />
+                            </td>
+                            <td title={input.last_error ?? undefined}>{input.errors}</td>
+                            <td>
+                                <ButtonGroup>
+                                    <Button variant="primary" onClick={() => start(input.id)}>
+                                        Start
+                                    </Button>
+                                    <Button variant="danger" onClick={() => stop(input.id)}>
+                                        Stop
+                                    </Button>
+                                    <Button
+                                        variant="secondary"
+                                        active={previewing === input.id}
+                                        onClick={() => setPreviewing(previewing === input.id ? null : input.id)}
+                                    >
+                                        Preview
+                                    </Button>
+                                </ButtonGroup>
+                            </td>
+                        </tr>
+                    ))}
+                </tbody>
+            </Table>
+            {previewing && <Preview deviceId={previewing} />}
+            <h5>Log</h5>
+            <Logs
+                devices={[
+                    ...devices.map((device) => ({ id: device.id, label: `Output ${device.device_num}` })),
+                    ...inputs.map((input) => ({ id: input.id, label: input.label })),
+                ]}
+            />
         </div>
     );
 }